@@ -10,15 +10,16 @@ use clap::Args;
 use fm::{FileId, FileManager};
 use iter_extended::vecmap;
 use noirc_abi::{AbiParameter, AbiType, AbiValue};
-use noirc_errors::{CustomDiagnostic, FileDiagnostic};
+use noirc_errors::{CustomDiagnostic, DiagnosticKind, FileDiagnostic, Location};
 use noirc_evaluator::create_program;
 use noirc_evaluator::errors::RuntimeError;
 use noirc_evaluator::ssa::SsaProgramArtifact;
 use noirc_frontend::debug::build_debug_crate_file;
 use noirc_frontend::hir::def_map::{Contract, CrateDefMap};
+use noirc_frontend::hir::lint_levels::LintLevel;
 use noirc_frontend::hir::Context;
 use noirc_frontend::monomorphization::{
-    errors::MonomorphizationError, monomorphize, monomorphize_debug,
+    ast::Program, errors::MonomorphizationError, monomorphize, monomorphize_debug,
 };
 use noirc_frontend::node_interner::FuncId;
 use noirc_frontend::token::SecondaryAttribute;
@@ -28,6 +29,7 @@ use tracing::info;
 mod abi_gen;
 mod contract;
 mod debug;
+mod profile;
 mod program;
 mod stdlib;
 
@@ -36,6 +38,7 @@ use debug::filter_relevant_files;
 pub use contract::{CompiledContract, CompiledContractOutputs, ContractFunction};
 pub use debug::DebugFile;
 pub use noirc_frontend::graph::{CrateId, CrateName};
+pub use profile::{CompileProfile, ProfileOptions, BUILTIN_PROFILES, DEFAULT_PROFILE};
 pub use program::CompiledProgram;
 
 const STD_CRATE_NAME: &str = "std";
@@ -116,6 +119,11 @@ pub struct CompileOptions {
     #[arg(long)]
     pub debug_comptime_in_file: Option<String>,
 
+    /// JSON RPC url to resolve oracle calls made from comptime code.
+    /// Comptime code can't call oracles other than `print` unless this is set.
+    #[arg(long)]
+    pub comptime_oracle_resolver: Option<String>,
+
     /// Outputs the paths to any modified artifacts
     #[arg(long, hide = true)]
     pub show_artifact_paths: bool,
@@ -131,6 +139,10 @@ pub struct CompileOptions {
     /// A less aggressive inliner should generate smaller programs
     #[arg(long, hide = true, allow_hyphen_values = true, default_value_t = i64::MAX)]
     pub inliner_aggressiveness: i64,
+
+    /// Name of the profile from `Nargo.toml` to compile with, `dev` by default
+    #[arg(long)]
+    pub profile: Option<String>,
 }
 
 pub fn parse_expression_width(input: &str) -> Result<ExpressionWidth, std::io::Error> {
@@ -296,9 +308,20 @@ pub fn check_crate(
         options.debug_comptime_in_file.as_deref(),
         error_on_unused_imports,
     );
-    errors.extend(diagnostics.into_iter().map(|(error, file_id)| {
-        let diagnostic = CustomDiagnostic::from(&error);
-        diagnostic.in_file(file_id)
+    errors.extend(diagnostics.into_iter().filter_map(|(error, file_id)| {
+        let mut diagnostic = CustomDiagnostic::from(&error);
+        if let Some(lint) = error.lint() {
+            let span = diagnostic.secondaries.first().map(|label| label.span).unwrap_or_default();
+            match context.lint_level(lint, Location::new(span, file_id)) {
+                LintLevel::Allow => return None,
+                LintLevel::Warn => (),
+                LintLevel::Deny => {
+                    diagnostic.kind = DiagnosticKind::Error;
+                    diagnostic.notes.push(format!("the `{lint}` lint is denied"));
+                }
+            }
+        }
+        Some(diagnostic.in_file(file_id))
     }));
 
     if has_errors(&errors, options.deny_warnings) {
@@ -555,7 +578,21 @@ pub fn compile_no_check(
     } else {
         monomorphize(main_function, &mut context.def_interner)?
     };
+    compile_monomorphized(context, options, main_function, program, cached_program, force_compile)
+}
 
+/// Compile `program`, the result of monomorphizing `main_function`, as [`compile_no_check`] does.
+///
+/// This lets callers which need the monomorphized program, for example to hash it, avoid
+/// monomorphizing `main_function` a second time.
+pub fn compile_monomorphized(
+    context: &mut Context,
+    options: &CompileOptions,
+    main_function: FuncId,
+    program: Program,
+    cached_program: Option<CompiledProgram>,
+    force_compile: bool,
+) -> Result<CompiledProgram, CompileError> {
     let hash = fxhash::hash64(&program);
     let hashes_match = cached_program.as_ref().map_or(false, |program| program.hash == hash);
     if options.show_monomorphized {
@@ -0,0 +1,132 @@
+use acvm::acir::circuit::ExpressionWidth;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{parse_expression_width, CompileOptions};
+
+/// Name of the profile used when `--profile` isn't passed.
+pub const DEFAULT_PROFILE: &str = "dev";
+
+/// Names of the profiles which are defined even if they aren't declared in a `Nargo.toml`.
+pub const BUILTIN_PROFILES: [&str; 2] = [DEFAULT_PROFILE, "release"];
+
+/// A named set of [CompileOptions], declared in a `[profile.<name>]` table of a `Nargo.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompileProfile {
+    pub name: String,
+    pub options: ProfileOptions,
+}
+
+/// The options set by a [CompileProfile], deserialized from the keys of its `[profile.<name>]`
+/// table, which are the command line flags of the [CompileOptions] with the same names.
+///
+/// Each option which is set overrides the default of the corresponding [CompileOptions] field,
+/// while options set through command line flags take precedence over the profile. As a flag
+/// which isn't passed can't be told apart from one which is off, boolean options are OR-merged:
+/// a profile can turn an option on, but setting it to `false` never turns it off.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ProfileOptions {
+    #[serde(
+        serialize_with = "serialize_expression_width",
+        deserialize_with = "deserialize_expression_width",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expression_width: Option<ExpressionWidth>,
+    pub bounded_codegen: Option<bool>,
+    #[serde(rename = "force")]
+    pub force_compile: Option<bool>,
+    pub show_ssa: Option<bool>,
+    pub emit_ssa: Option<bool>,
+    pub show_brillig: Option<bool>,
+    pub print_acir: Option<bool>,
+    pub benchmark_codegen: Option<bool>,
+    pub deny_warnings: Option<bool>,
+    pub silence_warnings: Option<bool>,
+    pub disable_macros: Option<bool>,
+    pub show_monomorphized: Option<bool>,
+    pub instrument_debug: Option<bool>,
+    pub force_brillig: Option<bool>,
+    pub debug_comptime_in_file: Option<String>,
+    pub comptime_oracle_resolver: Option<String>,
+    pub show_artifact_paths: Option<bool>,
+    pub skip_underconstrained_check: Option<bool>,
+    pub inliner_aggressiveness: Option<i64>,
+}
+
+impl CompileProfile {
+    /// An empty profile, leaving all options to their defaults.
+    pub fn new(name: &str) -> Self {
+        CompileProfile { name: name.to_string(), ..Default::default() }
+    }
+
+    /// Returns `options` with the settings of this profile applied to the options which weren't
+    /// set on the command line.
+    pub fn apply(&self, options: &CompileOptions) -> CompileOptions {
+        let profile = &self.options;
+        let or = |flag: bool, setting: Option<bool>| flag || setting.unwrap_or_default();
+
+        // Warnings can't be both denied and silenced, so the profile only decides when neither flag is set
+        let (deny_warnings, silence_warnings) = if options.deny_warnings || options.silence_warnings
+        {
+            (options.deny_warnings, options.silence_warnings)
+        } else {
+            (or(false, profile.deny_warnings), or(false, profile.silence_warnings))
+        };
+        let inliner_aggressiveness = match profile.inliner_aggressiveness {
+            Some(aggressiveness) if options.inliner_aggressiveness == i64::MAX => aggressiveness,
+            _ => options.inliner_aggressiveness,
+        };
+
+        CompileOptions {
+            expression_width: options.expression_width.or(profile.expression_width),
+            bounded_codegen: or(options.bounded_codegen, profile.bounded_codegen),
+            force_compile: or(options.force_compile, profile.force_compile),
+            show_ssa: or(options.show_ssa, profile.show_ssa),
+            emit_ssa: or(options.emit_ssa, profile.emit_ssa),
+            show_brillig: or(options.show_brillig, profile.show_brillig),
+            print_acir: or(options.print_acir, profile.print_acir),
+            benchmark_codegen: or(options.benchmark_codegen, profile.benchmark_codegen),
+            deny_warnings,
+            silence_warnings,
+            disable_macros: or(options.disable_macros, profile.disable_macros),
+            show_monomorphized: or(options.show_monomorphized, profile.show_monomorphized),
+            instrument_debug: or(options.instrument_debug, profile.instrument_debug),
+            force_brillig: or(options.force_brillig, profile.force_brillig),
+            debug_comptime_in_file: options
+                .debug_comptime_in_file
+                .clone()
+                .or_else(|| profile.debug_comptime_in_file.clone()),
+            comptime_oracle_resolver: options
+                .comptime_oracle_resolver
+                .clone()
+                .or_else(|| profile.comptime_oracle_resolver.clone()),
+            show_artifact_paths: or(options.show_artifact_paths, profile.show_artifact_paths),
+            skip_underconstrained_check: or(
+                options.skip_underconstrained_check,
+                profile.skip_underconstrained_check,
+            ),
+            inliner_aggressiveness,
+            profile: Some(self.name.clone()),
+        }
+    }
+}
+
+/// Writes the expression width as it's passed to `--expression-width`, e.g. `"4"`, or `"0"` when
+/// it's unbounded.
+fn serialize_expression_width<S: Serializer>(
+    expression_width: &Option<ExpressionWidth>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match expression_width {
+        Some(ExpressionWidth::Bounded { width }) => serializer.serialize_str(&width.to_string()),
+        Some(ExpressionWidth::Unbounded) => serializer.serialize_str("0"),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_expression_width<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<ExpressionWidth>, D::Error> {
+    let expression_width = String::deserialize(deserializer)?;
+    parse_expression_width(&expression_width).map(Some).map_err(serde::de::Error::custom)
+}
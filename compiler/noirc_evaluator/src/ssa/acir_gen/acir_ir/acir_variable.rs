@@ -679,6 +679,52 @@ impl<F: AcirField, B: BlackBoxFunctionSolver<F>> AcirContext<F, B> {
         Ok(result)
     }
 
+    /// Returns the product of two unsigned integers of `bit_size` bits modulo `2^bit_size`,
+    /// along with a variable which is zero if and only if the product fits in `bit_size` bits.
+    ///
+    /// This is needed for integers which are too wide for their product to fit in a field element,
+    /// as `mul_var` would then silently reduce it modulo the field. Instead, each operand is split into
+    /// a low limb of `half = bit_size / 2` bits and a high limb holding the remaining bits:
+    ///
+    /// `lhs * rhs = lhs_high * rhs_high * 2^{2 * half} + (lhs_high * rhs_low + lhs_low * rhs_high) * 2^{half} + lhs_low * rhs_low`
+    ///
+    /// None of these terms overflow the field. The product fits in `bit_size` bits if and only if
+    /// `lhs_high * rhs_high` is zero and the sum of the two lower terms fits in `bit_size` bits.
+    pub(crate) fn wide_mul_var(
+        &mut self,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        bit_size: u32,
+    ) -> Result<(AcirVar, AcirVar), RuntimeError> {
+        let half = bit_size / 2;
+        let one = self.add_constant(F::one());
+        let two_pow_half = F::from(2_u128).pow(&F::from(half as u128));
+        let two_pow_half_var = self.add_constant(two_pow_half);
+
+        // Splitting the operands also constrains them to fit in `bit_size` bits
+        let (lhs_high, lhs_low) =
+            self.euclidean_division_var(lhs, two_pow_half_var, bit_size, one)?;
+        let (rhs_high, rhs_low) =
+            self.euclidean_division_var(rhs, two_pow_half_var, bit_size, one)?;
+
+        let high = self.mul_var(lhs_high, rhs_high)?;
+        let lhs_high_rhs_low = self.mul_var(lhs_high, rhs_low)?;
+        let lhs_low_rhs_high = self.mul_var(lhs_low, rhs_high)?;
+        let middle = self.add_var(lhs_high_rhs_low, lhs_low_rhs_high)?;
+        let low = self.mul_var(lhs_low, rhs_low)?;
+        let lower_terms = self.add_mul_var(low, two_pow_half, middle)?;
+
+        // `middle` has at most `bit_size + 1` bits, so `lower_terms` has at most `bit_size + half + 2` bits
+        let two_pow_bit_size = self.add_constant(F::from(2_u128).pow(&F::from(bit_size as u128)));
+        let (carry, product) =
+            self.euclidean_division_var(lower_terms, two_pow_bit_size, bit_size + half + 2, one)?;
+
+        // Both `high` and `carry` are small enough that their sum can't wrap around the field,
+        // so the sum is zero if and only if both are zero.
+        let overflow = self.add_var(high, carry)?;
+        Ok((product, overflow))
+    }
+
     /// Adds a new Variable to context whose value will
     /// be constrained to be the subtraction of `lhs` and `rhs`
     pub(crate) fn sub_var(&mut self, lhs: AcirVar, rhs: AcirVar) -> Result<AcirVar, RuntimeError> {
@@ -762,7 +808,7 @@ impl<F: AcirField, B: BlackBoxFunctionSolver<F>> AcirContext<F, B> {
     pub(crate) fn not_var(&mut self, x: AcirVar, typ: AcirType) -> Result<AcirVar, RuntimeError> {
         let bit_size = typ.bit_size::<F>();
         // Subtracting from max flips the bits
-        let max = self.add_constant((1_u128 << bit_size) - 1);
+        let max = self.add_constant(F::from(2_u128).pow(&F::from(bit_size as u128)) - F::one());
         self.sub_var(max, x)
     }
 
@@ -790,8 +836,11 @@ impl<F: AcirField, B: BlackBoxFunctionSolver<F>> AcirContext<F, B> {
             // If `lhs` and `rhs` are known constants then we can calculate the result at compile time.
             // `rhs` must be non-zero.
             (Some(lhs_const), Some(rhs_const), _) if !rhs_const.is_zero() => {
-                let quotient = lhs_const.to_u128() / rhs_const.to_u128();
-                let remainder = lhs_const.to_u128() - quotient * rhs_const.to_u128();
+                // `lhs` may not fit in a u128, e.g. when truncating the partial products of a wide multiplication
+                let lhs_big = BigUint::from_bytes_be(&lhs_const.to_be_bytes());
+                let rhs_big = BigUint::from_bytes_be(&rhs_const.to_be_bytes());
+                let quotient = F::from_be_bytes_reduce(&(&lhs_big / &rhs_big).to_bytes_be());
+                let remainder = F::from_be_bytes_reduce(&(&lhs_big % &rhs_big).to_bytes_be());
 
                 let quotient_var = self.add_constant(quotient);
                 let remainder_var = self.add_constant(remainder);
@@ -880,29 +929,33 @@ impl<F: AcirField, B: BlackBoxFunctionSolver<F>> AcirContext<F, B> {
         // Constrain `r < rhs`.
         self.bound_constraint_with_offset(remainder_var, rhs, predicate, max_rhs_bits)?;
 
+        // When `b` isn't known and both `b` and `q` may be large, `b * q` can overflow the field.
+        // It is then computed in limbs instead, and constrained to fit in `bit_size` bits.
+        let unbounded = max_q_bits + max_rhs_bits >= F::max_num_bits() - 1 && !rhs_expr.is_const();
+        let rhs_times_quotient = if unbounded {
+            let (product, overflow) = self.wide_mul_var(rhs, quotient_var, bit_size)?;
+            let overflow = self.mul_var(overflow, predicate)?;
+            self.assert_eq_var(overflow, zero, None)?;
+            product
+        } else {
+            self.mul_var(rhs, quotient_var)?
+        };
+
         // a * predicate == (b * q + r) * predicate
         // => predicate * (a - b * q - r) == 0
         // When the predicate is 0, the equation always passes.
         // When the predicate is 1, the euclidean division needs to be
         // true.
-        let rhs_constraint = self.mul_var(rhs, quotient_var)?;
-        let rhs_constraint = self.add_var(rhs_constraint, remainder_var)?;
+        let rhs_constraint = self.add_var(rhs_times_quotient, remainder_var)?;
         let rhs_constraint = self.mul_var(rhs_constraint, predicate)?;
 
         let lhs_constraint = self.mul_var(lhs, predicate)?;
         self.assert_eq_var(lhs_constraint, rhs_constraint, None)?;
 
         // Avoids overflow: 'q*b+r < 2^max_q_bits*2^max_rhs_bits'
-        let mut avoid_overflow = false;
-        if max_q_bits + max_rhs_bits >= F::max_num_bits() - 1 {
-            // q*b+r can overflow; we avoid this when b is constant
-            if rhs_expr.is_const() {
-                avoid_overflow = true;
-            } else {
-                // we do not support unbounded division
-                unreachable!("overflow in unbounded division");
-            }
-        }
+        // q*b+r can overflow; when b isn't constant this is prevented by the limbs above
+        let avoid_overflow =
+            max_q_bits + max_rhs_bits >= F::max_num_bits() - 1 && rhs_expr.is_const();
 
         if let Some(rhs_const) = rhs_expr.to_const() {
             if avoid_overflow {
@@ -982,7 +1035,7 @@ impl<F: AcirField, B: BlackBoxFunctionSolver<F>> AcirContext<F, B> {
 
             let bit_size = bit_size_u128(rhs_offset);
             // r = 2^bit_size - rhs_offset -1, is of bit size  'bit_size' by construction
-            let r = (1_u128 << bit_size) - rhs_offset - 1;
+            let r = u128::MAX.checked_shr(128 - bit_size).unwrap_or(0) - rhs_offset;
             // however, since it is a constant, we can compute it's actual bit size
             let r_bit_size = bit_size_u128(r);
             // witness = lhs_offset + r
@@ -1156,7 +1209,7 @@ impl<F: AcirField, B: BlackBoxFunctionSolver<F>> AcirContext<F, B> {
         bit_count: u32,
     ) -> Result<AcirVar, RuntimeError> {
         let pow_last = self.add_constant(F::from(1_u128 << (bit_count - 1)));
-        let pow = self.add_constant(F::from(1_u128 << (bit_count)));
+        let pow = self.add_constant(F::from(2_u128).pow(&F::from(bit_count as u128)));
 
         // We check whether the inputs have same sign or not by computing the XOR of their bit sign
 
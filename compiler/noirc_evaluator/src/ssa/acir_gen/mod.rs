@@ -1965,10 +1965,10 @@ impl<'a> Context<'a> {
         match &binary_type {
             Type::Numeric(NumericType::Unsigned { bit_size })
             | Type::Numeric(NumericType::Signed { bit_size }) => {
-                // Conservative max bit size that is small enough such that two operands can be
-                // multiplied and still fit within the field modulus. This is necessary for the
-                // truncation technique: result % 2^bit_size to be valid.
-                let max_integer_bit_size = FieldElement::max_num_bits() / 2;
+                // Integers which are wider than half the field can't be multiplied directly in the field,
+                // so their products are computed in limbs. This works up to 128 bits, above which the
+                // integers themselves no longer fit in a field element.
+                let max_integer_bit_size = 128;
                 if *bit_size > max_integer_bit_size {
                     return Err(RuntimeError::UnsupportedIntegerSize {
                         num_bits: *bit_size,
@@ -1986,6 +1986,9 @@ impl<'a> Context<'a> {
         let result = match binary.operator {
             BinaryOp::Add => self.acir_context.add_var(lhs, rhs),
             BinaryOp::Sub => self.acir_context.sub_var(lhs, rhs),
+            BinaryOp::Mul if num_type.is_wide_integer() => {
+                return self.convert_wide_mul(lhs, rhs, &num_type, binary, dfg);
+            }
             BinaryOp::Mul => self.acir_context.mul_var(lhs, rhs),
             BinaryOp::Div => self.acir_context.div_var(
                 lhs,
@@ -2031,6 +2034,41 @@ impl<'a> Context<'a> {
         Ok(result)
     }
 
+    /// Multiplies two integers which are too wide for their product to fit in a field element.
+    ///
+    /// The result is the product modulo `2^bit_size`, which is the expected result for signed integers
+    /// as they are represented in two's complement. Unsigned multiplications are also constrained
+    /// not to overflow.
+    fn convert_wide_mul(
+        &mut self,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        num_type: &NumericType,
+        binary: &Binary,
+        dfg: &DataFlowGraph,
+    ) -> Result<AcirVar, RuntimeError> {
+        let bit_size = num_type.bit_size();
+        let (result, overflow) = self.acir_context.wide_mul_var(lhs, rhs, bit_size)?;
+
+        let max_lhs_bits = dfg.get_value_max_num_bits(binary.lhs);
+        let max_rhs_bits = dfg.get_value_max_num_bits(binary.rhs);
+        if matches!(num_type, NumericType::Unsigned { .. })
+            && max_lhs_bits + max_rhs_bits > bit_size
+        {
+            let overflow =
+                self.acir_context.mul_var(overflow, self.current_side_effects_enabled_var)?;
+            let zero = self.acir_context.add_constant(FieldElement::zero());
+            self.acir_context.assert_eq_var(
+                overflow,
+                zero,
+                Some(AssertionPayload::StaticString(
+                    "attempt to multiply with overflow".to_string(),
+                )),
+            )?;
+        }
+        Ok(result)
+    }
+
     /// Adds a range check against the bit size of the result of addition, subtraction or multiplication
     fn check_unsigned_overflow(
         &mut self,
@@ -2143,7 +2181,9 @@ impl<'a> Context<'a> {
                 ) {
                     // Subtractions must first have the integer modulus added before truncation can be
                     // applied. This is done in order to prevent underflow.
-                    let integer_modulus = self.acir_context.add_constant(2_u128.pow(bit_size));
+                    let integer_modulus = self.acir_context.add_constant(
+                        FieldElement::from(2_u128).pow(&FieldElement::from(bit_size as u128)),
+                    );
                     var = self.acir_context.add_var(var, integer_modulus)?;
                 }
             }
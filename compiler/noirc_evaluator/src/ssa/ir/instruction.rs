@@ -654,8 +654,9 @@ impl Instruction {
                     return SimplifiedTo(*value);
                 }
                 if let Some((numeric_constant, typ)) = dfg.get_numeric_constant_with_type(*value) {
-                    let integer_modulus = 2_u128.pow(*bit_size);
-                    let truncated = numeric_constant.to_u128() % integer_modulus;
+                    // `to_u128` keeps the lowest 128 bits of the constant, so only the bits above `bit_size` need masking
+                    let mask = u128::MAX.checked_shr(128 - *bit_size).unwrap_or(0);
+                    let truncated = numeric_constant.to_u128() & mask;
                     SimplifiedTo(dfg.make_constant(truncated.into(), typ))
                 } else if let Value::Instruction { instruction, .. } = &dfg[dfg.resolve(*value)] {
                     match &dfg[*instruction] {
@@ -321,8 +321,8 @@ fn eval_constant_binary_op(
                 return None;
             }
             let result = function(lhs, rhs)?;
-            // Check for overflow
-            if result >= 1 << *bit_size {
+            // Check for overflow. 128-bit results can't overflow as `function` is checked.
+            if *bit_size < 128 && result >= 1 << *bit_size {
                 return None;
             }
             result.into()
@@ -342,10 +342,12 @@ fn eval_constant_binary_op(
             }
 
             let result = function(lhs, rhs)?;
-            // Check for overflow
-            let two_pow_bit_size_minus_one = 1i128 << (*bit_size - 1);
-            if result >= two_pow_bit_size_minus_one || result < -two_pow_bit_size_minus_one {
-                return None;
+            // Check for overflow. 128-bit results can't overflow as `function` is checked.
+            if *bit_size < 128 {
+                let two_pow_bit_size_minus_one = 1i128 << (*bit_size - 1);
+                if result >= two_pow_bit_size_minus_one || result < -two_pow_bit_size_minus_one {
+                    return None;
+                }
             }
             convert_signed_integer_to_field_element(result, *bit_size)
         }
@@ -365,32 +367,21 @@ fn eval_constant_binary_op(
 fn try_convert_field_element_to_signed_integer(field: FieldElement, bit_size: u32) -> Option<i128> {
     let unsigned_int = truncate(field.try_into_u128()?, bit_size);
 
-    let max_positive_value = 1 << (bit_size - 1);
-    let is_positive = unsigned_int < max_positive_value;
-
-    let signed_int = if is_positive {
-        unsigned_int as i128
-    } else {
-        let x = (1u128 << bit_size) - unsigned_int;
-        -(x as i128)
-    };
+    // Sign-extend the value by moving its sign bit into the sign bit of an i128
+    let shift = 128 - bit_size;
+    let signed_int = ((unsigned_int << shift) as i128) >> shift;
 
     Some(signed_int)
 }
 
 fn convert_signed_integer_to_field_element(int: i128, bit_size: u32) -> FieldElement {
-    if int >= 0 {
-        FieldElement::from(int)
-    } else {
-        // We add an offset of `bit_size` bits to shift the negative values into the range [2^(bitsize-1), 2^bitsize)
-        let offset_int = (1i128 << bit_size) + int;
-        FieldElement::from(offset_int)
-    }
+    // Taking the two's complement representation of `int` shifts the negative values
+    // into the range [2^(bitsize-1), 2^bitsize)
+    FieldElement::from(truncate(int as u128, bit_size))
 }
 
 fn truncate(int: u128, bit_size: u32) -> u128 {
-    let max = 1 << bit_size;
-    int % max
+    int & u128::MAX.checked_shr(128 - bit_size).unwrap_or(0)
 }
 
 impl BinaryOp {
@@ -424,8 +415,8 @@ impl BinaryOp {
             BinaryOp::Xor => |x, y| Some(x ^ y),
             BinaryOp::Eq => |x, y| Some((x == y) as u128),
             BinaryOp::Lt => |x, y| Some((x < y) as u128),
-            BinaryOp::Shl => |x, y| Some(x << y),
-            BinaryOp::Shr => |x, y| Some(x >> y),
+            BinaryOp::Shl => |x, y| x.checked_shl(y.try_into().ok()?),
+            BinaryOp::Shr => |x, y| x.checked_shr(y.try_into().ok()?),
         }
     }
 
@@ -441,8 +432,8 @@ impl BinaryOp {
             BinaryOp::Xor => |x, y| Some(x ^ y),
             BinaryOp::Eq => |x, y| Some((x == y) as i128),
             BinaryOp::Lt => |x, y| Some((x < y) as i128),
-            BinaryOp::Shl => |x, y| Some(x << y),
-            BinaryOp::Shr => |x, y| Some(x >> y),
+            BinaryOp::Shl => |x, y| x.checked_shl(y.try_into().ok()?),
+            BinaryOp::Shr => |x, y| x.checked_shr(y.try_into().ok()?),
         }
     }
 }
@@ -457,8 +448,8 @@ mod test {
 
     proptest! {
         #[test]
-        fn signed_int_roundtrip(int: i128, bit_size in 1u32..=64) {
-            let int = int % (1i128 << (bit_size - 1));
+        fn signed_int_roundtrip(int: i128, bit_size in 1u32..=128) {
+            let int = if bit_size == 128 { int } else { int % (1i128 << (bit_size - 1)) };
 
             let int_as_field = convert_signed_integer_to_field_element(int, bit_size);
             let recovered_int = try_convert_field_element_to_signed_integer(int_as_field, bit_size).unwrap();
@@ -30,6 +30,17 @@ impl NumericType {
         }
     }
 
+    /// Returns true for integer types which are so wide that the product of two of their values
+    /// may not fit in a field element.
+    pub(crate) fn is_wide_integer(&self) -> bool {
+        match self {
+            NumericType::NativeField => false,
+            NumericType::Unsigned { bit_size } | NumericType::Signed { bit_size } => {
+                *bit_size > FieldElement::max_num_bits() / 2
+            }
+        }
+    }
+
     /// Returns None if the given Field value is within the numeric limits
     /// for the current NumericType. Otherwise returns a string describing
     /// the limits, as a range.
@@ -40,7 +51,7 @@ impl NumericType {
     ) -> Option<String> {
         match self {
             NumericType::Unsigned { bit_size } => {
-                let max = 2u128.pow(bit_size) - 1;
+                let max = u128::MAX >> (128 - bit_size);
                 if negative {
                     return Some(format!("0..={}", max));
                 }
@@ -109,11 +109,8 @@ impl Context<'_> {
 
             let (rhs_bit_size_pow_2, overflows) = 2_u128.overflowing_pow(bit_shift_size);
             if overflows {
-                assert!(bit_size < 128, "ICE - shift left with big integers are not supported");
-                if bit_size < 128 {
-                    let zero = self.numeric_constant(FieldElement::zero(), typ);
-                    return InsertInstructionResult::SimplifiedTo(zero).first();
-                }
+                let zero = self.numeric_constant(FieldElement::zero(), typ);
+                return InsertInstructionResult::SimplifiedTo(zero).first();
             }
             let pow = self.numeric_constant(FieldElement::from(rhs_bit_size_pow_2), typ.clone());
 
@@ -135,6 +132,13 @@ impl Context<'_> {
 
         if max_bit <= bit_size {
             self.insert_binary(lhs, BinaryOp::Mul, pow)
+        } else if (NumericType::Unsigned { bit_size }).is_wide_integer() {
+            // The product may not fit in a field element. A signed multiplication of wide integers
+            // returns it modulo 2^bit_size without checking for overflow, which is what we want here.
+            let lhs_signed = self.insert_cast(lhs, Type::signed(bit_size));
+            let pow_signed = self.insert_cast(pow, Type::signed(bit_size));
+            let result = self.insert_binary(lhs_signed, BinaryOp::Mul, pow_signed);
+            self.insert_cast(result, typ)
         } else {
             let lhs_field = self.insert_cast(lhs, Type::field());
             let pow_field = self.insert_cast(pow, Type::field());
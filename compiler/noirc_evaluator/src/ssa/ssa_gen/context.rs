@@ -43,6 +43,10 @@ pub(super) struct FunctionContext<'a> {
     /// These are ordered such that an inner loop is at the end of the vector and
     /// outer loops are at the beginning. When a loop is finished, it is popped.
     loops: Vec<Loop>,
+
+    /// The block every early `return` in the current function jumps to.
+    /// This is only created once the first `return` is found.
+    return_block: Option<BasicBlockId>,
 }
 
 /// Shared context for all functions during ssa codegen. This is the only
@@ -79,7 +83,8 @@ pub(super) struct SharedContext {
 #[derive(Copy, Clone)]
 pub(super) struct Loop {
     pub(super) loop_entry: BasicBlockId,
-    pub(super) loop_index: ValueId,
+    /// The index variable of a `for` loop. `while` and `loop` loops have no index.
+    pub(super) loop_index: Option<ValueId>,
     pub(super) loop_end: BasicBlockId,
 }
 
@@ -110,7 +115,8 @@ impl<'a> FunctionContext<'a> {
         let mut builder = FunctionBuilder::new(function_name, function_id);
         builder.set_runtime(runtime);
         let definitions = HashMap::default();
-        let mut this = Self { definitions, builder, shared_context, loops: Vec::new() };
+        let mut this =
+            Self { definitions, builder, shared_context, loops: Vec::new(), return_block: None };
         this.add_parameters_to_scope(parameters);
         this
     }
@@ -127,6 +133,7 @@ impl<'a> FunctionContext<'a> {
         force_brillig_runtime: bool,
     ) {
         self.definitions.clear();
+        self.return_block = None;
         if func.unconstrained || (force_brillig_runtime && func.inline_type != InlineType::Inline) {
             self.builder.new_brillig_function(func.name.clone(), id, func.inline_type);
         } else {
@@ -290,8 +297,8 @@ impl<'a> FunctionContext<'a> {
                 match numeric_type {
                     NumericType::NativeField => -value,
                     NumericType::Signed { bit_size } | NumericType::Unsigned { bit_size } => {
-                        let base = 1_u128 << bit_size;
-                        FieldElement::from(base) - value
+                        let base = FieldElement::from(2_u128).pow(&FieldElement::from(bit_size));
+                        base - value
                     }
                 }
             } else {
@@ -310,8 +317,10 @@ impl<'a> FunctionContext<'a> {
         assert_eq!(self.builder.type_of_value(sign), Type::bool());
 
         // We compute the absolute value of lhs
-        let bit_width =
-            self.builder.numeric_constant(FieldElement::from(2_i128.pow(bit_size)), Type::field());
+        let bit_width = self.builder.numeric_constant(
+            FieldElement::from(2_u128).pow(&FieldElement::from(bit_size)),
+            Type::field(),
+        );
         let sign_not = self.builder.insert_not(sign);
 
         // We use unsafe casts here, this is fine as we're casting to a `field` type.
@@ -359,9 +368,14 @@ impl<'a> FunctionContext<'a> {
                     }
                     BinaryOpKind::Multiply => {
                         // Result is computed modulo the bit size
-                        let mut result =
-                            self.builder.insert_cast(result, Type::unsigned(2 * bit_size));
-                        result = self.builder.insert_truncate(result, bit_size, 2 * bit_size);
+                        let result = if (NumericType::Signed { bit_size }).is_wide_integer() {
+                            // Wide multiplications are already reduced modulo the bit size
+                            self.builder.insert_cast(result, Type::unsigned(bit_size))
+                        } else {
+                            let result =
+                                self.builder.insert_cast(result, Type::unsigned(2 * bit_size));
+                            self.builder.insert_truncate(result, bit_size, 2 * bit_size)
+                        };
 
                         self.check_signed_overflow(result, lhs, rhs, operator, bit_size, location);
                         self.insert_safe_cast(result, result_type, location)
@@ -452,7 +466,7 @@ impl<'a> FunctionContext<'a> {
     ) {
         let is_sub = operator == BinaryOpKind::Subtract;
         let half_width = self.builder.numeric_constant(
-            FieldElement::from(2_i128.pow(bit_size - 1)),
+            FieldElement::from(1_u128 << (bit_size - 1)),
             Type::unsigned(bit_size),
         );
         // We compute the sign of the operands. The overflow checks for signed integers depends on these signs
@@ -488,14 +502,26 @@ impl<'a> FunctionContext<'a> {
                 // First we compute the absolute value of operands, and their product
                 let lhs_abs = self.absolute_value_helper(lhs, lhs_sign, bit_size);
                 let rhs_abs = self.absolute_value_helper(rhs, rhs_sign, bit_size);
-                let product_field = self.builder.insert_binary(lhs_abs, BinaryOp::Mul, rhs_abs);
-                // It must not already overflow the bit_size
-                self.builder.set_location(location).insert_range_check(
-                    product_field,
-                    bit_size,
-                    Some("attempt to multiply with overflow".to_string()),
-                );
-                let product = self.builder.insert_cast(product_field, Type::unsigned(bit_size));
+                let product = if (NumericType::Signed { bit_size }).is_wide_integer() {
+                    // The product may not fit in a field element, so it is computed as an unsigned
+                    // multiplication which fails if it overflows the bit_size
+                    let lhs_abs = self.builder.insert_cast(lhs_abs, Type::unsigned(bit_size));
+                    let rhs_abs = self.builder.insert_cast(rhs_abs, Type::unsigned(bit_size));
+                    self.builder.set_location(location).insert_binary(
+                        lhs_abs,
+                        BinaryOp::Mul,
+                        rhs_abs,
+                    )
+                } else {
+                    let product_field = self.builder.insert_binary(lhs_abs, BinaryOp::Mul, rhs_abs);
+                    // It must not already overflow the bit_size
+                    self.builder.set_location(location).insert_range_check(
+                        product_field,
+                        bit_size,
+                        Some("attempt to multiply with overflow".to_string()),
+                    );
+                    self.builder.insert_cast(product_field, Type::unsigned(bit_size))
+                };
 
                 // Then we check the signed product fits in a signed integer of bit_size-bits
                 let not_same = self.builder.insert_not(same_sign);
@@ -940,7 +966,7 @@ impl<'a> FunctionContext<'a> {
     pub(crate) fn enter_loop(
         &mut self,
         loop_entry: BasicBlockId,
-        loop_index: ValueId,
+        loop_index: Option<ValueId>,
         loop_end: BasicBlockId,
     ) {
         self.loops.push(Loop { loop_entry, loop_index, loop_end });
@@ -954,6 +980,27 @@ impl<'a> FunctionContext<'a> {
         // The frontend should ensure break/continue are never used outside a loop
         *self.loops.last().expect("current_loop: not in a loop!")
     }
+
+    /// Returns the block early `return`s jump to, creating it if needed.
+    /// The block takes one parameter for each of the given return values.
+    pub(crate) fn return_block(&mut self, return_values: &[ValueId]) -> BasicBlockId {
+        if let Some(block) = self.return_block {
+            return block;
+        }
+
+        let block = self.builder.insert_block();
+        for value in return_values {
+            let typ = self.builder.type_of_value(*value);
+            self.builder.add_block_parameter(block, typ);
+        }
+        self.return_block = Some(block);
+        block
+    }
+
+    /// Takes the early return block of the current function, if any `return` created one.
+    pub(crate) fn take_return_block(&mut self) -> Option<BasicBlockId> {
+        self.return_block.take()
+    }
 }
 
 /// True if the given operator cannot be encoded directly and needs
@@ -8,6 +8,7 @@ use context::SharedContext;
 use iter_extended::{try_vecmap, vecmap};
 use noirc_errors::Location;
 use noirc_frontend::ast::{UnaryOp, Visibility};
+use noirc_frontend::hir_def::expr::Constructor;
 use noirc_frontend::hir_def::types::Type as HirType;
 use noirc_frontend::monomorphization::ast::{self, Expression, Program};
 
@@ -124,13 +125,29 @@ pub(crate) fn generate_ssa(
 impl<'a> FunctionContext<'a> {
     /// Codegen a function's body and set its return value to that of its last parameter.
     /// For functions returning nothing, this will be an empty list.
+    ///
+    /// If the function contains any early `return`s, they and the end of the body all
+    /// jump to a shared return block which holds the actual return instruction.
     fn codegen_function_body(&mut self, body: &Expression) -> Result<(), RuntimeError> {
         let entry_block = self.increment_parameter_rcs();
         let return_value = self.codegen_expression(body)?;
         let results = return_value.into_value_list(self);
-        self.end_scope(entry_block, &results);
 
-        self.builder.terminate_with_return(results);
+        // If the body ends in a `return` the current block is already terminated and
+        // its scope was already ended.
+        let current_block = self.builder.current_block();
+        if self.builder.current_function.dfg[current_block].terminator().is_none() {
+            self.end_scope(entry_block, &results);
+        }
+
+        if let Some(return_block) = self.take_return_block() {
+            self.builder.terminate_with_jmp(return_block, results);
+            self.builder.switch_to_block(return_block);
+            let results = self.builder.current_function.dfg.block_parameters(return_block).to_vec();
+            self.builder.terminate_with_return(results);
+        } else {
+            self.builder.terminate_with_return(results);
+        }
         Ok(())
     }
 
@@ -144,7 +161,10 @@ impl<'a> FunctionContext<'a> {
             Expression::Index(index) => self.codegen_index(index),
             Expression::Cast(cast) => self.codegen_cast(cast),
             Expression::For(for_expr) => self.codegen_for(for_expr),
+            Expression::While(while_expr) => self.codegen_while(while_expr),
+            Expression::Loop(block) => self.codegen_loop(block),
             Expression::If(if_expr) => self.codegen_if(if_expr),
+            Expression::Match(match_expr) => self.codegen_match(match_expr),
             Expression::Tuple(tuple) => self.codegen_tuple(tuple),
             Expression::ExtractTupleField(tuple, index) => {
                 self.codegen_extract_tuple_field(tuple, *index)
@@ -158,6 +178,7 @@ impl<'a> FunctionContext<'a> {
             Expression::Semi(semi) => self.codegen_semi(semi),
             Expression::Break => Ok(self.codegen_break()),
             Expression::Continue => Ok(self.codegen_continue()),
+            Expression::Return(expr) => self.codegen_return(expr.as_deref()),
         }
     }
 
@@ -489,7 +510,7 @@ impl<'a> FunctionContext<'a> {
 
         // Remember the blocks and variable used in case there are break/continue instructions
         // within the loop which need to jump to them.
-        self.enter_loop(loop_entry, loop_index, loop_end);
+        self.enter_loop(loop_entry, Some(loop_index), loop_end);
 
         self.builder.set_location(for_expr.start_range_location);
         let start_index = self.codegen_non_tuple_expression(&for_expr.start_range)?;
@@ -525,6 +546,77 @@ impl<'a> FunctionContext<'a> {
         Ok(Self::unit_value())
     }
 
+    /// Codegens a while loop. These are only allowed in unconstrained code.
+    ///
+    /// For example, the expression `while cond { body }` is codegen'd as:
+    ///
+    ///   jmp while_entry()
+    /// while_entry():
+    ///   v0 = ... codegen cond ...
+    ///   jmpif v0, then: while_body, else: while_end
+    /// while_body():
+    ///   ... codegen body ...
+    ///   jmp while_entry()
+    /// while_end():
+    ///   ... This is the current insert point after codegen_while finishes ...
+    fn codegen_while(&mut self, while_expr: &ast::While) -> Result<Values, RuntimeError> {
+        let while_entry = self.builder.insert_block();
+        let while_body = self.builder.insert_block();
+        let while_end = self.builder.insert_block();
+
+        self.builder.terminate_with_jmp(while_entry, vec![]);
+
+        // Remember the blocks in case there are break/continue instructions
+        // within the loop which need to jump to them.
+        self.enter_loop(while_entry, None, while_end);
+
+        // Compile the loop entry block
+        self.builder.switch_to_block(while_entry);
+        let condition = self.codegen_non_tuple_expression(&while_expr.condition)?;
+        self.builder.terminate_with_jmpif(condition, while_body, while_end);
+
+        // Compile the loop body
+        self.builder.switch_to_block(while_body);
+        self.codegen_expression(&while_expr.body)?;
+        self.builder.terminate_with_jmp(while_entry, vec![]);
+
+        // Finish by switching to the end of the loop
+        self.builder.switch_to_block(while_end);
+        self.exit_loop();
+        Ok(Self::unit_value())
+    }
+
+    /// Codegens an unconditional loop, which can only be exited with a `break`.
+    /// These are only allowed in unconstrained code.
+    ///
+    /// For example, the expression `loop { body }` is codegen'd as:
+    ///
+    ///   jmp loop_body()
+    /// loop_body():
+    ///   ... codegen body ...
+    ///   jmp loop_body()
+    /// loop_end():
+    ///   ... This is the current insert point after codegen_loop finishes ...
+    fn codegen_loop(&mut self, block: &Expression) -> Result<Values, RuntimeError> {
+        let loop_body = self.builder.insert_block();
+        let loop_end = self.builder.insert_block();
+
+        self.builder.terminate_with_jmp(loop_body, vec![]);
+
+        // Remember the blocks in case there are break/continue instructions
+        // within the loop which need to jump to them.
+        self.enter_loop(loop_body, None, loop_end);
+
+        self.builder.switch_to_block(loop_body);
+        self.codegen_expression(block)?;
+        self.builder.terminate_with_jmp(loop_body, vec![]);
+
+        // Finish by switching to the end of the loop
+        self.builder.switch_to_block(loop_end);
+        self.exit_loop();
+        Ok(Self::unit_value())
+    }
+
     /// Codegens an if expression, handling the case of what to do if there is no 'else'.
     ///
     /// For example, the expression `if cond { a } else { b }` is codegen'd as:
@@ -589,6 +681,125 @@ impl<'a> FunctionContext<'a> {
         Ok(result)
     }
 
+    /// Codegen a match as a chain of `if`-`else`s, one for each case, each of which
+    /// jumps to its own end block like a regular `if` would:
+    ///
+    /// ```text
+    /// v0 = eq variable, constructor_0
+    /// jmpif v0 then: case_0, else: case_1_check
+    /// case_0():
+    ///   ... bind constructor arguments ...
+    ///   jmp end(case_0_result)
+    /// case_1_check():
+    ///   ... the remaining cases, ending with a jmp end(case_1_result)
+    /// end(result):
+    /// ```
+    ///
+    /// The last case doesn't need to be checked if there's no default case since
+    /// exhaustiveness checking guarantees it must match.
+    ///
+    /// This is done for both ACIR and Brillig functions. Brillig has no indirect jump to
+    /// dispatch on the tag of an enum with a jump table, so unconstrained code takes the
+    /// same chain of comparisons.
+    fn codegen_match(&mut self, match_expr: &ast::Match) -> Result<Values, RuntimeError> {
+        let variable = self.lookup(match_expr.variable_to_match.0);
+        self.codegen_match_cases(match_expr, &variable, 0)
+    }
+
+    fn codegen_match_cases(
+        &mut self,
+        match_expr: &ast::Match,
+        variable: &Values,
+        case_index: usize,
+    ) -> Result<Values, RuntimeError> {
+        let Some(case) = match_expr.cases.get(case_index) else {
+            let default_case =
+                match_expr.default_case.as_ref().expect("ICE: match has no case to take");
+            return self.codegen_expression(default_case);
+        };
+
+        let is_last_case = case_index + 1 == match_expr.cases.len();
+        if is_last_case && match_expr.default_case.is_none() {
+            let arguments = Self::match_case_arguments(variable, case);
+            return self.codegen_match_case(case, arguments);
+        }
+
+        let condition = self.codegen_match_condition(variable, &case.constructor)?;
+        let arguments = Self::match_case_arguments(variable, case);
+        let case_block = self.builder.insert_block();
+        let else_block = self.builder.insert_block();
+        let end_block = self.builder.insert_block();
+
+        self.builder.terminate_with_jmpif(condition, case_block, else_block);
+
+        self.builder.switch_to_block(case_block);
+        let case_values = self.codegen_match_case(case, arguments)?.into_value_list(self);
+        self.builder.terminate_with_jmp(end_block, case_values);
+
+        self.builder.switch_to_block(else_block);
+        let else_values =
+            self.codegen_match_cases(match_expr, variable, case_index + 1)?.into_value_list(self);
+        self.builder.terminate_with_jmp(end_block, else_values);
+
+        let result = Self::map_type(&match_expr.typ, |typ| {
+            self.builder.add_block_parameter(end_block, typ).into()
+        });
+
+        self.builder.switch_to_block(end_block);
+        Ok(result)
+    }
+
+    /// Returns a boolean value for whether the given variable matches the constructor
+    fn codegen_match_condition(
+        &mut self,
+        variable: &Values,
+        constructor: &Constructor,
+    ) -> Result<ValueId, RuntimeError> {
+        Ok(match constructor {
+            Constructor::True => variable.clone().into_leaf().eval(self),
+            Constructor::False => {
+                let value = variable.clone().into_leaf().eval(self);
+                self.builder.insert_not(value)
+            }
+            Constructor::Int(value, is_negative) => {
+                let variable = variable.clone().into_leaf().eval(self);
+                let typ = self.builder.type_of_value(variable);
+                let constant = self.checked_numeric_constant(*value, *is_negative, typ)?;
+                self.builder.insert_binary(variable, BinaryOp::Eq, constant)
+            }
+            Constructor::Variant(_, index) => {
+                let tag = Self::get_field_ref(variable, 0).clone().into_leaf().eval(self);
+                let expected = self.builder.field_constant(*index as u128);
+                self.builder.insert_binary(tag, BinaryOp::Eq, expected)
+            }
+            Constructor::Unit | Constructor::Tuple(_) | Constructor::Struct(_) => {
+                self.builder.numeric_constant(true, Type::bool())
+            }
+        })
+    }
+
+    /// Extracts the values of each argument of the given case's constructor from the matched variable
+    fn match_case_arguments(variable: &Values, case: &ast::MatchCase) -> Vec<Values> {
+        vecmap(&case.argument_fields, |field| Self::get_field_ref(variable, *field).clone())
+    }
+
+    fn codegen_match_case(
+        &mut self,
+        case: &ast::MatchCase,
+        arguments: Vec<Values>,
+    ) -> Result<Values, RuntimeError> {
+        for ((id, _), argument) in case.arguments.iter().zip(arguments) {
+            // Like let bindings, each binding to an array increments its reference count
+            let argument = argument.map(|value| {
+                let value = value.eval(self);
+                self.builder.increment_array_reference_count(value);
+                Tree::Leaf(value::Value::Normal(value))
+            });
+            self.define(*id, argument);
+        }
+        self.codegen_expression(&case.branch)
+    }
+
     fn codegen_tuple(&mut self, tuple: &[Expression]) -> Result<Values, RuntimeError> {
         Ok(Tree::Branch(try_vecmap(tuple, |expr| self.codegen_expression(expr))?))
     }
@@ -739,6 +950,22 @@ impl<'a> FunctionContext<'a> {
         Ok(Self::unit_value())
     }
 
+    /// Codegens an early `return` as a jump to the function's return block,
+    /// ending the function's scope beforehand as the end of the body would.
+    fn codegen_return(&mut self, expr: Option<&Expression>) -> Result<Values, RuntimeError> {
+        let return_values = match expr {
+            Some(expr) => self.codegen_expression(expr)?.into_value_list(self),
+            None => Vec::new(),
+        };
+
+        let entry_block = self.builder.current_function.entry_block();
+        self.end_scope(entry_block, &return_values);
+
+        let return_block = self.return_block(&return_values);
+        self.builder.terminate_with_jmp(return_block, return_values);
+        Ok(Self::unit_value())
+    }
+
     fn codegen_break(&mut self) -> Values {
         let loop_end = self.current_loop().loop_end;
         self.builder.terminate_with_jmp(loop_end, Vec::new());
@@ -749,8 +976,12 @@ impl<'a> FunctionContext<'a> {
         let loop_ = self.current_loop();
 
         // Must remember to increment i before jumping
-        let new_loop_index = self.make_offset(loop_.loop_index, 1);
-        self.builder.terminate_with_jmp(loop_.loop_entry, vec![new_loop_index]);
+        if let Some(loop_index) = loop_.loop_index {
+            let new_loop_index = self.make_offset(loop_index, 1);
+            self.builder.terminate_with_jmp(loop_.loop_entry, vec![new_loop_index]);
+        } else {
+            self.builder.terminate_with_jmp(loop_.loop_entry, vec![]);
+        }
         Self::unit_value()
     }
 }
@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+use crate::ast::{Ident, UnresolvedGenerics, UnresolvedType};
+use crate::token::SecondaryAttribute;
+
+use iter_extended::vecmap;
+use noirc_errors::Span;
+
+use super::{Documented, ItemVisibility};
+
+/// Ast node for an enum
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoirEnumeration {
+    pub name: Ident,
+    pub attributes: Vec<SecondaryAttribute>,
+    pub visibility: ItemVisibility,
+    pub generics: UnresolvedGenerics,
+    pub variants: Vec<Documented<EnumVariant>>,
+    pub span: Span,
+}
+
+impl NoirEnumeration {
+    pub fn is_abi(&self) -> bool {
+        self.attributes.iter().any(|attr| attr.is_abi())
+    }
+}
+
+/// We only support variants of the form `Name(A, B, ...)` currently.
+/// Enum variants like `Name { a: A, b: B, .. }` will be implemented later
+/// after the feature is more mature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumVariant {
+    pub name: Ident,
+
+    /// This is `None` for unit variants like `Name` and `Some` for
+    /// variants with parameters like `Name(A, B)`, including `Name()`.
+    pub parameters: Option<Vec<UnresolvedType>>,
+}
+
+impl Display for NoirEnumeration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let generics = vecmap(&self.generics, |generic| generic.to_string());
+        let generics = if generics.is_empty() { "".into() } else { generics.join(", ") };
+
+        writeln!(f, "enum {}{} {{", self.name, generics)?;
+
+        for variant in self.variants.iter() {
+            let variant = &variant.item;
+            write!(f, "    {}", variant.name)?;
+            if let Some(parameters) = &variant.parameters {
+                let parameters = vecmap(parameters, ToString::to_string);
+                write!(f, "({})", parameters.join(", "))?;
+            }
+            writeln!(f, ",")?;
+        }
+
+        write!(f, "}}")
+    }
+}
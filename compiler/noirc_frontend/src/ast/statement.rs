@@ -18,7 +18,7 @@ use crate::node_interner::{
     InternedExpressionKind, InternedPattern, InternedStatementKind, NodeInterner,
 };
 use crate::parser::{ParserError, ParserErrorReason};
-use crate::token::{SecondaryAttribute, Token};
+use crate::token::{CfgPredicate, SecondaryAttribute, Token};
 
 /// This is used when an identifier fails to parse in the parser.
 /// Instead of failing the parse, we can often recover using this
@@ -43,10 +43,19 @@ pub enum StatementKind {
     Expression(Expression),
     Assign(AssignStatement),
     For(ForLoopStatement),
+    /// A `while` loop, only allowed in unconstrained code
+    While(WhileStatement),
+    /// A `loop` loop, only allowed in unconstrained code
+    Loop(LoopStatement),
     Break,
     Continue,
+    /// An early `return`, only allowed in unconstrained and comptime code
+    Return(Option<Expression>),
     /// This statement should be executed at compile-time
     Comptime(Box<Statement>),
+    /// A statement with a `#[cfg(...)]` attribute, only compiled if the predicate is
+    /// satisfied by the features enabled for the current crate
+    Cfg(CfgPredicate, Box<Statement>),
     // This is an expression with a trailing semi-colon
     Semi(Expression),
     // This is an interned StatementKind during comptime code.
@@ -89,6 +98,7 @@ impl StatementKind {
             | StatementKind::Semi(_)
             | StatementKind::Break
             | StatementKind::Continue
+            | StatementKind::Return(_)
             | StatementKind::Error => {
                 // To match rust, statements always require a semicolon, even at the end of a block
                 if semi.is_none() {
@@ -101,8 +111,13 @@ impl StatementKind {
                     statement.add_semicolon(semi, span, last_statement_in_block, emit_error);
                 StatementKind::Comptime(statement)
             }
-            // A semicolon on a for loop is optional and does nothing
-            StatementKind::For(_) => self,
+            StatementKind::Cfg(predicate, mut statement) => {
+                *statement =
+                    statement.add_semicolon(semi, span, last_statement_in_block, emit_error);
+                StatementKind::Cfg(predicate, statement)
+            }
+            // A semicolon on a loop is optional and does nothing
+            StatementKind::For(_) | StatementKind::While(_) | StatementKind::Loop(_) => self,
 
             // No semicolon needed for a resolved statement
             StatementKind::Interned(_) => self,
@@ -114,7 +129,8 @@ impl StatementKind {
                     | (ExpressionKind::Unsafe(..), semi, _)
                     | (ExpressionKind::Interned(..), semi, _)
                     | (ExpressionKind::InternedStatement(..), semi, _)
-                    | (ExpressionKind::If(_), semi, _) => {
+                    | (ExpressionKind::If(_), semi, _)
+                    | (ExpressionKind::Match(_), semi, _) => {
                         if semi.is_some() {
                             StatementKind::Semi(expr)
                         } else {
@@ -945,6 +961,19 @@ pub struct ForLoopStatement {
     pub span: Span,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct WhileStatement {
+    pub condition: Expression,
+    pub block: Expression,
+    pub span: Span,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LoopStatement {
+    pub block: Expression,
+    pub span: Span,
+}
+
 impl Display for StatementKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -953,9 +982,16 @@ impl Display for StatementKind {
             StatementKind::Expression(expression) => expression.fmt(f),
             StatementKind::Assign(assign) => assign.fmt(f),
             StatementKind::For(for_loop) => for_loop.fmt(f),
+            StatementKind::While(while_loop) => while_loop.fmt(f),
+            StatementKind::Loop(loop_) => loop_.fmt(f),
             StatementKind::Break => write!(f, "break"),
             StatementKind::Continue => write!(f, "continue"),
+            StatementKind::Return(Some(expression)) => write!(f, "return {expression}"),
+            StatementKind::Return(None) => write!(f, "return"),
             StatementKind::Comptime(statement) => write!(f, "comptime {}", statement.kind),
+            StatementKind::Cfg(predicate, statement) => {
+                write!(f, "#[cfg({predicate})] {}", statement.kind)
+            }
             StatementKind::Semi(semi) => write!(f, "{semi};"),
             StatementKind::Interned(_) => write!(f, "(resolved);"),
             StatementKind::Error => write!(f, "Error"),
@@ -1062,3 +1098,15 @@ impl Display for ForLoopStatement {
         write!(f, "for {} in {range} {}", self.identifier, self.block)
     }
 }
+
+impl Display for WhileStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "while {} {}", self.condition, self.block)
+    }
+}
+
+impl Display for LoopStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "loop {}", self.block)
+    }
+}
@@ -6,17 +6,17 @@ use crate::{
         ArrayLiteral, AsTraitPath, AssignStatement, BlockExpression, CallExpression,
         CastExpression, ConstrainStatement, ConstructorExpression, Expression, ExpressionKind,
         ForLoopStatement, ForRange, Ident, IfExpression, IndexExpression, InfixExpression, LValue,
-        Lambda, LetStatement, Literal, MemberAccessExpression, MethodCallExpression,
+        Lambda, LetStatement, Literal, LoopStatement, MemberAccessExpression, MethodCallExpression,
         ModuleDeclaration, NoirFunction, NoirStruct, NoirTrait, NoirTraitImpl, NoirTypeAlias, Path,
         PrefixExpression, Statement, StatementKind, TraitImplItem, TraitItem, TypeImpl, UseTree,
-        UseTreeKind,
+        UseTreeKind, WhileStatement,
     },
     node_interner::{
         ExprId, InternedExpressionKind, InternedPattern, InternedStatementKind,
         InternedUnresolvedTypeData, QuotedTypeId,
     },
     parser::{Item, ItemKind, ParsedSubModule},
-    token::{MetaAttribute, SecondaryAttribute, Tokens},
+    token::{CfgPredicate, MetaAttribute, SecondaryAttribute, Tokens},
     ParsedModule, QuotedType,
 };
 
@@ -30,6 +30,7 @@ use super::{
 pub enum AttributeTarget {
     Module,
     Struct,
+    Enum,
     Trait,
     Function,
     Let,
@@ -142,6 +143,10 @@ pub trait Visitor {
         true
     }
 
+    fn visit_noir_enum(&mut self, _: &NoirEnumeration, _: Span) -> bool {
+        true
+    }
+
     fn visit_noir_type_alias(&mut self, _: &NoirTypeAlias, _: Span) -> bool {
         true
     }
@@ -216,6 +221,10 @@ pub trait Visitor {
         true
     }
 
+    fn visit_match_expression(&mut self, _: &MatchExpression, _: Span) -> bool {
+        true
+    }
+
     fn visit_tuple(&mut self, _: &[Expression], _: Span) -> bool {
         true
     }
@@ -296,14 +305,30 @@ pub trait Visitor {
         true
     }
 
+    fn visit_while_statement(&mut self, _: &WhileStatement) -> bool {
+        true
+    }
+
+    fn visit_loop_statement(&mut self, _: &LoopStatement) -> bool {
+        true
+    }
+
     fn visit_comptime_statement(&mut self, _: &Statement) -> bool {
         true
     }
 
+    fn visit_cfg_statement(&mut self, _: &CfgPredicate, _: &Statement) -> bool {
+        true
+    }
+
     fn visit_break(&mut self) {}
 
     fn visit_continue(&mut self) {}
 
+    fn visit_return(&mut self, _: Option<&Expression>) -> bool {
+        true
+    }
+
     fn visit_interned_statement(&mut self, _: InternedStatementKind) {}
 
     fn visit_error_statement(&mut self) {}
@@ -523,6 +548,7 @@ impl Item {
             }
             ItemKind::TypeAlias(noir_type_alias) => noir_type_alias.accept(self.span, visitor),
             ItemKind::Struct(noir_struct) => noir_struct.accept(self.span, visitor),
+            ItemKind::Enum(noir_enum) => noir_enum.accept(self.span, visitor),
             ItemKind::ModuleDecl(module_declaration) => {
                 module_declaration.accept(self.span, visitor);
             }
@@ -771,6 +797,26 @@ impl NoirStruct {
     }
 }
 
+impl NoirEnumeration {
+    pub fn accept(&self, span: Span, visitor: &mut impl Visitor) {
+        if visitor.visit_noir_enum(self, span) {
+            self.accept_children(visitor);
+        }
+    }
+
+    pub fn accept_children(&self, visitor: &mut impl Visitor) {
+        for attribute in &self.attributes {
+            attribute.accept(AttributeTarget::Enum, visitor);
+        }
+
+        for variant in &self.variants {
+            for parameter in variant.item.parameters.iter().flatten() {
+                parameter.accept(visitor);
+            }
+        }
+    }
+}
+
 impl NoirTypeAlias {
     pub fn accept(&self, span: Span, visitor: &mut impl Visitor) {
         if visitor.visit_noir_type_alias(self, span) {
@@ -833,6 +879,9 @@ impl Expression {
             ExpressionKind::If(if_expression) => {
                 if_expression.accept(self.span, visitor);
             }
+            ExpressionKind::Match(match_expression) => {
+                match_expression.accept(self.span, visitor);
+            }
             ExpressionKind::Tuple(expressions) => {
                 if visitor.visit_tuple(expressions, self.span) {
                     visit_expressions(expressions, visitor);
@@ -1040,6 +1089,22 @@ impl IfExpression {
     }
 }
 
+impl MatchExpression {
+    pub fn accept(&self, span: Span, visitor: &mut impl Visitor) {
+        if visitor.visit_match_expression(self, span) {
+            self.accept_children(visitor);
+        }
+    }
+
+    pub fn accept_children(&self, visitor: &mut impl Visitor) {
+        self.expression.accept(visitor);
+        for (pattern, branch) in &self.rules {
+            pattern.accept(visitor);
+            branch.accept(visitor);
+        }
+    }
+}
+
 impl Lambda {
     pub fn accept(&self, span: Span, visitor: &mut impl Visitor) {
         if visitor.visit_lambda(self, span) {
@@ -1104,16 +1169,34 @@ impl Statement {
             StatementKind::For(for_loop_statement) => {
                 for_loop_statement.accept(visitor);
             }
+            StatementKind::While(while_statement) => {
+                while_statement.accept(visitor);
+            }
+            StatementKind::Loop(loop_statement) => {
+                loop_statement.accept(visitor);
+            }
             StatementKind::Comptime(statement) => {
                 if visitor.visit_comptime_statement(statement) {
                     statement.accept(visitor);
                 }
             }
+            StatementKind::Cfg(predicate, statement) => {
+                if visitor.visit_cfg_statement(predicate, statement) {
+                    statement.accept(visitor);
+                }
+            }
             StatementKind::Semi(expression) => {
                 expression.accept(visitor);
             }
             StatementKind::Break => visitor.visit_break(),
             StatementKind::Continue => visitor.visit_continue(),
+            StatementKind::Return(expression) => {
+                if visitor.visit_return(expression.as_ref()) {
+                    if let Some(expression) = expression {
+                        expression.accept(visitor);
+                    }
+                }
+            }
             StatementKind::Interned(id) => visitor.visit_interned_statement(*id),
             StatementKind::Error => visitor.visit_error_statement(),
         }
@@ -1176,6 +1259,31 @@ impl ForLoopStatement {
     }
 }
 
+impl WhileStatement {
+    pub fn accept(&self, visitor: &mut impl Visitor) {
+        if visitor.visit_while_statement(self) {
+            self.accept_children(visitor);
+        }
+    }
+
+    pub fn accept_children(&self, visitor: &mut impl Visitor) {
+        self.condition.accept(visitor);
+        self.block.accept(visitor);
+    }
+}
+
+impl LoopStatement {
+    pub fn accept(&self, visitor: &mut impl Visitor) {
+        if visitor.visit_loop_statement(self) {
+            self.accept_children(visitor);
+        }
+    }
+
+    pub fn accept_children(&self, visitor: &mut impl Visitor) {
+        self.block.accept(visitor);
+    }
+}
+
 impl LValue {
     pub fn accept(&self, visitor: &mut impl Visitor) {
         if visitor.visit_lvalue(self) {
@@ -403,6 +403,13 @@ impl DebugInstrumenter {
                     self.walk_expr(alt);
                 }
             }
+            ast::ExpressionKind::Match(match_expr) => {
+                // Patterns aren't walked since the variables they bind aren't tracked
+                self.walk_expr(&mut match_expr.expression);
+                for (_, branch) in match_expr.rules.iter_mut() {
+                    self.walk_expr(branch);
+                }
+            }
             ast::ExpressionKind::Tuple(exprs) => {
                 exprs.iter_mut().for_each(|ref mut expr| {
                     self.walk_expr(expr);
@@ -458,6 +465,19 @@ impl DebugInstrumenter {
             ast::StatementKind::For(ref mut for_stmt) => {
                 self.walk_for(for_stmt);
             }
+            ast::StatementKind::While(ref mut while_stmt) => {
+                self.walk_expr(&mut while_stmt.condition);
+                self.walk_expr(&mut while_stmt.block);
+            }
+            ast::StatementKind::Loop(ref mut loop_stmt) => {
+                self.walk_expr(&mut loop_stmt.block);
+            }
+            ast::StatementKind::Return(Some(ref mut expr)) => {
+                self.walk_expr(expr);
+            }
+            ast::StatementKind::Cfg(_, ref mut statement) => {
+                self.walk_statement(statement);
+            }
             _ => {} // Constrain, Error
         }
     }
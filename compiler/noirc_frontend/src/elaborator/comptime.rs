@@ -7,7 +7,10 @@ use noirc_errors::{Location, Span};
 use crate::{
     ast::{Documented, Expression, ExpressionKind},
     hir::{
-        comptime::{Interpreter, InterpreterError, Value},
+        comptime::{
+            display_generated_item, ComptimeStep, ComptimeStepKind, Interpreter, InterpreterError,
+            Value,
+        },
         def_collector::{
             dc_crate::{
                 CollectedItems, CompilationError, ModuleAttribute, UnresolvedFunctions,
@@ -351,10 +354,12 @@ impl<'context> Elaborator<'context> {
 
     fn add_items(
         &mut self,
-        items: Vec<Item>,
+        mut items: Vec<Item>,
         generated_items: &mut CollectedItems,
         location: Location,
     ) {
+        dc_mod::remove_disabled_items(&mut items, self.interner, self.crate_id);
+
         for item in items {
             self.add_item(item, generated_items, location);
         }
@@ -366,6 +371,16 @@ impl<'context> Elaborator<'context> {
         generated_items: &mut CollectedItems,
         location: Location,
     ) {
+        if self.interner.is_in_lsp_mode() {
+            let code = display_generated_item(self.interner, &item.kind);
+            self.interner.push_comptime_expansion(location, code);
+        }
+
+        let function = self.current_function();
+        self.debug_comptime_step(location, function, |_| {
+            ComptimeStepKind::Expansion(item.kind.to_string())
+        });
+
         match item.kind {
             ItemKind::Function(function) => {
                 let module_id = self.module_id();
@@ -450,6 +465,19 @@ impl<'context> Elaborator<'context> {
                     generated_items.types.insert(type_id, the_struct);
                 }
             }
+            ItemKind::Enum(enum_def) => {
+                if let Some((type_id, the_enum)) = dc_mod::collect_enum(
+                    self.interner,
+                    self.def_maps.get_mut(&self.crate_id).unwrap(),
+                    Documented::new(enum_def, item.doc_comments),
+                    self.file,
+                    self.local_module,
+                    self.crate_id,
+                    &mut self.errors,
+                ) {
+                    generated_items.enums.insert(type_id, the_enum);
+                }
+            }
             ItemKind::Impl(r#impl) => {
                 let module = self.module_id();
                 dc_mod::collect_impl(self.interner, generated_items, r#impl, self.file, module);
@@ -469,11 +497,15 @@ impl<'context> Elaborator<'context> {
     }
 
     pub fn setup_interpreter<'local>(&'local mut self) -> Interpreter<'local, 'context> {
-        let current_function = match self.current_item {
+        let current_function = self.current_function();
+        Interpreter::new(self, self.crate_id, current_function)
+    }
+
+    pub(super) fn current_function(&self) -> Option<FuncId> {
+        match self.current_item {
             Some(DependencyId::Function(function)) => Some(function),
             _ => None,
-        };
-        Interpreter::new(self, self.crate_id, current_function)
+        }
     }
 
     pub(super) fn debug_comptime<T: Display, F: FnMut(&mut NodeInterner) -> T>(
@@ -490,6 +522,27 @@ impl<'context> Elaborator<'context> {
         }
     }
 
+    /// Notifies the comptime debugger, if any, of the step about to be taken at `location`.
+    /// `kind` is only computed when a debugger is set.
+    pub(crate) fn debug_comptime_step(
+        &mut self,
+        location: Location,
+        function: Option<FuncId>,
+        kind: impl FnOnce(&NodeInterner) -> ComptimeStepKind,
+    ) {
+        // Taking the debugger out of the interner lets it borrow the interner while it runs
+        let Some(mut debugger) = self.interner.comptime_debugger.take() else {
+            return;
+        };
+
+        let kind = kind(self.interner);
+        let call_stack = &self.interpreter_call_stack;
+        let step = ComptimeStep::new(kind, location, call_stack, function, self.interner);
+        debugger.on_step(&step);
+
+        self.interner.comptime_debugger = Some(debugger);
+    }
+
     /// Run all the attributes on each item. The ordering is unspecified to users but currently
     /// we run trait attributes first to (e.g.) register derive handlers before derive is
     /// called on structs.
@@ -0,0 +1,699 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use iter_extended::vecmap;
+use noirc_errors::{Location, Span};
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::{
+    ast::{
+        Expression, FunctionKind, FunctionReturnType, Ident, ItemVisibility, MatchExpression,
+        UnresolvedType, UnresolvedTypeData, Visibility,
+    },
+    hir::{
+        def_collector::dc_crate::UnresolvedEnum, resolution::errors::ResolverError,
+        type_check::TypeCheckError,
+    },
+    hir_def::{
+        expr::{
+            Case, Constructor, HirBlockExpression, HirEnumConstructorExpression, HirExpression,
+            HirIdent, HirMatch,
+        },
+        function::{FuncMeta, FunctionBody, HirFunction, Parameters},
+        stmt::{HirLetStatement, HirPattern, HirStatement},
+    },
+    node_interner::{
+        DefinitionId, DefinitionKind, DependencyId, ExprId, FuncId, FunctionModifiers, ReferenceId,
+        StmtId, StructId,
+    },
+    token::Attributes,
+    EnumVariant, Shared, StructType, Type,
+};
+
+use super::Elaborator;
+
+/// A pattern of a `match` rule after it has been elaborated
+#[derive(Debug, Clone)]
+pub(super) enum Pattern {
+    /// A pattern checking for a constructor, e.g. `Some(x)`, `(a, b)` or `3`.
+    /// Integers are constructors too even though they can't all be enumerated.
+    Constructor(Constructor, Vec<Pattern>),
+
+    /// A pattern binding the value matched to a variable, e.g. `x`
+    Binding(DefinitionId),
+
+    /// A pattern matching anything without binding it: `_`.
+    /// Patterns that failed to elaborate are also wildcards to avoid further errors.
+    Wildcard,
+}
+
+/// A pattern that is yet to be checked against the value of `variable`
+#[derive(Debug, Clone)]
+struct Column {
+    variable: DefinitionId,
+    pattern: Pattern,
+}
+
+impl Column {
+    fn new(variable: DefinitionId, pattern: Pattern) -> Self {
+        Self { variable, pattern }
+    }
+}
+
+/// A single rule of a `match`, along with the part of its patterns still to be checked
+#[derive(Debug, Clone)]
+struct Row {
+    columns: Vec<Column>,
+
+    /// Pairs of variables bound by the patterns and the variables they are bound to
+    bindings: Vec<(DefinitionId, DefinitionId)>,
+
+    body: ExprId,
+
+    /// The index of this rule within the match, used to check which rules are reachable
+    rule_index: usize,
+}
+
+impl Row {
+    fn new(columns: Vec<Column>, body: ExprId, rule_index: usize) -> Self {
+        Self { columns, bindings: Vec::new(), body, rule_index }
+    }
+
+    fn remove_column(&mut self, variable: DefinitionId) -> Option<Column> {
+        let index = self.columns.iter().position(|column| column.variable == variable)?;
+        Some(self.columns.remove(index))
+    }
+
+    /// Bindings and wildcards always match so they can be removed from the columns,
+    /// remembering the variables they bind.
+    fn move_bindings_out_of_columns(&mut self) {
+        let columns = std::mem::take(&mut self.columns);
+
+        for column in columns {
+            match column.pattern {
+                Pattern::Binding(binding) => self.bindings.push((binding, column.variable)),
+                Pattern::Wildcard => (),
+                Pattern::Constructor(..) => self.columns.push(column),
+            }
+        }
+    }
+}
+
+impl Elaborator<'_> {
+    /// Resolves the variants of each enum and defines a function for each of them.
+    /// Each enum should already be present in the NodeInterner after def collection.
+    pub(super) fn collect_enum_definitions(&mut self, enums: &BTreeMap<StructId, UnresolvedEnum>) {
+        for (type_id, typ) in enums {
+            self.file = typ.file_id;
+            self.local_module = typ.module_id;
+
+            self.recover_generics(|this| {
+                this.current_item = Some(DependencyId::Struct(*type_id));
+                this.resolving_ids.insert(*type_id);
+
+                let enum_type = this.interner.get_struct(*type_id);
+                let generics = enum_type.borrow().generics.clone();
+                this.add_existing_generics(&typ.enum_def.generics, &generics);
+
+                let self_type_generics =
+                    vecmap(&generics, |generic| generic.clone().as_named_generic());
+                let self_type = Type::Struct(enum_type.clone(), self_type_generics);
+
+                let variants =
+                    vecmap(typ.enum_def.variants.iter().enumerate(), |(index, variant)| {
+                        let parameters = variant.item.parameters.clone();
+                        let is_function = parameters.is_some();
+                        let params =
+                            vecmap(parameters.unwrap_or_default(), |typ| this.resolve_type(typ));
+
+                        // Check that a public enum doesn't have a private type in one of its variants.
+                        if typ.enum_def.visibility != ItemVisibility::Private {
+                            let name = &variant.item.name;
+                            let ident =
+                                Ident::new(format!("{}::{}", typ.enum_def.name, name), name.span());
+                            for param in &params {
+                                this.check_type_is_not_more_private_then_item(
+                                    &ident,
+                                    typ.enum_def.visibility,
+                                    param,
+                                    name.span(),
+                                );
+                            }
+                        }
+
+                        this.define_enum_variant_function(
+                            &enum_type,
+                            &typ.enum_def.visibility,
+                            &self_type,
+                            &variant.item.name,
+                            &params,
+                            index,
+                            variant.doc_comments.clone(),
+                        );
+
+                        EnumVariant::new(variant.item.name.clone(), params, is_function)
+                    });
+
+                if typ.enum_def.is_abi() {
+                    for variant in &variants {
+                        for param in &variant.params {
+                            this.mark_type_as_used(param);
+                        }
+                    }
+                }
+
+                this.interner.update_struct(*type_id, |enum_def| enum_def.set_variants(variants));
+                this.resolving_ids.remove(type_id);
+            });
+        }
+    }
+
+    /// Defines the function constructing the given enum variant, e.g. `Option::Some`,
+    /// and declares it within the enum's module so that it can be referred to by path.
+    /// Unit variants are functions without parameters which are called implicitly
+    /// when they're referred to.
+    #[allow(clippy::too_many_arguments)]
+    fn define_enum_variant_function(
+        &mut self,
+        enum_type: &Shared<StructType>,
+        visibility: &ItemVisibility,
+        self_type: &Type,
+        name: &Ident,
+        params: &[Type],
+        variant_index: usize,
+        doc_comments: Vec<String>,
+    ) {
+        let type_id = enum_type.borrow().id;
+        let location = Location::new(name.span(), self.file);
+        let module_id = type_id.module_id();
+
+        let func_id = self.interner.push_empty_fn();
+        let modifiers = FunctionModifiers {
+            name: name.0.contents.clone(),
+            visibility: *visibility,
+            attributes: Attributes::empty(),
+            is_unconstrained: false,
+            generic_count: self.generics.len(),
+            is_comptime: false,
+            name_location: location,
+        };
+        let definition_id =
+            self.interner.push_function_definition(func_id, modifiers, module_id, location);
+        self.interner.add_definition_location(ReferenceId::Function(func_id), Some(module_id));
+        self.interner.set_doc_comments(ReferenceId::Function(func_id), doc_comments);
+
+        let module = Self::get_module_mut(self.def_maps, module_id);
+        if let Err((first_def, second_def)) =
+            module.declare_function(name.clone(), *visibility, func_id)
+        {
+            self.push_err(ResolverError::DuplicateDefinition {
+                name: name.to_string(),
+                first_span: first_def.span(),
+                second_span: second_def.span(),
+            });
+        }
+
+        let mut parameters = Vec::with_capacity(params.len());
+        let mut parameter_idents = Vec::with_capacity(params.len());
+        let mut arguments = Vec::with_capacity(params.len());
+
+        for (index, typ) in params.iter().enumerate() {
+            let name = format!("${index}");
+            let id = self.interner.push_definition(
+                name,
+                false,
+                false,
+                DefinitionKind::Local(None),
+                location,
+            );
+            self.interner.push_definition_type(id, typ.clone());
+
+            let ident = HirIdent::non_trait_method(id, location);
+            parameters.push((
+                HirPattern::Identifier(ident.clone()),
+                typ.clone(),
+                Visibility::Private,
+            ));
+            parameter_idents.push(ident.clone());
+
+            let argument = self.interner.push_expr(HirExpression::Ident(ident, None));
+            self.interner.push_expr_type(argument, typ.clone());
+            self.interner.push_expr_location(argument, location.span, location.file);
+            arguments.push(argument);
+        }
+
+        let constructor =
+            HirEnumConstructorExpression { r#type: enum_type.clone(), variant_index, arguments };
+        let body = self.interner.push_expr(HirExpression::EnumConstructor(constructor));
+        self.interner.push_expr_type(body, self_type.clone());
+        self.interner.push_expr_location(body, location.span, location.file);
+        self.interner.update_fn(func_id, HirFunction::unchecked_from_expr(body));
+
+        let function_type = Type::Function(
+            params.to_vec(),
+            Box::new(self_type.clone()),
+            Box::new(Type::Unit),
+            false,
+        );
+        let generics = vecmap(&self.generics, |generic| generic.type_var.clone());
+        let typ = if generics.is_empty() {
+            function_type
+        } else {
+            Type::Forall(generics, Box::new(function_type))
+        };
+        self.interner.push_definition_type(definition_id, typ.clone());
+
+        let quoted_self_type = self.interner.push_quoted_type(self_type.clone());
+        let return_type = FunctionReturnType::Ty(UnresolvedType {
+            typ: UnresolvedTypeData::Resolved(quoted_self_type),
+            span: name.span(),
+        });
+
+        let meta = FuncMeta {
+            name: HirIdent::non_trait_method(definition_id, location),
+            kind: FunctionKind::Normal,
+            parameters: Parameters(parameters),
+            parameter_idents,
+            return_type,
+            return_visibility: Visibility::Private,
+            typ,
+            direct_generics: Vec::new(),
+            all_generics: self.generics.clone(),
+            location,
+            has_body: true,
+            trait_constraints: Vec::new(),
+            struct_id: Some(type_id),
+            trait_id: None,
+            trait_impl: None,
+            is_entry_point: false,
+            has_inline_attribute: false,
+            function_body: FunctionBody::Resolved,
+            source_crate: self.crate_id,
+            source_module: self.local_module,
+            source_file: self.file,
+            self_type: Some(self_type.clone()),
+            enum_variant_index: Some(variant_index),
+        };
+        self.interner.push_fn_meta(meta, func_id);
+    }
+
+    /// If `func_id` is the function of a unit enum variant, e.g. `Option::None`,
+    /// returns the expression constructing that variant, since unit variants are
+    /// values rather than functions.
+    pub(super) fn unit_variant_constructor(&self, func_id: FuncId) -> Option<HirExpression> {
+        let meta = self.interner.function_meta(&func_id);
+        let variant_index = meta.enum_variant_index?;
+        let enum_type = self.interner.get_struct(meta.struct_id?);
+
+        if enum_type.borrow().variant_at(variant_index).is_function {
+            return None;
+        }
+
+        Some(HirExpression::EnumConstructor(HirEnumConstructorExpression {
+            r#type: enum_type,
+            variant_index,
+            arguments: Vec::new(),
+        }))
+    }
+
+    /// Elaborates a `match` by compiling its rules into a decision tree, reporting
+    /// any values not covered by the rules and any rules that can never be reached.
+    ///
+    /// The matched expression is first bound to a variable so that the decision tree
+    /// can refer to it, and to the values inside it, without evaluating it twice.
+    pub(super) fn elaborate_match(
+        &mut self,
+        match_expr: MatchExpression,
+        span: Span,
+    ) -> (HirExpression, Type) {
+        let (expression, typ) = self.elaborate_expression(match_expr.expression);
+        let (let_, variable) = self.wrap_in_let(expression, typ.clone());
+
+        let (rows, rule_spans, result_type) =
+            self.elaborate_match_rules(variable, match_expr.rules, &typ);
+
+        let mut reachable_rules = BTreeSet::new();
+        let tree = self.compile_rows(rows, &mut reachable_rules);
+
+        let mut missing_cases = BTreeSet::new();
+        self.find_missing_cases(&tree, &mut HashMap::default(), &mut missing_cases, variable);
+        if !missing_cases.is_empty() {
+            self.push_err(TypeCheckError::MissingCases { cases: missing_cases, span });
+        }
+
+        for (index, span) in rule_spans.into_iter().enumerate() {
+            if !reachable_rules.contains(&index) {
+                self.push_err(TypeCheckError::UnreachableCase { span });
+            }
+        }
+
+        let tree = self.interner.push_expr(HirExpression::Match(tree));
+        self.interner.push_expr_type(tree, result_type.clone());
+        self.interner.push_expr_location(tree, span, self.file);
+
+        let tree = self.interner.push_stmt(HirStatement::Expression(tree));
+        self.interner.push_stmt_location(tree, span, self.file);
+
+        let block = HirExpression::Block(HirBlockExpression { statements: vec![let_, tree] });
+        (block, result_type)
+    }
+
+    /// Binds the given expression to a new variable, returning the `let` statement
+    /// defining it and the variable.
+    fn wrap_in_let(&mut self, expression: ExprId, typ: Type) -> (StmtId, DefinitionId) {
+        let location = self.interner.expr_location(&expression);
+        let variable = self.fresh_match_variable(typ.clone(), location);
+
+        let pattern = HirPattern::Identifier(HirIdent::non_trait_method(variable, location));
+        let let_ = HirLetStatement {
+            pattern,
+            r#type: typ,
+            expression,
+            attributes: Vec::new(),
+            comptime: false,
+        };
+
+        let let_ = self.interner.push_stmt(HirStatement::Let(let_));
+        self.interner.push_stmt_location(let_, location.span, location.file);
+        (let_, variable)
+    }
+
+    /// Creates a variable that isn't visible from the source program, holding
+    /// the matched value or a value within it.
+    fn fresh_match_variable(&mut self, typ: Type, location: Location) -> DefinitionId {
+        let name = "internal_match_variable".to_string();
+        let kind = DefinitionKind::Local(None);
+        let id = self.interner.push_definition(name, false, false, kind, location);
+        self.interner.push_definition_type(id, typ);
+        id
+    }
+
+    /// Elaborates the pattern and branch of each rule, returning a row for each rule,
+    /// the span of each rule's pattern, and the type of the match expression.
+    fn elaborate_match_rules(
+        &mut self,
+        variable_to_match: DefinitionId,
+        rules: Vec<(Expression, Expression)>,
+        typ: &Type,
+    ) -> (Vec<Row>, Vec<Span>, Type) {
+        let result_type = self.interner.next_type_variable();
+        let mut rows = Vec::with_capacity(rules.len());
+        let mut rule_spans = Vec::with_capacity(rules.len());
+
+        for (index, (pattern, branch)) in rules.into_iter().enumerate() {
+            // Each rule has its own scope for the variables bound by its pattern
+            self.push_scope();
+
+            rule_spans.push(pattern.span);
+            let pattern = self.elaborate_match_pattern(pattern, typ.clone());
+            let columns = vec![Column::new(variable_to_match, pattern)];
+
+            let expr_span = branch.span;
+            let (body, body_type) = self.elaborate_expression(branch);
+
+            self.unify(&body_type, &result_type, || TypeCheckError::TypeMismatch {
+                expected_typ: result_type.to_string(),
+                expr_typ: body_type.to_string(),
+                expr_span,
+            });
+
+            self.pop_scope();
+            rows.push(Row::new(columns, body, index));
+        }
+
+        (rows, rule_spans, result_type)
+    }
+
+    /// Compiles the given rows into a decision tree, marking the rules reached
+    /// by any path of the tree in `reachable_rules`.
+    ///
+    /// This follows the algorithm described in "How to compile pattern matching"
+    /// by Jules Jacobs: each step tests a single variable against every constructor
+    /// of its type, splitting the rows among the constructors.
+    fn compile_rows(
+        &mut self,
+        mut rows: Vec<Row>,
+        reachable_rules: &mut BTreeSet<usize>,
+    ) -> HirMatch {
+        if rows.is_empty() {
+            return HirMatch::Failure;
+        }
+
+        for row in &mut rows {
+            row.move_bindings_out_of_columns();
+        }
+
+        // If the first row has nothing left to check, it always matches and
+        // the rows after it are unreachable from here.
+        if rows[0].columns.is_empty() {
+            let row = rows.remove(0);
+            reachable_rules.insert(row.rule_index);
+            return HirMatch::Success(self.bind_pattern_variables(row));
+        }
+
+        let branch_variable = rows[0].columns[0].variable;
+        let Pattern::Constructor(constructor, _) = &rows[0].columns[0].pattern else {
+            unreachable!("Bindings and wildcards should have been removed from the columns")
+        };
+        let location = self.interner.definition(branch_variable).location;
+
+        let cases = match constructor.clone() {
+            Constructor::Int(..) => {
+                return self.compile_int_cases(rows, branch_variable, reachable_rules)
+            }
+            Constructor::True | Constructor::False => vec![
+                (Constructor::False, Vec::new(), Vec::new()),
+                (Constructor::True, Vec::new(), Vec::new()),
+            ],
+            Constructor::Unit => vec![(Constructor::Unit, Vec::new(), Vec::new())],
+            Constructor::Tuple(types) => {
+                let arguments = self.fresh_match_variables(&types, location);
+                vec![(Constructor::Tuple(types), arguments, Vec::new())]
+            }
+            Constructor::Struct(typ) => {
+                let field_types = match typ.follow_bindings() {
+                    Type::Struct(struct_type, generics) => {
+                        vecmap(struct_type.borrow().get_fields(&generics), |(_, typ)| typ)
+                    }
+                    _ => Vec::new(),
+                };
+                let arguments = self.fresh_match_variables(&field_types, location);
+                vec![(Constructor::Struct(typ), arguments, Vec::new())]
+            }
+            Constructor::Variant(typ, _) => {
+                let variants = match typ.follow_bindings() {
+                    Type::Struct(enum_type, generics) => enum_type.borrow().get_variants(&generics),
+                    _ => Vec::new(),
+                };
+                vecmap(variants.into_iter().enumerate(), |(index, (_, params))| {
+                    let arguments = self.fresh_match_variables(&params, location);
+                    (Constructor::Variant(typ.clone(), index), arguments, Vec::new())
+                })
+            }
+        };
+
+        self.compile_constructor_cases(rows, branch_variable, cases, reachable_rules)
+    }
+
+    /// Compiles the cases of a type whose values can all be enumerated by a constructor,
+    /// e.g. booleans or enums. `cases` holds the constructor, the variables of each of
+    /// the constructor's arguments, and an empty list of rows, for each possible constructor.
+    fn compile_constructor_cases(
+        &mut self,
+        rows: Vec<Row>,
+        branch_variable: DefinitionId,
+        mut cases: Vec<(Constructor, Vec<DefinitionId>, Vec<Row>)>,
+        reachable_rules: &mut BTreeSet<usize>,
+    ) -> HirMatch {
+        for mut row in rows {
+            let Some(column) = row.remove_column(branch_variable) else {
+                // This row matches every constructor of the variable
+                for (_, _, rows) in &mut cases {
+                    rows.push(row.clone());
+                }
+                continue;
+            };
+
+            let Pattern::Constructor(constructor, arguments) = column.pattern else {
+                unreachable!("Bindings and wildcards should have been removed from the columns")
+            };
+
+            // A constructor from another type can only be found here if there was a type
+            // error, in which case the row is ignored.
+            let Some((_, variables, rows)) = cases.get_mut(constructor.variant_index()) else {
+                continue;
+            };
+
+            for (variable, pattern) in variables.iter().zip(arguments) {
+                row.columns.push(Column::new(*variable, pattern));
+            }
+            rows.push(row);
+        }
+
+        let cases = vecmap(cases, |(constructor, arguments, rows)| {
+            Case::new(constructor, arguments, self.compile_rows(rows, reachable_rules))
+        });
+
+        HirMatch::Switch(branch_variable, cases, None)
+    }
+
+    /// Compiles the cases of an integer variable. Since integers can't be enumerated,
+    /// there is a case for each integer tested by a row and a fallback for any other value.
+    fn compile_int_cases(
+        &mut self,
+        rows: Vec<Row>,
+        branch_variable: DefinitionId,
+        reachable_rules: &mut BTreeSet<usize>,
+    ) -> HirMatch {
+        let mut raw_cases: Vec<(Constructor, Vec<Row>)> = Vec::new();
+        let mut fallback_rows = Vec::new();
+
+        for mut row in rows {
+            let Some(column) = row.remove_column(branch_variable) else {
+                for (_, rows) in &mut raw_cases {
+                    rows.push(row.clone());
+                }
+                fallback_rows.push(row);
+                continue;
+            };
+
+            let Pattern::Constructor(constructor @ Constructor::Int(..), _) = column.pattern else {
+                // Only possible if there was a type error
+                continue;
+            };
+
+            if let Some((_, rows)) = raw_cases.iter_mut().find(|(case, _)| *case == constructor) {
+                rows.push(row);
+            } else {
+                // Rows before this one that matched any integer also match this one
+                let mut rows = fallback_rows.clone();
+                rows.push(row);
+                raw_cases.push((constructor, rows));
+            }
+        }
+
+        let cases = vecmap(raw_cases, |(constructor, rows)| {
+            Case::new(constructor, Vec::new(), self.compile_rows(rows, reachable_rules))
+        });
+        let fallback = self.compile_rows(fallback_rows, reachable_rules);
+
+        HirMatch::Switch(branch_variable, cases, Some(Box::new(fallback)))
+    }
+
+    fn fresh_match_variables(&mut self, types: &[Type], location: Location) -> Vec<DefinitionId> {
+        vecmap(types, |typ| self.fresh_match_variable(typ.clone(), location))
+    }
+
+    /// Returns the body of the given row, preceded by a `let` for each variable
+    /// bound by the row's patterns.
+    fn bind_pattern_variables(&mut self, row: Row) -> ExprId {
+        if row.bindings.is_empty() {
+            return row.body;
+        }
+
+        let location = self.interner.expr_location(&row.body);
+
+        let mut statements = vecmap(row.bindings, |(binding, variable)| {
+            let typ = self.interner.definition_type(binding);
+
+            let ident = HirIdent::non_trait_method(variable, location);
+            let expression = self.interner.push_expr(HirExpression::Ident(ident, None));
+            self.interner.push_expr_type(expression, typ.clone());
+            self.interner.push_expr_location(expression, location.span, location.file);
+
+            let pattern = HirPattern::Identifier(HirIdent::non_trait_method(binding, location));
+            let let_ = HirLetStatement {
+                pattern,
+                r#type: typ,
+                expression,
+                attributes: Vec::new(),
+                comptime: false,
+            };
+
+            let let_ = self.interner.push_stmt(HirStatement::Let(let_));
+            self.interner.push_stmt_location(let_, location.span, location.file);
+            let_
+        });
+
+        let body = self.interner.push_stmt(HirStatement::Expression(row.body));
+        self.interner.push_stmt_location(body, location.span, location.file);
+        statements.push(body);
+
+        let block = HirExpression::Block(HirBlockExpression { statements });
+        let block = self.interner.push_expr(block);
+        self.interner.push_expr_type(block, self.interner.id_type(row.body));
+        self.interner.push_expr_location(block, location.span, location.file);
+        block
+    }
+
+    /// Walks the decision tree, collecting an example of a value reaching each `Failure`.
+    /// `env` holds the constructor each variable was matched against on the current path.
+    fn find_missing_cases(
+        &self,
+        tree: &HirMatch,
+        env: &mut HashMap<DefinitionId, (Constructor, Vec<DefinitionId>)>,
+        missing_cases: &mut BTreeSet<String>,
+        starting_variable: DefinitionId,
+    ) {
+        match tree {
+            HirMatch::Success(_) => (),
+            HirMatch::Failure => {
+                missing_cases.insert(self.missing_case(starting_variable, env));
+            }
+            HirMatch::Switch(variable, cases, fallback) => {
+                for case in cases {
+                    let constructor = (case.constructor.clone(), case.arguments.clone());
+                    env.insert(*variable, constructor);
+                    self.find_missing_cases(&case.body, env, missing_cases, starting_variable);
+                }
+
+                // A variable that isn't in `env` is displayed as `_`
+                env.remove(variable);
+                if let Some(fallback) = fallback {
+                    self.find_missing_cases(fallback, env, missing_cases, starting_variable);
+                }
+            }
+        }
+    }
+
+    fn missing_case(
+        &self,
+        variable: DefinitionId,
+        env: &HashMap<DefinitionId, (Constructor, Vec<DefinitionId>)>,
+    ) -> String {
+        let Some((constructor, arguments)) = env.get(&variable) else {
+            return "_".to_string();
+        };
+
+        let arguments = vecmap(arguments, |argument| self.missing_case(*argument, env));
+
+        match constructor {
+            Constructor::True => "true".to_string(),
+            Constructor::False => "false".to_string(),
+            Constructor::Unit => "()".to_string(),
+            Constructor::Int(value, false) => value.to_string(),
+            Constructor::Int(value, true) => format!("-{value}"),
+            Constructor::Tuple(_) => format!("({})", arguments.join(", ")),
+            Constructor::Struct(typ) => {
+                let Type::Struct(struct_type, generics) = typ.follow_bindings() else {
+                    return "_".to_string();
+                };
+                let struct_type = struct_type.borrow();
+                let fields = struct_type.get_fields(&generics).into_iter().zip(arguments);
+                let fields = vecmap(fields, |((name, _), argument)| format!("{name}: {argument}"));
+                format!("{} {{ {} }}", struct_type.name, fields.join(", "))
+            }
+            Constructor::Variant(typ, index) => {
+                let Type::Struct(enum_type, _) = typ.follow_bindings() else {
+                    return "_".to_string();
+                };
+                let enum_type = enum_type.borrow();
+                let variant = enum_type.variant_at(*index);
+                if variant.is_function {
+                    format!("{}::{}({})", enum_type.name, variant.name, arguments.join(", "))
+                } else {
+                    format!("{}::{}", enum_type.name, variant.name)
+                }
+            }
+        }
+    }
+}
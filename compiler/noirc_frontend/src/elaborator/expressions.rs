@@ -12,7 +12,7 @@ use crate::{
         PrefixExpression, StatementKind, UnaryOp, UnresolvedTypeData, UnresolvedTypeExpression,
     },
     hir::{
-        comptime::{self, InterpreterError},
+        comptime::{self, ComptimeStepKind, InterpreterError},
         resolution::{
             errors::ResolverError, import::PathResolutionError, visibility::method_call_is_visible,
         },
@@ -51,6 +51,7 @@ impl<'context> Elaborator<'context> {
             ExpressionKind::Cast(cast) => self.elaborate_cast(*cast, expr.span),
             ExpressionKind::Infix(infix) => return self.elaborate_infix(*infix, expr.span),
             ExpressionKind::If(if_) => self.elaborate_if(*if_),
+            ExpressionKind::Match(match_) => self.elaborate_match(*match_, expr.span),
             ExpressionKind::Variable(variable) => return self.elaborate_variable(variable),
             ExpressionKind::Tuple(tuple) => self.elaborate_tuple(tuple),
             ExpressionKind::Lambda(lambda) => self.elaborate_lambda(*lambda),
@@ -545,7 +546,9 @@ impl<'context> Elaborator<'context> {
             (typ, generics)
         } else {
             match self.lookup_type_or_error(path) {
-                Some(Type::Struct(r#type, struct_generics)) => (r#type, struct_generics),
+                Some(Type::Struct(r#type, struct_generics)) if !r#type.borrow().is_enum() => {
+                    (r#type, struct_generics)
+                }
                 Some(typ) => {
                     self.push_err(ResolverError::NonStructUsedInConstructor {
                         typ: typ.to_string(),
@@ -823,10 +826,17 @@ impl<'context> Elaborator<'context> {
     }
 
     fn elaborate_lambda(&mut self, lambda: Lambda) -> (HirExpression, Type) {
+        let return_type = self.resolve_inferred_type(lambda.return_type);
+
         self.push_scope();
         let scope_index = self.scopes.current_scope_index();
 
-        self.lambda_stack.push(LambdaContext { captures: Vec::new(), scope_index });
+        self.lambda_stack.push(LambdaContext {
+            captures: Vec::new(),
+            scope_index,
+            return_type: return_type.clone(),
+            in_comptime_context: self.in_comptime_context,
+        });
 
         let mut arg_types = Vec::with_capacity(lambda.parameters.len());
         let parameters = vecmap(lambda.parameters, |(pattern, typ)| {
@@ -836,7 +846,6 @@ impl<'context> Elaborator<'context> {
             (self.elaborate_pattern(pattern, typ.clone(), parameter, true), typ)
         });
 
-        let return_type = self.resolve_inferred_type(lambda.return_type);
         let body_span = lambda.body.span;
         let (body, body_type) = self.elaborate_expression(lambda.body);
 
@@ -907,7 +916,13 @@ impl<'context> Elaborator<'context> {
 
         let location = Location::new(span, self.file);
         match value.into_expression(self.interner, location) {
-            Ok(new_expr) => self.elaborate_expression(new_expr),
+            Ok(new_expr) => {
+                let function = self.current_function();
+                self.debug_comptime_step(location, function, |_| {
+                    ComptimeStepKind::Expansion(new_expr.to_string())
+                });
+                self.elaborate_expression(new_expr)
+            }
             Err(error) => make_error(self, error),
         }
     }
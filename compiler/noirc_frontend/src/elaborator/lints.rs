@@ -6,7 +6,7 @@ use crate::{
         type_check::TypeCheckError,
     },
     hir_def::{
-        expr::{HirBlockExpression, HirExpression, HirIdent, HirLiteral},
+        expr::{HirBlockExpression, HirExpression, HirIdent, HirLiteral, HirMatch},
         function::FuncMeta,
         stmt::HirStatement,
     },
@@ -217,7 +217,7 @@ pub(crate) fn overflowing_int(
         HirExpression::Literal(HirLiteral::Integer(value, negative)) => match annotated_type {
             Type::Integer(Signedness::Unsigned, bit_count) => {
                 let bit_count: u32 = (*bit_count).into();
-                let max = 2u128.pow(bit_count) - 1;
+                let max = u128::MAX >> (128 - bit_count);
                 if value > max.into() || negative {
                     errors.push(TypeCheckError::OverflowingAssignment {
                         expr: if negative { -value } else { value },
@@ -293,8 +293,14 @@ fn can_return_without_recursing(interner: &NodeInterner, func_id: FuncId, expr_i
             HirStatement::Assign(s) => check(s.expression),
             HirStatement::Expression(e) => check(e),
             HirStatement::Semi(e) => check(e),
+            // Functions with early returns are never checked, so this only occurs in lambdas,
+            // whose bodies aren't checked either.
+            HirStatement::Return(_) => true,
             // Rust doesn't seem to check the for loop body (it's bounds might mean it's never called).
             HirStatement::For(e) => check(e.start_range) && check(e.end_range),
+            HirStatement::While(e) => check(e.condition),
+            // The body of a `loop` is always entered at least once.
+            HirStatement::Loop(e) => check(e),
             HirStatement::Constrain(_)
             | HirStatement::Comptime(_)
             | HirStatement::Break
@@ -326,6 +332,8 @@ fn can_return_without_recursing(interner: &NodeInterner, func_id: FuncId, expr_i
         HirExpression::If(e) => {
             check(e.condition) && (check(e.consequence) || e.alternative.map(check).unwrap_or(true))
         }
+        HirExpression::Match(e) => can_match_return_without_recursing(interner, func_id, &e),
+        HirExpression::EnumConstructor(e) => e.arguments.iter().cloned().all(check),
         HirExpression::Tuple(e) => e.iter().cloned().all(check),
         HirExpression::Unsafe(b) => check_block(b),
         // Rust doesn't check the lambda body (it might not be called).
@@ -338,3 +346,22 @@ fn can_return_without_recursing(interner: &NodeInterner, func_id: FuncId, expr_i
         | HirExpression::Error => true,
     }
 }
+
+/// Check if any branch of a match can return without calling a specific function.
+fn can_match_return_without_recursing(
+    interner: &NodeInterner,
+    func_id: FuncId,
+    tree: &HirMatch,
+) -> bool {
+    let check = |tree| can_match_return_without_recursing(interner, func_id, tree);
+
+    match tree {
+        HirMatch::Success(expr_id) => can_return_without_recursing(interner, func_id, *expr_id),
+        HirMatch::Failure => true,
+        HirMatch::Switch(_, cases, fallback) => {
+            (cases.is_empty() && fallback.is_none())
+                || cases.iter().any(|case| check(&case.body))
+                || fallback.as_deref().map_or(false, check)
+        }
+    }
+}
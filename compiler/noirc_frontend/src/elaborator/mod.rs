@@ -43,6 +43,7 @@ use crate::{
 };
 
 mod comptime;
+mod enums;
 mod expressions;
 mod lints;
 mod path_resolution;
@@ -77,6 +78,10 @@ pub struct LambdaContext {
     /// the index in the scope tree
     /// (sometimes being filled by ScopeTree's find method)
     pub scope_index: usize,
+    /// The declared (or inferred) return type of the lambda, used to type check `return`s
+    pub return_type: Type,
+    /// Whether the lambda was created inside a comptime block, global or attribute
+    pub in_comptime_context: bool,
 }
 
 pub struct Elaborator<'context> {
@@ -153,6 +158,10 @@ pub struct Elaborator<'context> {
     /// block, global, or attribute.
     in_comptime_context: bool,
 
+    /// True if the function currently being elaborated contains an early `return`
+    /// (not counting those within lambdas).
+    has_early_return: bool,
+
     crate_id: CrateId,
 
     /// The scope of --debug-comptime, or None if unset
@@ -213,6 +222,7 @@ impl<'context> Elaborator<'context> {
             current_trait: None,
             interpreter_call_stack,
             in_comptime_context: false,
+            has_early_return: false,
         }
     }
 
@@ -273,6 +283,7 @@ impl<'context> Elaborator<'context> {
 
         // Must resolve structs before we resolve globals.
         self.collect_struct_definitions(&items.types);
+        self.collect_enum_definitions(&items.enums);
 
         self.define_function_metas(&mut items.functions, &mut items.impls, &mut items.trait_impls);
 
@@ -400,6 +411,7 @@ impl<'context> Elaborator<'context> {
 
         self.trait_bounds = func_meta.trait_constraints.clone();
         self.function_context.push(FunctionContext::default());
+        self.has_early_return = false;
 
         let modifiers = self.interner.function_modifiers(&id).clone();
 
@@ -476,7 +488,9 @@ impl<'context> Elaborator<'context> {
         }
 
         // Check that the body can return without calling the function.
-        if let FunctionKind::Normal | FunctionKind::Recursive = kind {
+        // This doesn't follow early returns, so functions containing them are skipped.
+        if matches!(kind, FunctionKind::Normal | FunctionKind::Recursive) && !self.has_early_return
+        {
             self.run_lint(|elaborator| {
                 lints::unbounded_recursion(
                     elaborator.interner,
@@ -753,6 +767,11 @@ impl<'context> Elaborator<'context> {
 
         let location = Location::new(func.name_ident().span(), self.file);
         let id = self.interner.function_definition_id(func_id);
+
+        // Lint levels set on the function apply to its signature as well as its body
+        let function_span = func.name_ident().span().merge(func.def.span);
+        let function_location = Location::new(function_span, self.file);
+        self.interner.lint_scopes.add_function(function_location, &func.def.attributes.secondary);
         let name_ident = HirIdent::non_trait_method(id, location);
 
         let is_entry_point = self.is_entry_point_function(func, in_contract);
@@ -862,6 +881,7 @@ impl<'context> Elaborator<'context> {
             function_body: FunctionBody::Unresolved(func.kind, body, func.def.span),
             self_type: self.self_type.clone(),
             source_file: self.file,
+            enum_variant_index: None,
         };
 
         self.interner.push_fn_meta(meta, func_id);
@@ -883,7 +903,7 @@ impl<'context> Elaborator<'context> {
                 for generic in generics {
                     self.mark_type_as_used(generic);
                 }
-                for (_, typ) in struct_type.borrow().get_fields(generics) {
+                for typ in struct_type.borrow().get_field_and_variant_types(generics) {
                     self.mark_type_as_used(&typ);
                 }
             }
@@ -4,8 +4,8 @@ use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
     ast::{
-        Expression, ExpressionKind, Ident, ItemVisibility, Path, Pattern, TypePath, UnresolvedType,
-        ERROR_IDENT,
+        ConstructorExpression, Expression, ExpressionKind, Ident, ItemVisibility, Literal, Path,
+        Pattern, TypePath, UnaryOp, UnresolvedType, UnresolvedTypeData, ERROR_IDENT,
     },
     hir::{
         def_collector::dc_crate::CompilationError,
@@ -13,14 +13,16 @@ use crate::{
         type_check::{Source, TypeCheckError},
     },
     hir_def::{
-        expr::{HirExpression, HirIdent, HirMethodReference, ImplKind, TraitMethod},
+        expr::{Constructor, HirExpression, HirIdent, HirMethodReference, ImplKind, TraitMethod},
         stmt::HirPattern,
     },
     node_interner::{DefinitionId, DefinitionKind, ExprId, FuncId, GlobalId, TraitImplKind},
     Kind, Shared, StructType, Type, TypeAlias, TypeBindings,
 };
 
-use super::{path_resolution::PathResolutionItem, Elaborator, ResolverMeta};
+use super::{
+    enums::Pattern as MatchPattern, path_resolution::PathResolutionItem, Elaborator, ResolverMeta,
+};
 
 impl<'context> Elaborator<'context> {
     pub(super) fn elaborate_pattern(
@@ -310,6 +312,283 @@ impl<'context> Elaborator<'context> {
         ret
     }
 
+    /// Elaborates the pattern of a `match` rule. These patterns are parsed as expressions
+    /// since a pattern like `Foo::Bar(x)` can't be told apart from a call until its path
+    /// is resolved. Any variables bound by the pattern are added to the current scope.
+    pub(super) fn elaborate_match_pattern(
+        &mut self,
+        pattern: Expression,
+        expected_type: Type,
+    ) -> MatchPattern {
+        let span = pattern.span;
+        match pattern.kind {
+            ExpressionKind::Literal(Literal::Integer(value, negative)) => {
+                let actual_type = self.polymorphic_integer_or_field();
+                self.unify_pattern_type(&actual_type, &expected_type, span);
+                MatchPattern::Constructor(Constructor::Int(value, negative), Vec::new())
+            }
+            ExpressionKind::Literal(Literal::Bool(value)) => {
+                self.unify_pattern_type(&Type::Bool, &expected_type, span);
+                let constructor = if value { Constructor::True } else { Constructor::False };
+                MatchPattern::Constructor(constructor, Vec::new())
+            }
+            ExpressionKind::Literal(Literal::Unit) => {
+                self.unify_pattern_type(&Type::Unit, &expected_type, span);
+                MatchPattern::Constructor(Constructor::Unit, Vec::new())
+            }
+            ExpressionKind::Prefix(prefix) if prefix.operator == UnaryOp::Minus => {
+                match prefix.rhs.kind {
+                    ExpressionKind::Literal(Literal::Integer(value, negative)) => {
+                        let literal = Literal::Integer(value, !negative);
+                        let pattern = Expression::new(ExpressionKind::Literal(literal), span);
+                        self.elaborate_match_pattern(pattern, expected_type)
+                    }
+                    _ => {
+                        self.push_err(ResolverError::InvalidPattern { span });
+                        MatchPattern::Wildcard
+                    }
+                }
+            }
+            ExpressionKind::Variable(path) => self.elaborate_path_pattern(path, expected_type),
+            ExpressionKind::Call(call) => {
+                let ExpressionKind::Variable(path) = call.func.kind else {
+                    self.push_err(ResolverError::InvalidPattern { span });
+                    return MatchPattern::Wildcard;
+                };
+
+                let Some((func_id, item)) = self.resolve_enum_variant(&path) else {
+                    let name = path.to_string();
+                    self.push_err(ResolverError::NotAnEnumVariant { name, span: path.span });
+                    return MatchPattern::Wildcard;
+                };
+
+                self.elaborate_variant_pattern(
+                    func_id,
+                    item,
+                    path,
+                    Some(call.arguments),
+                    expected_type,
+                )
+            }
+            ExpressionKind::Tuple(fields) => {
+                let field_types = vecmap(&fields, |_| self.interner.next_type_variable());
+                let actual_type = Type::Tuple(field_types.clone());
+                self.unify_pattern_type(&actual_type, &expected_type, span);
+
+                let fields = fields.into_iter().zip(field_types.iter().cloned());
+                let fields =
+                    vecmap(fields, |(field, typ)| self.elaborate_match_pattern(field, typ));
+                MatchPattern::Constructor(Constructor::Tuple(field_types), fields)
+            }
+            ExpressionKind::Constructor(constructor) => {
+                self.elaborate_struct_match_pattern(*constructor, expected_type, span)
+            }
+            ExpressionKind::Parenthesized(pattern) => {
+                self.elaborate_match_pattern(*pattern, expected_type)
+            }
+            ExpressionKind::Interned(id) => {
+                let kind = self.interner.get_expression_kind(id).clone();
+                self.elaborate_match_pattern(Expression::new(kind, span), expected_type)
+            }
+            ExpressionKind::Error => MatchPattern::Wildcard,
+            _ => {
+                self.push_err(ResolverError::InvalidPattern { span });
+                MatchPattern::Wildcard
+            }
+        }
+    }
+
+    /// A path in a pattern is either a unit enum variant like `Option::None`,
+    /// a new variable binding the matched value, or `_`.
+    fn elaborate_path_pattern(&mut self, path: Path, expected_type: Type) -> MatchPattern {
+        if let Some((func_id, item)) = self.resolve_enum_variant(&path) {
+            return self.elaborate_variant_pattern(func_id, item, path, None, expected_type);
+        }
+
+        let Some(name) = path.as_ident() else {
+            let name = path.to_string();
+            self.push_err(ResolverError::NotAnEnumVariant { name, span: path.span });
+            return MatchPattern::Wildcard;
+        };
+
+        if name.0.contents == "_" {
+            return MatchPattern::Wildcard;
+        }
+
+        let ident = self.add_variable_decl(
+            name.clone(),
+            false, // mutable
+            false, // allow_shadowing
+            true,  // warn_if_unused
+            DefinitionKind::Local(None),
+        );
+        self.interner.push_definition_type(ident.id, expected_type);
+        MatchPattern::Binding(ident.id)
+    }
+
+    /// Returns the function of the enum variant the given path refers to, if any.
+    fn resolve_enum_variant(&mut self, path: &Path) -> Option<(FuncId, PathResolutionItem)> {
+        let resolution = self.resolve_path(path.clone()).ok()?;
+        let func_id = resolution.item.function_id()?;
+        self.interner.function_meta(&func_id).enum_variant_index?;
+
+        for error in resolution.errors {
+            self.push_err(error);
+        }
+        Some((func_id, resolution.item))
+    }
+
+    /// Elaborates a pattern for an enum variant. `arguments` is `None` for patterns
+    /// without parentheses, which is only allowed for unit variants.
+    fn elaborate_variant_pattern(
+        &mut self,
+        func_id: FuncId,
+        item: PathResolutionItem,
+        path: Path,
+        arguments: Option<Vec<Expression>>,
+        expected_type: Type,
+    ) -> MatchPattern {
+        let meta = self.interner.function_meta(&func_id);
+        let variant_index = meta.enum_variant_index.expect("Expected an enum variant function");
+        let enum_id = meta.struct_id.expect("Expected an enum variant to belong to an enum");
+        let enum_type = self.interner.get_struct(enum_id);
+
+        let span = path.span;
+        let location = Location::new(path.last_ident().span(), self.file);
+        self.interner.add_function_reference(func_id, location);
+
+        let mut generics = self.resolve_item_turbofish(item);
+        if generics.is_empty() {
+            generics = enum_type.borrow().instantiate(self.interner);
+        }
+
+        let actual_type = Type::Struct(enum_type.clone(), generics.clone());
+        self.unify_pattern_type(&actual_type, &expected_type, span);
+
+        let is_function = enum_type.borrow().variant_at(variant_index).is_function;
+        let (_, params) = enum_type.borrow().get_variants(&generics).swap_remove(variant_index);
+
+        let found = arguments.as_ref().map_or(0, Vec::len);
+        if is_function != arguments.is_some() || found != params.len() {
+            self.push_err(TypeCheckError::ArityMisMatch { expected: params.len(), found, span });
+        }
+
+        let mut arguments = arguments.unwrap_or_default().into_iter();
+        let arguments = vecmap(params, |param| match arguments.next() {
+            Some(argument) => self.elaborate_match_pattern(argument, param),
+            None => MatchPattern::Wildcard,
+        });
+
+        MatchPattern::Constructor(Constructor::Variant(actual_type, variant_index), arguments)
+    }
+
+    /// Elaborates a struct pattern like `Foo { a, b: 3 }` within a `match`.
+    /// The field patterns are returned in the order the fields are declared in.
+    fn elaborate_struct_match_pattern(
+        &mut self,
+        constructor: ConstructorExpression,
+        expected_type: Type,
+        span: Span,
+    ) -> MatchPattern {
+        let mut typ = constructor.typ.typ;
+        if let UnresolvedTypeData::Interned(id) = typ {
+            typ = self.interner.get_unresolved_type_data(id).clone();
+        }
+        let UnresolvedTypeData::Named(path, generics, _) = typ else {
+            self.push_err(ResolverError::NonStructUsedInConstructor { typ: typ.to_string(), span });
+            return MatchPattern::Wildcard;
+        };
+
+        let last_segment = path.last_segment();
+        let is_self_type = last_segment.ident.is_self_type_name();
+
+        let (struct_type, struct_generics) = if let Some(struct_id) = constructor.struct_type {
+            let typ = self.interner.get_struct(struct_id);
+            let generics = typ.borrow().instantiate(self.interner);
+            (typ, generics)
+        } else {
+            match self.lookup_type_or_error(path) {
+                Some(Type::Struct(struct_type, generics)) if !struct_type.borrow().is_enum() => {
+                    (struct_type, generics)
+                }
+                Some(typ) => {
+                    let typ = typ.to_string();
+                    self.push_err(ResolverError::NonStructUsedInConstructor { typ, span });
+                    return MatchPattern::Wildcard;
+                }
+                None => return MatchPattern::Wildcard,
+            }
+        };
+
+        let turbofish = (!generics.ordered_args.is_empty()).then_some(generics.ordered_args);
+        let struct_generics = self.resolve_struct_turbofish_generics(
+            &struct_type.borrow(),
+            struct_generics,
+            turbofish,
+            span,
+        );
+
+        let actual_type = Type::Struct(struct_type.clone(), struct_generics.clone());
+        self.unify_pattern_type(&actual_type, &expected_type, span);
+
+        let struct_id = struct_type.borrow().id;
+        let reference_location = Location::new(last_segment.ident.span(), self.file);
+        self.interner.add_struct_reference(struct_id, reference_location, is_self_type);
+
+        let fields = struct_type.borrow().get_fields_with_visibility(&struct_generics);
+        let mut patterns = vecmap(&fields, |_| None);
+
+        for (field, pattern) in constructor.fields {
+            let Some(index) = fields.iter().position(|(name, ..)| *name == field.0.contents) else {
+                self.push_err(ResolverError::NoSuchField {
+                    field,
+                    struct_definition: struct_type.borrow().name.clone(),
+                });
+                continue;
+            };
+
+            if patterns[index].is_some() {
+                self.push_err(ResolverError::DuplicateField { field });
+                continue;
+            }
+
+            let (name, visibility, field_type) = &fields[index];
+            self.check_struct_field_visibility(
+                &struct_type.borrow(),
+                name,
+                *visibility,
+                field.span(),
+            );
+
+            let reference_location = Location::new(field.span(), self.file);
+            self.interner.add_struct_member_reference(struct_id, index, reference_location);
+
+            patterns[index] = Some(self.elaborate_match_pattern(pattern, field_type.clone()));
+        }
+
+        let missing_fields = fields.iter().zip(&patterns).filter(|(_, pattern)| pattern.is_none());
+        let missing_fields = vecmap(missing_fields, |((name, ..), _)| name.clone());
+        if !missing_fields.is_empty() {
+            self.push_err(ResolverError::MissingFields {
+                span,
+                missing_fields,
+                struct_definition: struct_type.borrow().name.clone(),
+            });
+        }
+
+        let patterns = vecmap(patterns, |pattern| pattern.unwrap_or(MatchPattern::Wildcard));
+        MatchPattern::Constructor(Constructor::Struct(actual_type), patterns)
+    }
+
+    fn unify_pattern_type(&mut self, actual: &Type, expected: &Type, span: Span) {
+        self.unify(actual, expected, || TypeCheckError::TypeMismatchWithSource {
+            expected: expected.clone(),
+            actual: actual.clone(),
+            span,
+            source: Source::Assignment,
+        });
+    }
+
     pub(super) fn add_variable_decl(
         &mut self,
         name: Ident,
@@ -559,7 +838,18 @@ impl<'context> Elaborator<'context> {
         let id = self.interner.push_expr(HirExpression::Ident(expr.clone(), generics.clone()));
 
         self.interner.push_expr_location(id, span, self.file);
-        let typ = self.type_check_variable_with_bindings(expr, id, generics, bindings);
+        let mut typ = self.type_check_variable_with_bindings(expr, id, generics, bindings);
+
+        // Unit enum variants are values rather than functions so they're constructed directly
+        if let Some(DefinitionKind::Function(func_id)) = definition_kind {
+            if let Some(constructor) = self.unit_variant_constructor(func_id) {
+                self.interner.replace_expr(&id, constructor);
+                if let Type::Function(_, return_type, _, _) = typ {
+                    typ = *return_type;
+                }
+            }
+        }
+
         self.interner.push_expr_type(id, typ.clone());
 
         (id, typ)
@@ -4,7 +4,7 @@ use crate::{
     ast::{
         AssignStatement, BinaryOpKind, ConstrainKind, ConstrainStatement, Expression,
         ExpressionKind, ForLoopStatement, ForRange, Ident, InfixExpression, ItemVisibility, LValue,
-        LetStatement, Path, Statement, StatementKind,
+        LetStatement, Literal, LoopStatement, Path, Statement, StatementKind, WhileStatement,
     },
     hir::{
         resolution::{
@@ -17,10 +17,10 @@ use crate::{
         expr::HirIdent,
         stmt::{
             HirAssignStatement, HirConstrainStatement, HirForStatement, HirLValue, HirLetStatement,
-            HirStatement,
+            HirStatement, HirWhileStatement,
         },
     },
-    node_interner::{DefinitionId, DefinitionKind, GlobalId, StmtId},
+    node_interner::{DefinitionId, DefinitionKind, DependencyId, GlobalId, StmtId},
     StructType, Type,
 };
 
@@ -33,9 +33,23 @@ impl<'context> Elaborator<'context> {
             StatementKind::Constrain(constrain) => self.elaborate_constrain(constrain),
             StatementKind::Assign(assign) => self.elaborate_assign(assign),
             StatementKind::For(for_stmt) => self.elaborate_for(for_stmt),
+            StatementKind::While(while_stmt) => self.elaborate_while(while_stmt),
+            StatementKind::Loop(loop_stmt) => self.elaborate_loop(loop_stmt),
             StatementKind::Break => self.elaborate_jump(true, statement.span),
             StatementKind::Continue => self.elaborate_jump(false, statement.span),
+            StatementKind::Return(expression) => self.elaborate_return(expression, statement.span),
             StatementKind::Comptime(statement) => self.elaborate_comptime_statement(*statement),
+            StatementKind::Cfg(predicate, statement) => {
+                if self.interner.is_cfg_enabled(self.crate_id, &predicate) {
+                    self.elaborate_statement_value(*statement)
+                } else {
+                    // A disabled statement is elaborated as if it were `();`
+                    let unit =
+                        Expression::new(ExpressionKind::Literal(Literal::Unit), statement.span);
+                    let (expr, _typ) = self.elaborate_expression(unit);
+                    (HirStatement::Semi(expr), Type::Unit)
+                }
+            }
             StatementKind::Expression(expr) => {
                 let (expr, typ) = self.elaborate_expression(expr);
                 (HirStatement::Expression(expr), typ)
@@ -256,6 +270,49 @@ impl<'context> Elaborator<'context> {
         (statement, Type::Unit)
     }
 
+    fn elaborate_while(&mut self, while_stmt: WhileStatement) -> (HirStatement, Type) {
+        if self.in_constrained_function() {
+            let span = while_stmt.span;
+            self.push_err(ResolverError::LoopInConstrainedFn { is_while: true, span });
+        }
+
+        let condition_span = while_stmt.condition.span;
+        let (condition, condition_type) = self.elaborate_expression(while_stmt.condition);
+
+        self.unify(&condition_type, &Type::Bool, || TypeCheckError::TypeMismatch {
+            expected_typ: Type::Bool.to_string(),
+            expr_typ: condition_type.to_string(),
+            expr_span: condition_span,
+        });
+
+        self.nested_loops += 1;
+        self.push_scope();
+
+        let (block, _block_type) = self.elaborate_expression(while_stmt.block);
+
+        self.pop_scope();
+        self.nested_loops -= 1;
+
+        (HirStatement::While(HirWhileStatement { condition, block }), Type::Unit)
+    }
+
+    fn elaborate_loop(&mut self, loop_stmt: LoopStatement) -> (HirStatement, Type) {
+        if self.in_constrained_function() {
+            let span = loop_stmt.span;
+            self.push_err(ResolverError::LoopInConstrainedFn { is_while: false, span });
+        }
+
+        self.nested_loops += 1;
+        self.push_scope();
+
+        let (block, _block_type) = self.elaborate_expression(loop_stmt.block);
+
+        self.pop_scope();
+        self.nested_loops -= 1;
+
+        (HirStatement::Loop(block), Type::Unit)
+    }
+
     fn elaborate_jump(&mut self, is_break: bool, span: noirc_errors::Span) -> (HirStatement, Type) {
         let in_constrained_function = self.in_constrained_function();
 
@@ -270,6 +327,62 @@ impl<'context> Elaborator<'context> {
         (expr, self.interner.next_type_variable())
     }
 
+    fn elaborate_return(
+        &mut self,
+        expression: Option<Expression>,
+        span: Span,
+    ) -> (HirStatement, Type) {
+        if self.in_constrained_function() {
+            self.push_err(ResolverError::EarlyReturnInConstrainedFn { span });
+        }
+
+        let (expression, typ, expr_span) = match expression {
+            Some(expression) => {
+                let expr_span = expression.span;
+                let (expression, typ) = self.elaborate_expression(expression);
+                (Some(expression), typ, expr_span)
+            }
+            None => (None, Type::Unit, span),
+        };
+
+        if self.lambda_stack.is_empty() {
+            self.has_early_return = true;
+        }
+
+        match self.current_return_type() {
+            Some(expected_type) => {
+                self.unify(&typ, &expected_type, || TypeCheckError::TypeMismatch {
+                    expected_typ: expected_type.to_string(),
+                    expr_typ: typ.to_string(),
+                    expr_span,
+                });
+            }
+            None => self.push_err(ResolverError::ReturnOutsideFunction { span }),
+        }
+
+        (HirStatement::Return(expression), self.interner.next_type_variable())
+    }
+
+    /// The return type of the innermost function or lambda a `return` would exit from.
+    /// Returns `None` if a `return` would have to exit a `comptime` block or global instead.
+    fn current_return_type(&self) -> Option<Type> {
+        if let Some(lambda) = self.lambda_stack.last() {
+            return (lambda.in_comptime_context == self.in_comptime_context)
+                .then(|| lambda.return_type.clone());
+        }
+
+        if self.in_comptime_context {
+            return None;
+        }
+
+        match self.current_item {
+            Some(DependencyId::Function(id)) => {
+                Some(self.interner.function_meta(&id).return_type().clone())
+            }
+            _ => None,
+        }
+    }
+
     fn get_lvalue_name_and_span(&self, lvalue: &HirLValue) -> (String, Span) {
         match lvalue {
             HirLValue::Ident(name, _) => {
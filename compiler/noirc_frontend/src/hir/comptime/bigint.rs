@@ -0,0 +1,68 @@
+use acvm::{acir::BlackBoxFunc, blackbox_solver::BigIntSolver, BlackBoxResolutionError};
+use num_bigint::BigUint;
+
+/// The big integers created by `std::bigint::BigInt` operations during comptime evaluation.
+///
+/// A `BigInt` value is a pair of ids: `pointer` identifies the integer itself while `modulus`
+/// identifies the modulus it was created with, mirroring how ACIR represents big integers.
+#[derive(Debug, Default)]
+pub(crate) struct ComptimeBigInts {
+    solver: BigIntSolver,
+    moduli: Vec<BigUint>,
+    next_id: u32,
+}
+
+impl ComptimeBigInts {
+    /// Creates a new big integer from its little-endian bytes, returning its
+    /// `(pointer, modulus)` ids.
+    pub(crate) fn from_le_bytes(
+        &mut self,
+        bytes: &[u8],
+        modulus: &[u8],
+    ) -> Result<(u32, u32), BlackBoxResolutionError> {
+        let modulus_id = self.get_or_insert_modulus(BigUint::from_bytes_le(modulus));
+        let id = self.next_id();
+        self.solver.bigint_from_bytes(bytes, modulus, id)?;
+        Ok((id, modulus_id))
+    }
+
+    /// Returns the little-endian bytes of a big integer, padded to 32 bytes.
+    pub(crate) fn to_le_bytes(&self, id: u32) -> Result<Vec<u8>, BlackBoxResolutionError> {
+        let mut bytes = self.solver.bigint_to_bytes(id)?;
+        if bytes.len() < 32 {
+            bytes.resize(32, 0);
+        }
+        Ok(bytes)
+    }
+
+    /// Applies a binary big integer operation, returning the `pointer` id of the result.
+    /// Both operands must have been created with the same modulus.
+    pub(crate) fn op(
+        &mut self,
+        (lhs, lhs_modulus): (u32, u32),
+        (rhs, rhs_modulus): (u32, u32),
+        func: BlackBoxFunc,
+    ) -> Result<u32, BlackBoxResolutionError> {
+        if lhs_modulus != rhs_modulus {
+            let message = "attempted to operate on big integers with different moduli".to_string();
+            return Err(BlackBoxResolutionError::Failed(func, message));
+        }
+        let id = self.next_id();
+        self.solver.bigint_op(lhs, rhs, id, func)?;
+        Ok(id)
+    }
+
+    fn get_or_insert_modulus(&mut self, modulus: BigUint) -> u32 {
+        if let Some(index) = self.moduli.iter().position(|existing| existing == &modulus) {
+            return index as u32;
+        }
+        self.moduli.push(modulus);
+        (self.moduli.len() - 1) as u32
+    }
+
+    fn next_id(&mut self) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt::Debug;
+
+use noirc_errors::Location;
+use rustc_hash::FxHashSet as HashSet;
+
+use crate::node_interner::{FuncId, NodeInterner};
+
+/// Observes the evaluation of comptime code, one step at a time.
+///
+/// A debugger is set with
+/// [`Context::set_comptime_debugger`][crate::hir::Context::set_comptime_debugger].
+/// Evaluation is paused for as long as [`ComptimeDebugger::on_step`] runs, so a debugger
+/// can implement breakpoints by blocking until the user asks to continue.
+pub trait ComptimeDebugger {
+    /// Called before each step is taken.
+    fn on_step(&mut self, step: &ComptimeStep);
+}
+
+impl Debug for dyn ComptimeDebugger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ComptimeDebugger")
+    }
+}
+
+/// What the comptime interpreter or elaborator is about to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComptimeStepKind {
+    /// A statement is about to be evaluated.
+    Statement,
+    /// A `quote` expression was evaluated to these tokens, after unquoting any values in it.
+    Quote(String),
+    /// A comptime value was turned into this code, which is about to be elaborated
+    /// in place of a comptime block, macro call, attribute or `Module::add_item` call.
+    Expansion(String),
+}
+
+/// A single step of comptime evaluation, along with the state of the interpreter at that point.
+pub struct ComptimeStep<'a> {
+    pub kind: ComptimeStepKind,
+
+    /// The location of the code this step evaluates or expands.
+    pub location: Location,
+
+    /// The locations of the comptime function calls leading to this step, outermost first.
+    pub call_stack: &'a im::Vector<Location>,
+
+    function: Option<FuncId>,
+    interner: &'a NodeInterner,
+}
+
+impl<'a> ComptimeStep<'a> {
+    pub(crate) fn new(
+        kind: ComptimeStepKind,
+        location: Location,
+        call_stack: &'a im::Vector<Location>,
+        function: Option<FuncId>,
+        interner: &'a NodeInterner,
+    ) -> Self {
+        Self { kind, location, call_stack, function, interner }
+    }
+
+    /// The name of the function being evaluated, if any.
+    pub fn function_name(&self) -> Option<&str> {
+        self.function.map(|function| self.interner.function_name(&function))
+    }
+
+    /// The local variables in scope along with their values, innermost scope first.
+    /// Variables shadowed by a variable in an inner scope are omitted.
+    pub fn variables(&self) -> Vec<(String, String)> {
+        let mut seen = HashSet::default();
+        let mut variables = Vec::new();
+
+        // The first scope holds globals, which aren't local to the current function
+        for scope in self.interner.comptime_scopes.iter().skip(1).rev() {
+            let mut scope_variables: Vec<_> = scope
+                .iter()
+                .map(|(id, value)| (self.interner.definition_name(*id), value))
+                .filter(|(name, _)| seen.insert(name.to_string()))
+                .map(|(name, value)| (name.to_string(), value.display(self.interner).to_string()))
+                .collect();
+            scope_variables.sort();
+            variables.extend(scope_variables);
+        }
+
+        variables
+    }
+}
@@ -6,14 +6,17 @@ use noirc_errors::Span;
 use crate::{
     ast::{
         ArrayLiteral, AsTraitPath, AssignStatement, BlockExpression, CallExpression,
-        CastExpression, ConstrainStatement, ConstructorExpression, Expression, ExpressionKind,
-        ForBounds, ForLoopStatement, ForRange, GenericTypeArgs, IfExpression, IndexExpression,
-        InfixExpression, LValue, Lambda, LetStatement, Literal, MemberAccessExpression,
-        MethodCallExpression, Pattern, PrefixExpression, Statement, StatementKind, UnresolvedType,
-        UnresolvedTypeData,
+        CastExpression, ConstrainStatement, ConstructorExpression, Documented, Expression,
+        ExpressionKind, ForBounds, ForLoopStatement, ForRange, FunctionDefinition,
+        FunctionReturnType, GenericTypeArgs, IfExpression, IndexExpression, InfixExpression,
+        LValue, Lambda, LetStatement, Literal, LoopStatement, MatchExpression,
+        MemberAccessExpression, MethodCallExpression, NoirFunction, NoirStruct, NoirTraitImpl,
+        Param, Pattern, PrefixExpression, Statement, StatementKind, StructField, TraitImplItem,
+        TraitImplItemKind, TypeImpl, UnresolvedType, UnresolvedTypeData, WhileStatement,
     },
     hir_def::traits::TraitConstraint,
     node_interner::{InternedStatementKind, NodeInterner},
+    parser::ItemKind,
     token::{Keyword, Token},
     Type,
 };
@@ -241,7 +244,8 @@ impl<'interner> TokenPrettyPrinter<'interner> {
             | Token::GreaterEqual
             | Token::Equal
             | Token::NotEqual
-            | Token::Arrow => write!(f, " {token} "),
+            | Token::Arrow
+            | Token::FatArrow => write!(f, " {token} "),
             Token::Assign => {
                 if last_was_op {
                     write!(f, "{token} ")
@@ -341,11 +345,13 @@ impl<'value, 'interner> Display for ValuePrinter<'value, 'interner> {
             Value::I16(value) => write!(f, "{value}"),
             Value::I32(value) => write!(f, "{value}"),
             Value::I64(value) => write!(f, "{value}"),
+            Value::I128(value) => write!(f, "{value}"),
             Value::U1(value) => write!(f, "{value}"),
             Value::U8(value) => write!(f, "{value}"),
             Value::U16(value) => write!(f, "{value}"),
             Value::U32(value) => write!(f, "{value}"),
             Value::U64(value) => write!(f, "{value}"),
+            Value::U128(value) => write!(f, "{value}"),
             Value::String(value) => write!(f, "{value}"),
             Value::CtString(value) => write!(f, "{value}"),
             Value::FormatString(value, _) => write!(f, "{value}"),
@@ -365,6 +371,19 @@ impl<'value, 'interner> Display for ValuePrinter<'value, 'interner> {
                 });
                 write!(f, "{typename} {{ {} }}", fields.join(", "))
             }
+            Value::Enum(tag, args, typ) => {
+                let Type::Struct(def, _) = typ.follow_bindings() else {
+                    return write!(f, "{typ}");
+                };
+                let def = def.borrow();
+                let variant = def.variant_at(*tag);
+                if variant.is_function {
+                    let args = vecmap(args, |arg| arg.display(self.interner).to_string());
+                    write!(f, "{}::{}({})", def.name, variant.name, args.join(", "))
+                } else {
+                    write!(f, "{}::{}", def.name, variant.name)
+                }
+            }
             Value::Pointer(value, _) => write!(f, "&mut {}", value.borrow().display(self.interner)),
             Value::Array(values, _) => {
                 let values = vecmap(values, |value| value.display(self.interner).to_string());
@@ -515,6 +534,107 @@ fn display_trait_constraint(interner: &NodeInterner, trait_constraint: &TraitCon
     )
 }
 
+// Returns the code of an item generated by comptime code, where all Interned and Resolved
+// nodes have been turned into the code they stand for.
+pub(crate) fn display_generated_item(interner: &NodeInterner, item: &ItemKind) -> String {
+    remove_interned_in_item_kind(interner, item.clone()).to_string()
+}
+
+// Returns the code of a function body set by comptime code, where all Interned and Resolved
+// nodes have been turned into the code they stand for.
+pub(crate) fn display_generated_block(interner: &NodeInterner, block: &BlockExpression) -> String {
+    remove_interned_in_block_expression(interner, block.clone()).to_string()
+}
+
+// Returns a new ItemKind where all Interned and Resolved nodes have been turned into non-interned nodes.
+fn remove_interned_in_item_kind(interner: &NodeInterner, item: ItemKind) -> ItemKind {
+    match item {
+        ItemKind::Function(function) => {
+            ItemKind::Function(remove_interned_in_function(interner, function))
+        }
+        ItemKind::Struct(noir_struct) => ItemKind::Struct(NoirStruct {
+            fields: vecmap(noir_struct.fields, |field| {
+                let typ = remove_interned_in_unresolved_type(interner, field.item.typ);
+                Documented::new(StructField { typ, ..field.item }, field.doc_comments)
+            }),
+            ..noir_struct
+        }),
+        ItemKind::Impl(type_impl) => ItemKind::Impl(TypeImpl {
+            object_type: remove_interned_in_unresolved_type(interner, type_impl.object_type),
+            methods: vecmap(type_impl.methods, |(method, span)| {
+                let function = remove_interned_in_function(interner, method.item);
+                (Documented::new(function, method.doc_comments), span)
+            }),
+            ..type_impl
+        }),
+        ItemKind::TraitImpl(trait_impl) => ItemKind::TraitImpl(NoirTraitImpl {
+            object_type: remove_interned_in_unresolved_type(interner, trait_impl.object_type),
+            items: vecmap(trait_impl.items, |item| {
+                let kind = remove_interned_in_trait_impl_item_kind(interner, item.item.kind);
+                let item_span = item.item.span;
+                Documented::new(TraitImplItem { kind, span: item_span }, item.doc_comments)
+            }),
+            ..trait_impl
+        }),
+        ItemKind::Global(let_statement, visibility) => {
+            ItemKind::Global(remove_interned_in_let_statement(interner, let_statement), visibility)
+        }
+        ItemKind::Import(..)
+        | ItemKind::Enum(_)
+        | ItemKind::Trait(_)
+        | ItemKind::TypeAlias(_)
+        | ItemKind::ModuleDecl(_)
+        | ItemKind::Submodules(_)
+        | ItemKind::InnerAttribute(_) => item,
+    }
+}
+
+fn remove_interned_in_trait_impl_item_kind(
+    interner: &NodeInterner,
+    kind: TraitImplItemKind,
+) -> TraitImplItemKind {
+    match kind {
+        TraitImplItemKind::Function(function) => {
+            TraitImplItemKind::Function(remove_interned_in_function(interner, function))
+        }
+        TraitImplItemKind::Constant(name, typ, expression) => TraitImplItemKind::Constant(
+            name,
+            remove_interned_in_unresolved_type(interner, typ),
+            remove_interned_in_expression(interner, expression),
+        ),
+        TraitImplItemKind::Type { name, alias } => TraitImplItemKind::Type {
+            name,
+            alias: remove_interned_in_unresolved_type(interner, alias),
+        },
+    }
+}
+
+fn remove_interned_in_function(interner: &NodeInterner, function: NoirFunction) -> NoirFunction {
+    let def = function.def;
+    let parameters = vecmap(def.parameters, |param| Param {
+        pattern: remove_interned_in_pattern(interner, param.pattern),
+        typ: remove_interned_in_unresolved_type(interner, param.typ),
+        ..param
+    });
+    let return_type = match def.return_type {
+        FunctionReturnType::Ty(typ) => {
+            FunctionReturnType::Ty(remove_interned_in_unresolved_type(interner, typ))
+        }
+        FunctionReturnType::Default(span) => FunctionReturnType::Default(span),
+    };
+    let body = remove_interned_in_block_expression(interner, def.body);
+    let def = FunctionDefinition { parameters, return_type, body, ..def };
+    NoirFunction { kind: function.kind, def }
+}
+
+fn remove_interned_in_block_expression(
+    interner: &NodeInterner,
+    block: BlockExpression,
+) -> BlockExpression {
+    let statements = vecmap(block.statements, |stmt| remove_interned_in_statement(interner, stmt));
+    BlockExpression { statements }
+}
+
 // Returns a new Expression where all Interned and Resolved expressions have been turned into non-interned ExpressionKind.
 fn remove_interned_in_expression(interner: &NodeInterner, expr: Expression) -> Expression {
     Expression { kind: remove_interned_in_expression_kind(interner, expr.kind), span: expr.span }
@@ -530,9 +650,7 @@ fn remove_interned_in_expression_kind(
             ExpressionKind::Literal(remove_interned_in_literal(interner, literal))
         }
         ExpressionKind::Block(block) => {
-            let statements =
-                vecmap(block.statements, |stmt| remove_interned_in_statement(interner, stmt));
-            ExpressionKind::Block(BlockExpression { statements })
+            ExpressionKind::Block(remove_interned_in_block_expression(interner, block))
         }
         ExpressionKind::Prefix(prefix) => ExpressionKind::Prefix(Box::new(PrefixExpression {
             rhs: remove_interned_in_expression(interner, prefix.rhs),
@@ -586,6 +704,14 @@ fn remove_interned_in_expression_kind(
                 .alternative
                 .map(|alternative| remove_interned_in_expression(interner, alternative)),
         })),
+        ExpressionKind::Match(match_expr) => ExpressionKind::Match(Box::new(MatchExpression {
+            expression: remove_interned_in_expression(interner, match_expr.expression),
+            rules: vecmap(match_expr.rules, |(pattern, branch)| {
+                let pattern = remove_interned_in_expression(interner, pattern);
+                let branch = remove_interned_in_expression(interner, branch);
+                (pattern, branch)
+            }),
+        })),
         ExpressionKind::Variable(_) => expr,
         ExpressionKind::Tuple(expressions) => ExpressionKind::Tuple(vecmap(expressions, |expr| {
             remove_interned_in_expression(interner, expr)
@@ -683,6 +809,18 @@ fn remove_interned_in_array_literal(
     }
 }
 
+fn remove_interned_in_let_statement(
+    interner: &NodeInterner,
+    let_statement: LetStatement,
+) -> LetStatement {
+    LetStatement {
+        pattern: remove_interned_in_pattern(interner, let_statement.pattern),
+        expression: remove_interned_in_expression(interner, let_statement.expression),
+        r#type: remove_interned_in_unresolved_type(interner, let_statement.r#type),
+        ..let_statement
+    }
+}
+
 // Returns a new Statement where all Interned statements have been turned into non-interned StatementKind.
 fn remove_interned_in_statement(interner: &NodeInterner, statement: Statement) -> Statement {
     Statement {
@@ -697,12 +835,9 @@ fn remove_interned_in_statement_kind(
     statement: StatementKind,
 ) -> StatementKind {
     match statement {
-        StatementKind::Let(let_statement) => StatementKind::Let(LetStatement {
-            pattern: remove_interned_in_pattern(interner, let_statement.pattern),
-            expression: remove_interned_in_expression(interner, let_statement.expression),
-            r#type: remove_interned_in_unresolved_type(interner, let_statement.r#type),
-            ..let_statement
-        }),
+        StatementKind::Let(let_statement) => {
+            StatementKind::Let(remove_interned_in_let_statement(interner, let_statement))
+        }
         StatementKind::Constrain(constrain) => StatementKind::Constrain(ConstrainStatement {
             arguments: vecmap(constrain.arguments, |expr| {
                 remove_interned_in_expression(interner, expr)
@@ -732,9 +867,22 @@ fn remove_interned_in_statement_kind(
             block: remove_interned_in_expression(interner, for_loop.block),
             ..for_loop
         }),
+        StatementKind::While(while_stmt) => StatementKind::While(WhileStatement {
+            condition: remove_interned_in_expression(interner, while_stmt.condition),
+            block: remove_interned_in_expression(interner, while_stmt.block),
+            span: while_stmt.span,
+        }),
+        StatementKind::Loop(loop_stmt) => StatementKind::Loop(LoopStatement {
+            block: remove_interned_in_expression(interner, loop_stmt.block),
+            span: loop_stmt.span,
+        }),
         StatementKind::Comptime(statement) => {
             StatementKind::Comptime(Box::new(remove_interned_in_statement(interner, *statement)))
         }
+        StatementKind::Cfg(predicate, statement) => StatementKind::Cfg(
+            predicate,
+            Box::new(remove_interned_in_statement(interner, *statement)),
+        ),
         StatementKind::Semi(expr) => {
             StatementKind::Semi(remove_interned_in_expression(interner, expr))
         }
@@ -742,6 +890,9 @@ fn remove_interned_in_statement_kind(
             let statement = interner.get_statement_kind(id).clone();
             remove_interned_in_statement_kind(interner, statement)
         }
+        StatementKind::Return(expr) => {
+            StatementKind::Return(expr.map(|expr| remove_interned_in_expression(interner, expr)))
+        }
         StatementKind::Break | StatementKind::Continue | StatementKind::Error => statement,
     }
 }
@@ -837,12 +988,12 @@ fn remove_interned_in_unresolved_type_data(
             }))
         }
         UnresolvedTypeData::Interned(id) => interner.get_unresolved_type_data(id).clone(),
+        UnresolvedTypeData::Resolved(id) => interner.get_quoted_type(id).to_display_ast().typ,
         UnresolvedTypeData::FieldElement
         | UnresolvedTypeData::Integer(_, _)
         | UnresolvedTypeData::Bool
         | UnresolvedTypeData::Unit
         | UnresolvedTypeData::String(_)
-        | UnresolvedTypeData::Resolved(_)
         | UnresolvedTypeData::Quoted(_)
         | UnresolvedTypeData::Expression(_)
         | UnresolvedTypeData::Unspecified
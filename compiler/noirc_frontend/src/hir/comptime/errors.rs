@@ -14,6 +14,8 @@ use acvm::{acir::AcirField, BlackBoxResolutionError, FieldElement};
 use fm::FileId;
 use noirc_errors::{CustomDiagnostic, Location};
 
+use super::value::Value;
+
 /// The possible errors that can halt the interpreter.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InterpreterError {
@@ -50,6 +52,10 @@ pub enum InterpreterError {
         typ: Type,
         location: Location,
     },
+    NonBoolUsedInWhile {
+        typ: Type,
+        location: Location,
+    },
     NonBoolUsedInConstrain {
         typ: Type,
         location: Location,
@@ -177,7 +183,34 @@ pub enum InterpreterError {
     ContinueNotInLoop {
         location: Location,
     },
+    ReturnNotInFunction {
+        location: Location,
+    },
     BlackBoxError(BlackBoxResolutionError, Location),
+    OracleResolverNotSet {
+        oracle: String,
+        location: Location,
+    },
+    OracleCallFailed {
+        oracle: String,
+        error: String,
+        location: Location,
+    },
+    InvalidOracleResponse {
+        oracle: String,
+        typ: Type,
+        location: Location,
+    },
+    FailedToReadFile {
+        path: String,
+        error: String,
+        location: Location,
+    },
+    FailedToParseFile {
+        path: String,
+        error: String,
+        location: Location,
+    },
     FailedToResolveTraitBound {
         trait_bound: TraitBound,
         location: Location,
@@ -237,9 +270,11 @@ pub enum InterpreterError {
     },
 
     // These cases are not errors, they are just used to prevent us from running more code
-    // until the loop can be resumed properly. These cases will never be displayed to users.
+    // until the loop can be resumed properly or the current function returns. These cases will
+    // never be displayed to users.
     Break,
     Continue,
+    Return(Value),
 }
 
 #[allow(unused)]
@@ -267,6 +302,7 @@ impl InterpreterError {
             | InterpreterError::ErrorNodeEncountered { location, .. }
             | InterpreterError::NonFunctionCalled { location, .. }
             | InterpreterError::NonBoolUsedInIf { location, .. }
+            | InterpreterError::NonBoolUsedInWhile { location, .. }
             | InterpreterError::NonBoolUsedInConstrain { location, .. }
             | InterpreterError::FailingConstraint { location, .. }
             | InterpreterError::NoMethodFound { location, .. }
@@ -295,8 +331,14 @@ impl InterpreterError {
             | InterpreterError::ImplMethodTypeMismatch { location, .. }
             | InterpreterError::DebugEvaluateComptime { location, .. }
             | InterpreterError::BlackBoxError(_, location)
+            | InterpreterError::OracleResolverNotSet { location, .. }
+            | InterpreterError::OracleCallFailed { location, .. }
+            | InterpreterError::InvalidOracleResponse { location, .. }
+            | InterpreterError::FailedToReadFile { location, .. }
+            | InterpreterError::FailedToParseFile { location, .. }
             | InterpreterError::BreakNotInLoop { location, .. }
             | InterpreterError::ContinueNotInLoop { location, .. }
+            | InterpreterError::ReturnNotInFunction { location, .. }
             | InterpreterError::TraitDefinitionMustBeAPath { location }
             | InterpreterError::FailedToResolveTraitDefinition { location }
             | InterpreterError::FailedToResolveTraitBound { location, .. }
@@ -317,8 +359,8 @@ impl InterpreterError {
             InterpreterError::NoMatchingImplFound { error, file } => {
                 Location::new(error.span, *file)
             }
-            InterpreterError::Break | InterpreterError::Continue => {
-                panic!("Tried to get the location of Break/Continue error!")
+            InterpreterError::Break | InterpreterError::Continue | InterpreterError::Return(_) => {
+                panic!("Tried to get the location of Break/Continue/Return error!")
             }
         }
     }
@@ -390,6 +432,11 @@ impl<'a> From<&'a InterpreterError> for CustomDiagnostic {
                 let secondary = "If conditions must be a boolean value".to_string();
                 CustomDiagnostic::simple_error(msg, secondary, location.span)
             }
+            InterpreterError::NonBoolUsedInWhile { typ, location } => {
+                let msg = format!("Expected a `bool` but found `{typ}`");
+                let secondary = "While conditions must be a boolean value".to_string();
+                CustomDiagnostic::simple_error(msg, secondary, location.span)
+            }
             InterpreterError::NonBoolUsedInConstrain { typ, location } => {
                 let msg = format!("Expected a `bool` but found `{typ}`");
                 CustomDiagnostic::simple_error(msg, String::new(), location.span)
@@ -548,6 +595,10 @@ impl<'a> From<&'a InterpreterError> for CustomDiagnostic {
                 let msg = "There is no loop to continue!".into();
                 CustomDiagnostic::simple_error(msg, String::new(), location.span)
             }
+            InterpreterError::ReturnNotInFunction { location } => {
+                let msg = "There is no function to return from!".into();
+                CustomDiagnostic::simple_error(msg, String::new(), location.span)
+            }
             InterpreterError::NoImpl { location } => {
                 let msg = "No impl found due to prior type error".into();
                 CustomDiagnostic::simple_error(msg, String::new(), location.span)
@@ -561,6 +612,27 @@ impl<'a> From<&'a InterpreterError> for CustomDiagnostic {
             InterpreterError::BlackBoxError(error, location) => {
                 CustomDiagnostic::simple_error(error.to_string(), String::new(), location.span)
             }
+            InterpreterError::OracleResolverNotSet { oracle, location } => {
+                let msg = format!("Cannot call oracle `{oracle}` at compile-time");
+                let secondary = "Comptime oracle calls are disabled unless an oracle resolver is set, e.g. with `--comptime-oracle-resolver`".to_string();
+                CustomDiagnostic::simple_error(msg, secondary, location.span)
+            }
+            InterpreterError::OracleCallFailed { oracle, error, location } => {
+                let msg = format!("Failed to resolve oracle `{oracle}` at compile-time");
+                CustomDiagnostic::simple_error(msg, error.clone(), location.span)
+            }
+            InterpreterError::InvalidOracleResponse { oracle, typ, location } => {
+                let msg = format!("Oracle `{oracle}` returned a response which does not match its return type `{typ}`");
+                CustomDiagnostic::simple_error(msg, String::new(), location.span)
+            }
+            InterpreterError::FailedToReadFile { path, error, location } => {
+                let msg = format!("Failed to read file `{path}` at compile-time");
+                CustomDiagnostic::simple_error(msg, error.clone(), location.span)
+            }
+            InterpreterError::FailedToParseFile { path, error, location } => {
+                let msg = format!("Failed to parse file `{path}` at compile-time");
+                CustomDiagnostic::simple_error(msg, error.clone(), location.span)
+            }
             InterpreterError::FailedToResolveTraitBound { trait_bound, location } => {
                 let msg = format!("Failed to resolve trait bound `{trait_bound}`");
                 CustomDiagnostic::simple_error(msg, String::new(), location.span)
@@ -568,6 +640,7 @@ impl<'a> From<&'a InterpreterError> for CustomDiagnostic {
             InterpreterError::NoMatchingImplFound { error, .. } => error.into(),
             InterpreterError::Break => unreachable!("Uncaught InterpreterError::Break"),
             InterpreterError::Continue => unreachable!("Uncaught InterpreterError::Continue"),
+            InterpreterError::Return(_) => unreachable!("Uncaught InterpreterError::Return"),
             InterpreterError::TraitDefinitionMustBeAPath { location } => {
                 let msg = "Trait definition arguments must be a variable or path".to_string();
                 CustomDiagnostic::simple_error(msg, String::new(), location.span)
@@ -0,0 +1,61 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::graph::CrateId;
+
+/// The files comptime code is allowed to read and the ones it has read so far.
+///
+/// Comptime code may only read files within the package directory of the crate it is
+/// defined in, so crates without a known package directory can't read any files.
+#[derive(Debug, Default)]
+pub(crate) struct ComptimeFiles {
+    package_roots: HashMap<CrateId, PathBuf>,
+
+    /// Every file read during this compilation, or which was missing when comptime code
+    /// tried to read it. The compiled program depends on their contents, so it must be
+    /// recompiled if any of them change.
+    read_files: BTreeSet<PathBuf>,
+}
+
+impl ComptimeFiles {
+    pub(crate) fn set_package_root(&mut self, crate_id: CrateId, root: PathBuf) {
+        self.package_roots.insert(crate_id, root);
+    }
+
+    /// Reads the file at `path`, relative to the package directory of `crate_id`.
+    pub(crate) fn read(&mut self, crate_id: CrateId, path: &str) -> io::Result<Vec<u8>> {
+        let Some(root) = self.package_roots.get(&crate_id) else {
+            let error = "files can only be read from within a package";
+            return Err(io::Error::new(io::ErrorKind::PermissionDenied, error));
+        };
+
+        // Paths are canonicalized so that neither `..` nor symlinks can escape the package
+        let root = root.canonicalize()?;
+        let requested_path = root.join(path);
+        let path = match requested_path.canonicalize() {
+            Ok(path) => path,
+            Err(error) => {
+                // Creating the file would change the result of this read
+                if error.kind() == io::ErrorKind::NotFound && requested_path.starts_with(&root) {
+                    self.read_files.insert(requested_path);
+                }
+                return Err(error);
+            }
+        };
+        if !path.starts_with(&root) {
+            let error = "files outside of the package directory cannot be read";
+            return Err(io::Error::new(io::ErrorKind::PermissionDenied, error));
+        }
+
+        let contents = std::fs::read(&path)?;
+        self.read_files.insert(path);
+        Ok(contents)
+    }
+
+    pub(crate) fn read_files(&self) -> impl Iterator<Item = &Path> {
+        self.read_files.iter().map(PathBuf::as_path)
+    }
+}
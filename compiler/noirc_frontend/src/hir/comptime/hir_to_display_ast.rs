@@ -4,13 +4,14 @@ use noirc_errors::{Span, Spanned};
 use crate::ast::{
     ArrayLiteral, AssignStatement, BlockExpression, CallExpression, CastExpression, ConstrainKind,
     ConstructorExpression, ExpressionKind, ForLoopStatement, ForRange, GenericTypeArgs, Ident,
-    IfExpression, IndexExpression, InfixExpression, LValue, Lambda, Literal,
-    MemberAccessExpression, MethodCallExpression, Path, PathSegment, Pattern, PrefixExpression,
-    UnresolvedType, UnresolvedTypeData, UnresolvedTypeExpression,
+    IfExpression, IndexExpression, InfixExpression, LValue, Lambda, Literal, LoopStatement,
+    MatchExpression, MemberAccessExpression, MethodCallExpression, Path, PathSegment, Pattern,
+    PrefixExpression, UnresolvedType, UnresolvedTypeData, UnresolvedTypeExpression, WhileStatement,
 };
 use crate::ast::{ConstrainStatement, Expression, Statement, StatementKind};
 use crate::hir_def::expr::{
-    HirArrayLiteral, HirBlockExpression, HirExpression, HirIdent, HirLiteral,
+    Case, Constructor, HirArrayLiteral, HirBlockExpression, HirExpression, HirIdent, HirLiteral,
+    HirMatch,
 };
 use crate::hir_def::stmt::{HirLValue, HirPattern, HirStatement};
 use crate::hir_def::types::{Type, TypeBinding};
@@ -59,8 +60,19 @@ impl HirStatement {
                 block: for_stmt.block.to_display_ast(interner),
                 span,
             }),
+            HirStatement::While(while_stmt) => StatementKind::While(WhileStatement {
+                condition: while_stmt.condition.to_display_ast(interner),
+                block: while_stmt.block.to_display_ast(interner),
+                span,
+            }),
+            HirStatement::Loop(block) => {
+                StatementKind::Loop(LoopStatement { block: block.to_display_ast(interner), span })
+            }
             HirStatement::Break => StatementKind::Break,
             HirStatement::Continue => StatementKind::Continue,
+            HirStatement::Return(expr) => {
+                StatementKind::Return(expr.map(|expr| expr.to_display_ast(interner)))
+            }
             HirStatement::Expression(expr) => {
                 StatementKind::Expression(expr.to_display_ast(interner))
             }
@@ -154,6 +166,30 @@ impl HirExpression {
                     struct_type,
                 }))
             }
+            HirExpression::EnumConstructor(constructor) => {
+                let typ = constructor.r#type.borrow();
+                let variant = typ.variant_at(constructor.variant_index);
+                let segments = vec![
+                    PathSegment::from(Ident::new(typ.name.to_string(), span)),
+                    PathSegment::from(variant.name.clone()),
+                ];
+                let path = Path { segments, kind: crate::ast::PathKind::Plain, span };
+
+                if variant.is_function {
+                    let func = Box::new(Expression::new(ExpressionKind::Variable(path), span));
+                    let arguments =
+                        vecmap(constructor.arguments.clone(), |arg| arg.to_display_ast(interner));
+                    let is_macro_call = false;
+                    ExpressionKind::Call(Box::new(CallExpression {
+                        func,
+                        arguments,
+                        is_macro_call,
+                    }))
+                } else {
+                    ExpressionKind::Variable(path)
+                }
+            }
+            HirExpression::Match(match_expr) => match_expr.to_display_ast(interner, span).kind,
             HirExpression::MemberAccess(access) => {
                 ExpressionKind::MemberAccess(Box::new(MemberAccessExpression {
                     lhs: access.lhs.to_display_ast(interner),
@@ -227,9 +263,101 @@ impl ExprId {
     }
 }
 
+impl HirMatch {
+    /// Convert to AST for display. The decision tree is shown as nested `match`es
+    /// on each variable it tests, so the original patterns are lost.
+    fn to_display_ast(&self, interner: &NodeInterner, span: Span) -> Expression {
+        match self {
+            HirMatch::Success(expr) => expr.to_display_ast(interner),
+            HirMatch::Failure => Expression::new(ExpressionKind::Error, span),
+            HirMatch::Switch(variable, cases, fallback) => {
+                let variable_name = interner.definition_name(*variable).to_owned();
+                let expression = Expression::new(
+                    ExpressionKind::Variable(Path::from_single(variable_name, span)),
+                    span,
+                );
+
+                let mut rules = vecmap(cases, |case| {
+                    let pattern = case.to_display_ast(interner, span);
+                    (pattern, case.body.to_display_ast(interner, span))
+                });
+
+                if let Some(fallback) = fallback {
+                    let wildcard = Path::from_single("_".to_string(), span);
+                    let wildcard = Expression::new(ExpressionKind::Variable(wildcard), span);
+                    rules.push((wildcard, fallback.to_display_ast(interner, span)));
+                }
+
+                let kind = ExpressionKind::Match(Box::new(MatchExpression { expression, rules }));
+                Expression::new(kind, span)
+            }
+        }
+    }
+}
+
+impl Case {
+    /// Convert this case's constructor and the variables it binds into a pattern
+    fn to_display_ast(&self, interner: &NodeInterner, span: Span) -> Expression {
+        let arguments = vecmap(&self.arguments, |argument| {
+            let name = interner.definition_name(*argument).to_owned();
+            Expression::new(ExpressionKind::Variable(Path::from_single(name, span)), span)
+        });
+
+        let kind = match &self.constructor {
+            Constructor::True => ExpressionKind::Literal(Literal::Bool(true)),
+            Constructor::False => ExpressionKind::Literal(Literal::Bool(false)),
+            Constructor::Unit => ExpressionKind::Literal(Literal::Unit),
+            Constructor::Int(value, sign) => {
+                ExpressionKind::Literal(Literal::Integer(*value, *sign))
+            }
+            Constructor::Tuple(_) => ExpressionKind::Tuple(arguments),
+            Constructor::Struct(typ) => {
+                let Type::Struct(def, _) = typ.follow_bindings() else {
+                    return Expression::new(ExpressionKind::Error, span);
+                };
+                let def = def.borrow();
+                let type_name = Path::from_single(def.name.to_string(), span);
+                let fields = def.get_fields_as_written().into_iter().map(|field| field.name);
+                let fields = fields.zip(arguments).collect();
+                ExpressionKind::Constructor(Box::new(ConstructorExpression {
+                    typ: UnresolvedType::from_path(type_name),
+                    fields,
+                    struct_type: None,
+                }))
+            }
+            Constructor::Variant(typ, index) => {
+                let Type::Struct(def, _) = typ.follow_bindings() else {
+                    return Expression::new(ExpressionKind::Error, span);
+                };
+                let def = def.borrow();
+                let variant = def.variant_at(*index);
+                let segments = vec![
+                    PathSegment::from(Ident::new(def.name.to_string(), span)),
+                    PathSegment::from(variant.name.clone()),
+                ];
+                let path = Path { segments, kind: crate::ast::PathKind::Plain, span };
+
+                if variant.is_function {
+                    let func = Box::new(Expression::new(ExpressionKind::Variable(path), span));
+                    let is_macro_call = false;
+                    ExpressionKind::Call(Box::new(CallExpression {
+                        func,
+                        arguments,
+                        is_macro_call,
+                    }))
+                } else {
+                    ExpressionKind::Variable(path)
+                }
+            }
+        };
+
+        Expression::new(kind, span)
+    }
+}
+
 impl HirPattern {
     /// Convert to AST for display (some details lost)
-    fn to_display_ast(&self, interner: &NodeInterner) -> Pattern {
+    pub(crate) fn to_display_ast(&self, interner: &NodeInterner) -> Pattern {
         match self {
             HirPattern::Identifier(ident) => Pattern::Identifier(ident.to_display_ast(interner)),
             HirPattern::Mutable(pattern, location) => {
@@ -272,7 +400,7 @@ impl HirIdent {
 
 impl Type {
     /// Convert to AST for display (some details lost)
-    fn to_display_ast(&self) -> UnresolvedType {
+    pub(super) fn to_display_ast(&self) -> UnresolvedType {
         let typ = match self {
             Type::FieldElement => UnresolvedTypeData::FieldElement,
             Type::Array(length, element) => {
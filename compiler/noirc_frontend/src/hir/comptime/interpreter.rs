@@ -4,7 +4,7 @@ use std::{collections::hash_map::Entry, rc::Rc};
 use acvm::{acir::AcirField, FieldElement};
 use fm::FileId;
 use im::Vector;
-use iter_extended::try_vecmap;
+use iter_extended::{try_vecmap, vecmap};
 use noirc_errors::Location;
 use rustc_hash::FxHashMap as HashMap;
 
@@ -24,14 +24,14 @@ use crate::TypeVariable;
 use crate::{
     hir_def::{
         expr::{
-            HirArrayLiteral, HirBlockExpression, HirCallExpression, HirCastExpression,
-            HirConstructorExpression, HirExpression, HirIdent, HirIfExpression, HirIndexExpression,
-            HirInfixExpression, HirLambda, HirLiteral, HirMemberAccess, HirMethodCallExpression,
-            HirPrefixExpression,
+            Constructor, HirArrayLiteral, HirBlockExpression, HirCallExpression, HirCastExpression,
+            HirConstructorExpression, HirEnumConstructorExpression, HirExpression, HirIdent,
+            HirIfExpression, HirIndexExpression, HirInfixExpression, HirLambda, HirLiteral,
+            HirMatch, HirMemberAccess, HirMethodCallExpression, HirPrefixExpression,
         },
         stmt::{
             HirAssignStatement, HirConstrainStatement, HirForStatement, HirLValue, HirLetStatement,
-            HirPattern, HirStatement,
+            HirPattern, HirStatement, HirWhileStatement,
         },
         types::Kind,
     },
@@ -41,9 +41,12 @@ use crate::{
 
 use super::errors::{IResult, InterpreterError};
 use super::value::{unwrap_rc, Value};
+use super::ComptimeStepKind;
 
 mod builtin;
+mod file;
 mod foreign;
+mod oracle;
 mod unquote;
 
 #[allow(unused)]
@@ -55,6 +58,10 @@ pub struct Interpreter<'local, 'interner> {
 
     in_loop: bool,
 
+    /// How many function or closure calls deep we currently are.
+    /// A `return` is only valid if this is non-zero.
+    function_depth: usize,
+
     current_function: Option<FuncId>,
 
     /// Maps each bound generic to each binding it has in the current callstack.
@@ -73,7 +80,8 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
     ) -> Self {
         let bound_generics = Vec::new();
         let in_loop = false;
-        Self { elaborator, crate_id, current_function, bound_generics, in_loop }
+        let function_depth = 0;
+        Self { elaborator, crate_id, current_function, bound_generics, in_loop, function_depth }
     }
 
     pub(crate) fn call_function(
@@ -164,7 +172,7 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
         }
 
         let function_body = self.get_function_body(function, location)?;
-        let result = self.evaluate(function_body)?;
+        let result = self.evaluate_function_body(function_body)?;
         self.exit_function(previous_state);
         Ok(result)
     }
@@ -231,7 +239,13 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
             self.call_builtin(&builtin, arguments, return_type, location)
         } else if let Some(foreign) = func_attrs.foreign() {
             let foreign = foreign.clone();
-            foreign::call_foreign(self.elaborator.interner, &foreign, arguments, location)
+            foreign::call_foreign(
+                self.elaborator.interner,
+                &foreign,
+                arguments,
+                return_type,
+                location,
+            )
         } else if let Some(oracle) = func_attrs.oracle() {
             if oracle == "print" {
                 self.print_oracle(arguments)
@@ -239,8 +253,8 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
             } else if oracle.starts_with("__debug") {
                 Ok(Value::Unit)
             } else {
-                let item = format!("Comptime evaluation for oracle functions like {oracle}");
-                Err(InterpreterError::Unimplemented { item, location })
+                let oracle = oracle.clone();
+                self.call_oracle(&oracle, arguments, return_type, location)
             }
         } else {
             let name = self.elaborator.interner.function_name(&function);
@@ -294,12 +308,20 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
             self.define(param.ident.id, arg);
         }
 
-        let result = self.evaluate(closure.body)?;
+        let result = self.evaluate_function_body(closure.body)?;
 
         self.exit_function(previous_state);
         Ok(result)
     }
 
+    /// Evaluates the body of a function or closure, stopping early at any `return`
+    fn evaluate_function_body(&mut self, body: ExprId) -> IResult<Value> {
+        match self.evaluate(body) {
+            Err(InterpreterError::Return(value)) => Ok(value),
+            result => result,
+        }
+    }
+
     /// Enters a function, pushing a new scope and resetting any required state.
     /// Returns the previous values of the internal state, to be reset when
     /// `exit_function` is called.
@@ -310,11 +332,13 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
             scope = self.elaborator.interner.comptime_scopes.drain(1..).collect();
         }
         self.push_scope();
+        self.function_depth += 1;
         (std::mem::take(&mut self.in_loop), scope)
     }
 
     pub(super) fn exit_function(&mut self, mut state: (bool, Vec<HashMap<DefinitionId, Value>>)) {
         self.in_loop = state.0;
+        self.function_depth -= 1;
 
         // Keep only the global scope
         self.elaborator.interner.comptime_scopes.truncate(1);
@@ -520,6 +544,10 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
             HirExpression::MethodCall(call) => self.evaluate_method_call(call, id),
             HirExpression::Cast(cast) => self.evaluate_cast(&cast, id),
             HirExpression::If(if_) => self.evaluate_if(if_, id),
+            HirExpression::Match(match_) => self.evaluate_match(match_, id),
+            HirExpression::EnumConstructor(constructor) => {
+                self.evaluate_enum_constructor(constructor, id)
+            }
             HirExpression::Tuple(tuple) => self.evaluate_tuple(tuple),
             HirExpression::Lambda(lambda) => self.evaluate_lambda(lambda, id),
             HirExpression::Quote(tokens) => self.evaluate_quote(tokens, id),
@@ -680,7 +708,15 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
     ) -> IResult<Value> {
         let typ = self.elaborator.interner.id_type(id).follow_bindings();
         let location = self.elaborator.interner.expr_location(&id);
+        Self::integer_value_of_type(value, is_negative, typ, location)
+    }
 
+    fn integer_value_of_type(
+        value: FieldElement,
+        is_negative: bool,
+        typ: Type,
+        location: Location,
+    ) -> IResult<Value> {
         if let Type::FieldElement = &typ {
             Ok(Value::Field(value))
         } else if let Type::Integer(sign, bit_size) = &typ {
@@ -724,6 +760,13 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                     let value = if is_negative { 0u64.wrapping_sub(value) } else { value };
                     Ok(Value::U64(value))
                 }
+                (Signedness::Unsigned, IntegerBitSize::HundredTwentyEight) => {
+                    let value: u128 = value.try_into_u128().ok_or(
+                        InterpreterError::IntegerOutOfRangeForType { value, typ, location },
+                    )?;
+                    let value = if is_negative { 0u128.wrapping_sub(value) } else { value };
+                    Ok(Value::U128(value))
+                }
                 (Signedness::Signed, IntegerBitSize::One) => {
                     return Err(InterpreterError::TypeUnsupported { typ, location });
                 }
@@ -759,6 +802,23 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                     let value = if is_negative { -value } else { value };
                     Ok(Value::I64(value))
                 }
+                (Signedness::Signed, IntegerBitSize::HundredTwentyEight) => {
+                    // The magnitude of `i128::MIN` doesn't fit in an `i128`, so negative
+                    // values are subtracted from zero rather than negated
+                    let signed_value = value.try_into_u128().and_then(|magnitude| {
+                        if is_negative {
+                            0i128.checked_sub_unsigned(magnitude)
+                        } else {
+                            magnitude.try_into().ok()
+                        }
+                    });
+                    let value = signed_value.ok_or(InterpreterError::IntegerOutOfRangeForType {
+                        value,
+                        typ,
+                        location,
+                    })?;
+                    Ok(Value::I128(value))
+                }
             }
         } else if let Type::TypeVariable(variable) = &typ {
             if variable.is_integer_or_field() {
@@ -859,10 +919,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 Value::I16(value) => Ok(Value::I16(-value)),
                 Value::I32(value) => Ok(Value::I32(-value)),
                 Value::I64(value) => Ok(Value::I64(-value)),
+                Value::I128(value) => Ok(Value::I128(-value)),
                 Value::U8(value) => Ok(Value::U8(0 - value)),
                 Value::U16(value) => Ok(Value::U16(0 - value)),
                 Value::U32(value) => Ok(Value::U32(0 - value)),
                 Value::U64(value) => Ok(Value::U64(0 - value)),
+                Value::U128(value) => Ok(Value::U128(0 - value)),
                 value => {
                     let location = self.elaborator.interner.expr_location(&id);
                     let operator = "minus";
@@ -876,10 +938,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 Value::I16(value) => Ok(Value::I16(!value)),
                 Value::I32(value) => Ok(Value::I32(!value)),
                 Value::I64(value) => Ok(Value::I64(!value)),
+                Value::I128(value) => Ok(Value::I128(!value)),
                 Value::U8(value) => Ok(Value::U8(!value)),
                 Value::U16(value) => Ok(Value::U16(!value)),
                 Value::U32(value) => Ok(Value::U32(!value)),
                 Value::U64(value) => Ok(Value::U64(!value)),
+                Value::U128(value) => Ok(Value::U128(!value)),
                 value => {
                     let location = self.elaborator.interner.expr_location(&id);
                     let typ = value.get_type().into_owned();
@@ -940,6 +1004,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I64(lhs), Value::I64(rhs)) => {
                     Ok(Value::I64(lhs.checked_add(rhs).ok_or(error("+"))?))
                 }
+                (Value::I128(lhs), Value::I128(rhs)) => {
+                    Ok(Value::I128(lhs.checked_add(rhs).ok_or(error("+"))?))
+                }
                 (Value::U8(lhs), Value::U8(rhs)) => {
                     Ok(Value::U8(lhs.checked_add(rhs).ok_or(error("+"))?))
                 }
@@ -952,6 +1019,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::U64(lhs), Value::U64(rhs)) => {
                     Ok(Value::U64(lhs.checked_add(rhs).ok_or(error("+"))?))
                 }
+                (Value::U128(lhs), Value::U128(rhs)) => {
+                    Ok(Value::U128(lhs.checked_add(rhs).ok_or(error("+"))?))
+                }
                 (lhs, rhs) => Err(error("+")),
             },
             BinaryOpKind::Subtract => match (lhs_value.clone(), rhs_value.clone()) {
@@ -968,6 +1038,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I64(lhs), Value::I64(rhs)) => {
                     Ok(Value::I64(lhs.checked_sub(rhs).ok_or(error("-"))?))
                 }
+                (Value::I128(lhs), Value::I128(rhs)) => {
+                    Ok(Value::I128(lhs.checked_sub(rhs).ok_or(error("-"))?))
+                }
                 (Value::U8(lhs), Value::U8(rhs)) => {
                     Ok(Value::U8(lhs.checked_sub(rhs).ok_or(error("-"))?))
                 }
@@ -980,6 +1053,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::U64(lhs), Value::U64(rhs)) => {
                     Ok(Value::U64(lhs.checked_sub(rhs).ok_or(error("-"))?))
                 }
+                (Value::U128(lhs), Value::U128(rhs)) => {
+                    Ok(Value::U128(lhs.checked_sub(rhs).ok_or(error("-"))?))
+                }
                 (lhs, rhs) => Err(error("-")),
             },
             BinaryOpKind::Multiply => match (lhs_value.clone(), rhs_value.clone()) {
@@ -996,6 +1072,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I64(lhs), Value::I64(rhs)) => {
                     Ok(Value::I64(lhs.checked_mul(rhs).ok_or(error("*"))?))
                 }
+                (Value::I128(lhs), Value::I128(rhs)) => {
+                    Ok(Value::I128(lhs.checked_mul(rhs).ok_or(error("*"))?))
+                }
                 (Value::U8(lhs), Value::U8(rhs)) => {
                     Ok(Value::U8(lhs.checked_mul(rhs).ok_or(error("*"))?))
                 }
@@ -1008,6 +1087,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::U64(lhs), Value::U64(rhs)) => {
                     Ok(Value::U64(lhs.checked_mul(rhs).ok_or(error("*"))?))
                 }
+                (Value::U128(lhs), Value::U128(rhs)) => {
+                    Ok(Value::U128(lhs.checked_mul(rhs).ok_or(error("*"))?))
+                }
                 (lhs, rhs) => Err(error("*")),
             },
             BinaryOpKind::Divide => match (lhs_value.clone(), rhs_value.clone()) {
@@ -1024,6 +1106,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I64(lhs), Value::I64(rhs)) => {
                     Ok(Value::I64(lhs.checked_div(rhs).ok_or(error("/"))?))
                 }
+                (Value::I128(lhs), Value::I128(rhs)) => {
+                    Ok(Value::I128(lhs.checked_div(rhs).ok_or(error("/"))?))
+                }
                 (Value::U8(lhs), Value::U8(rhs)) => {
                     Ok(Value::U8(lhs.checked_div(rhs).ok_or(error("/"))?))
                 }
@@ -1036,6 +1121,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::U64(lhs), Value::U64(rhs)) => {
                     Ok(Value::U64(lhs.checked_div(rhs).ok_or(error("/"))?))
                 }
+                (Value::U128(lhs), Value::U128(rhs)) => {
+                    Ok(Value::U128(lhs.checked_div(rhs).ok_or(error("/"))?))
+                }
                 (lhs, rhs) => Err(error("/")),
             },
             BinaryOpKind::Equal => match (lhs_value.clone(), rhs_value.clone()) {
@@ -1044,10 +1132,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I16(lhs), Value::I16(rhs)) => Ok(Value::Bool(lhs == rhs)),
                 (Value::I32(lhs), Value::I32(rhs)) => Ok(Value::Bool(lhs == rhs)),
                 (Value::I64(lhs), Value::I64(rhs)) => Ok(Value::Bool(lhs == rhs)),
+                (Value::I128(lhs), Value::I128(rhs)) => Ok(Value::Bool(lhs == rhs)),
                 (Value::U8(lhs), Value::U8(rhs)) => Ok(Value::Bool(lhs == rhs)),
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::Bool(lhs == rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::Bool(lhs == rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::Bool(lhs == rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::Bool(lhs == rhs)),
                 (Value::Bool(lhs), Value::Bool(rhs)) => Ok(Value::Bool(lhs == rhs)),
                 (lhs, rhs) => Err(error("==")),
             },
@@ -1057,10 +1147,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I16(lhs), Value::I16(rhs)) => Ok(Value::Bool(lhs != rhs)),
                 (Value::I32(lhs), Value::I32(rhs)) => Ok(Value::Bool(lhs != rhs)),
                 (Value::I64(lhs), Value::I64(rhs)) => Ok(Value::Bool(lhs != rhs)),
+                (Value::I128(lhs), Value::I128(rhs)) => Ok(Value::Bool(lhs != rhs)),
                 (Value::U8(lhs), Value::U8(rhs)) => Ok(Value::Bool(lhs != rhs)),
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::Bool(lhs != rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::Bool(lhs != rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::Bool(lhs != rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::Bool(lhs != rhs)),
                 (Value::Bool(lhs), Value::Bool(rhs)) => Ok(Value::Bool(lhs != rhs)),
                 (lhs, rhs) => Err(error("!=")),
             },
@@ -1070,10 +1162,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I16(lhs), Value::I16(rhs)) => Ok(Value::Bool(lhs < rhs)),
                 (Value::I32(lhs), Value::I32(rhs)) => Ok(Value::Bool(lhs < rhs)),
                 (Value::I64(lhs), Value::I64(rhs)) => Ok(Value::Bool(lhs < rhs)),
+                (Value::I128(lhs), Value::I128(rhs)) => Ok(Value::Bool(lhs < rhs)),
                 (Value::U8(lhs), Value::U8(rhs)) => Ok(Value::Bool(lhs < rhs)),
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::Bool(lhs < rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::Bool(lhs < rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::Bool(lhs < rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::Bool(lhs < rhs)),
                 (lhs, rhs) => Err(error("<")),
             },
             BinaryOpKind::LessEqual => match (lhs_value.clone(), rhs_value.clone()) {
@@ -1082,10 +1176,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I16(lhs), Value::I16(rhs)) => Ok(Value::Bool(lhs <= rhs)),
                 (Value::I32(lhs), Value::I32(rhs)) => Ok(Value::Bool(lhs <= rhs)),
                 (Value::I64(lhs), Value::I64(rhs)) => Ok(Value::Bool(lhs <= rhs)),
+                (Value::I128(lhs), Value::I128(rhs)) => Ok(Value::Bool(lhs <= rhs)),
                 (Value::U8(lhs), Value::U8(rhs)) => Ok(Value::Bool(lhs <= rhs)),
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::Bool(lhs <= rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::Bool(lhs <= rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::Bool(lhs <= rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::Bool(lhs <= rhs)),
                 (lhs, rhs) => Err(error("<=")),
             },
             BinaryOpKind::Greater => match (lhs_value.clone(), rhs_value.clone()) {
@@ -1094,10 +1190,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I16(lhs), Value::I16(rhs)) => Ok(Value::Bool(lhs > rhs)),
                 (Value::I32(lhs), Value::I32(rhs)) => Ok(Value::Bool(lhs > rhs)),
                 (Value::I64(lhs), Value::I64(rhs)) => Ok(Value::Bool(lhs > rhs)),
+                (Value::I128(lhs), Value::I128(rhs)) => Ok(Value::Bool(lhs > rhs)),
                 (Value::U8(lhs), Value::U8(rhs)) => Ok(Value::Bool(lhs > rhs)),
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::Bool(lhs > rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::Bool(lhs > rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::Bool(lhs > rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::Bool(lhs > rhs)),
                 (lhs, rhs) => Err(error(">")),
             },
             BinaryOpKind::GreaterEqual => match (lhs_value.clone(), rhs_value.clone()) {
@@ -1106,10 +1204,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I16(lhs), Value::I16(rhs)) => Ok(Value::Bool(lhs >= rhs)),
                 (Value::I32(lhs), Value::I32(rhs)) => Ok(Value::Bool(lhs >= rhs)),
                 (Value::I64(lhs), Value::I64(rhs)) => Ok(Value::Bool(lhs >= rhs)),
+                (Value::I128(lhs), Value::I128(rhs)) => Ok(Value::Bool(lhs >= rhs)),
                 (Value::U8(lhs), Value::U8(rhs)) => Ok(Value::Bool(lhs >= rhs)),
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::Bool(lhs >= rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::Bool(lhs >= rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::Bool(lhs >= rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::Bool(lhs >= rhs)),
                 (lhs, rhs) => Err(error(">=")),
             },
             BinaryOpKind::And => match (lhs_value.clone(), rhs_value.clone()) {
@@ -1118,10 +1218,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I16(lhs), Value::I16(rhs)) => Ok(Value::I16(lhs & rhs)),
                 (Value::I32(lhs), Value::I32(rhs)) => Ok(Value::I32(lhs & rhs)),
                 (Value::I64(lhs), Value::I64(rhs)) => Ok(Value::I64(lhs & rhs)),
+                (Value::I128(lhs), Value::I128(rhs)) => Ok(Value::I128(lhs & rhs)),
                 (Value::U8(lhs), Value::U8(rhs)) => Ok(Value::U8(lhs & rhs)),
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs & rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs & rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs & rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs & rhs)),
                 (lhs, rhs) => Err(error("&")),
             },
             BinaryOpKind::Or => match (lhs_value.clone(), rhs_value.clone()) {
@@ -1130,10 +1232,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I16(lhs), Value::I16(rhs)) => Ok(Value::I16(lhs | rhs)),
                 (Value::I32(lhs), Value::I32(rhs)) => Ok(Value::I32(lhs | rhs)),
                 (Value::I64(lhs), Value::I64(rhs)) => Ok(Value::I64(lhs | rhs)),
+                (Value::I128(lhs), Value::I128(rhs)) => Ok(Value::I128(lhs | rhs)),
                 (Value::U8(lhs), Value::U8(rhs)) => Ok(Value::U8(lhs | rhs)),
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs | rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs | rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs | rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs | rhs)),
                 (lhs, rhs) => Err(error("|")),
             },
             BinaryOpKind::Xor => match (lhs_value.clone(), rhs_value.clone()) {
@@ -1142,10 +1246,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I16(lhs), Value::I16(rhs)) => Ok(Value::I16(lhs ^ rhs)),
                 (Value::I32(lhs), Value::I32(rhs)) => Ok(Value::I32(lhs ^ rhs)),
                 (Value::I64(lhs), Value::I64(rhs)) => Ok(Value::I64(lhs ^ rhs)),
+                (Value::I128(lhs), Value::I128(rhs)) => Ok(Value::I128(lhs ^ rhs)),
                 (Value::U8(lhs), Value::U8(rhs)) => Ok(Value::U8(lhs ^ rhs)),
                 (Value::U16(lhs), Value::U16(rhs)) => Ok(Value::U16(lhs ^ rhs)),
                 (Value::U32(lhs), Value::U32(rhs)) => Ok(Value::U32(lhs ^ rhs)),
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(lhs ^ rhs)),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(lhs ^ rhs)),
                 (lhs, rhs) => Err(error("^")),
             },
             BinaryOpKind::ShiftRight => match (lhs_value.clone(), rhs_value.clone()) {
@@ -1161,6 +1267,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I64(lhs), Value::I64(rhs)) => Ok(Value::I64(
                     lhs.checked_shr(rhs.try_into().map_err(|_| error(">>"))?).ok_or(error(">>"))?,
                 )),
+                (Value::I128(lhs), Value::I128(rhs)) => Ok(Value::I128(
+                    lhs.checked_shr(rhs.try_into().map_err(|_| error(">>"))?).ok_or(error(">>"))?,
+                )),
                 (Value::U8(lhs), Value::U8(rhs)) => {
                     Ok(Value::U8(lhs.checked_shr(rhs.into()).ok_or(error(">>"))?))
                 }
@@ -1173,6 +1282,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(
                     lhs.checked_shr(rhs.try_into().map_err(|_| error(">>"))?).ok_or(error(">>"))?,
                 )),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(
+                    lhs.checked_shr(rhs.try_into().map_err(|_| error(">>"))?).ok_or(error(">>"))?,
+                )),
                 (lhs, rhs) => Err(error(">>")),
             },
             BinaryOpKind::ShiftLeft => match (lhs_value.clone(), rhs_value.clone()) {
@@ -1188,6 +1300,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I64(lhs), Value::I64(rhs)) => Ok(Value::I64(
                     lhs.checked_shl(rhs.try_into().map_err(|_| error("<<"))?).ok_or(error("<<"))?,
                 )),
+                (Value::I128(lhs), Value::I128(rhs)) => Ok(Value::I128(
+                    lhs.checked_shl(rhs.try_into().map_err(|_| error("<<"))?).ok_or(error("<<"))?,
+                )),
                 (Value::U8(lhs), Value::U8(rhs)) => {
                     Ok(Value::U8(lhs.checked_shl(rhs.into()).ok_or(error("<<"))?))
                 }
@@ -1200,6 +1315,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::U64(lhs), Value::U64(rhs)) => Ok(Value::U64(
                     lhs.checked_shl(rhs.try_into().map_err(|_| error("<<"))?).ok_or(error("<<"))?,
                 )),
+                (Value::U128(lhs), Value::U128(rhs)) => Ok(Value::U128(
+                    lhs.checked_shl(rhs.try_into().map_err(|_| error("<<"))?).ok_or(error("<<"))?,
+                )),
                 (lhs, rhs) => Err(error("<<")),
             },
             BinaryOpKind::Modulo => match (lhs_value.clone(), rhs_value.clone()) {
@@ -1215,6 +1333,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::I64(lhs), Value::I64(rhs)) => {
                     Ok(Value::I64(lhs.checked_rem(rhs).ok_or(error("%"))?))
                 }
+                (Value::I128(lhs), Value::I128(rhs)) => {
+                    Ok(Value::I128(lhs.checked_rem(rhs).ok_or(error("%"))?))
+                }
                 (Value::U8(lhs), Value::U8(rhs)) => {
                     Ok(Value::U8(lhs.checked_rem(rhs).ok_or(error("%"))?))
                 }
@@ -1227,6 +1348,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Value::U64(lhs), Value::U64(rhs)) => {
                     Ok(Value::U64(lhs.checked_rem(rhs).ok_or(error("%"))?))
                 }
+                (Value::U128(lhs), Value::U128(rhs)) => {
+                    Ok(Value::U128(lhs.checked_rem(rhs).ok_or(error("%"))?))
+                }
                 (lhs, rhs) => Err(error("%")),
             },
         }
@@ -1347,10 +1471,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
             Value::I16(value) => value as usize,
             Value::I32(value) => value as usize,
             Value::I64(value) => value as usize,
+            Value::I128(value) => value as usize,
             Value::U8(value) => value as usize,
             Value::U16(value) => value as usize,
             Value::U32(value) => value as usize,
             Value::U64(value) => value as usize,
+            Value::U128(value) => value as usize,
             value => {
                 let typ = value.get_type().into_owned();
                 return Err(InterpreterError::NonIntegerUsedAsIndex { typ, location });
@@ -1527,10 +1653,12 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
             Value::U16(value) => ((value as u128).into(), false),
             Value::U32(value) => ((value as u128).into(), false),
             Value::U64(value) => ((value as u128).into(), false),
+            Value::U128(value) => (value.into(), false),
             Value::I8(value) => signed_int_to_field!(value),
             Value::I16(value) => signed_int_to_field!(value),
             Value::I32(value) => signed_int_to_field!(value),
             Value::I64(value) => signed_int_to_field!(value),
+            Value::I128(value) => (value.unsigned_abs().into(), value < 0),
             Value::Bool(value) => {
                 (if value { FieldElement::one() } else { FieldElement::zero() }, false)
             }
@@ -1574,6 +1702,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Signedness::Unsigned, IntegerBitSize::SixtyFour) => {
                     cast_to_int!(lhs, to_u128, u64, U64)
                 }
+                (Signedness::Unsigned, IntegerBitSize::HundredTwentyEight) => {
+                    cast_to_int!(lhs, to_u128, u128, U128)
+                }
                 (Signedness::Signed, IntegerBitSize::One) => {
                     let location = interner.expr_location(&id);
                     Err(InterpreterError::TypeUnsupported { typ: cast.r#type.clone(), location })
@@ -1588,6 +1719,9 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 (Signedness::Signed, IntegerBitSize::SixtyFour) => {
                     cast_to_int!(lhs, to_i128, i64, I64)
                 }
+                (Signedness::Signed, IntegerBitSize::HundredTwentyEight) => {
+                    cast_to_int!(lhs, to_i128, i128, I128)
+                }
             },
             Type::Bool => Ok(Value::Bool(!lhs.is_zero() || lhs_is_negative)),
             typ => {
@@ -1627,6 +1761,93 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
         result
     }
 
+    fn evaluate_match(&mut self, match_: HirMatch, id: ExprId) -> IResult<Value> {
+        let location = self.elaborator.interner.expr_location(&id);
+
+        match match_ {
+            HirMatch::Success(expr) => self.evaluate(expr),
+            // Matches which can fail are already reported as missing cases
+            HirMatch::Failure => Err(InterpreterError::ErrorNodeEncountered { location }),
+            HirMatch::Switch(variable, cases, fallback) => {
+                let value = self.lookup_id(variable, location)?;
+
+                for case in cases {
+                    if let Some(arguments) =
+                        self.match_constructor(&value, &case.constructor, location)?
+                    {
+                        self.push_scope();
+                        for (argument, value) in case.arguments.into_iter().zip(arguments) {
+                            self.define(argument, value);
+                        }
+                        let result = self.evaluate_match(case.body, id);
+                        self.pop_scope();
+                        return result;
+                    }
+                }
+
+                match fallback {
+                    Some(fallback) => self.evaluate_match(*fallback, id),
+                    None => Err(InterpreterError::ErrorNodeEncountered { location }),
+                }
+            }
+        }
+    }
+
+    /// Returns the arguments of the given constructor within `value` if `value`
+    /// was built with that constructor, or `None` otherwise.
+    fn match_constructor(
+        &self,
+        value: &Value,
+        constructor: &Constructor,
+        location: Location,
+    ) -> IResult<Option<Vec<Value>>> {
+        let arguments = match (constructor, value) {
+            (Constructor::True, Value::Bool(true))
+            | (Constructor::False, Value::Bool(false))
+            | (Constructor::Unit, Value::Unit) => Some(Vec::new()),
+            (Constructor::True | Constructor::False, Value::Bool(_)) => None,
+            (Constructor::Int(int, is_negative), value) => {
+                let typ = value.get_type().into_owned();
+                let int = Self::integer_value_of_type(*int, *is_negative, typ, location)?;
+                (&int == value).then(Vec::new)
+            }
+            (Constructor::Tuple(_), Value::Tuple(fields)) => Some(fields.clone()),
+            (Constructor::Struct(_), Value::Struct(fields, typ)) => {
+                let Type::Struct(struct_type, generics) = typ.follow_bindings() else {
+                    let typ = typ.clone();
+                    return Err(InterpreterError::NonStructInConstructor { typ, location });
+                };
+                let field_names = struct_type.borrow().get_fields(&generics);
+                Some(vecmap(field_names, |(name, _)| fields[&Rc::new(name)].clone()))
+            }
+            (Constructor::Variant(_, index), Value::Enum(tag, arguments, _)) => {
+                (index == tag).then(|| arguments.clone())
+            }
+            (constructor, value) => {
+                let expected = match constructor {
+                    Constructor::True | Constructor::False => Type::Bool,
+                    Constructor::Unit => Type::Unit,
+                    Constructor::Tuple(types) => Type::Tuple(types.clone()),
+                    Constructor::Struct(typ) | Constructor::Variant(typ, _) => typ.clone(),
+                    Constructor::Int(..) => unreachable!("Integers can match any value"),
+                };
+                let actual = value.get_type().into_owned();
+                return Err(InterpreterError::TypeMismatch { expected, actual, location });
+            }
+        };
+        Ok(arguments)
+    }
+
+    fn evaluate_enum_constructor(
+        &mut self,
+        constructor: HirEnumConstructorExpression,
+        id: ExprId,
+    ) -> IResult<Value> {
+        let arguments = try_vecmap(constructor.arguments, |argument| self.evaluate(argument))?;
+        let typ = self.elaborator.interner.id_type(id).follow_bindings();
+        Ok(Value::Enum(constructor.variant_index, arguments, typ))
+    }
+
     fn evaluate_tuple(&mut self, tuple: Vec<ExprId>) -> IResult<Value> {
         let fields = try_vecmap(tuple, |field| self.evaluate(field))?;
         Ok(Value::Tuple(fields))
@@ -1645,17 +1866,28 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
     fn evaluate_quote(&mut self, mut tokens: Tokens, expr_id: ExprId) -> IResult<Value> {
         let location = self.elaborator.interner.expr_location(&expr_id);
         let tokens = self.substitute_unquoted_values_into_tokens(tokens, location)?;
-        Ok(Value::Quoted(Rc::new(tokens)))
+        let value = Value::Quoted(Rc::new(tokens));
+        self.elaborator.debug_comptime_step(location, self.current_function, |interner| {
+            ComptimeStepKind::Quote(value.display(interner).to_string())
+        });
+        Ok(value)
     }
 
     pub fn evaluate_statement(&mut self, statement: StmtId) -> IResult<Value> {
+        let location = self.elaborator.interner.statement_location(statement);
+        self.elaborator
+            .debug_comptime_step(location, self.current_function, |_| ComptimeStepKind::Statement);
+
         match self.elaborator.interner.statement(&statement) {
             HirStatement::Let(let_) => self.evaluate_let(let_),
             HirStatement::Constrain(constrain) => self.evaluate_constrain(constrain),
             HirStatement::Assign(assign) => self.evaluate_assign(assign),
             HirStatement::For(for_) => self.evaluate_for(for_),
+            HirStatement::While(while_) => self.evaluate_while(while_),
+            HirStatement::Loop(block) => self.evaluate_loop(block),
             HirStatement::Break => self.evaluate_break(statement),
             HirStatement::Continue => self.evaluate_continue(statement),
+            HirStatement::Return(expression) => self.evaluate_return(expression, statement),
             HirStatement::Expression(expression) => self.evaluate(expression),
             HirStatement::Comptime(statement) => self.evaluate_comptime(statement),
             HirStatement::Semi(expression) => {
@@ -1804,7 +2036,7 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
     }
 
     fn evaluate_for(&mut self, for_: HirForStatement) -> IResult<Value> {
-        // i128 can store all values from i8 - u64
+        // i128 can store all values from i8 - u64, and the u128 values small enough to loop over
         let get_index = |this: &mut Self, expr| -> IResult<(_, fn(_) -> _)> {
             match this.evaluate(expr)? {
                 Value::I8(value) => Ok((value as i128, |i| Value::I8(i as i8))),
@@ -1815,6 +2047,17 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 Value::U16(value) => Ok((value as i128, |i| Value::U16(i as u16))),
                 Value::U32(value) => Ok((value as i128, |i| Value::U32(i as u32))),
                 Value::U64(value) => Ok((value as i128, |i| Value::U64(i as u64))),
+                Value::I128(value) => Ok((value, Value::I128)),
+                Value::U128(value) => match i128::try_from(value) {
+                    Ok(index) => Ok((index, |i| Value::U128(i as u128))),
+                    Err(_) => {
+                        let location = this.elaborator.interner.expr_location(&expr);
+                        let typ =
+                            Type::Integer(Signedness::Unsigned, IntegerBitSize::HundredTwentyEight);
+                        let value = value.into();
+                        Err(InterpreterError::IntegerOutOfRangeForType { value, typ, location })
+                    }
+                },
                 value => {
                     let location = this.elaborator.interner.expr_location(&expr);
                     let typ = value.get_type().into_owned();
@@ -1845,6 +2088,54 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
         Ok(Value::Unit)
     }
 
+    fn evaluate_while(&mut self, while_: HirWhileStatement) -> IResult<Value> {
+        let was_in_loop = std::mem::replace(&mut self.in_loop, true);
+
+        loop {
+            let condition = match self.evaluate(while_.condition)? {
+                Value::Bool(value) => value,
+                value => {
+                    let location = self.elaborator.interner.expr_location(&while_.condition);
+                    let typ = value.get_type().into_owned();
+                    return Err(InterpreterError::NonBoolUsedInWhile { typ, location });
+                }
+            };
+
+            if !condition {
+                break;
+            }
+
+            if !self.evaluate_loop_body(while_.block)? {
+                break;
+            }
+        }
+
+        self.in_loop = was_in_loop;
+        Ok(Value::Unit)
+    }
+
+    fn evaluate_loop(&mut self, block: ExprId) -> IResult<Value> {
+        let was_in_loop = std::mem::replace(&mut self.in_loop, true);
+
+        while self.evaluate_loop_body(block)? {}
+
+        self.in_loop = was_in_loop;
+        Ok(Value::Unit)
+    }
+
+    /// Evaluates a single iteration of a `while` or `loop` body in a fresh scope.
+    /// Returns `false` if the body executed a `break`.
+    fn evaluate_loop_body(&mut self, block: ExprId) -> IResult<bool> {
+        self.push_scope();
+        let result = match self.evaluate(block) {
+            Ok(_) | Err(InterpreterError::Continue) => Ok(true),
+            Err(InterpreterError::Break) => Ok(false),
+            Err(other) => Err(other),
+        };
+        self.pop_scope();
+        result
+    }
+
     fn evaluate_break(&mut self, id: StmtId) -> IResult<Value> {
         if self.in_loop {
             Err(InterpreterError::Break)
@@ -1863,6 +2154,19 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
         }
     }
 
+    fn evaluate_return(&mut self, expression: Option<ExprId>, id: StmtId) -> IResult<Value> {
+        if self.function_depth == 0 {
+            let location = self.elaborator.interner.statement_location(id);
+            return Err(InterpreterError::ReturnNotInFunction { location });
+        }
+
+        let value = match expression {
+            Some(expression) => self.evaluate(expression)?,
+            None => Value::Unit,
+        };
+        Err(InterpreterError::Return(value))
+    }
+
     pub(super) fn evaluate_comptime(&mut self, statement: StmtId) -> IResult<Value> {
         self.evaluate_statement(statement)
     }
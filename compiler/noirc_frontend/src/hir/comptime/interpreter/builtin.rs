@@ -1,13 +1,13 @@
 use std::rc::Rc;
 
-use acvm::{AcirField, FieldElement};
+use acvm::{acir::BlackBoxFunc, AcirField, FieldElement};
 use builtin_helpers::{
     block_expression_to_value, check_argument_count, check_function_not_yet_resolved,
     check_one_argument, check_three_arguments, check_two_arguments, get_bool, get_expr, get_field,
     get_format_string, get_function_def, get_module, get_quoted, get_slice, get_struct,
-    get_trait_constraint, get_trait_def, get_trait_impl, get_tuple, get_type, get_typed_expr,
-    get_u32, get_unresolved_type, has_named_attribute, hir_pattern_to_tokens,
-    mutate_func_meta_type, parse, quote_ident, replace_func_meta_parameters,
+    get_struct_field, get_struct_fields, get_trait_constraint, get_trait_def, get_trait_impl,
+    get_tuple, get_type, get_typed_expr, get_u32, get_unresolved_type, has_named_attribute,
+    hir_pattern_to_tokens, mutate_func_meta_type, parse, quote_ident, replace_func_meta_parameters,
     replace_func_meta_return_type,
 };
 use im::Vector;
@@ -25,11 +25,12 @@ use crate::{
     },
     hir::{
         comptime::{
+            display::display_generated_block,
             errors::IResult,
             value::{ExprValue, TypedExpr},
             InterpreterError, Value,
         },
-        def_collector::dc_crate::CollectedItems,
+        def_collector::{dc_crate::CollectedItems, dc_mod::remove_disabled_items},
         def_map::ModuleDefId,
     },
     hir_def::expr::{HirExpression, HirLiteral},
@@ -61,6 +62,22 @@ impl<'local, 'context> Interpreter<'local, 'context> {
             "array_len" => array_len(interner, arguments, location),
             "assert_constant" => Ok(Value::Bool(true)),
             "as_slice" => as_slice(interner, arguments, location),
+            "bigint_add" => {
+                bigint_op(interner, arguments, return_type, location, BlackBoxFunc::BigIntAdd)
+            }
+            "bigint_div" => {
+                bigint_op(interner, arguments, return_type, location, BlackBoxFunc::BigIntDiv)
+            }
+            "bigint_from_le_bytes" => {
+                bigint_from_le_bytes(interner, arguments, return_type, location)
+            }
+            "bigint_mul" => {
+                bigint_op(interner, arguments, return_type, location, BlackBoxFunc::BigIntMul)
+            }
+            "bigint_sub" => {
+                bigint_op(interner, arguments, return_type, location, BlackBoxFunc::BigIntSub)
+            }
+            "bigint_to_le_bytes" => bigint_to_le_bytes(interner, arguments, return_type, location),
             "ctstring_eq" => ctstring_eq(arguments, location),
             "ctstring_hash" => ctstring_hash(arguments, location),
             "derive_pedersen_generators" => {
@@ -110,12 +127,17 @@ impl<'local, 'context> Interpreter<'local, 'context> {
             "expr_resolve" => expr_resolve(self, arguments, location),
             "is_unconstrained" => Ok(Value::Bool(true)),
             "field_less_than" => field_less_than(arguments, location),
+            "file_read_bytes" => self.file_read_bytes(arguments, location),
+            "file_read_json" => self.file_read_json(arguments, return_type, location),
+            "file_read_string" => self.file_read_string(arguments, location),
+            "file_read_toml" => self.file_read_toml(arguments, return_type, location),
             "fmtstr_as_ctstring" => fmtstr_as_ctstring(interner, arguments, location),
             "fmtstr_quoted_contents" => fmtstr_quoted_contents(interner, arguments, location),
             "fresh_type_variable" => fresh_type_variable(interner),
             "function_def_add_attribute" => function_def_add_attribute(self, arguments, location),
             "function_def_body" => function_def_body(interner, arguments, location),
             "function_def_eq" => function_def_eq(arguments, location),
+            "function_def_generics" => function_def_generics(interner, arguments, location),
             "function_def_has_named_attribute" => {
                 function_def_has_named_attribute(interner, arguments, location)
             }
@@ -141,14 +163,19 @@ impl<'local, 'context> Interpreter<'local, 'context> {
             "function_def_set_unconstrained" => {
                 function_def_set_unconstrained(self, arguments, location)
             }
+            "function_def_where_clause" => function_def_where_clause(interner, arguments, location),
             "module_add_item" => module_add_item(self, arguments, location),
+            "module_child_modules" => module_child_modules(self, arguments, location),
             "module_eq" => module_eq(arguments, location),
             "module_functions" => module_functions(self, arguments, location),
+            "module_globals" => module_globals(self, arguments, location),
             "module_has_named_attribute" => module_has_named_attribute(self, arguments, location),
             "module_hash" => module_hash(arguments, location),
             "module_is_contract" => module_is_contract(self, arguments, location),
             "module_name" => module_name(interner, arguments, location),
             "module_structs" => module_structs(self, arguments, location),
+            "module_traits" => module_traits(self, arguments, location),
+            "module_type_aliases" => module_type_aliases(self, arguments, location),
             "modulus_be_bits" => modulus_be_bits(arguments, location),
             "modulus_be_bytes" => modulus_be_bytes(arguments, location),
             "modulus_le_bits" => modulus_le_bits(arguments, location),
@@ -179,9 +206,11 @@ impl<'local, 'context> Interpreter<'local, 'context> {
                 struct_def_has_named_attribute(interner, arguments, location)
             }
             "struct_def_hash" => struct_def_hash(arguments, location),
+            "struct_def_methods" => struct_def_methods(interner, arguments, location),
             "struct_def_module" => struct_def_module(self, arguments, location),
             "struct_def_name" => struct_def_name(interner, arguments, location),
             "struct_def_set_fields" => struct_def_set_fields(interner, arguments, location),
+            "struct_def_trait_impls" => struct_def_trait_impls(interner, arguments, location),
             "to_be_radix" => to_be_radix(arguments, return_type, location),
             "to_le_radix" => to_le_radix(arguments, return_type, location),
             "trait_constraint_eq" => trait_constraint_eq(arguments, location),
@@ -190,8 +219,14 @@ impl<'local, 'context> Interpreter<'local, 'context> {
                 trait_def_as_trait_constraint(interner, arguments, location)
             }
             "trait_def_eq" => trait_def_eq(arguments, location),
+            "trait_def_generics" => trait_def_generics(interner, arguments, location),
             "trait_def_hash" => trait_def_hash(arguments, location),
+            "trait_def_methods" => trait_def_methods(interner, arguments, location),
+            "trait_def_module" => trait_def_module(self, arguments, location),
+            "trait_def_name" => trait_def_name(interner, arguments, location),
             "trait_impl_methods" => trait_impl_methods(interner, arguments, location),
+            "trait_impl_self_type" => trait_impl_self_type(interner, arguments, location),
+            "trait_impl_trait_def" => trait_impl_trait_def(interner, arguments, location),
             "trait_impl_trait_generic_args" => {
                 trait_impl_trait_generic_args(interner, arguments, location)
             }
@@ -475,6 +510,55 @@ fn struct_def_has_named_attribute(
     Ok(Value::Bool(has_named_attribute(&name, interner.struct_attributes(&struct_id))))
 }
 
+// fn methods(self) -> [FunctionDefinition]
+fn struct_def_methods(
+    interner: &NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let argument = check_one_argument(arguments, location)?;
+    let struct_id = get_struct(argument)?;
+
+    // Sort by name so that the methods are returned in a deterministic order
+    let mut methods: Vec<_> =
+        interner.get_struct_methods(struct_id).into_iter().flatten().collect();
+    methods.sort_by(|(name1, _), (name2, _)| name1.cmp(name2));
+
+    let methods = methods
+        .into_iter()
+        .flat_map(|(_, methods)| &methods.direct)
+        .map(|func_id| Value::FunctionDefinition(*func_id))
+        .collect();
+
+    let slice_type = Type::Slice(Box::new(Type::Quoted(QuotedType::FunctionDefinition)));
+    Ok(Value::Slice(methods, slice_type))
+}
+
+// fn trait_impls(self) -> [TraitImpl]
+fn struct_def_trait_impls(
+    interner: &NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let argument = check_one_argument(arguments, location)?;
+    let struct_id = get_struct(argument)?;
+
+    let mut trait_impl_ids: Vec<_> = interner
+        .trait_implementations
+        .iter()
+        .filter(|(_, trait_impl)| match trait_impl.borrow().typ.follow_bindings() {
+            Type::Struct(struct_type, _) => struct_type.borrow().id == struct_id,
+            _ => false,
+        })
+        .map(|(trait_impl_id, _)| *trait_impl_id)
+        .collect();
+    trait_impl_ids.sort_by_key(|trait_impl_id| trait_impl_id.0);
+
+    let trait_impls = trait_impl_ids.into_iter().map(Value::TraitImpl).collect();
+    let slice_type = Type::Slice(Box::new(Type::Quoted(QuotedType::TraitImpl)));
+    Ok(Value::Slice(trait_impls, slice_type))
+}
+
 /// fn fields(self) -> [(Quoted, Type)]
 /// Returns (name, type) pairs of each field of this StructDefinition
 fn struct_def_fields(
@@ -1108,6 +1192,69 @@ fn trait_def_eq(arguments: Vec<(Value, Location)>, location: Location) -> IResul
     eq_item(arguments, location, get_trait_def)
 }
 
+// fn generics(self) -> [Type]
+fn trait_def_generics(
+    interner: &NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let argument = check_one_argument(arguments, location)?;
+    let trait_id = get_trait_def(argument)?;
+    let the_trait = interner.get_trait(trait_id);
+
+    let generics =
+        the_trait.generics.iter().map(|generic| Value::Type(generic.clone().as_named_generic()));
+
+    let typ = Type::Slice(Box::new(Type::Quoted(QuotedType::Type)));
+    Ok(Value::Slice(generics.collect(), typ))
+}
+
+// fn methods(self) -> [FunctionDefinition]
+fn trait_def_methods(
+    interner: &NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let argument = check_one_argument(arguments, location)?;
+    let trait_id = get_trait_def(argument)?;
+    let the_trait = interner.get_trait(trait_id);
+
+    // `methods` holds the methods in the order they were declared, `method_ids` their ids
+    let methods = the_trait
+        .methods
+        .iter()
+        .filter_map(|method| the_trait.method_ids.get(&method.name.0.contents))
+        .map(|func_id| Value::FunctionDefinition(*func_id))
+        .collect();
+
+    let slice_type = Type::Slice(Box::new(Type::Quoted(QuotedType::FunctionDefinition)));
+    Ok(Value::Slice(methods, slice_type))
+}
+
+// fn module(self) -> Module
+fn trait_def_module(
+    interpreter: &Interpreter,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let argument = check_one_argument(arguments, location)?;
+    let trait_id = get_trait_def(argument)?;
+    let parent = trait_id.0.parent(interpreter.elaborator.def_maps);
+    Ok(Value::ModuleDefinition(parent.expect("Expected trait module parent to exist")))
+}
+
+// fn name(self) -> Quoted
+fn trait_def_name(
+    interner: &NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let argument = check_one_argument(arguments, location)?;
+    let trait_id = get_trait_def(argument)?;
+    let name = Token::Ident(interner.get_trait(trait_id).name.to_string());
+    Ok(Value::Quoted(Rc::new(vec![name])))
+}
+
 // fn methods(self) -> [FunctionDefinition]
 fn trait_impl_methods(
     interner: &mut NodeInterner,
@@ -1143,6 +1290,33 @@ fn trait_impl_trait_generic_args(
     Ok(Value::Slice(trait_generics, slice_type))
 }
 
+// fn self_type(self) -> Type
+fn trait_impl_self_type(
+    interner: &mut NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let argument = check_one_argument(arguments, location)?;
+
+    let trait_impl_id = get_trait_impl(argument)?;
+    let trait_impl = interner.get_trait_implementation(trait_impl_id);
+    let typ = trait_impl.borrow().typ.clone();
+    Ok(Value::Type(typ))
+}
+
+// fn trait_def(self) -> TraitDefinition
+fn trait_impl_trait_def(
+    interner: &mut NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let argument = check_one_argument(arguments, location)?;
+
+    let trait_impl_id = get_trait_impl(argument)?;
+    let trait_id = interner.get_trait_implementation(trait_impl_id).borrow().trait_id;
+    Ok(Value::TraitDefinition(trait_id))
+}
+
 // fn as_function_definition(self) -> Option<FunctionDefinition>
 fn typed_expr_as_function_definition(
     interner: &NodeInterner,
@@ -1288,11 +1462,13 @@ fn zeroed(return_type: Type, span: Span) -> IResult<Value> {
             (Signedness::Unsigned, IntegerBitSize::Sixteen) => Ok(Value::U16(0)),
             (Signedness::Unsigned, IntegerBitSize::ThirtyTwo) => Ok(Value::U32(0)),
             (Signedness::Unsigned, IntegerBitSize::SixtyFour) => Ok(Value::U64(0)),
+            (Signedness::Unsigned, IntegerBitSize::HundredTwentyEight) => Ok(Value::U128(0)),
             (Signedness::Signed, IntegerBitSize::One) => Ok(Value::I8(0)),
             (Signedness::Signed, IntegerBitSize::Eight) => Ok(Value::I8(0)),
             (Signedness::Signed, IntegerBitSize::Sixteen) => Ok(Value::I16(0)),
             (Signedness::Signed, IntegerBitSize::ThirtyTwo) => Ok(Value::I32(0)),
             (Signedness::Signed, IntegerBitSize::SixtyFour) => Ok(Value::I64(0)),
+            (Signedness::Signed, IntegerBitSize::HundredTwentyEight) => Ok(Value::I128(0)),
         },
         Type::Bool => Ok(Value::Bool(false)),
         Type::String(length_type) => {
@@ -2277,6 +2453,25 @@ fn function_def_body(
     }
 }
 
+// fn generics(self) -> [Type]
+fn function_def_generics(
+    interner: &NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let self_argument = check_one_argument(arguments, location)?;
+    let func_id = get_function_def(self_argument)?;
+    let func_meta = interner.function_meta(&func_id);
+
+    let generics = func_meta
+        .direct_generics
+        .iter()
+        .map(|generic| Value::Type(generic.clone().as_named_generic()));
+
+    let typ = Type::Slice(Box::new(Type::Quoted(QuotedType::Type)));
+    Ok(Value::Slice(generics.collect(), typ))
+}
+
 // fn has_named_attribute<let N: u32>(self, name: str<N>) -> bool {}
 fn function_def_has_named_attribute(
     interner: &NodeInterner,
@@ -2419,6 +2614,23 @@ fn function_def_set_body(
     let statement = Statement { kind: statement_kind, span: body_location.span };
     let body = BlockExpression { statements: vec![statement] };
 
+    let interner = &mut interpreter.elaborator.interner;
+    if interner.is_in_lsp_mode() {
+        let func_meta = interner.function_meta(&func_id);
+        let name_location = func_meta.name.location;
+        let parameters = vecmap(&func_meta.parameters.0, |(pattern, typ, _)| {
+            format!("{}: {typ}", pattern.to_display_ast(interner))
+        });
+        let return_type = match func_meta.return_type() {
+            Type::Unit => String::new(),
+            typ => format!(" -> {typ}"),
+        };
+        let name = interner.function_name(&func_id);
+        let body = display_generated_block(interner, &body);
+        let code = format!("fn {name}({}){return_type} {body}", parameters.join(", "));
+        interner.push_comptime_expansion(name_location, code);
+    }
+
     let func_meta = interpreter.elaborator.interner.function_meta_mut(&func_id);
     func_meta.has_body = true;
     func_meta.function_body = FunctionBody::Unresolved(FunctionKind::Normal, body, location.span);
@@ -2562,6 +2774,34 @@ fn function_def_set_unconstrained(
     Ok(Value::Unit)
 }
 
+// fn where_clause(self) -> [(Type, TraitConstraint)]
+fn function_def_where_clause(
+    interner: &NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let self_argument = check_one_argument(arguments, location)?;
+    let func_id = get_function_def(self_argument)?;
+    let func_meta = interner.function_meta(&func_id);
+
+    let constraints = func_meta
+        .trait_constraints
+        .iter()
+        .map(|constraint| {
+            let typ = Value::Type(constraint.typ.clone());
+            let bound = &constraint.trait_bound;
+            let bound = Value::TraitConstraint(bound.trait_id, bound.trait_generics.clone());
+            Value::Tuple(vec![typ, bound])
+        })
+        .collect();
+
+    let typ = Type::Slice(Box::new(Type::Tuple(vec![
+        Type::Quoted(QuotedType::Type),
+        Type::Quoted(QuotedType::TraitConstraint),
+    ])));
+    Ok(Value::Slice(constraints, typ))
+}
+
 // fn add_item(self, item: Quoted)
 fn module_add_item(
     interpreter: &mut Interpreter,
@@ -2573,12 +2813,14 @@ fn module_add_item(
     let module_data = interpreter.elaborator.get_module(module_id);
 
     let parser = Parser::parse_top_level_items;
-    let top_level_statements =
+    let mut top_level_statements =
         parse(interpreter.elaborator.interner, item, parser, "a top-level item")?;
 
     interpreter.elaborate_in_module(module_id, module_data.location.file, |elaborator| {
         let mut generated_items = CollectedItems::default();
 
+        remove_disabled_items(&mut top_level_statements, elaborator.interner, module_id.krate);
+
         for top_level_statement in top_level_statements {
             elaborator.add_item(top_level_statement, &mut generated_items, location);
         }
@@ -2599,22 +2841,38 @@ fn module_eq(arguments: Vec<(Value, Location)>, location: Location) -> IResult<V
     eq_item(arguments, location, get_module)
 }
 
+// fn child_modules(self) -> [Module]
+fn module_child_modules(
+    interpreter: &Interpreter,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let modules = module_definitions(interpreter, arguments, location)?
+        .into_iter()
+        .filter_map(|module_def_id| {
+            if let ModuleDefId::ModuleId(module_id) = module_def_id {
+                Some(Value::ModuleDefinition(module_id))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let slice_type = Type::Slice(Box::new(Type::Quoted(QuotedType::Module)));
+    Ok(Value::Slice(modules, slice_type))
+}
+
 // fn functions(self) -> [FunctionDefinition]
 fn module_functions(
     interpreter: &Interpreter,
     arguments: Vec<(Value, Location)>,
     location: Location,
 ) -> IResult<Value> {
-    let self_argument = check_one_argument(arguments, location)?;
-    let module_id = get_module(self_argument)?;
-    let module_data = interpreter.elaborator.get_module(module_id);
-    let func_ids = module_data
-        .definitions()
-        .definitions()
-        .iter()
+    let func_ids = module_definitions(interpreter, arguments, location)?
+        .into_iter()
         .filter_map(|module_def_id| {
             if let ModuleDefId::FunctionId(func_id) = module_def_id {
-                Some(Value::FunctionDefinition(*func_id))
+                Some(Value::FunctionDefinition(func_id))
             } else {
                 None
             }
@@ -2625,22 +2883,44 @@ fn module_functions(
     Ok(Value::Slice(func_ids, slice_type))
 }
 
+// fn globals(self) -> [(Quoted, Type)]
+fn module_globals(
+    interpreter: &Interpreter,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let interner = &interpreter.elaborator.interner;
+    let globals = module_definitions(interpreter, arguments, location)?
+        .into_iter()
+        .filter_map(|module_def_id| {
+            let ModuleDefId::GlobalId(global_id) = module_def_id else {
+                return None;
+            };
+            let global = interner.get_global(global_id);
+            let name = Value::Quoted(Rc::new(vec![Token::Ident(global.ident.to_string())]));
+            let typ = Value::Type(interner.definition_type(global.definition_id));
+            Some(Value::Tuple(vec![name, typ]))
+        })
+        .collect();
+
+    let slice_type = Type::Slice(Box::new(Type::Tuple(vec![
+        Type::Quoted(QuotedType::Quoted),
+        Type::Quoted(QuotedType::Type),
+    ])));
+    Ok(Value::Slice(globals, slice_type))
+}
+
 // fn structs(self) -> [StructDefinition]
 fn module_structs(
     interpreter: &Interpreter,
     arguments: Vec<(Value, Location)>,
     location: Location,
 ) -> IResult<Value> {
-    let self_argument = check_one_argument(arguments, location)?;
-    let module_id = get_module(self_argument)?;
-    let module_data = interpreter.elaborator.get_module(module_id);
-    let struct_ids = module_data
-        .definitions()
-        .definitions()
-        .iter()
+    let struct_ids = module_definitions(interpreter, arguments, location)?
+        .into_iter()
         .filter_map(|module_def_id| {
             if let ModuleDefId::TypeId(id) = module_def_id {
-                Some(Value::StructDefinition(*id))
+                Some(Value::StructDefinition(id))
             } else {
                 None
             }
@@ -2651,6 +2931,67 @@ fn module_structs(
     Ok(Value::Slice(struct_ids, slice_type))
 }
 
+// fn traits(self) -> [TraitDefinition]
+fn module_traits(
+    interpreter: &Interpreter,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let trait_ids = module_definitions(interpreter, arguments, location)?
+        .into_iter()
+        .filter_map(|module_def_id| {
+            if let ModuleDefId::TraitId(id) = module_def_id {
+                Some(Value::TraitDefinition(id))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let slice_type = Type::Slice(Box::new(Type::Quoted(QuotedType::TraitDefinition)));
+    Ok(Value::Slice(trait_ids, slice_type))
+}
+
+// fn type_aliases(self) -> [(Quoted, Type)]
+fn module_type_aliases(
+    interpreter: &Interpreter,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    let interner = &interpreter.elaborator.interner;
+    let aliases = module_definitions(interpreter, arguments, location)?
+        .into_iter()
+        .filter_map(|module_def_id| {
+            let ModuleDefId::TypeAliasId(alias_id) = module_def_id else {
+                return None;
+            };
+            let alias = interner.get_type_alias(alias_id);
+            let alias = alias.borrow();
+            let name = Value::Quoted(Rc::new(vec![Token::Ident(alias.name.to_string())]));
+            Some(Value::Tuple(vec![name, Value::Type(alias.typ.clone())]))
+        })
+        .collect();
+
+    let slice_type = Type::Slice(Box::new(Type::Tuple(vec![
+        Type::Quoted(QuotedType::Quoted),
+        Type::Quoted(QuotedType::Type),
+    ])));
+    Ok(Value::Slice(aliases, slice_type))
+}
+
+/// Returns each item defined directly in the module given as the only argument,
+/// in the order they were defined.
+fn module_definitions(
+    interpreter: &Interpreter,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Vec<ModuleDefId>> {
+    let self_argument = check_one_argument(arguments, location)?;
+    let module_id = get_module(self_argument)?;
+    let module_data = interpreter.elaborator.get_module(module_id);
+    Ok(module_data.definitions().definitions())
+}
+
 // fn has_named_attribute<let N: u32>(self, name: str<N>) -> bool {}
 fn module_has_named_attribute(
     interpreter: &Interpreter,
@@ -2792,6 +3133,85 @@ fn ctstring_hash(arguments: Vec<(Value, Location)>, location: Location) -> IResu
     hash_item(arguments, location, get_ctstring)
 }
 
+// fn from_le_bytes(bytes: [u8], modulus: [u8]) -> BigInt
+fn bigint_from_le_bytes(
+    interner: &mut NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    return_type: Type,
+    location: Location,
+) -> IResult<Value> {
+    let (bytes, modulus) = check_two_arguments(arguments, location)?;
+
+    let (bytes_location, modulus_location) = (bytes.1, modulus.1);
+    let (bytes, _) = get_slice(interner, bytes)?;
+    let (modulus, _) = get_slice(interner, modulus)?;
+    let bytes = try_vecmap(bytes, |byte| get_u8((byte, bytes_location)))?;
+    let modulus = try_vecmap(modulus, |byte| get_u8((byte, modulus_location)))?;
+
+    let (pointer, modulus) = interner
+        .comptime_bigints
+        .from_le_bytes(&bytes, &modulus)
+        .map_err(|error| InterpreterError::BlackBoxError(error, location))?;
+
+    Ok(bigint_to_value(pointer, modulus, return_type))
+}
+
+// fn bigint_add(self, other: BigInt) -> BigInt (and sub, mul, div)
+fn bigint_op(
+    interner: &mut NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    return_type: Type,
+    location: Location,
+    func: BlackBoxFunc,
+) -> IResult<Value> {
+    let (lhs, rhs) = check_two_arguments(arguments, location)?;
+
+    let lhs = get_bigint(interner, lhs)?;
+    let rhs = get_bigint(interner, rhs)?;
+
+    let pointer = interner
+        .comptime_bigints
+        .op(lhs, rhs, func)
+        .map_err(|error| InterpreterError::BlackBoxError(error, location))?;
+
+    Ok(bigint_to_value(pointer, lhs.1, return_type))
+}
+
+// fn to_le_bytes(self) -> [u8; 32]
+fn bigint_to_le_bytes(
+    interner: &mut NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    return_type: Type,
+    location: Location,
+) -> IResult<Value> {
+    let self_argument = check_one_argument(arguments, location)?;
+    let (pointer, _) = get_bigint(interner, self_argument)?;
+
+    let bytes = interner
+        .comptime_bigints
+        .to_le_bytes(pointer)
+        .map_err(|error| InterpreterError::BlackBoxError(error, location))?;
+
+    let bytes = bytes.into_iter().map(Value::U8).collect();
+    Ok(Value::Array(bytes, return_type))
+}
+
+/// Returns the `(pointer, modulus)` ids of a `BigInt` struct value.
+fn get_bigint(interner: &NodeInterner, argument: (Value, Location)) -> IResult<(u32, u32)> {
+    let location = argument.1;
+    let bigint = get_struct_fields(interner, argument)?;
+    let pointer = get_struct_field("pointer", &bigint, location, get_u32)?;
+    let modulus = get_struct_field("modulus", &bigint, location, get_u32)?;
+    Ok((pointer, modulus))
+}
+
+fn bigint_to_value(pointer: u32, modulus: u32, typ: Type) -> Value {
+    let mut fields = HashMap::default();
+    fields.insert(Rc::new("pointer".to_string()), Value::U32(pointer));
+    fields.insert(Rc::new("modulus".to_string()), Value::U32(modulus));
+    Value::Struct(fields, typ)
+}
+
 fn derive_generators(
     interner: &mut NodeInterner,
     arguments: Vec<(Value, Location)>,
@@ -2823,7 +3243,6 @@ fn derive_generators(
         starting_index,
     );
 
-    let is_infinite = FieldElement::zero();
     let x_field_name: Rc<String> = Rc::new("x".to_owned());
     let y_field_name: Rc<String> = Rc::new("y".to_owned());
     let is_infinite_field_name: Rc<String> = Rc::new("is_infinite".to_owned());
@@ -2836,8 +3255,7 @@ fn derive_generators(
         let mut embedded_curve_point_fields = HashMap::default();
         embedded_curve_point_fields.insert(x_field_name.clone(), Value::Field(x));
         embedded_curve_point_fields.insert(y_field_name.clone(), Value::Field(y));
-        embedded_curve_point_fields
-            .insert(is_infinite_field_name.clone(), Value::Field(is_infinite));
+        embedded_curve_point_fields.insert(is_infinite_field_name.clone(), Value::Bool(false));
         let embedded_curve_point_struct =
             Value::Struct(embedded_curve_point_fields, *elements.clone());
         results.push_back(embedded_curve_point_struct);
@@ -3,6 +3,7 @@ use std::{hash::Hasher, rc::Rc};
 
 use acvm::FieldElement;
 use noirc_errors::Location;
+use rustc_hash::FxHashMap as HashMap;
 
 use crate::hir::comptime::display::tokens_to_string;
 use crate::hir::comptime::value::add_token_spans;
@@ -185,6 +186,33 @@ pub(crate) fn get_u64((value, location): (Value, Location)) -> IResult<u64> {
     }
 }
 
+pub(crate) fn get_struct_fields(
+    interner: &NodeInterner,
+    (value, location): (Value, Location),
+) -> IResult<(HashMap<Rc<String>, Value>, Type)> {
+    match value {
+        Value::Struct(fields, typ) => Ok((fields, typ)),
+        value => type_mismatch(value, interner.next_type_variable(), location),
+    }
+}
+
+/// Looks up a field of a struct value returned from `get_struct_fields` and converts it with `f`.
+pub(crate) fn get_struct_field<T>(
+    field_name: &str,
+    (fields, typ): &(HashMap<Rc<String>, Value>, Type),
+    location: Location,
+    f: impl FnOnce((Value, Location)) -> IResult<T>,
+) -> IResult<T> {
+    match fields.get(&Rc::new(field_name.to_string())) {
+        Some(value) => f((value.clone(), location)),
+        None => Err(InterpreterError::ExpectedStructToHaveField {
+            typ: typ.clone(),
+            field_name: field_name.to_string(),
+            location,
+        }),
+    }
+}
+
 pub(crate) fn get_expr(
     interner: &NodeInterner,
     (value, location): (Value, Location),
@@ -0,0 +1,252 @@
+use std::rc::Rc;
+
+use acvm::FieldElement;
+use im::Vector;
+use noirc_errors::Location;
+use rustc_hash::FxHashMap as HashMap;
+use serde_json::Value as Json;
+
+use crate::{
+    ast::{IntegerBitSize, Signedness},
+    hir::comptime::{errors::IResult, InterpreterError, Value},
+    QuotedType, Type,
+};
+
+use super::builtin::builtin_helpers::{check_one_argument, get_str};
+use super::Interpreter;
+
+impl<'local, 'context> Interpreter<'local, 'context> {
+    // fn read_bytes<let N: u32>(path: str<N>) -> [u8]
+    pub(super) fn file_read_bytes(
+        &mut self,
+        arguments: Vec<(Value, Location)>,
+        location: Location,
+    ) -> IResult<Value> {
+        let (_, bytes) = self.read_file(arguments, location)?;
+        let bytes = bytes.into_iter().map(Value::U8).collect();
+        let byte_type = Type::Integer(Signedness::Unsigned, IntegerBitSize::Eight);
+        Ok(Value::Slice(bytes, Type::Slice(Box::new(byte_type))))
+    }
+
+    // fn read_string<let N: u32>(path: str<N>) -> CtString
+    pub(super) fn file_read_string(
+        &mut self,
+        arguments: Vec<(Value, Location)>,
+        location: Location,
+    ) -> IResult<Value> {
+        let (path, bytes) = self.read_file(arguments, location)?;
+        let string = String::from_utf8(bytes).map_err(|error| {
+            InterpreterError::FailedToParseFile { path, error: error.to_string(), location }
+        })?;
+        Ok(Value::CtString(Rc::new(string)))
+    }
+
+    // fn read_json<T, let N: u32>(path: str<N>) -> T
+    pub(super) fn file_read_json(
+        &mut self,
+        arguments: Vec<(Value, Location)>,
+        return_type: Type,
+        location: Location,
+    ) -> IResult<Value> {
+        let (path, bytes) = self.read_file(arguments, location)?;
+        let parse_error = |error: String| InterpreterError::FailedToParseFile {
+            path: path.clone(),
+            error,
+            location,
+        };
+
+        let json =
+            serde_json::from_slice(&bytes).map_err(|error| parse_error(error.to_string()))?;
+        json_to_value(&json, &return_type, location).map_err(parse_error)
+    }
+
+    // fn read_toml<T, let N: u32>(path: str<N>) -> T
+    pub(super) fn file_read_toml(
+        &mut self,
+        arguments: Vec<(Value, Location)>,
+        return_type: Type,
+        location: Location,
+    ) -> IResult<Value> {
+        let (path, bytes) = self.read_file(arguments, location)?;
+        let parse_error = |error: String| InterpreterError::FailedToParseFile {
+            path: path.clone(),
+            error,
+            location,
+        };
+
+        let toml = String::from_utf8(bytes).map_err(|error| parse_error(error.to_string()))?;
+        let toml: toml::Value =
+            toml::from_str(&toml).map_err(|error| parse_error(error.to_string()))?;
+
+        // TOML values are a subset of JSON values so they can share the same conversion
+        let json = serde_json::to_value(toml).map_err(|error| parse_error(error.to_string()))?;
+        json_to_value(&json, &return_type, location).map_err(parse_error)
+    }
+
+    /// Reads the file whose path is given as the only argument, relative to the package
+    /// directory of the crate the calling function is defined in.
+    fn read_file(
+        &mut self,
+        arguments: Vec<(Value, Location)>,
+        location: Location,
+    ) -> IResult<(String, Vec<u8>)> {
+        let argument = check_one_argument(arguments, location)?;
+        let path = get_str(self.elaborator.interner, argument)?.to_string();
+
+        let crate_id = match self.current_function {
+            Some(function) => self.elaborator.interner.function_meta(&function).source_crate,
+            None => self.crate_id,
+        };
+
+        match self.elaborator.interner.comptime_files.read(crate_id, &path) {
+            Ok(bytes) => Ok((path, bytes)),
+            Err(error) => {
+                Err(InterpreterError::FailedToReadFile { path, error: error.to_string(), location })
+            }
+        }
+    }
+}
+
+/// Converts a JSON value to a value of type `typ`, returning a description of the
+/// mismatch if the JSON value doesn't have the expected shape.
+fn json_to_value(json: &Json, typ: &Type, location: Location) -> Result<Value, String> {
+    let mismatch = || format!("Expected a value of type `{typ}`, found `{json}`");
+
+    let value = match typ.follow_bindings() {
+        Type::Unit if json.is_null() => Value::Unit,
+        Type::Bool => Value::Bool(json.as_bool().ok_or_else(mismatch)?),
+        Type::FieldElement => Value::Field(json_to_field(json).ok_or_else(mismatch)?),
+        Type::Integer(signedness, bit_size) => {
+            json_to_integer(json, signedness, bit_size).ok_or_else(mismatch)?
+        }
+        Type::String(length) => {
+            let string = json.as_str().ok_or_else(mismatch)?;
+            let length = length.evaluate_to_u32(location.span).map_err(|_| mismatch())?;
+            if string.len() != length as usize {
+                return Err(mismatch());
+            }
+            Value::String(Rc::new(string.to_string()))
+        }
+        Type::Quoted(QuotedType::CtString) => {
+            Value::CtString(Rc::new(json.as_str().ok_or_else(mismatch)?.to_string()))
+        }
+        Type::Array(length, element_type) => {
+            let elements = json.as_array().ok_or_else(mismatch)?;
+            let length = length.evaluate_to_u32(location.span).map_err(|_| mismatch())?;
+            if elements.len() != length as usize {
+                return Err(mismatch());
+            }
+            let elements = json_elements_to_values(elements, &element_type, location)?;
+            Value::Array(elements, typ.clone())
+        }
+        Type::Slice(element_type) => {
+            let elements = json.as_array().ok_or_else(mismatch)?;
+            let elements = json_elements_to_values(elements, &element_type, location)?;
+            Value::Slice(elements, typ.clone())
+        }
+        Type::Tuple(types) => {
+            let elements = json.as_array().ok_or_else(mismatch)?;
+            if elements.len() != types.len() {
+                return Err(mismatch());
+            }
+            let elements = elements.iter().zip(&types);
+            let elements = elements.map(|(json, typ)| json_to_value(json, typ, location));
+            Value::Tuple(elements.collect::<Result<_, _>>()?)
+        }
+        Type::Struct(struct_type, generics) => {
+            let object = json.as_object().ok_or_else(mismatch)?;
+            let mut fields = HashMap::default();
+            for (name, field_type) in struct_type.borrow().get_fields(&generics) {
+                let field = object.get(&name).ok_or_else(|| format!("Missing field `{name}`"))?;
+                fields.insert(Rc::new(name), json_to_value(field, &field_type, location)?);
+            }
+            Value::Struct(fields, typ.clone())
+        }
+        Type::Alias(alias, generics) => {
+            return json_to_value(json, &alias.borrow().get_type(&generics), location);
+        }
+        _ => return Err(mismatch()),
+    };
+    Ok(value)
+}
+
+fn json_elements_to_values(
+    elements: &[Json],
+    element_type: &Type,
+    location: Location,
+) -> Result<Vector<Value>, String> {
+    elements.iter().map(|element| json_to_value(element, element_type, location)).collect()
+}
+
+/// Fields may be given either as JSON numbers or, since those can't hold most field
+/// elements, as decimal or hexadecimal strings.
+fn json_to_field(json: &Json) -> Option<FieldElement> {
+    if let Some(string) = json.as_str() {
+        return FieldElement::try_from_str(string);
+    }
+    if let Some(value) = json.as_u64() {
+        return Some(FieldElement::from(value as u128));
+    }
+    let value = json.as_i64()?;
+    Some(-FieldElement::from(value.unsigned_abs() as u128))
+}
+
+/// Integers are given as JSON numbers. 128-bit integers may also be given as decimal or
+/// hexadecimal strings, like fields, as JSON numbers can't hold most of them.
+fn json_to_integer(json: &Json, signedness: Signedness, bit_size: IntegerBitSize) -> Option<Value> {
+    if bit_size == IntegerBitSize::HundredTwentyEight {
+        return match signedness {
+            Signedness::Unsigned => json_to_u128(json).map(Value::U128),
+            Signedness::Signed => json_to_i128(json).map(Value::I128),
+        };
+    }
+
+    let value = match signedness {
+        Signedness::Unsigned => {
+            let value = json.as_u64()?;
+            match bit_size {
+                IntegerBitSize::One if value <= 1 => Value::U1(value == 1),
+                IntegerBitSize::Eight => Value::U8(value.try_into().ok()?),
+                IntegerBitSize::Sixteen => Value::U16(value.try_into().ok()?),
+                IntegerBitSize::ThirtyTwo => Value::U32(value.try_into().ok()?),
+                IntegerBitSize::SixtyFour => Value::U64(value),
+                _ => return None,
+            }
+        }
+        Signedness::Signed => {
+            let value = json.as_i64()?;
+            match bit_size {
+                IntegerBitSize::Eight => Value::I8(value.try_into().ok()?),
+                IntegerBitSize::Sixteen => Value::I16(value.try_into().ok()?),
+                IntegerBitSize::ThirtyTwo => Value::I32(value.try_into().ok()?),
+                IntegerBitSize::SixtyFour => Value::I64(value),
+                _ => return None,
+            }
+        }
+    };
+    Some(value)
+}
+
+fn json_to_u128(json: &Json) -> Option<u128> {
+    match json.as_str() {
+        Some(string) => parse_u128(string),
+        None => json.as_u64().map(u128::from),
+    }
+}
+
+fn json_to_i128(json: &Json) -> Option<i128> {
+    let Some(string) = json.as_str() else {
+        return json.as_i64().map(i128::from);
+    };
+    match string.strip_prefix('-') {
+        Some(magnitude) => 0_i128.checked_sub_unsigned(parse_u128(magnitude)?),
+        None => parse_u128(string)?.try_into().ok(),
+    }
+}
+
+fn parse_u128(string: &str) -> Option<u128> {
+    match string.strip_prefix("0x") {
+        Some(hex) => u128::from_str_radix(hex, 16).ok(),
+        None => string.parse().ok(),
+    }
+}
@@ -1,4 +1,7 @@
-use acvm::blackbox_solver::BlackBoxFunctionSolver;
+use acvm::{
+    blackbox_solver::{self, BlackBoxFunctionSolver},
+    BlackBoxResolutionError, FieldElement,
+};
 use bn254_blackbox_solver::Bn254BlackBoxSolver;
 use im::Vector;
 use iter_extended::try_vecmap;
@@ -7,21 +10,40 @@ use noirc_errors::Location;
 use crate::{
     hir::comptime::{errors::IResult, InterpreterError, Value},
     node_interner::NodeInterner,
+    Kind, Type,
 };
 
 use super::builtin::builtin_helpers::{
-    check_one_argument, check_two_arguments, get_array, get_field, get_u32, get_u64,
+    check_argument_count, check_one_argument, check_three_arguments, check_two_arguments,
+    get_array, get_bool, get_field, get_slice, get_struct_field, get_struct_fields, get_u32,
+    get_u64, get_u8,
 };
 
 pub(super) fn call_foreign(
     interner: &mut NodeInterner,
     name: &str,
     arguments: Vec<(Value, Location)>,
+    return_type: Type,
     location: Location,
 ) -> IResult<Value> {
     match name {
+        "aes128_encrypt" => aes128_encrypt(interner, arguments, return_type, location),
+        "blake2s" => {
+            blake_hash(interner, arguments, return_type, location, blackbox_solver::blake2s)
+        }
+        "blake3" => blake_hash(interner, arguments, return_type, location, blackbox_solver::blake3),
+        "ecdsa_secp256k1" => {
+            ecdsa(interner, arguments, location, blackbox_solver::ecdsa_secp256k1_verify)
+        }
+        "ecdsa_secp256r1" => {
+            ecdsa(interner, arguments, location, blackbox_solver::ecdsa_secp256r1_verify)
+        }
+        "embedded_curve_add" => embedded_curve_add(interner, arguments, return_type, location),
+        "multi_scalar_mul" => multi_scalar_mul(interner, arguments, return_type, location),
         "poseidon2_permutation" => poseidon2_permutation(interner, arguments, location),
         "keccakf1600" => keccakf1600(interner, arguments, location),
+        "schnorr_verify" => schnorr_verify(interner, arguments, location),
+        "sha256_compression" => sha256_compression(interner, arguments, return_type, location),
         _ => {
             let item = format!("Comptime evaluation for builtin function {name}");
             Err(InterpreterError::Unimplemented { item, location })
@@ -68,9 +90,244 @@ fn keccakf1600(
     for (it, input_value) in state.iter_mut().zip(input.iter()) {
         *it = *input_value;
     }
-    let result_lanes = acvm::blackbox_solver::keccakf1600(state)
+    let result_lanes = blackbox_solver::keccakf1600(state)
         .map_err(|error| InterpreterError::BlackBoxError(error, location))?;
 
     let array: Vector<Value> = result_lanes.into_iter().map(Value::U64).collect();
     Ok(Value::Array(array, typ))
 }
+
+// aes128_encrypt<let N: u32>(input: [u8; N], iv: [u8; 16], key: [u8; 16]) -> [u8]
+fn aes128_encrypt(
+    interner: &mut NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    return_type: Type,
+    location: Location,
+) -> IResult<Value> {
+    let (inputs, iv, key) = check_three_arguments(arguments, location)?;
+
+    let inputs = get_array_map(interner, inputs, get_u8)?;
+    let iv = get_fixed_array_map(interner, iv, get_u8)?;
+    let key = get_fixed_array_map(interner, key, get_u8)?;
+
+    let output = blackbox_solver::aes128_encrypt(&inputs, iv, key)
+        .map_err(|error| InterpreterError::BlackBoxError(error, location))?;
+
+    let output = output.into_iter().map(Value::U8).collect();
+    Ok(Value::Slice(output, return_type))
+}
+
+// blake2s<let N: u32>(input: [u8; N]) -> [u8; 32]
+// blake3<let N: u32>(input: [u8; N]) -> [u8; 32]
+fn blake_hash(
+    interner: &mut NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    return_type: Type,
+    location: Location,
+    f: impl Fn(&[u8]) -> Result<[u8; 32], BlackBoxResolutionError>,
+) -> IResult<Value> {
+    let inputs = check_one_argument(arguments, location)?;
+
+    let inputs = get_array_map(interner, inputs, get_u8)?;
+    let hash = f(&inputs).map_err(|error| InterpreterError::BlackBoxError(error, location))?;
+
+    let hash = hash.into_iter().map(Value::U8).collect();
+    Ok(Value::Array(hash, return_type))
+}
+
+// verify_signature<let N: u32>(
+//     public_key_x: [u8; 32],
+//     public_key_y: [u8; 32],
+//     signature: [u8; 64],
+//     message_hash: [u8; N],
+// ) -> bool
+fn ecdsa(
+    interner: &mut NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+    f: impl Fn(&[u8], &[u8; 32], &[u8; 32], &[u8; 64]) -> Result<bool, BlackBoxResolutionError>,
+) -> IResult<Value> {
+    check_argument_count(4, &arguments, location)?;
+    let [public_key_x, public_key_y, signature, message_hash] = arguments.try_into().unwrap();
+
+    let public_key_x = get_fixed_array_map(interner, public_key_x, get_u8)?;
+    let public_key_y = get_fixed_array_map(interner, public_key_y, get_u8)?;
+    let signature = get_fixed_array_map(interner, signature, get_u8)?;
+    let message_hash = get_array_or_slice_map(interner, message_hash, get_u8)?;
+
+    let is_valid = f(&message_hash, &public_key_x, &public_key_y, &signature)
+        .map_err(|error| InterpreterError::BlackBoxError(error, location))?;
+
+    Ok(Value::Bool(is_valid))
+}
+
+// embedded_curve_add_array_return(
+//     _point1: EmbeddedCurvePoint,
+//     _point2: EmbeddedCurvePoint,
+// ) -> [Field; 3]
+fn embedded_curve_add(
+    interner: &mut NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    return_type: Type,
+    location: Location,
+) -> IResult<Value> {
+    let (point1, point2) = check_two_arguments(arguments, location)?;
+
+    let (x1, y1, is_infinite1) = get_embedded_curve_point(interner, point1)?;
+    let (x2, y2, is_infinite2) = get_embedded_curve_point(interner, point2)?;
+
+    // Currently locked to only bn254!
+    let (x, y, is_infinite) = Bn254BlackBoxSolver
+        .ec_add(&x1, &y1, &is_infinite1, &x2, &y2, &is_infinite2)
+        .map_err(|error| InterpreterError::BlackBoxError(error, location))?;
+
+    let point = [x, y, is_infinite].into_iter().map(Value::Field).collect();
+    Ok(Value::Array(point, return_type))
+}
+
+// multi_scalar_mul_array_return<let N: u32>(
+//     points: [EmbeddedCurvePoint; N],
+//     scalars: [EmbeddedCurveScalar; N],
+// ) -> [Field; 3]
+fn multi_scalar_mul(
+    interner: &mut NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    return_type: Type,
+    location: Location,
+) -> IResult<Value> {
+    let (points, scalars) = check_two_arguments(arguments, location)?;
+
+    let points =
+        get_array_map(interner, points, |point| get_embedded_curve_point(interner, point))?;
+    let scalars =
+        get_array_map(interner, scalars, |scalar| get_embedded_curve_scalar(interner, scalar))?;
+
+    let points: Vec<_> =
+        points.into_iter().flat_map(|(x, y, is_infinite)| [x, y, is_infinite]).collect();
+    let (scalars_lo, scalars_hi): (Vec<_>, Vec<_>) = scalars.into_iter().unzip();
+
+    // Currently locked to only bn254!
+    let (x, y, is_infinite) = Bn254BlackBoxSolver
+        .multi_scalar_mul(&points, &scalars_lo, &scalars_hi)
+        .map_err(|error| InterpreterError::BlackBoxError(error, location))?;
+
+    let point = [x, y, is_infinite].into_iter().map(Value::Field).collect();
+    Ok(Value::Array(point, return_type))
+}
+
+// verify_signature<let N: u32>(
+//     public_key_x: Field,
+//     public_key_y: Field,
+//     signature: [u8; 64],
+//     message: [u8; N],
+// ) -> bool
+fn schnorr_verify(
+    interner: &mut NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    location: Location,
+) -> IResult<Value> {
+    check_argument_count(4, &arguments, location)?;
+    let [public_key_x, public_key_y, signature, message] = arguments.try_into().unwrap();
+
+    let public_key_x = get_field(public_key_x)?;
+    let public_key_y = get_field(public_key_y)?;
+    let signature = get_fixed_array_map(interner, signature, get_u8)?;
+    let message = get_array_or_slice_map(interner, message, get_u8)?;
+
+    // Currently locked to only bn254!
+    let is_valid = Bn254BlackBoxSolver
+        .schnorr_verify(&public_key_x, &public_key_y, &signature, &message)
+        .map_err(|error| InterpreterError::BlackBoxError(error, location))?;
+
+    Ok(Value::Bool(is_valid))
+}
+
+// sha256_compression(input: [u32; 16], state: [u32; 8]) -> [u32; 8]
+fn sha256_compression(
+    interner: &mut NodeInterner,
+    arguments: Vec<(Value, Location)>,
+    return_type: Type,
+    location: Location,
+) -> IResult<Value> {
+    let (input, state) = check_two_arguments(arguments, location)?;
+
+    let input = get_fixed_array_map(interner, input, get_u32)?;
+    let mut state = get_fixed_array_map(interner, state, get_u32)?;
+
+    blackbox_solver::sha256_compression(&mut state, &input);
+
+    let state = state.into_iter().map(Value::U32).collect();
+    Ok(Value::Array(state, return_type))
+}
+
+/// Returns the `(x, y, is_infinite)` coordinates of an `EmbeddedCurvePoint` struct value.
+fn get_embedded_curve_point(
+    interner: &NodeInterner,
+    (value, location): (Value, Location),
+) -> IResult<(FieldElement, FieldElement, FieldElement)> {
+    let point = get_struct_fields(interner, (value, location))?;
+    let x = get_struct_field("x", &point, location, get_field)?;
+    let y = get_struct_field("y", &point, location, get_field)?;
+    let is_infinite = get_struct_field("is_infinite", &point, location, get_bool)?;
+    Ok((x, y, FieldElement::from(is_infinite)))
+}
+
+/// Returns the `(lo, hi)` limbs of an `EmbeddedCurveScalar` struct value.
+fn get_embedded_curve_scalar(
+    interner: &NodeInterner,
+    (value, location): (Value, Location),
+) -> IResult<(FieldElement, FieldElement)> {
+    let scalar = get_struct_fields(interner, (value, location))?;
+    let lo = get_struct_field("lo", &scalar, location, get_field)?;
+    let hi = get_struct_field("hi", &scalar, location, get_field)?;
+    Ok((lo, hi))
+}
+
+fn get_array_map<T>(
+    interner: &NodeInterner,
+    (value, location): (Value, Location),
+    f: impl FnMut((Value, Location)) -> IResult<T>,
+) -> IResult<Vec<T>> {
+    let (values, _) = get_array(interner, (value, location))?;
+    map_values(values, location, f)
+}
+
+/// Like `get_array_map` but also accepts slices, for foreign functions with a slice variant.
+fn get_array_or_slice_map<T>(
+    interner: &NodeInterner,
+    (value, location): (Value, Location),
+    f: impl FnMut((Value, Location)) -> IResult<T>,
+) -> IResult<Vec<T>> {
+    let (values, _) = match value {
+        Value::Slice(..) => get_slice(interner, (value, location))?,
+        value => get_array(interner, (value, location))?,
+    };
+    map_values(values, location, f)
+}
+
+/// Like `get_array_map` but for arrays whose length is fixed by the foreign function's signature.
+fn get_fixed_array_map<T, const N: usize>(
+    interner: &NodeInterner,
+    (value, location): (Value, Location),
+    f: impl FnMut((Value, Location)) -> IResult<T>,
+) -> IResult<[T; N]> {
+    let (values, typ) = get_array(interner, (value, location))?;
+    let values = map_values(values, location, f)?;
+    values.try_into().map_err(|_| {
+        let element_type = match typ.follow_bindings() {
+            Type::Array(_, element_type) => element_type,
+            _ => Box::new(interner.next_type_variable()),
+        };
+        let length = Box::new(Type::Constant(N.into(), Kind::u32()));
+        let expected = Type::Array(length, element_type);
+        InterpreterError::TypeMismatch { expected, actual: typ, location }
+    })
+}
+
+fn map_values<T>(
+    values: Vector<Value>,
+    location: Location,
+    mut f: impl FnMut((Value, Location)) -> IResult<T>,
+) -> IResult<Vec<T>> {
+    try_vecmap(values, |value| f((value, location)))
+}
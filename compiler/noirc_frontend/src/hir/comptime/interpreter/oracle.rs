@@ -0,0 +1,288 @@
+use std::iter::Peekable;
+use std::rc::Rc;
+
+use acvm::{acir::brillig::ForeignCallParam, pwg::ForeignCallWaitInfo, AcirField, FieldElement};
+use im::Vector;
+use noirc_errors::Location;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::{
+    ast::{IntegerBitSize, Signedness},
+    hir::comptime::{errors::IResult, InterpreterError, Value},
+    Type,
+};
+
+use super::Interpreter;
+
+impl<'local, 'context> Interpreter<'local, 'context> {
+    /// Calls `oracle` through the comptime oracle resolver. This is an error if none was set.
+    pub(super) fn call_oracle(
+        &mut self,
+        oracle: &str,
+        arguments: Vec<(Value, Location)>,
+        return_type: Type,
+        location: Location,
+    ) -> IResult<Value> {
+        let Some(oracles) = &mut self.elaborator.interner.comptime_oracles else {
+            let oracle = oracle.to_string();
+            return Err(InterpreterError::OracleResolverNotSet { oracle, location });
+        };
+
+        let mut inputs = Vec::new();
+        for (argument, argument_location) in arguments {
+            push_foreign_call_params(argument, argument_location, &mut inputs)?;
+        }
+        let foreign_call = ForeignCallWaitInfo { function: oracle.to_string(), inputs };
+
+        let response = oracles.resolve(&foreign_call).map_err(|error| {
+            let (oracle, error) = (oracle.to_string(), error.to_string());
+            InterpreterError::OracleCallFailed { oracle, error, location }
+        })?;
+
+        let mut outputs = response.values.into_iter();
+        match foreign_call_params_to_value(&return_type, &mut outputs, location) {
+            Some(value) if outputs.next().is_none() => Ok(value),
+            _ => {
+                let oracle = oracle.to_string();
+                Err(InterpreterError::InvalidOracleResponse { oracle, typ: return_type, location })
+            }
+        }
+    }
+}
+
+/// Encodes `value` the same way unconstrained code passes it to an oracle at runtime:
+/// each field of a struct or tuple is a separate parameter, arrays are flattened into a
+/// single parameter, and slices are preceded by their length.
+fn push_foreign_call_params(
+    value: Value,
+    location: Location,
+    params: &mut Vec<ForeignCallParam<FieldElement>>,
+) -> IResult<()> {
+    match value {
+        Value::Unit => (),
+        Value::Tuple(fields) => {
+            for field in fields {
+                push_foreign_call_params(field, location, params)?;
+            }
+        }
+        Value::Struct(..) => {
+            for field in struct_fields_in_order(value) {
+                push_foreign_call_params(field, location, params)?;
+            }
+        }
+        Value::Slice(ref elements, _) => {
+            params.push(ForeignCallParam::Single(elements.len().into()));
+            let mut fields = Vec::new();
+            push_fields(value, location, &mut fields)?;
+            params.push(ForeignCallParam::Array(fields));
+        }
+        Value::Array(..) | Value::String(_) => {
+            let mut fields = Vec::new();
+            push_fields(value, location, &mut fields)?;
+            params.push(ForeignCallParam::Array(fields));
+        }
+        value => params.push(ForeignCallParam::Single(value_to_field(value, location)?)),
+    }
+    Ok(())
+}
+
+/// Flattens `value` into `fields`, as is done for the contents of arrays passed to oracles.
+fn push_fields(value: Value, location: Location, fields: &mut Vec<FieldElement>) -> IResult<()> {
+    match value {
+        Value::Unit => (),
+        Value::Tuple(elements) => {
+            for element in elements {
+                push_fields(element, location, fields)?;
+            }
+        }
+        Value::Array(elements, _) | Value::Slice(elements, _) => {
+            for element in elements {
+                push_fields(element, location, fields)?;
+            }
+        }
+        Value::Struct(..) => {
+            for field in struct_fields_in_order(value) {
+                push_fields(field, location, fields)?;
+            }
+        }
+        Value::String(string) => {
+            fields.extend(string.bytes().map(|byte| FieldElement::from(byte as u128)));
+        }
+        value => fields.push(value_to_field(value, location)?),
+    }
+    Ok(())
+}
+
+fn struct_fields_in_order(value: Value) -> Vec<Value> {
+    let Value::Struct(mut fields, typ) = value else {
+        unreachable!("Expected a struct value");
+    };
+    let Type::Struct(struct_type, generics) = typ.follow_bindings() else {
+        unreachable!("Expected a struct type");
+    };
+    let field_names = struct_type.borrow().get_fields(&generics);
+    field_names
+        .into_iter()
+        .map(|(name, _)| fields.remove(&Rc::new(name)).expect("Expected struct to have field"))
+        .collect()
+}
+
+/// Converts a numeric value to a field element. Signed integers use their two's complement.
+fn value_to_field(value: Value, location: Location) -> IResult<FieldElement> {
+    let field = match value {
+        Value::Field(field) => field,
+        Value::Bool(value) | Value::U1(value) => FieldElement::from(value),
+        Value::U8(value) => FieldElement::from(value as u128),
+        Value::U16(value) => FieldElement::from(value as u128),
+        Value::U32(value) => FieldElement::from(value as u128),
+        Value::U64(value) => FieldElement::from(value as u128),
+        Value::U128(value) => FieldElement::from(value),
+        Value::I8(value) => FieldElement::from(value as u8 as u128),
+        Value::I16(value) => FieldElement::from(value as u16 as u128),
+        Value::I32(value) => FieldElement::from(value as u32 as u128),
+        Value::I64(value) => FieldElement::from(value as u64 as u128),
+        Value::I128(value) => FieldElement::from(value as u128),
+        value => {
+            let typ = value.get_type().into_owned();
+            return Err(InterpreterError::TypeUnsupported { typ, location });
+        }
+    };
+    Ok(field)
+}
+
+/// Decodes a value of type `typ` from the parameters returned by an oracle. This is the inverse
+/// of `push_foreign_call_params`. Returns `None` if the parameters don't match the type.
+fn foreign_call_params_to_value(
+    typ: &Type,
+    params: &mut impl Iterator<Item = ForeignCallParam<FieldElement>>,
+    location: Location,
+) -> Option<Value> {
+    match typ.follow_bindings() {
+        Type::Unit => Some(Value::Unit),
+        Type::Tuple(types) => {
+            let fields =
+                types.iter().map(|typ| foreign_call_params_to_value(typ, params, location));
+            Some(Value::Tuple(fields.collect::<Option<_>>()?))
+        }
+        Type::Struct(..) => {
+            struct_from(typ, |typ| foreign_call_params_to_value(typ, params, location))
+        }
+        Type::Alias(alias, generics) => {
+            foreign_call_params_to_value(&alias.borrow().get_type(&generics), params, location)
+        }
+        Type::Slice(element_type) => {
+            // The length is implied by the number of fields returned
+            let ForeignCallParam::Single(_) = params.next()? else {
+                return None;
+            };
+            let ForeignCallParam::Array(fields) = params.next()? else {
+                return None;
+            };
+            let mut fields = fields.into_iter().peekable();
+            let mut elements = Vector::new();
+            while fields.peek().is_some() {
+                elements.push_back(fields_to_value(&element_type, &mut fields, location)?);
+            }
+            Some(Value::Slice(elements, typ.clone()))
+        }
+        Type::Array(..) | Type::String(_) => {
+            let ForeignCallParam::Array(fields) = params.next()? else {
+                return None;
+            };
+            let mut fields = fields.into_iter().peekable();
+            let value = fields_to_value(typ, &mut fields, location)?;
+            fields.peek().is_none().then_some(value)
+        }
+        typ => {
+            let ForeignCallParam::Single(field) = params.next()? else {
+                return None;
+            };
+            field_to_value(&typ, field)
+        }
+    }
+}
+
+/// Decodes a value of type `typ` from the flattened contents of an array returned by an oracle.
+fn fields_to_value<I: Iterator<Item = FieldElement>>(
+    typ: &Type,
+    fields: &mut Peekable<I>,
+    location: Location,
+) -> Option<Value> {
+    match typ.follow_bindings() {
+        Type::Unit => Some(Value::Unit),
+        Type::Tuple(types) => {
+            let elements = types.iter().map(|typ| fields_to_value(typ, fields, location));
+            Some(Value::Tuple(elements.collect::<Option<_>>()?))
+        }
+        Type::Struct(..) => struct_from(typ, |typ| fields_to_value(typ, fields, location)),
+        Type::Alias(alias, generics) => {
+            fields_to_value(&alias.borrow().get_type(&generics), fields, location)
+        }
+        Type::Array(length, element_type) => {
+            let length = length.evaluate_to_u32(location.span).ok()?;
+            let elements = (0..length).map(|_| fields_to_value(&element_type, fields, location));
+            Some(Value::Array(elements.collect::<Option<_>>()?, typ.clone()))
+        }
+        Type::String(length) => {
+            let length = length.evaluate_to_u32(location.span).ok()?;
+            let bytes = (0..length).map(|_| u8::try_from(fields.next()?.try_to_u64()?).ok());
+            let string = String::from_utf8(bytes.collect::<Option<_>>()?).ok()?;
+            Some(Value::String(Rc::new(string)))
+        }
+        typ => field_to_value(&typ, fields.next()?),
+    }
+}
+
+/// Builds a struct value of type `typ`, decoding each of its fields in order with `f`.
+fn struct_from(typ: &Type, mut f: impl FnMut(&Type) -> Option<Value>) -> Option<Value> {
+    let Type::Struct(struct_type, generics) = typ.follow_bindings() else {
+        return None;
+    };
+    let mut fields = HashMap::default();
+    for (name, field_type) in struct_type.borrow().get_fields(&generics) {
+        fields.insert(Rc::new(name), f(&field_type)?);
+    }
+    Some(Value::Struct(fields, typ.clone()))
+}
+
+/// Converts a field returned by an oracle to a value of the numeric type `typ`,
+/// returning `None` if it doesn't fit in that type.
+fn field_to_value(typ: &Type, field: FieldElement) -> Option<Value> {
+    let value = match typ {
+        Type::FieldElement => Value::Field(field),
+        Type::Bool if field.num_bits() <= 1 => Value::Bool(field.is_one()),
+        Type::Integer(Signedness::Unsigned, IntegerBitSize::HundredTwentyEight) => {
+            Value::U128(field.try_into_u128()?)
+        }
+        Type::Integer(Signedness::Signed, IntegerBitSize::HundredTwentyEight) => {
+            Value::I128(field.try_into_u128()? as i128)
+        }
+        Type::Integer(signedness, bit_size) => {
+            let value = field.try_to_u64()?;
+            match (signedness, bit_size) {
+                (Signedness::Unsigned, IntegerBitSize::One) if value <= 1 => Value::U1(value == 1),
+                (Signedness::Unsigned, IntegerBitSize::Eight) => Value::U8(value.try_into().ok()?),
+                (Signedness::Unsigned, IntegerBitSize::Sixteen) => {
+                    Value::U16(value.try_into().ok()?)
+                }
+                (Signedness::Unsigned, IntegerBitSize::ThirtyTwo) => {
+                    Value::U32(value.try_into().ok()?)
+                }
+                (Signedness::Unsigned, IntegerBitSize::SixtyFour) => Value::U64(value),
+                (Signedness::Signed, IntegerBitSize::Eight) => {
+                    Value::I8(u8::try_from(value).ok()? as i8)
+                }
+                (Signedness::Signed, IntegerBitSize::Sixteen) => {
+                    Value::I16(u16::try_from(value).ok()? as i16)
+                }
+                (Signedness::Signed, IntegerBitSize::ThirtyTwo) => {
+                    Value::I32(u32::try_from(value).ok()? as i32)
+                }
+                (Signedness::Signed, IntegerBitSize::SixtyFour) => Value::I64(value as i64),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    Some(value)
+}
@@ -1,10 +1,20 @@
+mod bigint;
+mod debugger;
 mod display;
 mod errors;
+mod files;
 mod hir_to_display_ast;
 mod interpreter;
+mod oracles;
 mod tests;
 mod value;
 
+pub(crate) use bigint::ComptimeBigInts;
+pub use debugger::{ComptimeDebugger, ComptimeStep, ComptimeStepKind};
+pub(crate) use display::display_generated_item;
 pub use errors::InterpreterError;
+pub(crate) use files::ComptimeFiles;
 pub use interpreter::Interpreter;
+pub use oracles::ComptimeOracleResolver;
+pub(crate) use oracles::ComptimeOracles;
 pub use value::Value;
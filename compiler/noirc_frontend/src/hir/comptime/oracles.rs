@@ -0,0 +1,54 @@
+use std::fmt::Debug;
+
+use acvm::{acir::brillig::ForeignCallResult, pwg::ForeignCallWaitInfo, FieldElement};
+use noirc_printable_type::ForeignCallError;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Resolves oracle calls made from comptime code.
+///
+/// Oracle calls are rejected at compile-time unless a resolver is set with
+/// [`Context::set_comptime_oracle_resolver`][crate::hir::Context::set_comptime_oracle_resolver].
+/// Arguments and results are encoded the same way as for oracle calls made from unconstrained
+/// code at runtime, so a resolver for one can be used for the other.
+pub trait ComptimeOracleResolver {
+    fn resolve(
+        &mut self,
+        foreign_call: &ForeignCallWaitInfo<FieldElement>,
+    ) -> Result<ForeignCallResult<FieldElement>, ForeignCallError>;
+}
+
+/// The resolver for comptime oracle calls along with every response it has returned.
+///
+/// Responses are cached so that an oracle is only asked once for each distinct call, and the
+/// same call always evaluates to the same value within a compilation, no matter how many
+/// times or in which order comptime code ends up being evaluated.
+pub(crate) struct ComptimeOracles {
+    resolver: Box<dyn ComptimeOracleResolver>,
+    responses: HashMap<String, ForeignCallResult<FieldElement>>,
+}
+
+impl ComptimeOracles {
+    pub(crate) fn new(resolver: Box<dyn ComptimeOracleResolver>) -> Self {
+        Self { resolver, responses: HashMap::default() }
+    }
+
+    pub(crate) fn resolve(
+        &mut self,
+        foreign_call: &ForeignCallWaitInfo<FieldElement>,
+    ) -> Result<ForeignCallResult<FieldElement>, ForeignCallError> {
+        let key = serde_json::to_string(foreign_call)?;
+        if let Some(response) = self.responses.get(&key) {
+            return Ok(response.clone());
+        }
+
+        let response = self.resolver.resolve(foreign_call)?;
+        self.responses.insert(key, response.clone());
+        Ok(response)
+    }
+}
+
+impl Debug for ComptimeOracles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComptimeOracles").field("responses", &self.responses).finish()
+    }
+}
@@ -1,14 +1,21 @@
 #![cfg(test)]
 
+use std::cell::{Cell, RefCell};
 use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
+use std::rc::Rc;
 
+use acvm::acir::brillig::ForeignCallResult;
+use acvm::pwg::ForeignCallWaitInfo;
+use acvm::FieldElement;
 use fm::{FileId, FileManager};
 use noirc_arena::Index;
 use noirc_errors::Location;
+use noirc_printable_type::ForeignCallError;
 
 use super::errors::InterpreterError;
 use super::value::Value;
+use super::{ComptimeDebugger, ComptimeOracleResolver, ComptimeStep, ComptimeStepKind};
 use crate::elaborator::Elaborator;
 use crate::hir::def_collector::dc_crate::DefCollector;
 use crate::hir::def_collector::dc_mod::collect_defs;
@@ -17,6 +24,24 @@ use crate::hir::{Context, ParsedFiles};
 use crate::parse_program;
 
 fn interpret_helper(src: &str) -> Result<Value, InterpreterError> {
+    interpret_with_oracle_resolver(src, None)
+}
+
+fn interpret_with_oracle_resolver(
+    src: &str,
+    oracle_resolver: Option<Box<dyn ComptimeOracleResolver>>,
+) -> Result<Value, InterpreterError> {
+    interpret_with_context(src, |context| {
+        if let Some(oracle_resolver) = oracle_resolver {
+            context.set_comptime_oracle_resolver(oracle_resolver);
+        }
+    })
+}
+
+fn interpret_with_context(
+    src: &str,
+    setup_context: impl FnOnce(&mut Context),
+) -> Result<Value, InterpreterError> {
     let file = FileId::default();
 
     // Can't use Index::test_new here for some reason, even with #[cfg(test)].
@@ -37,6 +62,7 @@ fn interpret_helper(src: &str) -> Result<Value, InterpreterError> {
     let parsed_files = ParsedFiles::new();
     let mut context = Context::new(file_manager, parsed_files);
     context.def_interner.populate_dummy_operator_traits();
+    setup_context(&mut context);
 
     let krate = context.crate_graph.add_crate_root(FileId::dummy());
 
@@ -128,6 +154,26 @@ fn mutating_mutable_references() {
     assert_eq!(result, Value::I64(4));
 }
 
+#[test]
+fn wide_integer_arithmetic() {
+    let program = "comptime fn main() -> pub i128 {
+        let x: i128 = -9223372036854775808;
+        x * 18446744073709551615
+    }";
+    let result = interpret(program);
+    assert_eq!(result, Value::I128(-170141183460469231722463931679029329920));
+}
+
+#[test]
+fn u128_overflow_errors() {
+    let program = "comptime fn main() -> pub u128 {
+        let x: u128 = 18446744073709551616;
+        x * x
+    }";
+    let result = interpret_expect_error(program);
+    assert!(matches!(result, InterpreterError::InvalidValuesForBinary { .. }));
+}
+
 #[test]
 fn mutation_leaks() {
     let program = "comptime fn main() -> pub i8 {
@@ -296,3 +342,125 @@ fn generic_functions() {
     let result = interpret(program);
     assert_eq!(result, Value::U8(2));
 }
+
+/// Answers every oracle call by echoing its inputs back, counting how many calls reach it.
+struct EchoOracleResolver {
+    calls: Rc<Cell<usize>>,
+}
+
+impl ComptimeOracleResolver for EchoOracleResolver {
+    fn resolve(
+        &mut self,
+        foreign_call: &ForeignCallWaitInfo<FieldElement>,
+    ) -> Result<ForeignCallResult<FieldElement>, ForeignCallError> {
+        self.calls.set(self.calls.get() + 1);
+        Ok(ForeignCallResult { values: foreign_call.inputs.clone() })
+    }
+}
+
+#[test]
+fn oracle_call_without_resolver_errors() {
+    let program = "
+    #[oracle(echo)]
+    unconstrained fn echo(x: Field) -> Field {}
+
+    comptime fn main() -> pub Field {
+        echo(1)
+    }";
+    let result = interpret_expect_error(program);
+    assert!(matches!(result, InterpreterError::OracleResolverNotSet { .. }));
+}
+
+#[test]
+fn oracle_call_with_resolver() {
+    let program = "
+    #[oracle(echo)]
+    unconstrained fn echo(x: Field, y: i8, z: [u32; 2]) -> (Field, i8, [u32; 2]) {}
+
+    comptime fn main() -> pub Field {
+        let (x, y, z) = echo(3, -1, [4, 5]);
+        assert(y == -1);
+        x + (z[0] + z[1]) as Field
+    }";
+    let calls = Rc::new(Cell::new(0));
+    let resolver = Box::new(EchoOracleResolver { calls: calls.clone() });
+    let result = interpret_with_oracle_resolver(program, Some(resolver)).unwrap();
+    assert_eq!(result, Value::Field(12u128.into()));
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn oracle_responses_are_cached() {
+    let program = "
+    #[oracle(echo)]
+    unconstrained fn echo(x: Field) -> Field {}
+
+    comptime fn main() -> pub Field {
+        echo(1) + echo(1) + echo(2)
+    }";
+    let calls = Rc::new(Cell::new(0));
+    let resolver = Box::new(EchoOracleResolver { calls: calls.clone() });
+    let result = interpret_with_oracle_resolver(program, Some(resolver)).unwrap();
+    assert_eq!(result, Value::Field(4u128.into()));
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn invalid_oracle_response_errors() {
+    let program = "
+    #[oracle(echo)]
+    unconstrained fn echo(x: Field) -> (Field, Field) {}
+
+    comptime fn main() -> pub Field {
+        let (x, y) = echo(1);
+        x + y
+    }";
+    let resolver = Box::new(EchoOracleResolver { calls: Rc::default() });
+    let result = interpret_with_oracle_resolver(program, Some(resolver)).unwrap_err();
+    assert!(matches!(result, InterpreterError::InvalidOracleResponse { .. }));
+}
+
+/// Records the kind of each step along with the variables in scope at that point.
+struct RecordingDebugger {
+    steps: Rc<RefCell<Vec<(ComptimeStepKind, Vec<(String, String)>)>>>,
+}
+
+impl ComptimeDebugger for RecordingDebugger {
+    fn on_step(&mut self, step: &ComptimeStep) {
+        assert_eq!(step.function_name(), Some("main"));
+        self.steps.borrow_mut().push((step.kind.clone(), step.variables()));
+    }
+}
+
+#[test]
+fn debugger_is_notified_of_each_statement_and_quote() {
+    let program = "comptime fn main() -> pub Field {
+        let x = 1;
+        let y = x + 1;
+        let _q = quote { $y };
+        y
+    }";
+    let steps = Rc::default();
+    let debugger = Box::new(RecordingDebugger { steps: Rc::clone(&steps) });
+    let result = interpret_with_context(program, |context| context.set_comptime_debugger(debugger));
+    assert_eq!(result.unwrap(), Value::Field(2u128.into()));
+
+    let steps = steps.borrow();
+    let kinds: Vec<_> = steps.iter().map(|(kind, _)| kind.clone()).collect();
+    let ComptimeStepKind::Quote(quoted) = &kinds[3] else {
+        panic!("Expected the fourth step to be a quote, found {:?}", kinds[3]);
+    };
+    assert!(quoted.contains('2'));
+    assert_eq!(kinds.len(), 5);
+    assert!(kinds
+        .iter()
+        .enumerate()
+        .all(|(i, kind)| i == 3 || kind == &ComptimeStepKind::Statement));
+
+    let variables = |variables: &[(&str, &str)]| -> Vec<(String, String)> {
+        variables.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+    };
+    assert_eq!(steps[0].1, variables(&[]));
+    assert_eq!(steps[1].1, variables(&[("x", "1")]));
+    assert_eq!(steps[2].1, variables(&[("x", "1"), ("y", "2")]));
+}
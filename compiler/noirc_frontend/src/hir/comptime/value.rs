@@ -14,8 +14,8 @@ use crate::{
     },
     hir::{def_map::ModuleId, type_check::generics::TraitGenerics},
     hir_def::expr::{
-        HirArrayLiteral, HirConstructorExpression, HirExpression, HirIdent, HirLambda, HirLiteral,
-        ImplKind,
+        HirArrayLiteral, HirConstructorExpression, HirEnumConstructorExpression, HirExpression,
+        HirIdent, HirLambda, HirLiteral, ImplKind,
     },
     node_interner::{ExprId, FuncId, NodeInterner, StmtId, StructId, TraitId, TraitImplId},
     parser::{Item, Parser},
@@ -38,11 +38,13 @@ pub enum Value {
     I16(i16),
     I32(i32),
     I64(i64),
+    I128(i128),
     U1(bool),
     U8(u8),
     U16(u16),
     U32(u32),
     U64(u64),
+    U128(u128),
     String(Rc<String>),
     FormatString(Rc<String>, Type),
     CtString(Rc<String>),
@@ -54,6 +56,7 @@ pub enum Value {
 
     Tuple(Vec<Value>),
     Struct(HashMap<Rc<String>, Value>, Type),
+    Enum(/*tag*/ usize, /*args*/ Vec<Value>, Type),
     Pointer(Shared<Value>, /* auto_deref */ bool),
     Array(Vector<Value>, Type),
     Slice(Vector<Value>, Type),
@@ -114,11 +117,15 @@ impl Value {
             Value::I16(_) => Type::Integer(Signedness::Signed, IntegerBitSize::Sixteen),
             Value::I32(_) => Type::Integer(Signedness::Signed, IntegerBitSize::ThirtyTwo),
             Value::I64(_) => Type::Integer(Signedness::Signed, IntegerBitSize::SixtyFour),
+            Value::I128(_) => Type::Integer(Signedness::Signed, IntegerBitSize::HundredTwentyEight),
             Value::U1(_) => Type::Integer(Signedness::Unsigned, IntegerBitSize::One),
             Value::U8(_) => Type::Integer(Signedness::Unsigned, IntegerBitSize::Eight),
             Value::U16(_) => Type::Integer(Signedness::Unsigned, IntegerBitSize::Sixteen),
             Value::U32(_) => Type::Integer(Signedness::Unsigned, IntegerBitSize::ThirtyTwo),
             Value::U64(_) => Type::Integer(Signedness::Unsigned, IntegerBitSize::SixtyFour),
+            Value::U128(_) => {
+                Type::Integer(Signedness::Unsigned, IntegerBitSize::HundredTwentyEight)
+            }
             Value::String(value) => {
                 let length = Type::Constant(value.len().into(), Kind::u32());
                 Type::String(Box::new(length))
@@ -130,6 +137,7 @@ impl Value {
                 Type::Tuple(vecmap(fields, |field| field.get_type().into_owned()))
             }
             Value::Struct(_, typ) => return Cow::Borrowed(typ),
+            Value::Enum(_, _, typ) => return Cow::Borrowed(typ),
             Value::Array(_, typ) => return Cow::Borrowed(typ),
             Value::Slice(_, typ) => return Cow::Borrowed(typ),
             Value::Quoted(_) => Type::Quoted(QuotedType::Quoted),
@@ -189,6 +197,11 @@ impl Value {
                 let value = (value as u128).into();
                 ExpressionKind::Literal(Literal::Integer(value, negative))
             }
+            Value::I128(value) => {
+                let negative = value < 0;
+                let value = value.unsigned_abs().into();
+                ExpressionKind::Literal(Literal::Integer(value, negative))
+            }
             Value::U1(value) => {
                 ExpressionKind::Literal(Literal::Integer((value as u128).into(), false))
             }
@@ -204,6 +217,7 @@ impl Value {
             Value::U64(value) => {
                 ExpressionKind::Literal(Literal::Integer((value as u128).into(), false))
             }
+            Value::U128(value) => ExpressionKind::Literal(Literal::Integer(value.into(), false)),
             Value::String(value) | Value::CtString(value) => {
                 ExpressionKind::Literal(Literal::Str(unwrap_rc(value)))
             }
@@ -244,6 +258,10 @@ impl Value {
                     struct_type,
                 }))
             }
+            value @ Value::Enum(..) => {
+                let hir = value.into_hir_expression(interner, location)?;
+                ExpressionKind::Resolved(hir)
+            }
             Value::Array(elements, _) => {
                 let elements =
                     try_vecmap(elements, |element| element.into_expression(interner, location))?;
@@ -336,6 +354,11 @@ impl Value {
                 let value = (value as u128).into();
                 HirExpression::Literal(HirLiteral::Integer(value, negative))
             }
+            Value::I128(value) => {
+                let negative = value < 0;
+                let value = value.unsigned_abs().into();
+                HirExpression::Literal(HirLiteral::Integer(value, negative))
+            }
             Value::U1(value) => {
                 HirExpression::Literal(HirLiteral::Integer((value as u128).into(), false))
             }
@@ -351,6 +374,7 @@ impl Value {
             Value::U64(value) => {
                 HirExpression::Literal(HirLiteral::Integer((value as u128).into(), false))
             }
+            Value::U128(value) => HirExpression::Literal(HirLiteral::Integer(value.into(), false)),
             Value::String(value) | Value::CtString(value) => {
                 HirExpression::Literal(HirLiteral::Str(unwrap_rc(value)))
             }
@@ -390,6 +414,21 @@ impl Value {
                     fields,
                 })
             }
+            Value::Enum(variant_index, arguments, typ) => {
+                let r#type = match typ.follow_bindings() {
+                    Type::Struct(def, _) => def,
+                    _ => return Err(InterpreterError::NonStructInConstructor { typ, location }),
+                };
+
+                let arguments =
+                    try_vecmap(arguments, |arg| arg.into_hir_expression(interner, location))?;
+
+                HirExpression::EnumConstructor(HirEnumConstructorExpression {
+                    r#type,
+                    variant_index,
+                    arguments,
+                })
+            }
             Value::Array(elements, _) => {
                 let elements = try_vecmap(elements, |element| {
                     element.into_hir_expression(interner, location)
@@ -468,6 +507,7 @@ impl Value {
             Value::U16(value) => Token::Int((value as u128).into()),
             Value::U32(value) => Token::Int((value as u128).into()),
             Value::U64(value) => Token::Int((value as u128).into()),
+            Value::U128(value) => Token::Int(value.into()),
             Value::I8(value) => {
                 if value < 0 {
                     return Ok(vec![Token::Minus, Token::Int((-value as u128).into())]);
@@ -496,6 +536,13 @@ impl Value {
                     Token::Int((value as u128).into())
                 }
             }
+            Value::I128(value) => {
+                if value < 0 {
+                    return Ok(vec![Token::Minus, Token::Int(value.unsigned_abs().into())]);
+                } else {
+                    Token::Int((value as u128).into())
+                }
+            }
             Value::Field(value) => Token::Int(value),
             other => Token::UnquoteMarker(other.into_hir_expression(interner, location)?),
         };
@@ -511,10 +558,12 @@ impl Value {
             Self::I16(value) => (*value >= 0).then_some(*value as u128),
             Self::I32(value) => (*value >= 0).then_some(*value as u128),
             Self::I64(value) => (*value >= 0).then_some(*value as u128),
+            Self::I128(value) => (*value >= 0).then_some(*value as u128),
             Self::U8(value) => Some(*value as u128),
             Self::U16(value) => Some(*value as u128),
             Self::U32(value) => Some(*value as u128),
             Self::U64(value) => Some(*value as u128),
+            Self::U128(value) => Some(*value),
             _ => None,
         }
     }
@@ -4,6 +4,7 @@ use crate::elaborator::Elaborator;
 use crate::graph::CrateId;
 use crate::hir::comptime::InterpreterError;
 use crate::hir::def_map::{CrateDefMap, LocalModuleId, ModuleId};
+use crate::hir::lint_levels::Lint;
 use crate::hir::resolution::errors::ResolverError;
 use crate::hir::type_check::TypeCheckError;
 use crate::locations::ReferencesTracker;
@@ -21,9 +22,9 @@ use crate::node_interner::{
 };
 
 use crate::ast::{
-    ExpressionKind, GenericTypeArgs, Ident, ItemVisibility, LetStatement, Literal, NoirFunction,
-    NoirStruct, NoirTrait, NoirTypeAlias, Path, PathKind, PathSegment, UnresolvedGenerics,
-    UnresolvedTraitConstraint, UnresolvedType, UnsupportedNumericGenericType,
+    ExpressionKind, GenericTypeArgs, Ident, ItemVisibility, LetStatement, Literal, NoirEnumeration,
+    NoirFunction, NoirStruct, NoirTrait, NoirTypeAlias, Path, PathKind, PathSegment,
+    UnresolvedGenerics, UnresolvedTraitConstraint, UnresolvedType, UnsupportedNumericGenericType,
 };
 
 use crate::parser::{ParserError, SortedModule};
@@ -64,6 +65,12 @@ pub struct UnresolvedStruct {
     pub struct_def: NoirStruct,
 }
 
+pub struct UnresolvedEnum {
+    pub file_id: FileId,
+    pub module_id: LocalModuleId,
+    pub enum_def: NoirEnumeration,
+}
+
 #[derive(Clone)]
 pub struct UnresolvedTrait {
     pub file_id: FileId,
@@ -140,6 +147,7 @@ pub struct DefCollector {
 pub struct CollectedItems {
     pub functions: Vec<UnresolvedFunctions>,
     pub(crate) types: BTreeMap<StructId, UnresolvedStruct>,
+    pub(crate) enums: BTreeMap<StructId, UnresolvedEnum>,
     pub(crate) type_aliases: BTreeMap<TypeAliasId, UnresolvedTypeAlias>,
     pub(crate) traits: BTreeMap<TraitId, UnresolvedTrait>,
     pub globals: Vec<UnresolvedGlobal>,
@@ -152,6 +160,7 @@ impl CollectedItems {
     pub fn is_empty(&self) -> bool {
         self.functions.is_empty()
             && self.types.is_empty()
+            && self.enums.is_empty()
             && self.type_aliases.is_empty()
             && self.traits.is_empty()
             && self.globals.is_empty()
@@ -180,6 +189,35 @@ pub enum CompilationError {
     DebugComptimeScopeNotFound(Vec<PathBuf>),
 }
 
+impl CompilationError {
+    /// Returns the lint this warning belongs to, if its level can be changed by users.
+    pub fn lint(&self) -> Option<Lint> {
+        let lint = match self {
+            CompilationError::ResolverError(error) => match error {
+                ResolverError::UnusedVariable { .. } => Lint::UnusedVariables,
+                ResolverError::UnusedItem { item: UnusedItem::Import, .. } => Lint::UnusedImports,
+                ResolverError::UnusedItem { .. } => Lint::DeadCode,
+                ResolverError::UnconditionalRecursion { .. } => Lint::UnconditionalRecursion,
+                ResolverError::UnnecessaryPub { .. } => Lint::UnnecessaryPub,
+                ResolverError::TypeIsMorePrivateThenItem { .. } => Lint::PrivateInterfaces,
+                _ => return None,
+            },
+            CompilationError::TypeError(error) => match error {
+                TypeCheckError::CallDeprecated { .. } => Lint::Deprecated,
+                TypeCheckError::UnusedResultError { .. } => Lint::UnusedResults,
+                TypeCheckError::UnneededTraitConstraint { .. } => Lint::UnneededTraitConstraints,
+                TypeCheckError::UnreachableCase { .. } => Lint::UnreachablePatterns,
+                TypeCheckError::Unsafe { .. } | TypeCheckError::UnsafeFn { .. } => {
+                    Lint::UnsafeCalls
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(lint)
+    }
+}
+
 impl std::fmt::Display for CompilationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -253,6 +291,7 @@ impl DefCollector {
             items: CollectedItems {
                 functions: vec![],
                 types: BTreeMap::new(),
+                enums: BTreeMap::new(),
                 type_aliases: BTreeMap::new(),
                 traits: BTreeMap::new(),
                 impls: HashMap::default(),
@@ -278,6 +317,9 @@ impl DefCollector {
         let mut errors: Vec<(CompilationError, FileId)> = vec![];
         let crate_id = def_map.krate;
 
+        let root_module = ModuleId { krate: crate_id, local_id: def_map.root };
+        context.def_interner.lint_scopes.add_file_module(root_file_id, root_module);
+
         // Recursively resolve the dependencies
         //
         // Dependencies are fetched from the crate graph
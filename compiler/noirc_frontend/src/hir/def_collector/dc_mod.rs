@@ -12,18 +12,19 @@ use rustc_hash::FxHashMap as HashMap;
 
 use crate::ast::{
     Documented, Expression, FunctionDefinition, Ident, ItemVisibility, LetStatement,
-    ModuleDeclaration, NoirFunction, NoirStruct, NoirTrait, NoirTraitImpl, NoirTypeAlias, Pattern,
-    TraitImplItemKind, TraitItem, TypeImpl, UnresolvedType, UnresolvedTypeData,
+    ModuleDeclaration, NoirEnumeration, NoirFunction, NoirStruct, NoirTrait, NoirTraitImpl,
+    NoirTypeAlias, Pattern, TraitImplItemKind, TraitItem, TypeImpl, UnresolvedType,
+    UnresolvedTypeData,
 };
 use crate::hir::resolution::errors::ResolverError;
 use crate::node_interner::{ModuleAttributes, NodeInterner, ReferenceId, StructId};
-use crate::token::SecondaryAttribute;
+use crate::token::{CfgPredicate, SecondaryAttribute};
 use crate::usage_tracker::{UnusedItem, UsageTracker};
 use crate::{
     graph::CrateId,
-    hir::def_collector::dc_crate::{UnresolvedStruct, UnresolvedTrait},
+    hir::def_collector::dc_crate::{UnresolvedEnum, UnresolvedStruct, UnresolvedTrait},
     node_interner::{FunctionModifiers, TraitId, TypeAliasId},
-    parser::{SortedModule, SortedSubModule},
+    parser::{Item, ItemKind, SortedModule, SortedSubModule},
 };
 use crate::{Generics, Kind, ResolvedGeneric, Type, TypeVariable};
 
@@ -93,6 +94,8 @@ pub fn collect_defs(
 
     errors.extend(collector.collect_structs(context, ast.types, crate_id));
 
+    errors.extend(collector.collect_enums(context, ast.enums, crate_id));
+
     errors.extend(collector.collect_type_aliases(context, ast.type_aliases, crate_id));
 
     errors.extend(collector.collect_functions(context, ast.functions, crate_id));
@@ -296,6 +299,32 @@ impl<'a> ModCollector<'a> {
         definition_errors
     }
 
+    /// Collect any enum definitions declared within the ast.
+    /// Returns a vector of errors if any enums were already defined,
+    /// or if an enum has duplicate variants in it.
+    fn collect_enums(
+        &mut self,
+        context: &mut Context,
+        enums: Vec<Documented<NoirEnumeration>>,
+        krate: CrateId,
+    ) -> Vec<(CompilationError, FileId)> {
+        let mut definition_errors = vec![];
+        for enum_definition in enums {
+            if let Some((id, the_enum)) = collect_enum(
+                &mut context.def_interner,
+                &mut self.def_collector.def_map,
+                enum_definition,
+                self.file_id,
+                self.module_id,
+                krate,
+                &mut definition_errors,
+            ) {
+                self.def_collector.items.enums.insert(id, the_enum);
+            }
+        }
+        definition_errors
+    }
+
     /// Collect any type aliases definitions declared within the ast.
     /// Returns a vector of errors if any type aliases were already defined.
     fn collect_type_aliases(
@@ -636,6 +665,9 @@ impl<'a> ModCollector<'a> {
                 false, // is struct
             ) {
                 Ok(child) => {
+                    let location = Location::new(submodule.span, file_id);
+                    context.def_interner.lint_scopes.add_inline_module(location, child);
+
                     self.collect_attributes(
                         submodule.outer_attributes,
                         file_id,
@@ -717,7 +749,8 @@ impl<'a> ModCollector<'a> {
         context.visited_files.insert(child_file_id, location);
 
         // Parse the AST for the module we just found and then recursively look for it's defs
-        let (ast, parsing_errors) = context.parsed_file_results(child_file_id);
+        let (mut ast, parsing_errors) = context.parsed_file_results(child_file_id);
+        remove_disabled_items(&mut ast.items, &context.def_interner, crate_id);
         let ast = ast.into_sorted();
 
         errors.extend(
@@ -737,6 +770,8 @@ impl<'a> ModCollector<'a> {
             false, // is struct
         ) {
             Ok(child_mod_id) => {
+                context.def_interner.lint_scopes.add_file_module(child_file_id, child_mod_id);
+
                 self.collect_attributes(
                     mod_decl.outer_attributes,
                     child_file_id,
@@ -916,9 +951,13 @@ pub fn collect_function(
         }
     }
 
+    let is_test = function.def.attributes.is_test_function();
+    if is_test && interner.crates_without_tests.contains(&module.krate) {
+        return None;
+    }
+
     let module_data = &mut def_map.modules[module.local_id.0];
 
-    let is_test = function.def.attributes.is_test_function();
     let is_entry_point_function = if module_data.is_contract {
         function.attributes().is_contract_entry_point()
     } else {
@@ -1045,6 +1084,78 @@ pub fn collect_struct(
     Some((id, unresolved))
 }
 
+pub fn collect_enum(
+    interner: &mut NodeInterner,
+    def_map: &mut CrateDefMap,
+    enum_definition: Documented<NoirEnumeration>,
+    file_id: FileId,
+    module_id: LocalModuleId,
+    krate: CrateId,
+    definition_errors: &mut Vec<(CompilationError, FileId)>,
+) -> Option<(StructId, UnresolvedEnum)> {
+    let doc_comments = enum_definition.doc_comments;
+    let enum_definition = enum_definition.item;
+
+    check_duplicate_variant_names(&enum_definition, file_id, definition_errors);
+
+    let name = enum_definition.name.clone();
+
+    let unresolved = UnresolvedEnum { file_id, module_id, enum_def: enum_definition };
+
+    let resolved_generics = Context::resolve_generics(
+        interner,
+        &unresolved.enum_def.generics,
+        definition_errors,
+        file_id,
+    );
+
+    // Create the corresponding module for the enum namespace, its variants are declared there
+    let location = Location::new(name.span(), file_id);
+    let id = match push_child_module(
+        interner,
+        def_map,
+        module_id,
+        &name,
+        ItemVisibility::Public,
+        location,
+        Vec::new(),
+        Vec::new(),
+        false, // add to parent scope
+        false, // is contract
+        true,  // is struct
+    ) {
+        Ok(module_id) => {
+            interner.new_enum(&unresolved, resolved_generics, krate, module_id.local_id, file_id)
+        }
+        Err(error) => {
+            definition_errors.push((error.into(), file_id));
+            return None;
+        }
+    };
+
+    interner.set_doc_comments(ReferenceId::Struct(id), doc_comments);
+
+    // Add the enum to scope so its path can be looked up later
+    let visibility = unresolved.enum_def.visibility;
+    let result = def_map.modules[module_id.0].declare_struct(name.clone(), visibility, id);
+
+    if let Err((first_def, second_def)) = result {
+        let error = DefCollectorErrorKind::Duplicate {
+            typ: DuplicateType::TypeDefinition,
+            first_def,
+            second_def,
+        };
+        definition_errors.push((error.into(), file_id));
+    }
+
+    if interner.is_in_lsp_mode() {
+        let parent_module_id = ModuleId { krate, local_id: module_id };
+        interner.register_struct(id, name.to_string(), visibility, parent_module_id);
+    }
+
+    Some((id, unresolved))
+}
+
 pub fn collect_impl(
     interner: &mut NodeInterner,
     items: &mut CollectedItems,
@@ -1157,6 +1268,42 @@ fn is_native_field(str: &str) -> bool {
     }
 }
 
+/// Removes the items of a parsed module, along with the methods of its impls, whose
+/// `#[cfg(...)]` attributes aren't satisfied by the features enabled for `krate`.
+pub(crate) fn remove_disabled_items(
+    items: &mut Vec<Item>,
+    interner: &NodeInterner,
+    krate: CrateId,
+) {
+    let is_enabled = |predicate: &CfgPredicate| interner.is_cfg_enabled(krate, predicate);
+    let function_is_enabled = |function: &NoirFunction| {
+        function.secondary_attributes().iter().all(|attribute| match attribute {
+            SecondaryAttribute::Cfg(predicate) => is_enabled(predicate),
+            _ => true,
+        })
+    };
+
+    items.retain(|item| item.cfg.iter().all(is_enabled));
+
+    for item in items {
+        match &mut item.kind {
+            ItemKind::Submodules(submodule) => {
+                remove_disabled_items(&mut submodule.contents.items, interner, krate);
+            }
+            ItemKind::Impl(type_impl) => {
+                type_impl.methods.retain(|(method, _)| function_is_enabled(&method.item));
+            }
+            ItemKind::TraitImpl(trait_impl) => {
+                trait_impl.items.retain(|item| match &item.item.kind {
+                    TraitImplItemKind::Function(function) => function_is_enabled(function),
+                    TraitImplItemKind::Constant(..) | TraitImplItemKind::Type { .. } => true,
+                });
+            }
+            _ => (),
+        }
+    }
+}
+
 type AssociatedTypes = Vec<(Ident, UnresolvedType)>;
 type AssociatedConstants = Vec<(Ident, UnresolvedType, Expression)>;
 
@@ -1274,6 +1421,32 @@ fn check_duplicate_field_names(
     }
 }
 
+fn check_duplicate_variant_names(
+    enum_definition: &NoirEnumeration,
+    file: FileId,
+    definition_errors: &mut Vec<(CompilationError, FileId)>,
+) {
+    let mut seen_variant_names = std::collections::HashSet::new();
+    for variant in &enum_definition.variants {
+        let variant_name = &variant.item.name;
+
+        if seen_variant_names.insert(variant_name) {
+            continue;
+        }
+
+        let previous_variant_name = *seen_variant_names.get(variant_name).unwrap();
+        definition_errors.push((
+            DefCollectorErrorKind::Duplicate {
+                typ: DuplicateType::EnumVariant,
+                first_def: previous_variant_name.clone(),
+                second_def: variant_name.clone(),
+            }
+            .into(),
+            file,
+        ));
+    }
+}
+
 #[cfg(test)]
 mod find_module_tests {
     use super::*;
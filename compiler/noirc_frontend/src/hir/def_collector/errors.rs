@@ -21,6 +21,7 @@ pub enum DuplicateType {
     TraitAssociatedType,
     TraitAssociatedConst,
     TraitAssociatedFunction,
+    EnumVariant,
 }
 
 #[derive(Error, Debug, Clone)]
@@ -116,6 +117,7 @@ impl fmt::Display for DuplicateType {
             DuplicateType::TraitAssociatedType => write!(f, "trait associated type"),
             DuplicateType::TraitAssociatedConst => write!(f, "trait associated constant"),
             DuplicateType::TraitAssociatedFunction => write!(f, "trait associated function"),
+            DuplicateType::EnumVariant => write!(f, "enum variant"),
         }
     }
 }
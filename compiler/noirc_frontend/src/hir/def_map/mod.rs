@@ -1,5 +1,6 @@
 use crate::graph::CrateId;
 use crate::hir::def_collector::dc_crate::{CompilationError, DefCollector};
+use crate::hir::def_collector::dc_mod::remove_disabled_items;
 use crate::hir::Context;
 use crate::node_interner::{FuncId, GlobalId, NodeInterner, StructId};
 use crate::parse_program;
@@ -92,7 +93,8 @@ impl CrateDefMap {
 
         // First parse the root file.
         let root_file_id = context.crate_graph[crate_id].root_file_id;
-        let (ast, parsing_errors) = context.parsed_file_results(root_file_id);
+        let (mut ast, parsing_errors) = context.parsed_file_results(root_file_id);
+        remove_disabled_items(&mut ast.items, &context.def_interner, crate_id);
         let ast = ast.into_sorted();
 
         // Allocate a default Module for the root, giving it a ModuleId
@@ -0,0 +1,228 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use fm::FileId;
+use noirc_errors::Location;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::graph::CrateId;
+use crate::hir::def_map::{DefMaps, ModuleId};
+use crate::token::SecondaryAttribute;
+
+/// A named kind of warning whose level can be set with `#[allow(name)]`, `#[warn(name)]` and
+/// `#[deny(name)]` on functions and modules, or in the `[lints]` table of a package's `Nargo.toml`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Lint {
+    /// Functions, structs, traits, type aliases and globals which are never used
+    DeadCode,
+    /// Calls to functions marked `#[deprecated]`
+    Deprecated,
+    /// Types which are more private than the items exposing them
+    PrivateInterfaces,
+    /// Functions which call themselves on every path
+    UnconditionalRecursion,
+    /// `pub` on the parameters or return type of functions which aren't entry points
+    UnnecessaryPub,
+    /// Trait constraints in `where` clauses which are already satisfied by an impl
+    UnneededTraitConstraints,
+    /// `match` cases which can never be reached
+    UnreachablePatterns,
+    /// Calls to unconstrained functions outside of an `unsafe` block
+    UnsafeCalls,
+    /// Imports which are never used
+    UnusedImports,
+    /// Expression statements whose non-unit result is discarded
+    UnusedResults,
+    /// Variables which are never used
+    UnusedVariables,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 11] = [
+        Lint::DeadCode,
+        Lint::Deprecated,
+        Lint::PrivateInterfaces,
+        Lint::UnconditionalRecursion,
+        Lint::UnnecessaryPub,
+        Lint::UnneededTraitConstraints,
+        Lint::UnreachablePatterns,
+        Lint::UnsafeCalls,
+        Lint::UnusedImports,
+        Lint::UnusedResults,
+        Lint::UnusedVariables,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Lint::DeadCode => "dead_code",
+            Lint::Deprecated => "deprecated",
+            Lint::PrivateInterfaces => "private_interfaces",
+            Lint::UnconditionalRecursion => "unconditional_recursion",
+            Lint::UnnecessaryPub => "unnecessary_pub",
+            Lint::UnneededTraitConstraints => "unneeded_trait_constraints",
+            Lint::UnreachablePatterns => "unreachable_patterns",
+            Lint::UnsafeCalls => "unsafe_calls",
+            Lint::UnusedImports => "unused_imports",
+            Lint::UnusedResults => "unused_results",
+            Lint::UnusedVariables => "unused_variables",
+        }
+    }
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Lint {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Lint::ALL.into_iter().find(|lint| lint.name() == s).ok_or(())
+    }
+}
+
+/// What to do with the warnings of a [`Lint`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LintLevel {
+    /// Don't report them
+    Allow,
+    /// Report them as warnings. This is the default.
+    Warn,
+    /// Report them as errors
+    Deny,
+}
+
+impl Display for LintLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintLevel::Allow => write!(f, "allow"),
+            LintLevel::Warn => write!(f, "warn"),
+            LintLevel::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+impl FromStr for LintLevel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(LintLevel::Allow),
+            "warn" => Ok(LintLevel::Warn),
+            "deny" => Ok(LintLevel::Deny),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Returns the lint level set by `attribute`, if it is `#[allow]`, `#[warn]` or `#[deny]`
+/// with the name of a known lint.
+pub(crate) fn attribute_lint_level(attribute: &SecondaryAttribute) -> Option<(Lint, LintLevel)> {
+    let (name, level) = match attribute {
+        SecondaryAttribute::Allow(name) => (name, LintLevel::Allow),
+        SecondaryAttribute::Warn(name) => (name, LintLevel::Warn),
+        SecondaryAttribute::Deny(name) => (name, LintLevel::Deny),
+        _ => return None,
+    };
+    Some((name.parse().ok()?, level))
+}
+
+/// Returns the level `levels` sets for `lint`. When a lint is set more than once the last
+/// level wins, as with attributes later on an item overriding earlier ones.
+fn find_level(levels: &[(Lint, LintLevel)], lint: Lint) -> Option<LintLevel> {
+    levels.iter().rev().find(|(other, _)| *other == lint).map(|(_, level)| *level)
+}
+
+/// The lint levels set on functions, modules and crates, along with the code each applies to.
+#[derive(Debug, Default)]
+pub(crate) struct LintScopes {
+    /// The levels set on each function, along with the location of its signature and body.
+    functions: Vec<(Location, Vec<(Lint, LintLevel)>)>,
+
+    /// The module each file defines. Outside of inline modules, all the code
+    /// in a file belongs to this module.
+    file_modules: HashMap<FileId, ModuleId>,
+
+    /// Each inline `mod name { ... }` module, along with its location.
+    inline_modules: Vec<(Location, ModuleId)>,
+
+    /// The default levels for each crate, from the `[lints]` table of its package.
+    crate_levels: HashMap<CrateId, Vec<(Lint, LintLevel)>>,
+}
+
+impl LintScopes {
+    pub(crate) fn add_function(&mut self, location: Location, attributes: &[SecondaryAttribute]) {
+        let levels: Vec<_> = attributes.iter().filter_map(attribute_lint_level).collect();
+        if !levels.is_empty() {
+            self.functions.push((location, levels));
+        }
+    }
+
+    pub(crate) fn add_file_module(&mut self, file: FileId, module: ModuleId) {
+        self.file_modules.insert(file, module);
+    }
+
+    pub(crate) fn add_inline_module(&mut self, location: Location, module: ModuleId) {
+        self.inline_modules.push((location, module));
+    }
+
+    pub(crate) fn set_crate_levels(&mut self, crate_id: CrateId, levels: Vec<(Lint, LintLevel)>) {
+        self.crate_levels.insert(crate_id, levels);
+    }
+
+    /// Returns the level of `lint` for a warning at `location`. This is the level set by the
+    /// innermost function or module containing `location`, or else by the crate.
+    pub(crate) fn level(&self, lint: Lint, location: Location, def_maps: &DefMaps) -> LintLevel {
+        let contains = |scope: &Location| {
+            scope.file == location.file
+                && scope.span.start() <= location.span.start()
+                && location.span.end() <= scope.span.end()
+        };
+        let size = |scope: &Location| scope.span.end() - scope.span.start();
+
+        let function_level = self
+            .functions
+            .iter()
+            .filter(|(function, levels)| contains(function) && find_level(levels, lint).is_some())
+            .min_by_key(|(function, _)| size(function))
+            .and_then(|(_, levels)| find_level(levels, lint));
+        if let Some(level) = function_level {
+            return level;
+        }
+
+        let inline_module = self
+            .inline_modules
+            .iter()
+            .filter(|(module, _)| contains(module))
+            .min_by_key(|(module, _)| size(module))
+            .map(|(_, module)| *module);
+        let Some(mut module) =
+            inline_module.or_else(|| self.file_modules.get(&location.file).copied())
+        else {
+            return LintLevel::Warn;
+        };
+
+        // Levels set on a module also apply to each of its submodules
+        let def_map = &def_maps[&module.krate];
+        loop {
+            let module_data = &def_map[module.local_id];
+            let levels: Vec<_> =
+                module_data.attributes.iter().filter_map(attribute_lint_level).collect();
+            if let Some(level) = find_level(&levels, lint) {
+                return level;
+            }
+
+            match module_data.parent {
+                Some(parent) => module.local_id = parent,
+                None => break,
+            }
+        }
+
+        self.crate_levels
+            .get(&module.krate)
+            .and_then(|levels| find_level(levels, lint))
+            .unwrap_or(LintLevel::Warn)
+    }
+}
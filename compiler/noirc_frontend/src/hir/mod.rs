@@ -1,6 +1,7 @@
 pub mod comptime;
 pub mod def_collector;
 pub mod def_map;
+pub mod lint_levels;
 pub mod resolution;
 pub mod scope;
 pub mod type_check;
@@ -8,6 +9,7 @@ pub mod type_check;
 use crate::ast::UnresolvedGenerics;
 use crate::debug::DebugInstrumenter;
 use crate::graph::{CrateGraph, CrateId};
+use crate::hir::comptime::{ComptimeDebugger, ComptimeOracleResolver, ComptimeOracles};
 use crate::hir_def::function::FuncMeta;
 use crate::node_interner::{FuncId, NodeInterner, StructId};
 use crate::parser::ParserError;
@@ -17,10 +19,11 @@ use def_collector::dc_crate::CompilationError;
 use def_map::{Contract, CrateDefMap};
 use fm::{FileId, FileManager};
 use iter_extended::vecmap;
+use lint_levels::{Lint, LintLevel};
 use noirc_errors::Location;
 use std::borrow::Cow;
-use std::collections::{BTreeMap, HashMap};
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 use self::def_map::TestFunction;
@@ -305,4 +308,47 @@ impl Context<'_, '_> {
     pub fn activate_lsp_mode(&mut self) {
         self.def_interner.lsp_mode = true;
     }
+
+    /// Allows comptime code to call oracles, which will be resolved by `resolver`.
+    pub fn set_comptime_oracle_resolver(&mut self, resolver: Box<dyn ComptimeOracleResolver>) {
+        self.def_interner.comptime_oracles = Some(ComptimeOracles::new(resolver));
+    }
+
+    /// Pauses comptime evaluation before each step to let `debugger` inspect it.
+    pub fn set_comptime_debugger(&mut self, debugger: Box<dyn ComptimeDebugger>) {
+        self.def_interner.comptime_debugger = Some(debugger);
+    }
+
+    /// Sets the default level of each of `levels` for warnings in `crate_id`. These are
+    /// overridden by `#[allow]`, `#[warn]` and `#[deny]` attributes within the crate.
+    pub fn set_lint_levels(&mut self, crate_id: CrateId, levels: Vec<(Lint, LintLevel)>) {
+        self.def_interner.lint_scopes.set_crate_levels(crate_id, levels);
+    }
+
+    /// Leaves the `#[test]` functions of `crate_id` out of compilation.
+    pub fn skip_test_functions(&mut self, crate_id: CrateId) {
+        self.def_interner.crates_without_tests.insert(crate_id);
+    }
+
+    /// Enables `features` for `crate_id`, in addition to any already enabled. A crate's
+    /// `#[cfg(...)]` attributes are checked against its enabled features.
+    pub fn enable_features(&mut self, crate_id: CrateId, features: BTreeSet<String>) {
+        self.def_interner.crate_features.entry(crate_id).or_default().extend(features);
+    }
+
+    /// Returns the level of `lint` for a warning at `location`.
+    pub fn lint_level(&self, lint: Lint, location: Location) -> LintLevel {
+        self.def_interner.lint_scopes.level(lint, location, &self.def_maps)
+    }
+
+    /// Allows comptime code in `crate_id` to read files within `root`, the crate's package directory.
+    pub fn set_package_root(&mut self, crate_id: CrateId, root: PathBuf) {
+        self.def_interner.comptime_files.set_package_root(crate_id, root);
+    }
+
+    /// Returns each file read by comptime code so far, including the ones it couldn't find.
+    /// Along with the source files, these are the inputs the compiled program depends on.
+    pub fn comptime_file_inputs(&self) -> impl Iterator<Item = &Path> {
+        self.def_interner.comptime_files.read_files()
+    }
 }
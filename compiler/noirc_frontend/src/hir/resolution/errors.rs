@@ -101,6 +101,12 @@ pub enum ResolverError {
     JumpInConstrainedFn { is_break: bool, span: Span },
     #[error("break/continue are only allowed within loops")]
     JumpOutsideLoop { is_break: bool, span: Span },
+    #[error("while/loop are only allowed in unconstrained functions")]
+    LoopInConstrainedFn { is_while: bool, span: Span },
+    #[error("Early return is only allowed in unconstrained functions")]
+    EarlyReturnInConstrainedFn { span: Span },
+    #[error("return is only allowed within a function or lambda")]
+    ReturnOutsideFunction { span: Span },
     #[error("Only `comptime` globals can be mutable")]
     MutableGlobal { span: Span },
     #[error("Self-referential structs are not supported")]
@@ -161,6 +167,10 @@ pub enum ResolverError {
         span: Span,
         missing_trait_location: Location,
     },
+    #[error("Expected a pattern but found an expression")]
+    InvalidPattern { span: Span },
+    #[error("`{name}` is not an enum variant")]
+    NotAnEnumVariant { name: String, span: Span },
 }
 
 impl ResolverError {
@@ -432,6 +442,28 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                     *span,
                 )
             },
+            ResolverError::LoopInConstrainedFn { is_while, span } => {
+                let item = if *is_while { "while" } else { "loop" };
+                Diagnostic::simple_error(
+                    format!("`{item}` is only allowed in unconstrained functions"),
+                    "Constrained code must always have a known number of loop iterations".into(),
+                    *span,
+                )
+            },
+            ResolverError::EarlyReturnInConstrainedFn { span } => {
+                Diagnostic::simple_error(
+                    "Early `return` is only allowed in unconstrained functions".into(),
+                    "Constrained functions must return the value of their final expression".into(),
+                    *span,
+                )
+            },
+            ResolverError::ReturnOutsideFunction { span } => {
+                Diagnostic::simple_error(
+                    "`return` is only allowed within a function or lambda".into(),
+                    "`comptime` blocks and globals are evaluated on their own and cannot return from the enclosing function".into(),
+                    *span,
+                )
+            },
             ResolverError::JumpOutsideLoop { is_break, span } => {
                 let item = if *is_break { "break" } else { "continue" };
                 Diagnostic::simple_error(
@@ -599,6 +631,20 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                     *span,
                 )
             },
+            ResolverError::InvalidPattern { span } => {
+                Diagnostic::simple_error(
+                    "Expected a pattern but found an expression".into(),
+                    "Patterns can be literals, variables, tuples, structs, or enum variants".into(),
+                    *span,
+                )
+            },
+            ResolverError::NotAnEnumVariant { name, span } => {
+                Diagnostic::simple_error(
+                    format!("`{name}` is not an enum variant"),
+                    "Expected an enum variant in this pattern".into(),
+                    *span,
+                )
+            },
             ResolverError::TraitNotImplemented { impl_trait, missing_trait: the_trait, type_missing_trait: typ, span, missing_trait_location} => {
                 let mut diagnostic = Diagnostic::simple_error(
                     format!("The trait bound `{typ}: {the_trait}` is not satisfied"), 
@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::rc::Rc;
 
 use acvm::FieldElement;
@@ -204,6 +205,25 @@ pub enum TypeCheckError {
     UnspecifiedType { span: Span },
     #[error("Binding `{typ}` here to the `_` inside would create a cyclic type")]
     CyclicType { typ: Type, span: Span },
+    #[error("{}", missing_cases_message(cases))]
+    MissingCases { cases: BTreeSet<String>, span: Span },
+    #[error("Unreachable match case")]
+    UnreachableCase { span: Span },
+}
+
+/// The number of missing cases shown in a `MissingCases` error before the rest are elided.
+const MAX_MISSING_CASES_SHOWN: usize = 3;
+
+fn missing_cases_message(cases: &BTreeSet<String>) -> String {
+    let shown = cases.iter().take(MAX_MISSING_CASES_SHOWN).map(|case| format!("`{case}`"));
+    let shown = shown.collect::<Vec<_>>().join(", ");
+
+    if cases.len() > MAX_MISSING_CASES_SHOWN {
+        let remaining = cases.len() - MAX_MISSING_CASES_SHOWN;
+        format!("Missing cases: {shown}, and {remaining} more not shown")
+    } else {
+        format!("Missing cases: {shown}")
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -511,6 +531,12 @@ impl<'a> From<&'a TypeCheckError> for Diagnostic {
             TypeCheckError::CyclicType { typ: _, span } => {
                 Diagnostic::simple_error(error.to_string(), "Cyclic types have unlimited size and are prohibited in Noir".into(), *span)
             }
+            TypeCheckError::MissingCases { span, .. } => {
+                Diagnostic::simple_error(error.to_string(), "This match is not exhaustive".into(), *span)
+            }
+            TypeCheckError::UnreachableCase { span } => {
+                Diagnostic::simple_warning(error.to_string(), "This pattern is matched by a previous case".into(), *span)
+            }
         }
     }
 }
@@ -30,11 +30,13 @@ pub enum HirExpression {
     Infix(HirInfixExpression),
     Index(HirIndexExpression),
     Constructor(HirConstructorExpression),
+    EnumConstructor(HirEnumConstructorExpression),
     MemberAccess(HirMemberAccess),
     Call(HirCallExpression),
     MethodCall(HirMethodCallExpression),
     Cast(HirCastExpression),
     If(HirIfExpression),
+    Match(HirMatch),
     Tuple(Vec<ExprId>),
     Lambda(HirLambda),
     Quote(Tokens),
@@ -280,6 +282,101 @@ pub struct HirConstructorExpression {
     pub fields: Vec<(Ident, ExprId)>,
 }
 
+/// Constructs a value of an enum by choosing one of its variants.
+/// These are created for the bodies of the functions of an enum's variants
+/// and for unit variants used as values.
+#[derive(Debug, Clone)]
+pub struct HirEnumConstructorExpression {
+    pub r#type: Shared<StructType>,
+    pub variant_index: usize,
+    pub arguments: Vec<ExprId>,
+}
+
+/// A `match` expression, compiled into a decision tree once its patterns are
+/// checked for exhaustiveness and reachability.
+#[derive(Debug, Clone)]
+pub enum HirMatch {
+    /// Jump directly to the given expression, with any pattern bindings
+    /// already introduced in it.
+    Success(ExprId),
+
+    /// No pattern matched. This is only reachable for matches that were
+    /// reported as non-exhaustive.
+    Failure,
+
+    /// Test the given variable against each case, in order. If none matches,
+    /// the optional fallback is taken. The fallback is only present when the
+    /// cases can't cover every value of the variable's type, e.g. for integers.
+    Switch(DefinitionId, Vec<Case>, Option<Box<HirMatch>>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Case {
+    pub constructor: Constructor,
+
+    /// The variables bound to each argument of the constructor, e.g. the
+    /// elements of a tuple or the parameters of an enum variant.
+    pub arguments: Vec<DefinitionId>,
+    pub body: HirMatch,
+}
+
+impl Case {
+    pub fn new(constructor: Constructor, arguments: Vec<DefinitionId>, body: HirMatch) -> Self {
+        Self { constructor, arguments, body }
+    }
+}
+
+/// A value that can be tested for in a `match`
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum Constructor {
+    True,
+    False,
+    Unit,
+    Int(FieldElement, bool), //true for negative integer and false for positive
+    Tuple(Vec<Type>),
+    Struct(Type),
+    Variant(Type, usize),
+}
+
+impl std::fmt::Display for Constructor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Constructor::True => write!(f, "true"),
+            Constructor::False => write!(f, "false"),
+            Constructor::Unit => write!(f, "()"),
+            Constructor::Int(value, is_negative) => {
+                if *is_negative {
+                    write!(f, "-{value}")
+                } else {
+                    write!(f, "{value}")
+                }
+            }
+            Constructor::Tuple(_) => Ok(()),
+            Constructor::Struct(typ) => write!(f, "{typ}"),
+            Constructor::Variant(typ, index) => match typ.follow_bindings() {
+                Type::Struct(def, _) => {
+                    let def = def.borrow();
+                    write!(f, "{}::{}", def.name, def.variant_at(*index).name)
+                }
+                other => write!(f, "{other}::{index}"),
+            },
+        }
+    }
+}
+
+impl Constructor {
+    /// The index of this constructor amongst the constructors of its type.
+    /// Integers have no such index since they can't all be enumerated.
+    pub fn variant_index(&self) -> usize {
+        match self {
+            Constructor::False | Constructor::Unit | Constructor::Tuple(_) => 0,
+            Constructor::Struct(_) | Constructor::Int(..) => 0,
+            Constructor::True => 1,
+            Constructor::Variant(_, index) => *index,
+        }
+    }
+}
+
 /// Indexing, as in `array[index]`
 #[derive(Debug, Clone)]
 pub struct HirIndexExpression {
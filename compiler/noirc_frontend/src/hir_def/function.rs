@@ -164,6 +164,9 @@ pub struct FuncMeta {
     /// If this function is from an impl (trait or regular impl), this
     /// is the object type of the impl. Otherwise this is None.
     pub self_type: Option<Type>,
+
+    /// If this function constructs a variant of an enum, this is the index of that variant.
+    pub enum_variant_index: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -16,8 +16,11 @@ pub enum HirStatement {
     Constrain(HirConstrainStatement),
     Assign(HirAssignStatement),
     For(HirForStatement),
+    While(HirWhileStatement),
+    Loop(ExprId),
     Break,
     Continue,
+    Return(Option<ExprId>),
     Expression(ExprId),
     Semi(ExprId),
     Comptime(StmtId),
@@ -50,6 +53,13 @@ pub struct HirForStatement {
     pub block: ExprId,
 }
 
+/// Corresponds to `while condition { block }` in the source code.
+#[derive(Debug, Clone)]
+pub struct HirWhileStatement {
+    pub condition: ExprId,
+    pub block: ExprId,
+}
+
 /// Corresponds to `lvalue = expression;` in the source code
 #[derive(Debug, Clone)]
 pub struct HirAssignStatement {
@@ -340,6 +340,11 @@ pub struct StructType {
     /// since these will handle applying generic arguments to fields as well.
     fields: Vec<StructField>,
 
+    /// Enums are represented as struct types without fields. For them, this
+    /// holds the enum's variants which are private for the same reason fields are.
+    /// This is `None` for structs.
+    variants: Option<Vec<EnumVariant>>,
+
     pub generics: Generics,
     pub location: Location,
 }
@@ -350,6 +355,22 @@ pub struct StructField {
     pub typ: Type,
 }
 
+#[derive(Clone)]
+pub struct EnumVariant {
+    pub name: Ident,
+    pub params: Vec<Type>,
+
+    /// True if this variant was declared as a function, e.g. `Foo(A)` or `Foo()`.
+    /// Unit variants like `Foo` are values rather than functions.
+    pub is_function: bool,
+}
+
+impl EnumVariant {
+    pub fn new(name: Ident, params: Vec<Type>, is_function: bool) -> EnumVariant {
+        Self { name, params, is_function }
+    }
+}
+
 /// Corresponds to generic lists such as `<T, U>` in the source program.
 /// Used mainly for resolved types which no longer need information such
 /// as names or kinds
@@ -422,7 +443,29 @@ impl StructType {
         fields: Vec<StructField>,
         generics: Generics,
     ) -> StructType {
-        StructType { id, fields, name, location, generics }
+        StructType { id, fields, variants: None, name, location, generics }
+    }
+
+    /// Creates an enum type. Like fields, its variants are set once they are resolved.
+    pub fn new_enum(
+        id: StructId,
+        name: Ident,
+        location: Location,
+        generics: Generics,
+    ) -> StructType {
+        let variants = Some(Vec::new());
+        StructType { id, fields: Vec::new(), variants, name, location, generics }
+    }
+
+    pub fn is_enum(&self) -> bool {
+        self.variants.is_some()
+    }
+
+    /// Sets the variants of an enum once they have been resolved.
+    /// Panics if this type is not an enum.
+    pub fn set_variants(&mut self, variants: Vec<EnumVariant>) {
+        assert!(self.is_enum(), "Cannot set the variants of a struct");
+        self.variants = Some(variants);
     }
 
     /// To account for cyclic references between structs, a struct's
@@ -524,11 +567,79 @@ impl StructType {
         self.fields.iter().map(|field| field.name.clone()).collect()
     }
 
-    /// Search the fields of a struct for any types with a `TypeKind::Numeric`
+    /// Returns the name and the parameter types of each variant of this enum, after being
+    /// applied to the given generic arguments. This is empty for structs.
+    pub fn get_variants(&self, generic_args: &[Type]) -> Vec<(String, Vec<Type>)> {
+        let substitutions = self.get_fields_substitutions(generic_args);
+
+        vecmap(self.variants.iter().flatten(), |variant| {
+            let name = variant.name.0.contents.clone();
+            let params = vecmap(&variant.params, |param| param.substitute(&substitutions));
+            (name, params)
+        })
+    }
+
+    /// Returns the types of the fields holding the arguments of this enum's variants, after
+    /// being applied to the given generic arguments, together with the name of each variant
+    /// and the index of the field holding each of its arguments. This is empty for structs.
+    ///
+    /// Variants share these fields: each argument takes the first field of its type that
+    /// isn't taken by a previous argument of the same variant, so there are only as many
+    /// fields of a given type as the variant with the most arguments of that type needs.
+    pub fn get_variant_fields(
+        &self,
+        generic_args: &[Type],
+    ) -> (Vec<Type>, Vec<(String, Vec<usize>)>) {
+        let mut fields: Vec<Type> = Vec::new();
+        let variants = vecmap(self.get_variants(generic_args), |(name, params)| {
+            let mut taken_fields = Vec::new();
+            let indices = vecmap(params, |param| {
+                let free_field = (0..fields.len())
+                    .find(|index| !taken_fields.contains(index) && fields[*index] == param);
+                let index = free_field.unwrap_or_else(|| {
+                    fields.push(param);
+                    fields.len() - 1
+                });
+                taken_fields.push(index);
+                index
+            });
+            (name, indices)
+        });
+        (fields, variants)
+    }
+
+    /// Returns the variants of this enum without substituting any generic arguments.
+    /// This is empty for structs.
+    pub fn get_variants_as_written(&self) -> Vec<EnumVariant> {
+        self.variants.iter().flatten().cloned().collect()
+    }
+
+    /// Returns the variant at the given index. Panics if this is not an enum
+    /// or if no variant exists at the given index.
+    pub fn variant_at(&self, index: usize) -> &EnumVariant {
+        &self.variants.as_ref().expect("Expected an enum")[index]
+    }
+
+    /// Returns the types of all the fields of a struct, or of all the parameters of
+    /// all the variants of an enum, after being applied to the given generic arguments.
+    pub(crate) fn get_field_and_variant_types(&self, generic_args: &[Type]) -> Vec<Type> {
+        let substitutions = self.get_fields_substitutions(generic_args);
+        let field_types = self.fields.iter().map(|field| &field.typ);
+        let variant_types = self.variants.iter().flatten().flat_map(|variant| &variant.params);
+        vecmap(field_types.chain(variant_types), |typ| typ.substitute(&substitutions))
+    }
+
+    /// Search the fields of a struct, or the variants of an enum, for any types
+    /// with a `TypeKind::Numeric`
     pub fn find_numeric_generics_in_fields(&self, found_names: &mut Vec<String>) {
         for field in self.fields.iter() {
             field.typ.find_numeric_type_vars(found_names);
         }
+        for variant in self.variants.iter().flatten() {
+            for param in &variant.params {
+                param.find_numeric_type_vars(found_names);
+            }
+        }
     }
 
     /// Instantiate this struct type, returning a Vec of the new generic args (in
@@ -1235,11 +1346,14 @@ impl Type {
             }
             Type::String(length) => length.is_valid_for_program_input(),
             Type::Tuple(elements) => elements.iter().all(|elem| elem.is_valid_for_program_input()),
-            Type::Struct(definition, generics) => definition
-                .borrow()
-                .get_fields(generics)
-                .into_iter()
-                .all(|(_, field)| field.is_valid_for_program_input()),
+            Type::Struct(definition, generics) => {
+                let definition = definition.borrow();
+                !definition.is_enum()
+                    && definition
+                        .get_fields(generics)
+                        .into_iter()
+                        .all(|(_, field)| field.is_valid_for_program_input())
+            }
 
             Type::InfixExpr(lhs, _, rhs) => {
                 lhs.is_valid_for_program_input() && rhs.is_valid_for_program_input()
@@ -1292,9 +1406,9 @@ impl Type {
             Type::Tuple(elements) => elements.iter().all(|elem| elem.is_valid_non_inlined_function_input()),
             Type::Struct(definition, generics) => definition
                 .borrow()
-                .get_fields(generics)
+                .get_field_and_variant_types(generics)
                 .into_iter()
-                .all(|(_, field)| field.is_valid_non_inlined_function_input()),
+                .all(|typ| typ.is_valid_non_inlined_function_input()),
         }
     }
 
@@ -1344,9 +1458,9 @@ impl Type {
             }
             Type::Struct(definition, generics) => definition
                 .borrow()
-                .get_fields(generics)
+                .get_field_and_variant_types(generics)
                 .into_iter()
-                .all(|(_, field)| field.is_valid_for_unconstrained_boundary()),
+                .all(|typ| typ.is_valid_for_unconstrained_boundary()),
         }
     }
 
@@ -1488,13 +1602,8 @@ impl Type {
         match self {
             Type::Slice(_) => true,
             Type::Struct(struct_typ, generics) => {
-                let fields = struct_typ.borrow().get_fields(generics);
-                for field in fields.iter() {
-                    if field.1.contains_slice() {
-                        return true;
-                    }
-                }
-                false
+                let types = struct_typ.borrow().get_field_and_variant_types(generics);
+                types.iter().any(|typ| typ.contains_slice())
             }
             Type::Tuple(types) => {
                 for typ in types.iter() {
@@ -2596,11 +2705,11 @@ impl Type {
         match self {
             Type::FieldElement => None,
             Type::Integer(sign, num_bits) => {
-                let mut max_bit_size = num_bits.bit_size();
+                let mut max_bit_size = u32::from(num_bits.bit_size());
                 if sign == &Signedness::Signed {
                     max_bit_size -= 1;
                 }
-                Some(((1u128 << max_bit_size) - 1).into())
+                Some((u128::MAX >> (128 - max_bit_size)).into())
             }
             Type::Bool => Some(FieldElement::one()),
             Type::TypeVariable(var) => {
@@ -2789,9 +2898,16 @@ impl From<&Type> for PrintableType {
             Type::Constant(_, _) => unreachable!(),
             Type::Struct(def, ref args) => {
                 let struct_type = def.borrow();
-                let fields = struct_type.get_fields(args);
-                let fields = vecmap(fields, |(name, typ)| (name, typ.into()));
-                PrintableType::Struct { fields, name: struct_type.name.to_string() }
+                let name = struct_type.name.to_string();
+                if struct_type.is_enum() {
+                    let (fields, variants) = struct_type.get_variant_fields(args);
+                    let fields = vecmap(fields, |typ| typ.into());
+                    PrintableType::Enum { name, fields, variants }
+                } else {
+                    let fields = struct_type.get_fields(args);
+                    let fields = vecmap(fields, |(name, typ)| (name, typ.into()));
+                    PrintableType::Struct { fields, name }
+                }
             }
             Type::Alias(alias, args) => alias.borrow().get_type(args).into(),
             Type::TraitAsType(..) => unreachable!(),
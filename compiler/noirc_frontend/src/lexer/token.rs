@@ -1,5 +1,7 @@
 use acvm::FieldElement;
+use iter_extended::vecmap;
 use noirc_errors::{Position, Span, Spanned};
+use std::collections::BTreeSet;
 use std::fmt::{self, Display};
 
 use crate::{
@@ -92,6 +94,8 @@ pub enum BorrowedToken<'input> {
     RightBracket,
     /// ->
     Arrow,
+    /// =>
+    FatArrow,
     /// |
     Pipe,
     /// #
@@ -213,6 +217,8 @@ pub enum Token {
     RightBracket,
     /// ->
     Arrow,
+    /// =>
+    FatArrow,
     /// |
     Pipe,
     /// #
@@ -297,6 +303,7 @@ pub fn token_to_borrowed_token(token: &Token) -> BorrowedToken<'_> {
         Token::LeftBracket => BorrowedToken::LeftBracket,
         Token::RightBracket => BorrowedToken::RightBracket,
         Token::Arrow => BorrowedToken::Arrow,
+        Token::FatArrow => BorrowedToken::FatArrow,
         Token::Pipe => BorrowedToken::Pipe,
         Token::Pound => BorrowedToken::Pound,
         Token::Comma => BorrowedToken::Comma,
@@ -445,6 +452,7 @@ impl fmt::Display for Token {
             Token::LeftBracket => write!(f, "["),
             Token::RightBracket => write!(f, "]"),
             Token::Arrow => write!(f, "->"),
+            Token::FatArrow => write!(f, "=>"),
             Token::Pipe => write!(f, "|"),
             Token::Pound => write!(f, "#"),
             Token::Comma => write!(f, ","),
@@ -871,6 +879,15 @@ pub enum SecondaryAttribute {
 
     /// Allow chosen warnings to happen so they are silenced.
     Allow(String),
+
+    /// Report chosen warnings as warnings, overriding an `allow` or `deny` on an outer item.
+    Warn(String),
+
+    /// Report chosen warnings as errors.
+    Deny(String),
+
+    /// Only compile the item or statement if the features enabled for its crate satisfy the predicate.
+    Cfg(CfgPredicate),
 }
 
 impl SecondaryAttribute {
@@ -888,6 +905,9 @@ impl SecondaryAttribute {
             SecondaryAttribute::Varargs => Some("varargs".to_string()),
             SecondaryAttribute::UseCallersScope => Some("use_callers_scope".to_string()),
             SecondaryAttribute::Allow(_) => Some("allow".to_string()),
+            SecondaryAttribute::Warn(_) => Some("warn".to_string()),
+            SecondaryAttribute::Deny(_) => Some("deny".to_string()),
+            SecondaryAttribute::Cfg(_) => Some("cfg".to_string()),
         }
     }
 
@@ -917,6 +937,9 @@ impl SecondaryAttribute {
             SecondaryAttribute::Varargs => "varargs".to_string(),
             SecondaryAttribute::UseCallersScope => "use_callers_scope".to_string(),
             SecondaryAttribute::Allow(ref k) => format!("allow({k})"),
+            SecondaryAttribute::Warn(ref k) => format!("warn({k})"),
+            SecondaryAttribute::Deny(ref k) => format!("deny({k})"),
+            SecondaryAttribute::Cfg(ref predicate) => format!("cfg({predicate})"),
         }
     }
 }
@@ -927,6 +950,50 @@ impl fmt::Display for SecondaryAttribute {
     }
 }
 
+/// The condition of a `#[cfg(...)]` attribute.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum CfgPredicate {
+    /// `feature = "name"`: the named feature is enabled.
+    Feature(String),
+    /// `not(predicate)`
+    Not(Box<CfgPredicate>),
+    /// `all(predicate, ..)`: every predicate holds, which is always the case if there are none.
+    All(Vec<CfgPredicate>),
+    /// `any(predicate, ..)`: at least one predicate holds, which is never the case if there are none.
+    Any(Vec<CfgPredicate>),
+}
+
+impl CfgPredicate {
+    /// Returns true if the predicate holds when exactly the given features are enabled.
+    pub fn is_satisfied_by(&self, features: &BTreeSet<String>) -> bool {
+        match self {
+            CfgPredicate::Feature(name) => features.contains(name),
+            CfgPredicate::Not(predicate) => !predicate.is_satisfied_by(features),
+            CfgPredicate::All(predicates) => {
+                predicates.iter().all(|predicate| predicate.is_satisfied_by(features))
+            }
+            CfgPredicate::Any(predicates) => {
+                predicates.iter().any(|predicate| predicate.is_satisfied_by(features))
+            }
+        }
+    }
+}
+
+impl Display for CfgPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CfgPredicate::Feature(name) => write!(f, "feature = {name:?}"),
+            CfgPredicate::Not(predicate) => write!(f, "not({predicate})"),
+            CfgPredicate::All(predicates) => {
+                write!(f, "all({})", vecmap(predicates, ToString::to_string).join(", "))
+            }
+            CfgPredicate::Any(predicates) => {
+                write!(f, "any({})", vecmap(predicates, ToString::to_string).join(", "))
+            }
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct MetaAttribute {
     pub name: Path,
@@ -986,6 +1053,7 @@ pub enum Keyword {
     CtString,
     Dep,
     Else,
+    Enum,
     Expr,
     Field,
     Fn,
@@ -997,6 +1065,8 @@ pub enum Keyword {
     Impl,
     In,
     Let,
+    Loop,
+    Match,
     Mod,
     Module,
     Mut,
@@ -1043,6 +1113,7 @@ impl fmt::Display for Keyword {
             Keyword::CtString => write!(f, "CtString"),
             Keyword::Dep => write!(f, "dep"),
             Keyword::Else => write!(f, "else"),
+            Keyword::Enum => write!(f, "enum"),
             Keyword::Expr => write!(f, "Expr"),
             Keyword::Field => write!(f, "Field"),
             Keyword::Fn => write!(f, "fn"),
@@ -1054,6 +1125,8 @@ impl fmt::Display for Keyword {
             Keyword::Impl => write!(f, "impl"),
             Keyword::In => write!(f, "in"),
             Keyword::Let => write!(f, "let"),
+            Keyword::Loop => write!(f, "loop"),
+            Keyword::Match => write!(f, "match"),
             Keyword::Mod => write!(f, "mod"),
             Keyword::Module => write!(f, "Module"),
             Keyword::Mut => write!(f, "mut"),
@@ -1103,6 +1176,7 @@ impl Keyword {
             "CtString" => Keyword::CtString,
             "dep" => Keyword::Dep,
             "else" => Keyword::Else,
+            "enum" => Keyword::Enum,
             "Expr" => Keyword::Expr,
             "Field" => Keyword::Field,
             "fn" => Keyword::Fn,
@@ -1114,6 +1188,8 @@ impl Keyword {
             "impl" => Keyword::Impl,
             "in" => Keyword::In,
             "let" => Keyword::Let,
+            "loop" => Keyword::Loop,
+            "match" => Keyword::Match,
             "mod" => Keyword::Mod,
             "Module" => Keyword::Module,
             "mut" => Keyword::Mut,
@@ -7,6 +7,7 @@ use noirc_errors::{
     Location,
 };
 
+use crate::hir_def::expr::Constructor;
 use crate::hir_def::function::FunctionSignature;
 use crate::{
     ast::{BinaryOpKind, IntegerBitSize, Signedness, Visibility},
@@ -36,7 +37,10 @@ pub enum Expression {
     Index(Index),
     Cast(Cast),
     For(For),
+    While(While),
+    Loop(Box<Expression>),
     If(If),
+    Match(Match),
     Tuple(Vec<Expression>),
     ExtractTupleField(Box<Expression>, usize),
     Call(Call),
@@ -46,6 +50,7 @@ pub enum Expression {
     Semi(Box<Expression>),
     Break,
     Continue,
+    Return(Option<Box<Expression>>),
 }
 
 /// A definition is either a local (variable), function, or is a built-in
@@ -98,6 +103,12 @@ pub struct For {
     pub end_range_location: Location,
 }
 
+#[derive(Debug, Clone, Hash)]
+pub struct While {
+    pub condition: Box<Expression>,
+    pub body: Box<Expression>,
+}
+
 #[derive(Debug, Clone, Hash)]
 pub enum Literal {
     Array(ArrayLiteral),
@@ -141,6 +152,30 @@ pub struct If {
     pub typ: Type,
 }
 
+/// A `match` on a single variable. Nested patterns have already been compiled
+/// away so each case only tests the outermost constructor of the variable.
+/// Enums are represented as a tuple of their tag followed by the arguments of
+/// each of their variants: `(tag, (variant 0 args..), (variant 1 args..), ..)`.
+#[derive(Debug, Clone, Hash)]
+pub struct Match {
+    pub variable_to_match: (LocalId, String),
+    pub cases: Vec<MatchCase>,
+    /// Taken if no case matches. This is only present if the cases can't
+    /// cover every value of the variable's type.
+    pub default_case: Option<Box<Expression>>,
+    pub typ: Type,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct MatchCase {
+    pub constructor: Constructor,
+    /// The variables bound to each argument of the constructor
+    pub arguments: Vec<(LocalId, String)>,
+    /// The index of the field of the matched value holding each argument
+    pub argument_fields: Vec<usize>,
+    pub branch: Expression,
+}
+
 #[derive(Debug, Clone, Hash)]
 pub struct Cast {
     pub lhs: Box<Expression>,
@@ -531,6 +531,10 @@ impl<'interner> Monomorphizer<'interner> {
                 ast::Expression::Tuple(fields)
             }
             HirExpression::Constructor(constructor) => self.constructor(constructor, expr)?,
+            HirExpression::EnumConstructor(constructor) => {
+                self.enum_constructor(constructor, expr)?
+            }
+            HirExpression::Match(match_expr) => self.match_expr(match_expr, expr)?,
 
             HirExpression::Lambda(lambda) => self.lambda(lambda, expr)?,
 
@@ -646,12 +650,25 @@ impl<'interner> Monomorphizer<'interner> {
                     block,
                 }))
             }
+            HirStatement::While(while_loop) => {
+                let condition = Box::new(self.expr(while_loop.condition)?);
+                let body = Box::new(self.expr(while_loop.block)?);
+                Ok(ast::Expression::While(ast::While { condition, body }))
+            }
+            HirStatement::Loop(block) => {
+                let block = Box::new(self.expr(block)?);
+                Ok(ast::Expression::Loop(block))
+            }
             HirStatement::Expression(expr) => self.expr(expr),
             HirStatement::Semi(expr) => {
                 self.expr(expr).map(|expr| ast::Expression::Semi(Box::new(expr)))
             }
             HirStatement::Break => Ok(ast::Expression::Break),
             HirStatement::Continue => Ok(ast::Expression::Continue),
+            HirStatement::Return(expr) => {
+                let expr = expr.map(|expr| self.expr(expr)).transpose()?.map(Box::new);
+                Ok(ast::Expression::Return(expr))
+            }
             HirStatement::Error => unreachable!(),
 
             // All `comptime` statements & expressions should be removed before runtime.
@@ -720,6 +737,118 @@ impl<'interner> Monomorphizer<'interner> {
         Ok(ast::Expression::Block(new_exprs))
     }
 
+    /// Enum values are tuples of their tag followed by the fields shared by the arguments
+    /// of all variants (see `StructType::get_variant_fields`).
+    /// Fields not holding an argument of the chosen variant are zeroed.
+    fn enum_constructor(
+        &mut self,
+        constructor: HirEnumConstructorExpression,
+        id: node_interner::ExprId,
+    ) -> Result<ast::Expression, MonomorphizationError> {
+        let location = self.interner.expr_location(&id);
+        let hir_type = self.interner.id_type(id);
+        let typ = Self::convert_type(&hir_type, location)?;
+
+        let ast::Type::Tuple(field_types) = typ else {
+            unreachable!("Expected enum type to be converted to a tuple, found {typ}")
+        };
+        let HirType::Struct(_, generics) = hir_type.follow_bindings() else {
+            unreachable!("Expected an enum type, found {hir_type}")
+        };
+        let (_, variants) = constructor.r#type.borrow().get_variant_fields(&generics);
+        let (_, argument_fields) = &variants[constructor.variant_index];
+
+        let tag = constructor.variant_index as u128;
+        let tag = ast::Literal::Integer(tag.into(), false, ast::Type::Field, location);
+        let mut fields = vec![ast::Expression::Literal(tag)];
+        for field_type in field_types.iter().skip(1) {
+            fields.push(self.zeroed_value_of_type(field_type, location));
+        }
+
+        for (argument, field) in constructor.arguments.into_iter().zip(argument_fields) {
+            // The tag is the first field so the other fields are offset by one
+            fields[field + 1] = self.expr(argument)?;
+        }
+
+        Ok(ast::Expression::Tuple(fields))
+    }
+
+    fn match_expr(
+        &mut self,
+        match_expr: HirMatch,
+        id: node_interner::ExprId,
+    ) -> Result<ast::Expression, MonomorphizationError> {
+        let location = self.interner.expr_location(&id);
+        let typ = Self::convert_type(&self.interner.id_type(id), location)?;
+        self.match_tree(match_expr, &typ, location)
+    }
+
+    fn match_tree(
+        &mut self,
+        match_expr: HirMatch,
+        typ: &ast::Type,
+        location: Location,
+    ) -> Result<ast::Expression, MonomorphizationError> {
+        match match_expr {
+            HirMatch::Success(expr) => self.expr(expr),
+            HirMatch::Failure => {
+                let message = "Unexpected non-exhaustive match found during monomorphization";
+                Err(MonomorphizationError::InternalError { message, location })
+            }
+            HirMatch::Switch(variable, cases, default_case) => {
+                let Some(Definition::Local(variable_id)) = self.lookup_local(variable) else {
+                    let message = "ICE: match variable was not defined before the match";
+                    return Err(MonomorphizationError::InternalError { message, location });
+                };
+                let variable_name = self.interner.definition_name(variable).to_owned();
+
+                let cases = try_vecmap(cases, |case| {
+                    let argument_fields =
+                        Self::constructor_argument_fields(&case.constructor, case.arguments.len());
+                    let arguments = vecmap(case.arguments, |argument| {
+                        let new_id = self.next_local_id();
+                        self.define_local(argument, new_id);
+                        (new_id, self.interner.definition_name(argument).to_owned())
+                    });
+                    let branch = self.match_tree(case.body, typ, location)?;
+                    let constructor = case.constructor;
+                    Ok(ast::MatchCase { constructor, arguments, argument_fields, branch })
+                })?;
+
+                let default_case = match default_case {
+                    Some(default_case) => {
+                        Some(Box::new(self.match_tree(*default_case, typ, location)?))
+                    }
+                    None => None,
+                };
+
+                let variable_to_match = (variable_id, variable_name);
+                let typ = typ.clone();
+                Ok(ast::Expression::Match(ast::Match {
+                    variable_to_match,
+                    cases,
+                    default_case,
+                    typ,
+                }))
+            }
+        }
+    }
+
+    /// Returns the index of the field of a matched value holding each argument of the given
+    /// constructor. For enums these are the fields their variants share, after the tag.
+    fn constructor_argument_fields(constructor: &Constructor, argument_count: usize) -> Vec<usize> {
+        match constructor {
+            Constructor::Variant(typ, index) => {
+                let HirType::Struct(def, generics) = typ.follow_bindings() else {
+                    unreachable!("Expected an enum type, found {typ}")
+                };
+                let (_, variants) = def.borrow().get_variant_fields(&generics);
+                vecmap(&variants[*index].1, |field| field + 1)
+            }
+            _ => (0..argument_count).collect(),
+        }
+    }
+
     fn block(
         &mut self,
         statement_ids: Vec<StmtId>,
@@ -1064,9 +1193,21 @@ impl<'interner> Monomorphizer<'interner> {
                     Self::check_type(arg, location)?;
                 }
 
-                let fields = def.borrow().get_fields(args);
-                let fields = try_vecmap(fields, |(_, field)| Self::convert_type(&field, location))?;
-                ast::Type::Tuple(fields)
+                let def = def.borrow();
+                if def.is_enum() {
+                    // Enums are a tag followed by the fields shared by the arguments of their variants
+                    let (variant_fields, _) = def.get_variant_fields(args);
+                    let mut fields = vec![ast::Type::Field];
+                    for field in variant_fields {
+                        fields.push(Self::convert_type(&field, location)?);
+                    }
+                    ast::Type::Tuple(fields)
+                } else {
+                    let fields = def.get_fields(args);
+                    let fields =
+                        try_vecmap(fields, |(_, field)| Self::convert_type(&field, location))?;
+                    ast::Type::Tuple(fields)
+                }
             }
 
             HirType::Alias(def, args) => {
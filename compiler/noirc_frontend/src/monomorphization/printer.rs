@@ -49,7 +49,10 @@ impl AstPrinter {
                 write!(f, " as {})", cast.r#type)
             }
             Expression::For(for_expr) => self.print_for(for_expr, f),
+            Expression::While(while_expr) => self.print_while(while_expr, f),
+            Expression::Loop(block) => self.print_loop(block, f),
             Expression::If(if_expr) => self.print_if(if_expr, f),
+            Expression::Match(match_expr) => self.print_match(match_expr, f),
             Expression::Tuple(tuple) => self.print_tuple(tuple, f),
             Expression::ExtractTupleField(expr, index) => {
                 self.print_expr(expr, f)?;
@@ -75,6 +78,11 @@ impl AstPrinter {
             }
             Expression::Break => write!(f, "break"),
             Expression::Continue => write!(f, "continue"),
+            Expression::Return(Some(expr)) => {
+                write!(f, "return ")?;
+                self.print_expr(expr, f)
+            }
+            Expression::Return(None) => write!(f, "return"),
         }
     }
 
@@ -207,6 +215,32 @@ impl AstPrinter {
         write!(f, "}}")
     }
 
+    fn print_while(
+        &mut self,
+        while_expr: &super::ast::While,
+        f: &mut Formatter,
+    ) -> Result<(), std::fmt::Error> {
+        write!(f, "while ")?;
+        self.print_expr(&while_expr.condition, f)?;
+        write!(f, " {{")?;
+
+        self.indent_level += 1;
+        self.print_expr_expect_block(&while_expr.body, f)?;
+        self.indent_level -= 1;
+        self.next_line(f)?;
+        write!(f, "}}")
+    }
+
+    fn print_loop(&mut self, block: &Expression, f: &mut Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "loop {{")?;
+
+        self.indent_level += 1;
+        self.print_expr_expect_block(block, f)?;
+        self.indent_level -= 1;
+        self.next_line(f)?;
+        write!(f, "}}")
+    }
+
     fn print_if(
         &mut self,
         if_expr: &super::ast::If,
@@ -231,6 +265,47 @@ impl AstPrinter {
         write!(f, "}}")
     }
 
+    fn print_match(
+        &mut self,
+        match_expr: &super::ast::Match,
+        f: &mut Formatter,
+    ) -> Result<(), std::fmt::Error> {
+        let (variable, name) = &match_expr.variable_to_match;
+        write!(f, "match {name}$l{} {{", variable.0)?;
+        self.indent_level += 1;
+
+        for case in &match_expr.cases {
+            self.next_line(f)?;
+            write!(f, "{}", case.constructor)?;
+
+            if !case.arguments.is_empty() {
+                let arguments = vecmap(&case.arguments, |(id, name)| format!("{name}$l{}", id.0));
+                write!(f, "({})", arguments.join(", "))?;
+            }
+
+            write!(f, " => {{")?;
+            self.indent_level += 1;
+            self.print_expr_expect_block(&case.branch, f)?;
+            self.indent_level -= 1;
+            self.next_line(f)?;
+            write!(f, "}},")?;
+        }
+
+        if let Some(default_case) = &match_expr.default_case {
+            self.next_line(f)?;
+            write!(f, "_ => {{")?;
+            self.indent_level += 1;
+            self.print_expr_expect_block(default_case, f)?;
+            self.indent_level -= 1;
+            self.next_line(f)?;
+            write!(f, "}},")?;
+        }
+
+        self.indent_level -= 1;
+        self.next_line(f)?;
+        write!(f, "}}")
+    }
+
     fn print_comma_separated(
         &mut self,
         exprs: &[Expression],
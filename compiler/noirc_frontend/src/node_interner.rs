@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::fmt;
 use std::hash::Hash;
 use std::marker::Copy;
@@ -12,6 +13,7 @@ use petgraph::algo::tarjan_scc;
 use petgraph::prelude::DiGraph;
 use petgraph::prelude::NodeIndex as PetGraphIndex;
 use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::FxHashSet as HashSet;
 
 use crate::ast::{
     ExpressionKind, Ident, LValue, Pattern, StatementKind, UnaryOp, UnresolvedTypeData,
@@ -19,12 +21,16 @@ use crate::ast::{
 use crate::graph::CrateId;
 use crate::hir::comptime;
 use crate::hir::def_collector::dc_crate::CompilationError;
-use crate::hir::def_collector::dc_crate::{UnresolvedStruct, UnresolvedTrait, UnresolvedTypeAlias};
+use crate::hir::def_collector::dc_crate::{
+    UnresolvedEnum, UnresolvedStruct, UnresolvedTrait, UnresolvedTypeAlias,
+};
 use crate::hir::def_map::DefMaps;
 use crate::hir::def_map::{LocalModuleId, ModuleDefId, ModuleId};
+use crate::hir::lint_levels::LintScopes;
 use crate::hir::type_check::generics::TraitGenerics;
 use crate::hir_def::traits::NamedType;
 use crate::hir_def::traits::ResolvedTraitBound;
+use crate::token::CfgPredicate;
 use crate::QuotedType;
 
 use crate::ast::{BinaryOpKind, FunctionDefinition, ItemVisibility};
@@ -269,8 +275,40 @@ pub struct NodeInterner {
     /// share the same global values.
     pub(crate) comptime_scopes: Vec<HashMap<DefinitionId, comptime::Value>>,
 
+    /// Big integers created by `std::bigint` operations in the comptime interpreter.
+    /// Like `comptime_scopes`, these are shared so that a `BigInt` stored in a comptime
+    /// global remains valid when it is used from another crate.
+    pub(crate) comptime_bigints: comptime::ComptimeBigInts,
+
+    /// Resolves oracle calls made from comptime code. Unless one is set, comptime code
+    /// can't call oracles other than `print`.
+    pub(crate) comptime_oracles: Option<comptime::ComptimeOracles>,
+
+    /// The files comptime code is allowed to read, along with those it has read.
+    pub(crate) comptime_files: comptime::ComptimeFiles,
+
+    /// The lint levels set with attributes and in each crate's package, along with
+    /// the code each of them applies to.
+    pub(crate) lint_scopes: LintScopes,
+
+    /// Crates whose `#[test]` functions are left out, as they may use dev-dependencies
+    /// which are only linked when running tests.
+    pub(crate) crates_without_tests: HashSet<CrateId>,
+
+    /// The features enabled for each crate, against which `#[cfg(...)]` attributes are
+    /// checked. A crate without an entry has no features enabled.
+    pub(crate) crate_features: HashMap<CrateId, BTreeSet<String>>,
+
+    /// Notified of each step taken while evaluating comptime code, if set.
+    pub(crate) comptime_debugger: Option<Box<dyn comptime::ComptimeDebugger>>,
+
     /// Captures the documentation comments for each module, struct, trait, function, etc.
     pub(crate) doc_comments: HashMap<ReferenceId, Vec<String>>,
+
+    /// In LSP mode, the code generated by comptime code (attributes, `Module::add_item`,
+    /// `FunctionDefinition::set_body`, etc.) keyed by the location that generated it.
+    /// The LSP uses this to show macro expansions.
+    pub(crate) comptime_expansions: HashMap<Location, Vec<String>>,
 }
 
 /// A dependency in the dependency graph may be a type or a definition.
@@ -675,8 +713,16 @@ impl Default for NodeInterner {
             reference_modules: HashMap::default(),
             auto_import_names: HashMap::default(),
             comptime_scopes: vec![HashMap::default()],
+            comptime_bigints: comptime::ComptimeBigInts::default(),
+            comptime_oracles: None,
+            comptime_files: comptime::ComptimeFiles::default(),
+            comptime_debugger: None,
+            lint_scopes: LintScopes::default(),
+            crates_without_tests: HashSet::default(),
+            crate_features: HashMap::default(),
             trait_impl_associated_types: HashMap::default(),
             doc_comments: HashMap::default(),
+            comptime_expansions: HashMap::default(),
         }
     }
 }
@@ -758,6 +804,25 @@ impl NodeInterner {
         struct_id
     }
 
+    pub fn new_enum(
+        &mut self,
+        typ: &UnresolvedEnum,
+        generics: Generics,
+        krate: CrateId,
+        local_id: LocalModuleId,
+        file_id: FileId,
+    ) -> StructId {
+        let struct_id = StructId(ModuleId { krate, local_id });
+        let name = typ.enum_def.name.clone();
+
+        // Variants will be filled in later
+        let location = Location::new(typ.enum_def.span, file_id);
+        let new_enum = StructType::new_enum(struct_id, name, location, generics);
+        self.structs.insert(struct_id, Shared::new(new_enum));
+        self.struct_attributes.insert(struct_id, typ.enum_def.attributes.clone());
+        struct_id
+    }
+
     pub fn push_type_alias(
         &mut self,
         typ: &UnresolvedTypeAlias,
@@ -2244,6 +2309,14 @@ impl NodeInterner {
         self.lsp_mode
     }
 
+    /// Returns true if the features enabled for `crate_id` satisfy `predicate`.
+    pub(crate) fn is_cfg_enabled(&self, crate_id: CrateId, predicate: &CfgPredicate) -> bool {
+        match self.crate_features.get(&crate_id) {
+            Some(features) => predicate.is_satisfied_by(features),
+            None => predicate.is_satisfied_by(&BTreeSet::new()),
+        }
+    }
+
     pub fn set_associated_types_for_impl(
         &mut self,
         impl_id: TraitImplId,
@@ -2315,6 +2388,22 @@ impl NodeInterner {
     pub fn doc_comments(&self, id: ReferenceId) -> Option<&Vec<String>> {
         self.doc_comments.get(&id)
     }
+
+    /// Records code generated by comptime code at the given location.
+    /// Callers only render and record the code when [Self::is_in_lsp_mode] is true.
+    pub(crate) fn push_comptime_expansion(&mut self, location: Location, code: String) {
+        self.comptime_expansions.entry(location).or_default().push(code);
+    }
+
+    /// Returns the code generated by comptime code at exactly the given location, if any.
+    pub fn comptime_expansion(&self, location: Location) -> Option<&Vec<String>> {
+        self.comptime_expansions.get(&location)
+    }
+
+    /// Returns all locations where comptime code generated some code, together with that code.
+    pub fn comptime_expansions(&self) -> impl Iterator<Item = (&Location, &Vec<String>)> {
+        self.comptime_expansions.iter()
+    }
 }
 
 impl Methods {
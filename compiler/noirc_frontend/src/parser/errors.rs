@@ -63,8 +63,6 @@ pub enum ParserErrorReason {
     ConstrainDeprecated,
     #[error("Invalid type expression: '{0}'. Only unsigned integer constants up to `u32`, globals, generics, +, -, *, /, and % may be used in this context.")]
     InvalidTypeExpression(Expression),
-    #[error("Early 'return' is unsupported")]
-    EarlyReturn,
     #[error("Patterns aren't allowed in a trait's function declarations")]
     PatternInTraitFunctionParameter,
     #[error("Patterns aren't allowed in a trait impl's associated constants")]
@@ -104,6 +102,10 @@ pub enum ParserErrorReason {
     WrongNumberOfAttributeArguments { name: String, min: usize, max: usize, found: usize },
     #[error("The `deprecated` attribute expects a string argument")]
     DeprecatedAttributeExpectsAStringArgument,
+    #[error("Malformed `cfg` attribute: expected `feature = \"name\"`, `not(..)`, `all(..)` or `any(..)`")]
+    MalformedCfgAttribute,
+    #[error("The `cfg` attributes of a statement must come before its other attributes")]
+    CfgAttributeAfterOtherAttributes,
 }
 
 /// Represents a parsing error, or a parsing error in the making.
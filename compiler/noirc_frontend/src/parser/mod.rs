@@ -13,9 +13,10 @@ mod parser;
 
 use crate::ast::{
     Documented, Ident, ImportStatement, ItemVisibility, LetStatement, ModuleDeclaration,
-    NoirFunction, NoirStruct, NoirTrait, NoirTraitImpl, NoirTypeAlias, TypeImpl, UseTree,
+    NoirEnumeration, NoirFunction, NoirStruct, NoirTrait, NoirTraitImpl, NoirTypeAlias, TypeImpl,
+    UseTree,
 };
-use crate::token::SecondaryAttribute;
+use crate::token::{CfgPredicate, SecondaryAttribute};
 
 pub use errors::ParserError;
 pub use errors::ParserErrorReason;
@@ -27,6 +28,7 @@ pub struct SortedModule {
     pub imports: Vec<ImportStatement>,
     pub functions: Vec<Documented<NoirFunction>>,
     pub types: Vec<Documented<NoirStruct>>,
+    pub enums: Vec<Documented<NoirEnumeration>>,
     pub traits: Vec<Documented<NoirTrait>>,
     pub trait_impls: Vec<NoirTraitImpl>,
     pub impls: Vec<TypeImpl>,
@@ -61,6 +63,10 @@ impl std::fmt::Display for SortedModule {
             write!(f, "{type_}")?;
         }
 
+        for enum_ in &self.enums {
+            write!(f, "{enum_}")?;
+        }
+
         for function in &self.functions {
             write!(f, "{function}")?;
         }
@@ -97,6 +103,7 @@ impl ParsedModule {
                 ItemKind::Import(import, visibility) => module.push_import(import, visibility),
                 ItemKind::Function(func) => module.push_function(func, item.doc_comments),
                 ItemKind::Struct(typ) => module.push_type(typ, item.doc_comments),
+                ItemKind::Enum(typ) => module.push_enum(typ, item.doc_comments),
                 ItemKind::Trait(noir_trait) => module.push_trait(noir_trait, item.doc_comments),
                 ItemKind::TraitImpl(trait_impl) => module.push_trait_impl(trait_impl),
                 ItemKind::Impl(r#impl) => module.push_impl(r#impl),
@@ -110,7 +117,7 @@ impl ParsedModule {
                     module.push_module_decl(mod_name, item.doc_comments);
                 }
                 ItemKind::Submodules(submodule) => {
-                    module.push_submodule(submodule.into_sorted(), item.doc_comments);
+                    module.push_submodule(submodule.into_sorted(item.span), item.doc_comments);
                 }
                 ItemKind::InnerAttribute(attribute) => module.inner_attributes.push(attribute),
             }
@@ -127,6 +134,9 @@ pub struct Item {
     pub kind: ItemKind,
     pub span: Span,
     pub doc_comments: Vec<String>,
+    /// The predicates of the item's `#[cfg(...)]` attributes. The item is only
+    /// compiled if all of them are satisfied by its crate's enabled features.
+    pub cfg: Vec<CfgPredicate>,
 }
 
 #[derive(Clone, Debug)]
@@ -134,6 +144,7 @@ pub enum ItemKind {
     Import(UseTree, ItemVisibility),
     Function(NoirFunction),
     Struct(NoirStruct),
+    Enum(NoirEnumeration),
     Trait(NoirTrait),
     TraitImpl(NoirTraitImpl),
     Impl(TypeImpl),
@@ -159,6 +170,7 @@ impl std::fmt::Display for ItemKind {
             ItemKind::Trait(t) => t.fmt(f),
             ItemKind::TraitImpl(i) => i.fmt(f),
             ItemKind::Struct(s) => s.fmt(f),
+            ItemKind::Enum(e) => e.fmt(f),
             ItemKind::Impl(i) => i.fmt(f),
             ItemKind::TypeAlias(t) => t.fmt(f),
             ItemKind::Submodules(s) => s.fmt(f),
@@ -185,8 +197,9 @@ pub struct ParsedSubModule {
 }
 
 impl ParsedSubModule {
-    pub fn into_sorted(self) -> SortedSubModule {
+    pub fn into_sorted(self, span: Span) -> SortedSubModule {
         SortedSubModule {
+            span,
             visibility: self.visibility,
             name: self.name,
             contents: self.contents.into_sorted(),
@@ -211,6 +224,8 @@ impl std::fmt::Display for SortedSubModule {
 #[derive(Clone)]
 pub struct SortedSubModule {
     pub name: Ident,
+    /// The span of the whole `mod name { ... }` item
+    pub span: Span,
     pub visibility: ItemVisibility,
     pub contents: SortedModule,
     pub outer_attributes: Vec<SecondaryAttribute>,
@@ -226,6 +241,10 @@ impl SortedModule {
         self.types.push(Documented::new(typ, doc_comments));
     }
 
+    fn push_enum(&mut self, typ: NoirEnumeration, doc_comments: Vec<String>) {
+        self.enums.push(Documented::new(typ, doc_comments));
+    }
+
     fn push_trait(&mut self, noir_trait: NoirTrait, doc_comments: Vec<String>) {
         self.traits.push(Documented::new(noir_trait, doc_comments));
     }
@@ -13,6 +13,7 @@ use super::{labels::ParsingRuleLabel, ParsedModule, ParserError, ParserErrorReas
 mod arguments;
 mod attributes;
 mod doc_comments;
+mod enums;
 mod expression;
 mod function;
 mod generics;
@@ -222,6 +223,18 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Like `eat_ident`, but also accepts the `match` keyword: functions and methods named
+    /// `match` were allowed before `match` expressions existed, so they still are.
+    fn eat_ident_or_match_keyword(&mut self) -> Option<Ident> {
+        if let Some(ident) = self.eat_ident() {
+            Some(ident)
+        } else if self.eat_keyword(Keyword::Match) {
+            Some(Ident::new("match".to_string(), self.previous_token_span))
+        } else {
+            None
+        }
+    }
+
     fn eat_self(&mut self) -> bool {
         if let Token::Ident(ident) = self.token.token() {
             if ident == "self" {
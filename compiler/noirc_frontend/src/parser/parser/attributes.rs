@@ -5,7 +5,7 @@ use crate::lexer::errors::LexerErrorKind;
 use crate::parser::labels::ParsingRuleLabel;
 use crate::parser::ParserErrorReason;
 use crate::token::{Attribute, FunctionAttribute, MetaAttribute, TestScope, Token};
-use crate::token::{CustomAttribute, SecondaryAttribute};
+use crate::token::{CfgPredicate, CustomAttribute, SecondaryAttribute};
 
 use super::parse_many::without_separator;
 use super::Parser;
@@ -58,6 +58,7 @@ impl<'a> Parser<'a> {
     /// SecondaryAttribute
     ///     = 'abi' '(' AttributeValue ')'
     ///     | 'allow' '(' AttributeValue ')'
+    ///     | 'cfg' '(' CfgPredicate ')'
     ///     | 'deprecated'
     ///     | 'deprecated' '(' string ')'
     ///     | 'contract_library_method'
@@ -73,6 +74,12 @@ impl<'a> Parser<'a> {
     /// AttributeValue
     ///     = Path
     ///     | integer
+    ///
+    /// CfgPredicate
+    ///     = 'feature' '=' string
+    ///     | 'not' '(' CfgPredicate ')'
+    ///     | 'all' '(' CfgPredicate* ')'
+    ///     | 'any' '(' CfgPredicate* ')'
     pub(crate) fn parse_attribute(&mut self) -> Option<(Attribute, Span)> {
         let start_span = self.current_token_span;
         let is_tag = self.eat_attribute_start()?;
@@ -144,6 +151,9 @@ impl<'a> Parser<'a> {
                     // The test attribute is the only secondary attribute that has `a = b` in its syntax
                     // (`should_fail_with = "..."``) so we parse it differently.
                     self.parse_test_attribute(start_span)
+                } else if ident.0.contents == "cfg" {
+                    // Like `test`, `cfg` has `a = b` in its syntax (`feature = "..."`)
+                    self.parse_cfg_attribute(start_span)
                 } else {
                     // Every other attribute has the form `name(arg1, arg2, .., argN)`
                     self.parse_ident_attribute_other_than_test(ident, start_span)
@@ -185,6 +195,9 @@ impl<'a> Parser<'a> {
             "builtin" => self.parse_single_name_attribute(ident, arguments, start_span, |name| {
                 Attribute::Function(FunctionAttribute::Builtin(name))
             }),
+            "deny" => self.parse_single_name_attribute(ident, arguments, start_span, |name| {
+                Attribute::Secondary(SecondaryAttribute::Deny(name))
+            }),
             "deprecated" => self.parse_deprecated_attribute(ident, arguments),
             "contract_library_method" => {
                 let attr = Attribute::Secondary(SecondaryAttribute::ContractLibraryMethod);
@@ -227,6 +240,9 @@ impl<'a> Parser<'a> {
                 let attr = Attribute::Secondary(SecondaryAttribute::Varargs);
                 self.parse_no_args_attribute(ident, arguments, attr)
             }
+            "warn" => self.parse_single_name_attribute(ident, arguments, start_span, |name| {
+                Attribute::Secondary(SecondaryAttribute::Warn(name))
+            }),
             _ => Attribute::Secondary(SecondaryAttribute::Meta(MetaAttribute {
                 name: Path::from_ident(ident.clone()),
                 arguments,
@@ -307,6 +323,69 @@ impl<'a> Parser<'a> {
         Attribute::Function(FunctionAttribute::Test(scope))
     }
 
+    fn parse_cfg_attribute(&mut self, start_span: Span) -> Attribute {
+        let predicate = if self.eat_left_paren() {
+            self.parse_cfg_predicate().filter(|_| self.eat_right_paren())
+        } else {
+            None
+        };
+
+        let Some(predicate) = predicate else {
+            // Skip the rest of the attribute without an error for each of its tokens
+            while !self.at_eof() && !self.at(Token::RightBracket) {
+                self.bump();
+            }
+            self.push_error(ParserErrorReason::MalformedCfgAttribute, self.span_since(start_span));
+            self.eat_right_bracket();
+
+            // An empty `any` never holds, so the malformed item isn't compiled
+            return Attribute::Secondary(SecondaryAttribute::Cfg(CfgPredicate::Any(Vec::new())));
+        };
+
+        self.skip_until_right_bracket();
+        Attribute::Secondary(SecondaryAttribute::Cfg(predicate))
+    }
+
+    fn parse_cfg_predicate(&mut self) -> Option<CfgPredicate> {
+        let ident = self.eat_ident()?;
+        match ident.0.contents.as_str() {
+            "feature" => {
+                if !self.eat_assign() {
+                    return None;
+                }
+                self.eat_str().map(CfgPredicate::Feature)
+            }
+            "not" => {
+                if !self.eat_left_paren() {
+                    return None;
+                }
+                let predicate = self.parse_cfg_predicate()?;
+                if !self.eat_right_paren() {
+                    return None;
+                }
+                Some(CfgPredicate::Not(Box::new(predicate)))
+            }
+            "all" | "any" => {
+                if !self.eat_left_paren() {
+                    return None;
+                }
+                let mut predicates = Vec::new();
+                while !self.eat_right_paren() {
+                    predicates.push(self.parse_cfg_predicate()?);
+                    if !self.eat_comma() && !self.at(Token::RightParen) {
+                        return None;
+                    }
+                }
+                if ident.0.contents == "all" {
+                    Some(CfgPredicate::All(predicates))
+                } else {
+                    Some(CfgPredicate::Any(predicates))
+                }
+            }
+            _ => None,
+        }
+    }
+
     fn parse_single_name_attribute<F>(
         &mut self,
         ident: &Ident,
@@ -392,8 +471,8 @@ mod tests {
     use noirc_errors::Span;
 
     use crate::{
-        parser::{parser::tests::expect_no_errors, Parser},
-        token::{Attribute, FunctionAttribute, SecondaryAttribute, TestScope},
+        parser::{parser::tests::expect_no_errors, Parser, ParserErrorReason},
+        token::{Attribute, CfgPredicate, FunctionAttribute, SecondaryAttribute, TestScope},
     };
 
     fn parse_inner_secondary_attribute_no_errors(src: &str, expected: SecondaryAttribute) {
@@ -553,6 +632,53 @@ mod tests {
         parse_attribute_no_errors(src, expected);
     }
 
+    #[test]
+    fn parses_attribute_warn_and_deny() {
+        let src = "#[warn(unused_imports)]";
+        let expected = Attribute::Secondary(SecondaryAttribute::Warn("unused_imports".to_string()));
+        parse_attribute_no_errors(src, expected);
+
+        let src = "#[deny(dead_code)]";
+        let expected = Attribute::Secondary(SecondaryAttribute::Deny("dead_code".to_string()));
+        parse_attribute_no_errors(src, expected);
+    }
+
+    #[test]
+    fn parses_attribute_cfg_feature() {
+        let src = "#[cfg(feature = \"bn254\")]";
+        let expected = Attribute::Secondary(SecondaryAttribute::Cfg(CfgPredicate::Feature(
+            "bn254".to_string(),
+        )));
+        parse_attribute_no_errors(src, expected);
+    }
+
+    #[test]
+    fn parses_attribute_cfg_combinators() {
+        let src = "#[cfg(all(feature = \"a\", not(any(feature = \"b\", feature = \"c\")),))]";
+        let feature = |name: &str| CfgPredicate::Feature(name.to_string());
+        let expected = Attribute::Secondary(SecondaryAttribute::Cfg(CfgPredicate::All(vec![
+            feature("a"),
+            CfgPredicate::Not(Box::new(CfgPredicate::Any(vec![feature("b"), feature("c")]))),
+        ])));
+        parse_attribute_no_errors(src, expected);
+    }
+
+    #[test]
+    fn errors_on_malformed_cfg_attribute() {
+        let src = "#[cfg(feature(bn254))]";
+        let mut parser = Parser::for_str(src);
+        let (attribute, _span) = parser.parse_attribute().unwrap();
+        assert_eq!(parser.errors.len(), 1);
+        assert!(matches!(
+            parser.errors[0].reason(),
+            Some(ParserErrorReason::MalformedCfgAttribute)
+        ));
+        assert_eq!(
+            attribute,
+            Attribute::Secondary(SecondaryAttribute::Cfg(CfgPredicate::Any(Vec::new())))
+        );
+    }
+
     #[test]
     fn parses_attribute_test_no_scope() {
         let src = "#[test]";
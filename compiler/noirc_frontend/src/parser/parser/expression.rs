@@ -4,7 +4,7 @@ use noirc_errors::Span;
 use crate::{
     ast::{
         ArrayLiteral, BlockExpression, CallExpression, CastExpression, ConstructorExpression,
-        Expression, ExpressionKind, Ident, IfExpression, IndexExpression, Literal,
+        Expression, ExpressionKind, Ident, IfExpression, IndexExpression, Literal, MatchExpression,
         MemberAccessExpression, MethodCallExpression, Statement, TypePath, UnaryOp, UnresolvedType,
     },
     parser::{labels::ParsingRuleLabel, parser::parse_many::separated_by_comma, ParserErrorReason},
@@ -193,7 +193,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_member_access_field_name(&mut self) -> Option<Ident> {
-        if let Some(ident) = self.eat_ident() {
+        if let Some(ident) = self.eat_ident_or_match_keyword() {
             Some(ident)
         } else if let Some(int) = self.eat_int() {
             Some(Ident::new(int.to_string(), self.previous_token_span))
@@ -252,6 +252,7 @@ impl<'a> Parser<'a> {
     ///     | UnsafeExpression
     ///     | PathExpression
     ///     | IfExpression
+    ///     | MatchExpression
     ///     | Lambda
     ///     | ComptimeExpression
     ///     | UnquoteExpression
@@ -298,6 +299,10 @@ impl<'a> Parser<'a> {
             return Some(kind);
         }
 
+        if let Some(kind) = self.parse_match_expr() {
+            return Some(kind);
+        }
+
         if let Some(kind) = self.parse_lambda() {
             return Some(kind);
         }
@@ -478,6 +483,57 @@ impl<'a> Parser<'a> {
         Some(ExpressionKind::If(Box::new(IfExpression { condition, consequence, alternative })))
     }
 
+    /// MatchExpression = 'match' ExpressionExceptConstructor '{' MatchRule* '}'
+    pub(super) fn parse_match_expr(&mut self) -> Option<ExpressionKind> {
+        if !self.eat_keyword(Keyword::Match) {
+            return None;
+        }
+
+        let expression = self.parse_expression_except_constructor_or_error();
+
+        if !self.eat_left_brace() {
+            self.expected_token(Token::LeftBrace);
+            return Some(ExpressionKind::Match(Box::new(MatchExpression {
+                expression,
+                rules: Vec::new(),
+            })));
+        }
+
+        let rules = self.parse_many(
+            "match cases",
+            without_separator().until(Token::RightBrace),
+            Self::parse_match_rule,
+        );
+
+        Some(ExpressionKind::Match(Box::new(MatchExpression { expression, rules })))
+    }
+
+    /// MatchRule = Expression '=>' ( Block ','? | Expression ',' )
+    ///
+    /// The comma after the last rule is optional.
+    fn parse_match_rule(&mut self) -> Option<(Expression, Expression)> {
+        let Some(pattern) = self.parse_expression() else {
+            self.push_expected_expression();
+            return None;
+        };
+
+        self.eat_or_error(Token::FatArrow);
+
+        let start_span = self.current_token_span;
+        let branch = if let Some(block) = self.parse_block() {
+            self.eat_comma();
+            Expression { kind: ExpressionKind::Block(block), span: self.span_since(start_span) }
+        } else {
+            let branch = self.parse_expression_or_error();
+            if !self.eat_comma() && !self.at(Token::RightBrace) {
+                self.expected_token(Token::Comma);
+            }
+            branch
+        };
+
+        Some((pattern, branch))
+    }
+
     /// ComptimeExpression = 'comptime' Block
     fn parse_comptime_expr(&mut self) -> Option<ExpressionKind> {
         if !self.eat_keyword(Keyword::Comptime) {
@@ -1419,6 +1475,67 @@ mod tests {
         };
     }
 
+    #[test]
+    fn parses_match() {
+        let src = "match x { Foo::Bar(y) => y, _ => { 1 } Foo::Baz => 2 }";
+        let expr = parse_expression_no_errors(src);
+        let ExpressionKind::Match(match_expr) = expr.kind else {
+            panic!("Expected match");
+        };
+        assert_eq!(match_expr.expression.to_string(), "x");
+        assert_eq!(match_expr.rules.len(), 3);
+
+        let (pattern, branch) = &match_expr.rules[0];
+        assert_eq!(pattern.to_string(), "Foo::Bar(y)");
+        assert_eq!(branch.to_string(), "y");
+
+        let (pattern, branch) = &match_expr.rules[1];
+        assert_eq!(pattern.to_string(), "_");
+        assert!(matches!(branch.kind, ExpressionKind::Block(_)));
+
+        let (pattern, _) = &match_expr.rules[2];
+        assert_eq!(pattern.to_string(), "Foo::Baz");
+    }
+
+    #[test]
+    fn parses_match_without_constructor_in_scrutinee() {
+        let src = "match foo { _ => 1 }";
+        let expr = parse_expression_no_errors(src);
+        let ExpressionKind::Match(match_expr) = expr.kind else {
+            panic!("Expected match");
+        };
+        assert_eq!(match_expr.expression.to_string(), "foo");
+        assert_eq!(match_expr.rules.len(), 1);
+    }
+
+    #[test]
+    fn parses_method_call_named_match() {
+        let src = "regex.match(input)";
+        let expr = parse_expression_no_errors(src);
+        let ExpressionKind::MethodCall(method_call) = expr.kind else {
+            panic!("Expected method call");
+        };
+        assert_eq!(method_call.method_name.to_string(), "match");
+    }
+
+    #[test]
+    fn parses_match_missing_comma() {
+        let src = "
+        match x { 1 => 2 3 => 4 }
+                         ^
+        ";
+        let (src, span) = get_source_with_error_span(src);
+        let mut parser = Parser::for_str(&src);
+        let expr = parser.parse_expression_or_error();
+        let ExpressionKind::Match(match_expr) = expr.kind else {
+            panic!("Expected match");
+        };
+        assert_eq!(match_expr.rules.len(), 2);
+
+        let error = get_single_error(&parser.errors, span);
+        assert_eq!(error.to_string(), "Expected a ',' but found '3'");
+    }
+
     #[test]
     fn parses_cast() {
         let src = "1 as u8";
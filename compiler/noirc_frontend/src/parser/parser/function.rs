@@ -86,7 +86,7 @@ impl<'a> Parser<'a> {
         allow_optional_body: bool,
         allow_self: bool,
     ) -> FunctionDefinitionWithOptionalBody {
-        let Some(name) = self.eat_ident() else {
+        let Some(name) = self.eat_ident_or_match_keyword() else {
             self.expected_identifier();
             return empty_function(self.previous_token_span);
         };
@@ -319,6 +319,13 @@ mod tests {
         assert!(noir_function.def.generics.is_empty());
     }
 
+    #[test]
+    fn parse_function_named_match() {
+        let src = "fn match(x: Field) {}";
+        let noir_function = parse_function_no_error(src);
+        assert_eq!("match", noir_function.def.name.to_string());
+    }
+
     #[test]
     fn parse_function_with_generics() {
         let src = "fn foo<A>() {}";
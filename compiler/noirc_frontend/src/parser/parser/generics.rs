@@ -83,7 +83,10 @@ impl<'a> Parser<'a> {
         let typ = self.parse_type_or_error();
         if let UnresolvedTypeData::Integer(signedness, bit_size) = &typ.typ {
             if matches!(signedness, Signedness::Signed)
-                || matches!(bit_size, IntegerBitSize::SixtyFour)
+                || matches!(
+                    bit_size,
+                    IntegerBitSize::SixtyFour | IntegerBitSize::HundredTwentyEight
+                )
             {
                 self.push_error(ParserErrorReason::ForbiddenNumericGenericType, typ.span);
             }
@@ -1,6 +1,8 @@
+use noirc_errors::Span;
+
 use crate::{
     parser::{labels::ParsingRuleLabel, Item, ItemKind},
-    token::{Keyword, Token},
+    token::{Attribute, Keyword, SecondaryAttribute, Token},
 };
 
 use super::{impls::Impl, parse_many::without_separator, Parser};
@@ -84,36 +86,52 @@ impl<'a> Parser<'a> {
         }
     }
 
-    /// Item = OuterDocComments ItemKind
+    /// Item
+    ///     = OuterDocComments InnerAttribute
+    ///     | OuterDocComments Attributes ItemKind
     fn parse_item(&mut self) -> Option<Item> {
         let start_span = self.current_token_span;
         let doc_comments = self.parse_outer_doc_comments();
-        let kind = self.parse_item_kind()?;
+
+        if let Some(attribute) = self.parse_inner_attribute() {
+            let kind = ItemKind::InnerAttribute(attribute);
+            let span = self.span_since(start_span);
+            return Some(Item { kind, span, doc_comments, cfg: Vec::new() });
+        }
+
+        let attributes_start_span = self.current_token_span;
+        let attributes = self.parse_attributes();
+        let cfg = attributes
+            .iter()
+            .filter_map(|(attribute, _)| match attribute {
+                Attribute::Secondary(SecondaryAttribute::Cfg(predicate)) => Some(predicate.clone()),
+                _ => None,
+            })
+            .collect();
+
+        let kind = self.parse_item_kind(attributes, attributes_start_span)?;
         let span = self.span_since(start_span);
 
-        Some(Item { kind, span, doc_comments })
+        Some(Item { kind, span, doc_comments, cfg })
     }
 
     /// ItemKind
-    ///     = InnerAttribute
-    ///     | Attributes Modifiers
+    ///     = Modifiers
     ///         ( Use
     ///         | ModOrContract
     ///         | Struct
+    ///         | Enum
     ///         | Impl
     ///         | Trait
     ///         | Global
     ///         | TypeAlias
     ///         | Function
     ///         )
-    fn parse_item_kind(&mut self) -> Option<ItemKind> {
-        if let Some(kind) = self.parse_inner_attribute() {
-            return Some(ItemKind::InnerAttribute(kind));
-        }
-
-        let start_span = self.current_token_span;
-        let attributes = self.parse_attributes();
-
+    fn parse_item_kind(
+        &mut self,
+        attributes: Vec<(Attribute, Span)>,
+        start_span: Span,
+    ) -> Option<ItemKind> {
         let modifiers = self.parse_modifiers(
             true, // allow mut
         );
@@ -141,6 +159,16 @@ impl<'a> Parser<'a> {
             )));
         }
 
+        if self.eat_keyword(Keyword::Enum) {
+            self.comptime_mutable_and_unconstrained_not_applicable(modifiers);
+
+            return Some(ItemKind::Enum(self.parse_enum(
+                attributes,
+                modifiers.visibility,
+                start_span,
+            )));
+        }
+
         if self.eat_keyword(Keyword::Impl) {
             self.comptime_mutable_and_unconstrained_not_applicable(modifiers);
 
@@ -211,7 +239,8 @@ impl<'a> Parser<'a> {
 mod tests {
     use crate::{
         parse_program,
-        parser::parser::tests::{get_single_error, get_source_with_error_span},
+        parser::parser::tests::{expect_no_errors, get_single_error, get_source_with_error_span},
+        token::CfgPredicate,
     };
 
     #[test]
@@ -227,6 +256,22 @@ mod tests {
         assert_eq!(error.to_string(), "Expected an item but found 'hello'");
     }
 
+    #[test]
+    fn parses_cfg_of_item() {
+        let src = "
+        #[cfg(feature = \"foo\")]
+        #[cfg(not(feature = \"bar\"))]
+        use foo::bar;
+        ";
+        let (module, errors) = parse_program(src);
+        expect_no_errors(&errors);
+        assert_eq!(module.items.len(), 1);
+        let cfg = &module.items[0].cfg;
+        assert_eq!(cfg.len(), 2);
+        assert_eq!(cfg[0], CfgPredicate::Feature("foo".to_string()));
+        assert_eq!(cfg[1], CfgPredicate::Not(Box::new(CfgPredicate::Feature("bar".to_string()))));
+    }
+
     #[test]
     fn errors_on_eof_in_nested_mod() {
         let src = "
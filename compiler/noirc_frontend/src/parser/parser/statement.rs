@@ -4,10 +4,10 @@ use crate::{
     ast::{
         AssignStatement, BinaryOp, BinaryOpKind, ConstrainKind, ConstrainStatement, Expression,
         ExpressionKind, ForBounds, ForLoopStatement, ForRange, Ident, InfixExpression, LValue,
-        LetStatement, Statement, StatementKind,
+        LetStatement, LoopStatement, Statement, StatementKind, WhileStatement,
     },
     parser::{labels::ParsingRuleLabel, ParserErrorReason},
-    token::{Attribute, Keyword, Token, TokenKind},
+    token::{Attribute, CfgPredicate, Keyword, SecondaryAttribute, Token, TokenKind},
 };
 
 use super::Parser;
@@ -25,7 +25,9 @@ impl<'a> Parser<'a> {
     /// Statement = Attributes StatementKind ';'?
     pub(crate) fn parse_statement(&mut self) -> Option<(Statement, (Option<Token>, Span))> {
         loop {
+            let attributes_start_span = self.current_token_span;
             let attributes = self.parse_attributes();
+            let (cfg, attributes) = self.split_cfg_attributes(attributes);
             let start_span = self.current_token_span;
             let kind = self.parse_statement_kind(attributes);
 
@@ -42,7 +44,14 @@ impl<'a> Parser<'a> {
             let span = self.span_since(start_span);
 
             if let Some(kind) = kind {
-                let statement = Statement { kind, span };
+                let mut statement = Statement { kind, span };
+                if !cfg.is_empty() {
+                    let span = self.span_since(attributes_start_span);
+                    for predicate in cfg.into_iter().rev() {
+                        let kind = StatementKind::Cfg(predicate, Box::new(statement));
+                        statement = Statement { kind, span };
+                    }
+                }
                 return Some((statement, (semicolon_token, semicolon_span)));
             }
 
@@ -56,6 +65,29 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Separates the leading `#[cfg(...)]` attributes of a statement from its other attributes.
+    /// A `cfg` attribute that follows another attribute is an error.
+    fn split_cfg_attributes(
+        &mut self,
+        attributes: Vec<(Attribute, Span)>,
+    ) -> (Vec<CfgPredicate>, Vec<(Attribute, Span)>) {
+        let mut cfg = Vec::new();
+        let mut other_attributes = Vec::new();
+        for (attribute, span) in attributes {
+            match attribute {
+                Attribute::Secondary(SecondaryAttribute::Cfg(predicate)) => {
+                    if other_attributes.is_empty() {
+                        cfg.push(predicate);
+                    } else {
+                        self.push_error(ParserErrorReason::CfgAttributeAfterOtherAttributes, span);
+                    }
+                }
+                attribute => other_attributes.push((attribute, span)),
+            }
+        }
+        (cfg, other_attributes)
+    }
+
     /// StatementKind
     ///     = BreakStatement
     ///     | ContinueStatement
@@ -64,7 +96,10 @@ impl<'a> Parser<'a> {
     ///     | ConstrainStatement
     ///     | ComptimeStatement
     ///     | ForStatement
+    ///     | WhileStatement
+    ///     | LoopStatement
     ///     | IfStatement
+    ///     | MatchStatement
     ///     | BlockStatement
     ///     | AssignStatement
     ///     | ExpressionStatement
@@ -77,6 +112,8 @@ impl<'a> Parser<'a> {
     ///
     /// IfStatement = IfExpression
     ///
+    /// MatchStatement = MatchExpression
+    ///
     /// BlockStatement = Block
     ///
     /// AssignStatement = Expression '=' Expression
@@ -106,9 +143,7 @@ impl<'a> Parser<'a> {
         }
 
         if self.eat_keyword(Keyword::Return) {
-            self.parse_expression();
-            self.push_error(ParserErrorReason::EarlyReturn, self.span_since(start_span));
-            return Some(StatementKind::Error);
+            return Some(self.parse_return());
         }
 
         if self.at_keyword(Keyword::Let) {
@@ -128,6 +163,14 @@ impl<'a> Parser<'a> {
             return Some(StatementKind::For(for_loop));
         }
 
+        if let Some(while_loop) = self.parse_while() {
+            return Some(StatementKind::While(while_loop));
+        }
+
+        if let Some(loop_) = self.parse_loop() {
+            return Some(StatementKind::Loop(loop_));
+        }
+
         if let Some(kind) = self.parse_if_expr() {
             return Some(StatementKind::Expression(Expression {
                 kind,
@@ -135,6 +178,13 @@ impl<'a> Parser<'a> {
             }));
         }
 
+        if let Some(kind) = self.parse_match_expr() {
+            return Some(StatementKind::Expression(Expression {
+                kind,
+                span: self.span_since(start_span),
+            }));
+        }
+
         if let Some(block) = self.parse_block() {
             return Some(StatementKind::Expression(Expression {
                 kind: ExpressionKind::Block(block),
@@ -244,9 +294,52 @@ impl<'a> Parser<'a> {
         }
 
         let range = self.parse_for_range();
+        let block = self.parse_loop_body();
+
+        Some(ForLoopStatement { identifier, range, block, span: self.span_since(start_span) })
+    }
+
+    /// WhileStatement = 'while' ExpressionExceptConstructor Block
+    fn parse_while(&mut self) -> Option<WhileStatement> {
+        let start_span = self.current_token_span;
+
+        if !self.eat_keyword(Keyword::While) {
+            return None;
+        }
+
+        let condition = self.parse_expression_except_constructor_or_error();
+        let block = self.parse_loop_body();
 
+        Some(WhileStatement { condition, block, span: self.span_since(start_span) })
+    }
+
+    /// LoopStatement = 'loop' Block
+    fn parse_loop(&mut self) -> Option<LoopStatement> {
+        let start_span = self.current_token_span;
+
+        if !self.eat_keyword(Keyword::Loop) {
+            return None;
+        }
+
+        let block = self.parse_loop_body();
+
+        Some(LoopStatement { block, span: self.span_since(start_span) })
+    }
+
+    /// ReturnStatement = 'return' Expression?
+    ///
+    /// The `return` keyword is assumed to have already been consumed.
+    fn parse_return(&mut self) -> StatementKind {
+        if self.at(Token::Semicolon) || self.at(Token::RightBrace) || self.at_eof() {
+            StatementKind::Return(None)
+        } else {
+            StatementKind::Return(Some(self.parse_expression_or_error()))
+        }
+    }
+
+    fn parse_loop_body(&mut self) -> Expression {
         let block_start_span = self.current_token_span;
-        let block = if let Some(block) = self.parse_block() {
+        if let Some(block) = self.parse_block() {
             Expression {
                 kind: ExpressionKind::Block(block),
                 span: self.span_since(block_start_span),
@@ -254,9 +347,7 @@ impl<'a> Parser<'a> {
         } else {
             self.expected_token(Token::LeftBrace);
             Expression { kind: ExpressionKind::Error, span: self.span_since(block_start_span) }
-        };
-
-        Some(ForLoopStatement { identifier, range, block, span: self.span_since(start_span) })
+        }
     }
 
     /// ForRange
@@ -427,6 +518,7 @@ mod tests {
             },
             Parser, ParserErrorReason,
         },
+        token::CfgPredicate,
     };
 
     fn parse_statement_no_errors(src: &str) -> Statement {
@@ -545,6 +637,33 @@ mod tests {
         };
     }
 
+    #[test]
+    fn parses_while() {
+        let src = "while x < 10 { x += 1; }";
+        let statement = parse_statement_no_errors(src);
+        let StatementKind::While(while_loop) = statement.kind else {
+            panic!("Expected while loop");
+        };
+        assert_eq!(while_loop.condition.to_string(), "(x < 10)");
+        let ExpressionKind::Block(block) = while_loop.block.kind else {
+            panic!("Expected block");
+        };
+        assert_eq!(block.statements.len(), 1);
+    }
+
+    #[test]
+    fn parses_loop() {
+        let src = "loop { break; }";
+        let statement = parse_statement_no_errors(src);
+        let StatementKind::Loop(loop_) = statement.kind else {
+            panic!("Expected loop");
+        };
+        let ExpressionKind::Block(block) = loop_.block.kind else {
+            panic!("Expected block");
+        };
+        assert_eq!(block.statements.len(), 1);
+    }
+
     #[test]
     fn parses_for_array() {
         let src = "for i in x { }";
@@ -662,6 +781,20 @@ mod tests {
         assert_eq!(block.statements.len(), 2);
     }
 
+    #[test]
+    fn parses_match_statement_followed_by_tuple() {
+        // This shouldn't be parsed as a call
+        let src = "{ match 1 { _ => 2 } (3, 4) }";
+        let statement = parse_statement_no_errors(src);
+        let StatementKind::Expression(expr) = statement.kind else {
+            panic!("Expected expr");
+        };
+        let ExpressionKind::Block(block) = expr.kind else {
+            panic!("Expected block");
+        };
+        assert_eq!(block.statements.len(), 2);
+    }
+
     #[test]
     fn parses_block_followed_by_tuple() {
         // This shouldn't be parsed as a call
@@ -677,18 +810,20 @@ mod tests {
     }
 
     #[test]
-    fn errors_on_return_statement() {
-        // This shouldn't be parsed as a call
-        let src = "
-        return 1
-        ^^^^^^^^
-        ";
-        let (src, span) = get_source_with_error_span(src);
-        let mut parser = Parser::for_str(&src);
-        let statement = parser.parse_statement_or_error();
-        assert!(matches!(statement.kind, StatementKind::Error));
-        let reason = get_single_error_reason(&parser.errors, span);
-        assert!(matches!(reason, ParserErrorReason::EarlyReturn));
+    fn parses_return_with_value() {
+        let src = "return 1 + 2;";
+        let statement = parse_statement_no_errors(src);
+        let StatementKind::Return(Some(expression)) = statement.kind else {
+            panic!("Expected return statement");
+        };
+        assert_eq!(expression.to_string(), "(1 + 2)");
+    }
+
+    #[test]
+    fn parses_return_without_value() {
+        let src = "return;";
+        let statement = parse_statement_no_errors(src);
+        assert!(matches!(statement.kind, StatementKind::Return(None)));
     }
 
     #[test]
@@ -722,4 +857,37 @@ mod tests {
         assert!(statement.is_none());
         assert_eq!(parser.errors.len(), 2);
     }
+
+    #[test]
+    fn parses_cfg_statement() {
+        let src = "#[cfg(feature = \"foo\")] #[cfg(feature = \"bar\")] let x = 1;";
+        let statement = parse_statement_no_errors(src);
+        let StatementKind::Cfg(predicate, statement) = statement.kind else {
+            panic!("Expected cfg statement");
+        };
+        assert_eq!(predicate, CfgPredicate::Feature("foo".to_string()));
+        let StatementKind::Cfg(predicate, statement) = statement.kind else {
+            panic!("Expected cfg statement");
+        };
+        assert_eq!(predicate, CfgPredicate::Feature("bar".to_string()));
+        let StatementKind::Let(let_statement) = statement.kind else {
+            panic!("Expected let statement");
+        };
+        assert!(let_statement.attributes.is_empty());
+    }
+
+    #[test]
+    fn errors_on_cfg_attribute_after_other_attributes() {
+        let src = "
+        #[allow(unused_variables)] #[cfg(feature = \"foo\")] let x = 1;
+                                   ^^^^^^^^^^^^^^^^^^^^^^^
+        ";
+        let (src, span) = get_source_with_error_span(src);
+        let mut parser = Parser::for_str(&src);
+        let statement = parser.parse_statement_or_error();
+        assert!(matches!(statement.kind, StatementKind::Let(..)));
+
+        let reason = get_single_error_reason(&parser.errors, span);
+        assert!(matches!(reason, ParserErrorReason::CfgAttributeAfterOtherAttributes));
+    }
 }
@@ -284,7 +284,7 @@ impl<'a> Parser<'a> {
         Some(UnresolvedTypeData::Function(args, Box::new(ret), Box::new(env), unconstrained))
     }
 
-    fn parse_parameter(&mut self) -> Option<UnresolvedType> {
+    pub(super) fn parse_parameter(&mut self) -> Option<UnresolvedType> {
         let typ = self.parse_type_or_error();
         if let UnresolvedTypeData::Error = typ.typ {
             None
@@ -3,7 +3,10 @@
 mod aliases;
 mod arithmetic_generics;
 mod bound_checks;
+mod cfg;
+mod enums;
 mod imports;
+mod lint_levels;
 mod metaprogramming;
 mod name_shadowing;
 mod references;
@@ -15,7 +18,7 @@ mod visibility;
 // XXX: These tests repeat a lot of code
 // what we should do is have test cases which are passed to a test harness
 // A test harness will allow for more expressive and readable tests
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use fm::FileId;
 
@@ -34,6 +37,7 @@ use crate::hir::Context;
 use crate::node_interner::{NodeInterner, StmtId};
 
 use crate::hir::def_collector::dc_crate::DefCollector;
+use crate::hir::def_collector::dc_mod::remove_disabled_items;
 use crate::hir::def_map::{CrateDefMap, LocalModuleId};
 use crate::hir_def::expr::HirExpression;
 use crate::hir_def::stmt::HirStatement;
@@ -68,6 +72,14 @@ pub(crate) fn get_program(src: &str) -> (ParsedModule, Context, Vec<(Compilation
 pub(crate) fn get_program_with_maybe_parser_errors(
     src: &str,
     allow_parser_errors: bool,
+) -> (ParsedModule, Context, Vec<(CompilationError, FileId)>) {
+    get_program_with_features(src, allow_parser_errors, BTreeSet::new())
+}
+
+pub(crate) fn get_program_with_features(
+    src: &str,
+    allow_parser_errors: bool,
+    features: BTreeSet<String>,
 ) -> (ParsedModule, Context, Vec<(CompilationError, FileId)>) {
     let root = std::path::Path::new("/");
     let fm = FileManager::new(root);
@@ -76,6 +88,7 @@ pub(crate) fn get_program_with_maybe_parser_errors(
     context.def_interner.populate_dummy_operator_traits();
     let root_file_id = FileId::dummy();
     let root_crate_id = context.crate_graph.add_crate_root(root_file_id);
+    context.enable_features(root_crate_id, features);
 
     let (program, parser_errors) = parse_program(src);
     let mut errors = vecmap(parser_errors, |e| (e.into(), root_file_id));
@@ -116,11 +129,14 @@ pub(crate) fn get_program_with_maybe_parser_errors(
         let debug_comptime_in_file = None;
         let error_on_unused_imports = true;
 
+        let mut enabled_program = program.clone();
+        remove_disabled_items(&mut enabled_program.items, &context.def_interner, root_crate_id);
+
         // Now we want to populate the CrateDefMap using the DefCollector
         errors.extend(DefCollector::collect_crate_and_dependencies(
             def_map,
             &mut context,
-            program.clone().into_sorted(),
+            enabled_program.into_sorted(),
             root_file_id,
             debug_comptime_in_file,
             error_on_unused_imports,
@@ -900,9 +916,12 @@ fn find_lambda_captures(stmts: &[StmtId], interner: &NodeInterner, result: &mut
             HirStatement::Constrain(constr_stmt) => constr_stmt.0,
             HirStatement::Semi(semi_expr) => semi_expr,
             HirStatement::For(for_loop) => for_loop.block,
+            HirStatement::While(while_loop) => while_loop.block,
+            HirStatement::Loop(block) => block,
             HirStatement::Error => panic!("Invalid HirStatement!"),
             HirStatement::Break => panic!("Unexpected break"),
             HirStatement::Continue => panic!("Unexpected continue"),
+            HirStatement::Return(_) => panic!("Unexpected return"),
             HirStatement::Comptime(_) => panic!("Unexpected comptime"),
         };
         let expr = interner.expression(&expr_id);
@@ -1378,6 +1397,172 @@ fn break_and_continue_outside_loop() {
     assert_eq!(get_program_errors(src).len(), 2);
 }
 
+#[test]
+fn while_and_loop_in_constrained_fn() {
+    let src = r#"
+        fn main() {
+            let mut i = 0;
+            while i < 10 {
+                i += 1;
+            }
+            loop {
+                if i == 20 {
+                    break;
+                }
+                i += 1;
+            }
+        }
+    "#;
+    let errors = get_program_errors(src);
+    // The `while` and `loop` themselves, plus the `break` inside the `loop`
+    assert_eq!(errors.len(), 3);
+
+    let loop_errors: Vec<_> = errors
+        .iter()
+        .filter_map(|(error, _)| match error {
+            CompilationError::ResolverError(ResolverError::LoopInConstrainedFn {
+                is_while,
+                ..
+            }) => Some(*is_while),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(loop_errors, vec![true, false]);
+}
+
+#[test]
+fn while_and_loop_in_unconstrained_fn() {
+    let src = r#"
+        unconstrained fn main() {
+            let mut i = 0;
+            while i < 10 {
+                if i == 2 {
+                    i += 2;
+                    continue;
+                }
+                i += 1;
+            }
+            loop {
+                if i == 20 {
+                    break;
+                }
+                i += 1;
+            }
+        }
+    "#;
+    assert_no_errors(src);
+}
+
+#[test]
+fn while_condition_must_be_bool() {
+    let src = r#"
+        unconstrained fn main() {
+            while 1 {}
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0].0,
+        CompilationError::TypeError(TypeCheckError::TypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn early_return_in_constrained_fn() {
+    let src = r#"
+        fn main(x: u32) -> pub u32 {
+            if x == 0 {
+                return 1;
+            }
+            x
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0].0,
+        CompilationError::ResolverError(ResolverError::EarlyReturnInConstrainedFn { .. })
+    ));
+}
+
+#[test]
+fn early_return_in_unconstrained_fn() {
+    let src = r#"
+        unconstrained fn main(x: u32) -> pub u32 {
+            if x == 0 {
+                return 1;
+            }
+            let f = |y: u32| {
+                if y == 0 {
+                    return 2;
+                }
+                y
+            };
+            f(x)
+        }
+    "#;
+    assert_no_errors(src);
+}
+
+#[test]
+fn early_return_type_mismatch() {
+    let src = r#"
+        unconstrained fn main() -> pub u32 {
+            return true;
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0].0,
+        CompilationError::TypeError(TypeCheckError::TypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn early_return_in_comptime_fn() {
+    let src = r#"
+        comptime fn first_even(xs: [u32; 4]) -> u32 {
+            for x in xs {
+                if x % 2 == 0 {
+                    return x;
+                }
+            }
+            0
+        }
+
+        fn main() {
+            comptime {
+                assert_eq(first_even([1, 3, 4, 6]), 4);
+                assert_eq(first_even([1, 3, 5, 7]), 0);
+            }
+        }
+    "#;
+    assert_no_errors(src);
+}
+
+#[test]
+fn return_in_comptime_block() {
+    let src = r#"
+        unconstrained fn main() {
+            comptime {
+                return;
+            }
+        }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(
+        errors[0].0,
+        CompilationError::ResolverError(ResolverError::ReturnOutsideFunction { .. })
+    ));
+    assert!(matches!(
+        errors[1].0,
+        CompilationError::InterpreterError(InterpreterError::ReturnNotInFunction { .. })
+    ));
+}
+
 // Regression for #2540
 #[test]
 fn for_loop_over_array() {
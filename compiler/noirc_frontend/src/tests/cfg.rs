@@ -0,0 +1,87 @@
+use std::collections::BTreeSet;
+
+use fm::FileId;
+
+use crate::hir::def_collector::dc_crate::CompilationError;
+
+use super::get_program_with_features;
+
+fn get_program_errors_with_features(
+    src: &str,
+    features: &[&str],
+) -> Vec<(CompilationError, FileId)> {
+    let features: BTreeSet<String> = features.iter().map(ToString::to_string).collect();
+    get_program_with_features(src, false, features).2
+}
+
+#[test]
+fn removes_items_of_disabled_features() {
+    let src = r#"
+    #[cfg(feature = "foo")]
+    fn foo() {}
+
+    fn main() {
+        foo();
+    }
+    "#;
+    assert!(get_program_errors_with_features(src, &["foo"]).is_empty());
+    assert_eq!(get_program_errors_with_features(src, &[]).len(), 1);
+}
+
+#[test]
+fn allows_alternative_items_depending_on_features() {
+    let src = r#"
+    #[cfg(feature = "foo")]
+    fn value() -> Field {
+        1
+    }
+
+    #[cfg(not(feature = "foo"))]
+    fn value() -> Field {
+        2
+    }
+
+    fn main() {
+        let _ = value();
+    }
+    "#;
+    assert!(get_program_errors_with_features(src, &["foo"]).is_empty());
+    assert!(get_program_errors_with_features(src, &[]).is_empty());
+}
+
+#[test]
+fn removes_items_in_submodules_and_impl_methods() {
+    let src = r#"
+    mod moo {
+        #[cfg(all(feature = "foo", feature = "bar"))]
+        pub fn foo() {}
+    }
+
+    pub struct Foo {}
+
+    impl Foo {
+        #[cfg(any(feature = "foo", feature = "bar"))]
+        fn bar() {}
+    }
+
+    fn main() {
+        moo::foo();
+        Foo::bar();
+    }
+    "#;
+    assert!(get_program_errors_with_features(src, &["foo", "bar"]).is_empty());
+    assert_eq!(get_program_errors_with_features(src, &["bar"]).len(), 1);
+    assert_eq!(get_program_errors_with_features(src, &[]).len(), 2);
+}
+
+#[test]
+fn skips_statements_of_disabled_features() {
+    let src = r#"
+    fn main() {
+        #[cfg(feature = "foo")]
+        let _ = undefined_variable;
+    }
+    "#;
+    assert!(get_program_errors_with_features(src, &[]).is_empty());
+    assert_eq!(get_program_errors_with_features(src, &["foo"]).len(), 1);
+}
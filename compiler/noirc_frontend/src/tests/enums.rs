@@ -0,0 +1,313 @@
+use crate::{
+    hir::{
+        def_collector::dc_crate::CompilationError, resolution::errors::ResolverError,
+        type_check::TypeCheckError,
+    },
+    tests::{assert_no_errors, get_program_errors, monomorphize_program},
+};
+
+#[test]
+fn parse_and_elaborate_enum_with_match() {
+    let src = r#"
+    enum Foo<T> {
+        A(T, u32),
+        B,
+        C(),
+    }
+
+    fn main() {
+        let _ = value(Foo::A(1, 2));
+        let _ = value(Foo::B);
+        let _ = value(Foo::C());
+    }
+
+    fn value(foo: Foo<Field>) -> Field {
+        match foo {
+            Foo::A(x, _) => x,
+            Foo::B => 2,
+            Foo::C() => 3,
+        }
+    }
+    "#;
+    assert_no_errors(src);
+}
+
+#[test]
+fn match_on_nested_patterns() {
+    let src = r#"
+    enum Foo {
+        A(bool, (Field, Field)),
+        B(Bar),
+    }
+
+    struct Bar {
+        x: Field,
+        y: u8,
+    }
+
+    fn main() {
+        let _ = value(Foo::A(true, (1, 2)));
+        let _ = value(Foo::B(Bar { x: 1, y: 2 }));
+    }
+
+    fn value(foo: Foo) -> Field {
+        match foo {
+            Foo::A(true, (x, _)) => x,
+            Foo::A(false, (_, y)) => y,
+            Foo::B(Bar { x, y: 3 }) => x,
+            Foo::B(Bar { x: _, y: _ }) => 0,
+        }
+    }
+    "#;
+    assert_no_errors(src);
+}
+
+#[test]
+fn errors_on_missing_cases() {
+    let src = r#"
+    enum Foo {
+        A(bool),
+        B,
+        C,
+    }
+
+    fn main() {
+        let _ = match Foo::B {
+            Foo::A(true) => 1,
+            Foo::C => 2,
+        };
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+
+    let CompilationError::TypeError(TypeCheckError::MissingCases { cases, .. }) = &errors[0].0
+    else {
+        panic!("Expected a missing cases error, got {:?}", errors[0].0);
+    };
+
+    let cases: Vec<_> = cases.iter().cloned().collect();
+    assert_eq!(cases, vec!["Foo::A(false)".to_string(), "Foo::B".to_string()]);
+}
+
+#[test]
+fn errors_on_missing_integer_cases() {
+    let src = r#"
+    fn main() {
+        let x: u8 = 3;
+        let _ = match x {
+            1 => 1,
+            2 => 2,
+        };
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+
+    let CompilationError::TypeError(TypeCheckError::MissingCases { cases, .. }) = &errors[0].0
+    else {
+        panic!("Expected a missing cases error, got {:?}", errors[0].0);
+    };
+    assert_eq!(cases.iter().next().unwrap(), "_");
+}
+
+#[test]
+fn missing_cases_message_elides_cases() {
+    let src = r#"
+    enum Foo {
+        A,
+        B,
+        C,
+        D,
+        E,
+    }
+
+    fn main() {
+        let _ = match Foo::A {
+            Foo::A => 1,
+        };
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].0.to_string(),
+        "Missing cases: `Foo::B`, `Foo::C`, `Foo::D`, and 1 more not shown"
+    );
+}
+
+#[test]
+fn warns_on_unreachable_case() {
+    let src = r#"
+    enum Foo {
+        A(Field),
+        B,
+    }
+
+    fn main() {
+        let _ = match Foo::B {
+            Foo::A(_) => 1,
+            _ => 2,
+            Foo::B => 3,
+        };
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0].0,
+        CompilationError::TypeError(TypeCheckError::UnreachableCase { .. })
+    ));
+}
+
+#[test]
+fn errors_on_non_pattern_expression() {
+    let src = r#"
+    fn main() {
+        let _ = match 1 {
+            1 + 2 => 1,
+            _ => 2,
+        };
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0].0,
+        CompilationError::ResolverError(ResolverError::InvalidPattern { .. })
+    ));
+}
+
+#[test]
+fn errors_on_function_used_as_enum_variant_pattern() {
+    let src = r#"
+    enum Foo {
+        A(Field),
+    }
+
+    fn not_a_variant(x: Field) -> Foo {
+        Foo::A(x)
+    }
+
+    fn main() {
+        let _ = match not_a_variant(1) {
+            not_a_variant(x) => x,
+        };
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert!(errors.iter().any(|(error, _)| matches!(
+        error,
+        CompilationError::ResolverError(ResolverError::NotAnEnumVariant { .. })
+    )));
+}
+
+#[test]
+fn errors_on_wrong_number_of_variant_arguments() {
+    let src = r#"
+    enum Foo {
+        A(Field, Field),
+    }
+
+    fn main() {
+        let _ = Foo::A(1);
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0].0,
+        CompilationError::TypeError(TypeCheckError::ParameterCountMismatch { .. })
+    ));
+}
+
+#[test]
+fn errors_on_enum_used_in_constructor_expression() {
+    let src = r#"
+    enum Foo {
+        A,
+    }
+
+    fn main() {
+        let _ = Foo {};
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0].0,
+        CompilationError::ResolverError(ResolverError::NonStructUsedInConstructor { .. })
+    ));
+}
+
+#[test]
+fn errors_on_duplicate_variant() {
+    let src = r#"
+    enum Foo {
+        A,
+        A(Field),
+    }
+
+    fn main() {
+        let _ = Foo::A;
+    }
+    "#;
+    let errors = get_program_errors(src);
+    assert!(!errors.is_empty());
+}
+
+#[test]
+fn evaluates_match_at_comptime() {
+    let src = r#"
+    enum Foo {
+        A(Field),
+        B(Field, Field),
+        C,
+    }
+
+    fn value(foo: Foo) -> Field {
+        match foo {
+            Foo::A(x) => x,
+            Foo::B(x, y) => x + y,
+            Foo::C => 0,
+        }
+    }
+
+    fn main() {
+        comptime {
+            assert_eq(value(Foo::A(1)), 1);
+            assert_eq(value(Foo::B(2, 3)), 5);
+            assert_eq(value(Foo::C), 0);
+        }
+    }
+    "#;
+    assert_no_errors(src);
+}
+
+#[test]
+fn monomorphizes_enum_into_tag_and_shared_fields() {
+    let src = r#"
+    enum Foo {
+        A(Field),
+        B,
+        C(u8, Field),
+    }
+
+    fn main() -> pub Field {
+        value(Foo::B) + value(Foo::C(3, 4))
+    }
+
+    fn value(foo: Foo) -> Field {
+        match foo {
+            Foo::A(x) => x,
+            Foo::B => 0,
+            Foo::C(y, x) => x + y as Field,
+        }
+    }
+    "#;
+    let program = monomorphize_program(src).unwrap().to_string();
+    // `A` and `C` share the `Field` field, so there's one for it and one for `u8`
+    assert!(program.contains("(1, 0, 0)"), "unexpected program: {program}");
+    assert!(program.contains("(2, 4, 3)"), "unexpected program: {program}");
+    assert!(program.contains("match internal_match_variable"), "unexpected program: {program}");
+}
@@ -0,0 +1,108 @@
+use noirc_errors::{CustomDiagnostic, Location};
+
+use crate::hir::lint_levels::{Lint, LintLevel};
+
+use super::get_program;
+
+/// Returns the lint and level of each warning in `src` that belongs to a lint,
+/// after setting the crate's default lint levels to `crate_levels`.
+fn get_lint_levels(src: &str, crate_levels: Vec<(Lint, LintLevel)>) -> Vec<(Lint, LintLevel)> {
+    let (_, mut context, errors) = get_program(src);
+    let crate_id = *context.def_maps.keys().next().unwrap();
+    context.set_lint_levels(crate_id, crate_levels);
+
+    errors
+        .iter()
+        .filter_map(|(error, file_id)| {
+            let lint = error.lint()?;
+            let span = CustomDiagnostic::from(error).secondaries[0].span;
+            Some((lint, context.lint_level(lint, Location::new(span, *file_id))))
+        })
+        .collect()
+}
+
+#[test]
+fn lints_default_to_warn() {
+    let src = r#"
+    fn main() {
+        let x = 1;
+    }
+    "#;
+    let levels = get_lint_levels(src, Vec::new());
+    assert_eq!(levels, vec![(Lint::UnusedVariables, LintLevel::Warn)]);
+}
+
+#[test]
+fn allow_on_function_applies_to_its_body() {
+    let src = r#"
+    #[allow(unused_variables)]
+    fn main() {
+        let x = 1;
+    }
+    "#;
+    let levels = get_lint_levels(src, Vec::new());
+    assert_eq!(levels, vec![(Lint::UnusedVariables, LintLevel::Allow)]);
+}
+
+#[test]
+fn deny_on_crate_root_applies_to_submodules() {
+    let src = r#"
+    #![deny(unused_variables)]
+
+    mod foo {
+        pub fn bar() {
+            let x = 1;
+        }
+    }
+
+    fn main() {
+        foo::bar();
+    }
+    "#;
+    let levels = get_lint_levels(src, Vec::new());
+    assert_eq!(levels, vec![(Lint::UnusedVariables, LintLevel::Deny)]);
+}
+
+#[test]
+fn innermost_lint_level_wins() {
+    let src = r#"
+    #![deny(unused_variables)]
+
+    #[warn(unused_variables)]
+    fn main() {
+        let x = 1;
+    }
+    "#;
+    let levels = get_lint_levels(src, Vec::new());
+    assert_eq!(levels, vec![(Lint::UnusedVariables, LintLevel::Warn)]);
+}
+
+#[test]
+fn attributes_override_crate_lint_levels() {
+    let src = r#"
+    #[allow(dead_code)]
+    mod foo {
+        fn bar() {}
+    }
+
+    fn baz() {}
+
+    fn main() {}
+    "#;
+    let levels = get_lint_levels(src, vec![(Lint::DeadCode, LintLevel::Deny)]);
+    assert_eq!(levels.len(), 2);
+    assert!(levels.contains(&(Lint::DeadCode, LintLevel::Allow)));
+    assert!(levels.contains(&(Lint::DeadCode, LintLevel::Deny)));
+}
+
+#[test]
+fn unknown_lints_are_ignored() {
+    let src = r#"
+    #[allow(not_a_lint)]
+    fn main() {
+        let x = 1;
+    }
+    "#;
+    let levels = get_lint_levels(src, Vec::new());
+    assert_eq!(levels, vec![(Lint::UnusedVariables, LintLevel::Warn)]);
+}
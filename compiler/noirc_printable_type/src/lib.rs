@@ -33,6 +33,15 @@ pub enum PrintableType {
         name: String,
         fields: Vec<(String, PrintableType)>,
     },
+    /// An enum is encoded as its variant's tag followed by `fields`, which are shared
+    /// by the arguments of all variants. Each variant lists the index of the field
+    /// holding each of its arguments: only these fields are meaningful for the variant
+    /// matching the tag.
+    Enum {
+        name: String,
+        fields: Vec<PrintableType>,
+        variants: Vec<(String, Vec<usize>)>,
+    },
     String {
         length: u32,
     },
@@ -57,6 +66,7 @@ pub enum PrintableValue<F> {
     String(String),
     Vec { array_elements: Vec<PrintableValue<F>>, is_slice: bool },
     Struct(BTreeMap<String, PrintableValue<F>>),
+    Enum { tag: usize, args: Vec<PrintableValue<F>> },
     Other,
 }
 
@@ -160,16 +170,18 @@ fn to_string<F: AcirField>(value: &PrintableValue<F>, typ: &PrintableType) -> Op
             output.push_str(&format_field_string(*f));
         }
         (PrintableValue::Field(f), PrintableType::UnsignedInteger { width }) => {
-            let uint_cast = f.to_u128() & ((1 << width) - 1); // Retain the lower 'width' bits
+            let mask = u128::MAX >> (128 - width);
+            let uint_cast = f.to_u128() & mask; // Retain the lower 'width' bits
             output.push_str(&uint_cast.to_string());
         }
         (PrintableValue::Field(f), PrintableType::SignedInteger { width }) => {
             let mut uint = f.to_u128(); // Interpret as uint
+            let mask = u128::MAX >> (128 - width);
 
             // Extract sign relative to width of input
             if (uint >> (width - 1)) == 1 {
                 output.push('-');
-                uint = (uint ^ ((1 << width) - 1)) + 1; // Two's complement relative to width of input
+                uint = (uint ^ mask) + 1; // Two's complement relative to width of input
             }
 
             output.push_str(&uint.to_string());
@@ -228,6 +240,26 @@ fn to_string<F: AcirField>(value: &PrintableValue<F>, typ: &PrintableType) -> Op
             output.push_str(" }");
         }
 
+        (PrintableValue::Enum { tag, args }, PrintableType::Enum { name, fields, variants }) => {
+            let (variant_name, arg_fields) = variants.get(*tag)?;
+            output.push_str(&format!("{name}::{variant_name}"));
+
+            if !args.is_empty() {
+                output.push('(');
+                let arg_types = arg_fields.iter().map(|field| &fields[*field]);
+                let mut args = args.iter().zip(arg_types).peekable();
+                while let Some((value, typ)) = args.next() {
+                    output.push_str(
+                        &PrintableValueDisplay::Plain(value.clone(), typ.clone()).to_string(),
+                    );
+                    if args.peek().is_some() {
+                        output.push_str(", ");
+                    }
+                }
+                output.push(')');
+            }
+        }
+
         (PrintableValue::Vec { array_elements, .. }, PrintableType::Tuple { types }) => {
             output.push('(');
             let mut elems = array_elements.iter().zip(types).peekable();
@@ -360,6 +392,19 @@ pub fn decode_value<F: AcirField>(
 
             PrintableValue::Struct(struct_map)
         }
+        PrintableType::Enum { fields, variants, .. } => {
+            let tag = field_iterator.next().unwrap().to_u128() as usize;
+
+            // All fields are present, but only the ones holding the active variant's arguments are kept
+            let mut values = vecmap(fields, |typ| Some(decode_value(field_iterator, typ)));
+            let args = variants.get(tag).map_or_else(Vec::new, |(_, arg_fields)| {
+                vecmap(arg_fields, |field| {
+                    values[*field].take().expect("Arguments of a variant have distinct fields")
+                })
+            });
+
+            PrintableValue::Enum { tag, args }
+        }
         PrintableType::Function { env, .. } => {
             let field_element = field_iterator.next().unwrap();
             let func_ref = PrintableValue::Field(field_element);
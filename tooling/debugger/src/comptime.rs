@@ -0,0 +1,258 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use easy_repl::{command, CommandStatus, Repl};
+use fm::{FileId, FileManager};
+use noirc_artifacts::debug::DebugArtifact;
+use noirc_driver::DebugFile;
+use noirc_errors::Location;
+use noirc_frontend::hir::comptime::{ComptimeDebugger, ComptimeStep, ComptimeStepKind};
+use thiserror::Error;
+
+use crate::source_code_printer::print_source_code_location;
+
+/// A source line to pause comptime evaluation at, written as `<file>:<line>`.
+/// The file may be given as any suffix of its path, such as `main.nr` or `src/main.nr`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SourceBreakpoint {
+    file: PathBuf,
+    line: usize,
+}
+
+#[derive(Error, Debug)]
+pub enum SourceBreakpointFromStrError {
+    #[error("Invalid breakpoint `{0}`, expected `<file>:<line>`")]
+    InvalidSourceBreakpointString(String),
+}
+
+impl FromStr for SourceBreakpoint {
+    type Err = SourceBreakpointFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || SourceBreakpointFromStrError::InvalidSourceBreakpointString(s.to_string());
+        let (file, line) = s.rsplit_once(':').ok_or_else(error)?;
+        let line = line.parse().map_err(|_| error())?;
+        Ok(SourceBreakpoint { file: PathBuf::from(file), line })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StepMode {
+    /// Pause at the first step within the package being compiled.
+    Start,
+    /// Pause only at breakpoints.
+    Continue,
+    /// Pause at the next step.
+    Step,
+    /// Pause at the next step that isn't in a function called from the current one.
+    Next { depth: usize },
+    /// Pause at the next step after the current function returns.
+    Out { depth: usize },
+}
+
+/// A [`ComptimeDebugger`] which pauses comptime evaluation at breakpoints and while stepping,
+/// and reads commands from the terminal while paused.
+pub struct ComptimeReplDebugger {
+    debug_artifact: DebugArtifact,
+    package_root: PathBuf,
+    breakpoints: Vec<SourceBreakpoint>,
+    mode: StepMode,
+}
+
+impl ComptimeReplDebugger {
+    /// Creates a debugger for the comptime code of the package at `package_root`,
+    /// whose source files must all be in `file_manager`.
+    pub fn new(file_manager: &FileManager, package_root: &Path) -> Self {
+        let file_map: BTreeMap<FileId, DebugFile> = file_manager
+            .as_file_map()
+            .all_file_ids()
+            .filter_map(|file_id| {
+                let path = file_manager.path(*file_id)?.to_path_buf();
+                let source = file_manager.fetch_file(*file_id)?.to_string();
+                Some((*file_id, DebugFile { source, path }))
+            })
+            .collect();
+        let debug_artifact = DebugArtifact { debug_symbols: Vec::new(), file_map };
+
+        ComptimeReplDebugger {
+            debug_artifact,
+            package_root: package_root.to_path_buf(),
+            breakpoints: Vec::new(),
+            mode: StepMode::Start,
+        }
+    }
+
+    fn should_pause(&self, step: &ComptimeStep) -> bool {
+        let depth = step.call_stack.len();
+        match self.mode {
+            StepMode::Start => {
+                self.path(step.location).is_some_and(|path| path.starts_with(&self.package_root))
+                    || self.at_breakpoint(step)
+            }
+            StepMode::Step => true,
+            StepMode::Next { depth: current } => depth <= current || self.at_breakpoint(step),
+            StepMode::Out { depth: current } => depth < current || self.at_breakpoint(step),
+            StepMode::Continue => self.at_breakpoint(step),
+        }
+    }
+
+    fn at_breakpoint(&self, step: &ComptimeStep) -> bool {
+        let Some(path) = self.path(step.location) else {
+            return false;
+        };
+        let Ok(line) = self.debug_artifact.location_line_number(step.location) else {
+            return false;
+        };
+        self.breakpoints
+            .iter()
+            .any(|breakpoint| breakpoint.line == line && path.ends_with(&breakpoint.file))
+    }
+
+    fn path(&self, location: Location) -> Option<&Path> {
+        self.debug_artifact.file_map.get(&location.file).map(|file| file.path.as_path())
+    }
+
+    fn show_step(&self, step: &ComptimeStep) {
+        match (&step.kind, step.function_name()) {
+            (ComptimeStepKind::Statement, Some(function)) => {
+                println!("Evaluating a statement in `{function}`");
+            }
+            (ComptimeStepKind::Statement, None) => println!("Evaluating a statement"),
+            (ComptimeStepKind::Quote(tokens), _) => println!("Quoted:\n{tokens}"),
+            (ComptimeStepKind::Expansion(code), _) => println!("Expanding to:\n{code}"),
+        }
+        print_source_code_location(&self.debug_artifact, &[step.location]);
+    }
+
+    fn show_call_stack(&self, step: &ComptimeStep) {
+        print_source_code_location(&self.debug_artifact, &[step.location]);
+        for location in step.call_stack.iter().rev() {
+            print_source_code_location(&self.debug_artifact, &[*location]);
+        }
+    }
+
+    fn add_breakpoint(&mut self, breakpoint: SourceBreakpoint) {
+        if self.breakpoints.contains(&breakpoint) {
+            println!("Breakpoint at {}:{} already set", breakpoint.file.display(), breakpoint.line);
+            return;
+        }
+        println!("Added breakpoint at {}:{}", breakpoint.file.display(), breakpoint.line);
+        self.breakpoints.push(breakpoint);
+    }
+
+    fn delete_breakpoint(&mut self, breakpoint: SourceBreakpoint) {
+        if self.breakpoints.contains(&breakpoint) {
+            self.breakpoints.retain(|existing| existing != &breakpoint);
+            println!("Breakpoint at {}:{} deleted", breakpoint.file.display(), breakpoint.line);
+        } else {
+            println!("Breakpoint at {}:{} not set", breakpoint.file.display(), breakpoint.line);
+        }
+    }
+}
+
+impl ComptimeDebugger for ComptimeReplDebugger {
+    fn on_step(&mut self, step: &ComptimeStep) {
+        if !self.should_pause(step) {
+            return;
+        }
+
+        self.show_step(step);
+
+        // Unless told otherwise, leaving the REPL (e.g. with `quit`) resumes evaluation
+        self.mode = StepMode::Continue;
+
+        let depth = step.call_stack.len();
+        let debugger = RefCell::new(self);
+        let debugger = &debugger;
+
+        let mut repl = Repl::builder()
+            .add(
+                "continue",
+                command! {
+                    "continue evaluation until the next breakpoint",
+                    () => || {
+                        debugger.borrow_mut().mode = StepMode::Continue;
+                        Ok(CommandStatus::Quit)
+                    }
+                },
+            )
+            .add(
+                "step",
+                command! {
+                    "step to the next statement, quote or expansion",
+                    () => || {
+                        debugger.borrow_mut().mode = StepMode::Step;
+                        Ok(CommandStatus::Quit)
+                    }
+                },
+            )
+            .add(
+                "next",
+                command! {
+                    "step to the next statement without entering function calls",
+                    () => || {
+                        debugger.borrow_mut().mode = StepMode::Next { depth };
+                        Ok(CommandStatus::Quit)
+                    }
+                },
+            )
+            .add(
+                "out",
+                command! {
+                    "step until the current function returns",
+                    () => || {
+                        debugger.borrow_mut().mode = StepMode::Out { depth };
+                        Ok(CommandStatus::Quit)
+                    }
+                },
+            )
+            .add(
+                "vars",
+                command! {
+                    "show the local variables in scope, innermost first",
+                    () => || {
+                        for (name, value) in step.variables() {
+                            println!("{name} = {value}");
+                        }
+                        Ok(CommandStatus::Done)
+                    }
+                },
+            )
+            .add(
+                "stacktrace",
+                command! {
+                    "show the comptime call stack, innermost first",
+                    () => || {
+                        debugger.borrow().show_call_stack(step);
+                        Ok(CommandStatus::Done)
+                    }
+                },
+            )
+            .add(
+                "break",
+                command! {
+                    "add a breakpoint at a source line, written as <file>:<line>",
+                    (LOCATION:SourceBreakpoint) => |breakpoint| {
+                        debugger.borrow_mut().add_breakpoint(breakpoint);
+                        Ok(CommandStatus::Done)
+                    }
+                },
+            )
+            .add(
+                "delete",
+                command! {
+                    "delete the breakpoint at a source line, written as <file>:<line>",
+                    (LOCATION:SourceBreakpoint) => |breakpoint| {
+                        debugger.borrow_mut().delete_breakpoint(breakpoint);
+                        Ok(CommandStatus::Done)
+                    }
+                },
+            )
+            .build()
+            .expect("Failed to initialize comptime debugger repl");
+
+        repl.run().expect("Comptime debugger error");
+    }
+}
@@ -1,3 +1,4 @@
+mod comptime;
 mod context;
 mod dap;
 pub mod errors;
@@ -12,6 +13,7 @@ use ::dap::server::Server;
 use acvm::acir::native_types::{WitnessMap, WitnessStack};
 use acvm::{BlackBoxFunctionSolver, FieldElement};
 
+pub use comptime::ComptimeReplDebugger;
 use nargo::NargoError;
 use noirc_driver::CompiledProgram;
 
@@ -0,0 +1,366 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::ControlFlow,
+    path::{Path, PathBuf},
+};
+
+use fm::FileManager;
+use lsp_types::Url;
+use nargo::{package::Package, workspace::Workspace};
+use noirc_driver::{compile_main, CompileOptions, CompiledProgram, DEFAULT_EXPRESSION_WIDTH};
+use noirc_errors::{Location, Span};
+
+use crate::{
+    resolve_workspace_for_source_path, source_overrides, workspace_package_for_file, LspState,
+};
+
+/// The number of opcodes attributed to a span of source code.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct OpcodeCounts {
+    pub(crate) acir: usize,
+    pub(crate) brillig: usize,
+}
+
+impl OpcodeCounts {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.acir == 0 && self.brillig == 0
+    }
+}
+
+impl std::fmt::Display for OpcodeCounts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if self.acir > 0 {
+            parts.push(format!("{} ACIR {}", self.acir, pluralize_opcode(self.acir)));
+        }
+        if self.brillig > 0 {
+            parts.push(format!("{} Brillig {}", self.brillig, pluralize_opcode(self.brillig)));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+fn pluralize_opcode(count: usize) -> &'static str {
+    if count == 1 {
+        "opcode"
+    } else {
+        "opcodes"
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OpcodeKind {
+    Acir,
+    Brillig,
+}
+
+/// Opcode counts for a compiled program, keyed by the source locations
+/// recorded in its debug info.
+#[derive(Debug, Default)]
+pub(crate) struct CircuitCosts {
+    /// The kind of every opcode.
+    opcode_kinds: Vec<OpcodeKind>,
+    /// For every file, the spans in the call stacks of the opcodes sorted by their start, each
+    /// with the index of its opcode in `opcode_kinds`. Files are identified by their paths so
+    /// that the costs don't depend on the `FileId`s of the context used for compiling.
+    locations: HashMap<PathBuf, Vec<(Span, usize)>>,
+}
+
+impl CircuitCosts {
+    fn new(program: &CompiledProgram, file_manager: &FileManager) -> Self {
+        let resolve_call_stack = |locations: &Vec<Location>| {
+            locations
+                .iter()
+                .filter_map(|location| {
+                    let path = file_manager.path(location.file)?;
+                    Some((path.to_path_buf(), location.span))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut opcodes = Vec::new();
+        for debug_info in &program.debug {
+            for locations in debug_info.locations.values() {
+                opcodes.push((OpcodeKind::Acir, resolve_call_stack(locations)));
+            }
+            for brillig_locations in debug_info.brillig_locations.values() {
+                for locations in brillig_locations.values() {
+                    opcodes.push((OpcodeKind::Brillig, resolve_call_stack(locations)));
+                }
+            }
+        }
+
+        Self::from_opcodes(opcodes)
+    }
+
+    /// Indexes the call stacks of `opcodes` by file and span.
+    fn from_opcodes(opcodes: Vec<(OpcodeKind, Vec<(PathBuf, Span)>)>) -> Self {
+        let mut opcode_kinds = Vec::with_capacity(opcodes.len());
+        let mut locations: HashMap<PathBuf, Vec<(Span, usize)>> = HashMap::new();
+        for (index, (kind, call_stack)) in opcodes.into_iter().enumerate() {
+            opcode_kinds.push(kind);
+            for (path, span) in call_stack {
+                locations.entry(path).or_default().push((span, index));
+            }
+        }
+        for file_locations in locations.values_mut() {
+            file_locations.sort_by_key(|(span, _)| span.start());
+        }
+
+        Self { opcode_kinds, locations }
+    }
+
+    /// Returns the number of opcodes that were generated, directly or through calls,
+    /// by the code in the given span.
+    pub(crate) fn opcode_counts(&self, path: &Path, span: Span) -> OpcodeCounts {
+        let mut counts = OpcodeCounts::default();
+        let Some(locations) = self.locations.get(path) else {
+            return counts;
+        };
+
+        // An opcode is only counted once even if several locations of its call stack are in `span`
+        let mut counted_opcodes = HashSet::new();
+        let first =
+            locations.partition_point(|(location_span, _)| location_span.start() < span.start());
+        for (location_span, opcode) in &locations[first..] {
+            if location_span.start() > span.end() {
+                break;
+            }
+            if location_span.end() <= span.end() && counted_opcodes.insert(*opcode) {
+                match self.opcode_kinds[*opcode] {
+                    OpcodeKind::Acir => counts.acir += 1,
+                    OpcodeKind::Brillig => counts.brillig += 1,
+                }
+            }
+        }
+        counts
+    }
+}
+
+/// Makes sure the circuit costs of the package containing the given document are computed,
+/// starting to compile the package's entry point if they weren't computed yet.
+///
+/// Requests only read the costs computed when a document is opened or saved, so that they
+/// never wait for a compilation.
+pub(crate) fn ensure_circuit_costs(state: &mut LspState, document_uri: &Url) {
+    let Some((workspace, package)) = workspace_and_package_for_uri(document_uri) else {
+        return;
+    };
+
+    let root_dir = &package.root_dir;
+    if !state.circuit_costs.contains_key(root_dir)
+        && !state.pending_circuit_costs.contains_key(root_dir)
+    {
+        spawn_circuit_costs_job(state, workspace, package);
+    }
+}
+
+/// Starts recomputing the circuit costs of the package containing the given document.
+pub(crate) fn refresh_circuit_costs(state: &mut LspState, document_uri: &Url) {
+    let Some((workspace, package)) = workspace_and_package_for_uri(document_uri) else {
+        return;
+    };
+
+    spawn_circuit_costs_job(state, workspace, package);
+}
+
+fn workspace_and_package_for_uri(document_uri: &Url) -> Option<(Workspace, Package)> {
+    let file_path = document_uri.to_file_path().ok()?;
+    let workspace = resolve_workspace_for_source_path(&file_path).ok()?;
+    let package = workspace_package_for_file(&workspace, &file_path)?.clone();
+    Some((workspace, package))
+}
+
+/// Compiles the package on another thread, which posts the costs back to the main loop where they
+/// are handled by [on_circuit_costs_computed].
+fn spawn_circuit_costs_job(state: &mut LspState, workspace: Workspace, package: Package) {
+    let job = CircuitCostsJob::new(state, workspace, package);
+    let client = state.client.clone();
+    std::thread::spawn(move || {
+        // The costs can only fail to be posted once the server is shutting down
+        let _ = client.emit(job.run());
+    });
+}
+
+/// The circuit costs of a package, computed by a [CircuitCostsJob].
+pub(crate) struct CircuitCostsComputed {
+    root_dir: PathBuf,
+    generation: u64,
+    /// `None` if the package failed to compile.
+    circuit_costs: Option<CircuitCosts>,
+}
+
+/// Stores the circuit costs computed for a package, unless a more recent compilation of the
+/// package was started since.
+pub(crate) fn on_circuit_costs_computed(
+    state: &mut LspState,
+    computed: CircuitCostsComputed,
+) -> ControlFlow<Result<(), async_lsp::Error>> {
+    if state.pending_circuit_costs.get(&computed.root_dir) != Some(&computed.generation) {
+        return ControlFlow::Continue(());
+    }
+    state.pending_circuit_costs.remove(&computed.root_dir);
+
+    // The costs of a package which doesn't compile are computed again once it's fixed and saved
+    match computed.circuit_costs {
+        Some(circuit_costs) => {
+            state.circuit_costs.insert(computed.root_dir, circuit_costs);
+        }
+        None => {
+            state.circuit_costs.remove(&computed.root_dir);
+        }
+    }
+    ControlFlow::Continue(())
+}
+
+/// A compilation of the entry point of a package, which only owns what it needs so that it can
+/// run away from the main loop.
+struct CircuitCostsJob {
+    workspace: Workspace,
+    package: Package,
+    /// The contents of the open documents, which override the files on disk.
+    input_files: HashMap<String, String>,
+    generation: u64,
+}
+
+impl CircuitCostsJob {
+    fn new(state: &mut LspState, workspace: Workspace, package: Package) -> Self {
+        state.circuit_costs_generation += 1;
+        let generation = state.circuit_costs_generation;
+        state.pending_circuit_costs.insert(package.root_dir.clone(), generation);
+        Self { workspace, package, input_files: state.input_files.clone(), generation }
+    }
+
+    fn run(self) -> CircuitCostsComputed {
+        // Only binary packages have an entry point to compile
+        let circuit_costs =
+            if self.package.is_binary() { self.compile() } else { Some(CircuitCosts::default()) };
+        CircuitCostsComputed {
+            root_dir: self.package.root_dir,
+            generation: self.generation,
+            circuit_costs,
+        }
+    }
+
+    fn compile(&self) -> Option<CircuitCosts> {
+        let mut file_manager = self.workspace.new_file_manager();
+        nargo::insert_all_files_for_workspace_into_file_manager_with_overrides(
+            &self.workspace,
+            &mut file_manager,
+            &source_overrides(&self.input_files),
+        );
+        let parsed_files = nargo::parse_all(&file_manager);
+
+        // The costs are those of the circuit `nargo compile` produces with the default profile
+        let options = CompileOptions::default();
+        let options = match self.workspace.profile(&options) {
+            Some(profile) => profile.apply(&options),
+            None => options,
+        };
+        let expression_width = options
+            .expression_width
+            .or(self.package.expression_width)
+            .unwrap_or(DEFAULT_EXPRESSION_WIDTH);
+
+        // We don't compile in LSP mode as we are only interested in the compiled program
+        let (mut context, crate_id) =
+            nargo::prepare_package(&file_manager, &parsed_files, &self.package);
+        let (program, _warnings) = compile_main(&mut context, crate_id, &options, None).ok()?;
+        let program = nargo::ops::transform_program(program, expression_width);
+
+        Some(CircuitCosts::new(&program, &file_manager))
+    }
+}
+
+/// Computes the circuit costs of the package containing the given document on the current thread.
+#[cfg(test)]
+pub(crate) fn compute_circuit_costs(state: &mut LspState, document_uri: &Url) {
+    let Some((workspace, package)) = workspace_and_package_for_uri(document_uri) else {
+        return;
+    };
+
+    let job = CircuitCostsJob::new(state, workspace, package);
+    let _ = on_circuit_costs_computed(state, job.run());
+}
+
+#[cfg(test)]
+mod circuit_costs_tests {
+    use crate::test_utils;
+
+    use super::*;
+    use tokio::test;
+
+    #[test]
+    async fn test_circuit_costs_of_main_and_unconstrained_function() {
+        let (mut state, noir_text_document) = test_utils::init_lsp_server("circuit_costs").await;
+        compute_circuit_costs(&mut state, &noir_text_document);
+
+        let path = noir_text_document.to_file_path().unwrap();
+        let package_root = path.parent().unwrap().parent().unwrap();
+        let circuit_costs = state.circuit_costs.get(package_root).unwrap();
+
+        let source = std::fs::read_to_string(&path).unwrap();
+        let whole_file = Span::from(0..source.len() as u32);
+        let counts = circuit_costs.opcode_counts(&path, whole_file);
+        assert!(counts.acir > 0);
+        assert!(counts.brillig > 0);
+
+        let empty = Span::from(0..0);
+        assert!(circuit_costs.opcode_counts(&path, empty).is_empty());
+    }
+
+    #[test]
+    async fn test_circuit_costs_of_superseded_compilations_are_ignored() {
+        let (mut state, noir_text_document) = test_utils::init_lsp_server("circuit_costs").await;
+        let (workspace, package) = workspace_and_package_for_uri(&noir_text_document).unwrap();
+        let first = CircuitCostsJob::new(&mut state, workspace.clone(), package.clone());
+        let second = CircuitCostsJob::new(&mut state, workspace, package.clone());
+
+        let _ = on_circuit_costs_computed(&mut state, second.run());
+        assert!(state.circuit_costs.contains_key(&package.root_dir));
+        assert!(state.pending_circuit_costs.is_empty());
+
+        state.circuit_costs.clear();
+        let _ = on_circuit_costs_computed(&mut state, first.run());
+        assert!(state.circuit_costs.is_empty());
+    }
+
+    #[test]
+    async fn test_opcode_counts_count_each_opcode_once() {
+        let path = PathBuf::from("/main.nr");
+        let other_path = PathBuf::from("/other.nr");
+        let circuit_costs = CircuitCosts::from_opcodes(vec![
+            // An opcode generated by a call at 10..20 to a closure defined at 30..40
+            (
+                OpcodeKind::Acir,
+                vec![(path.clone(), Span::from(10..20)), (path.clone(), Span::from(30..40))],
+            ),
+            (OpcodeKind::Brillig, vec![(path.clone(), Span::from(35..38))]),
+            (OpcodeKind::Acir, vec![(other_path.clone(), Span::from(10..20))]),
+        ]);
+
+        let counts = circuit_costs.opcode_counts(&path, Span::from(0..50));
+        assert_eq!(counts, OpcodeCounts { acir: 1, brillig: 1 });
+
+        let counts = circuit_costs.opcode_counts(&path, Span::from(30..40));
+        assert_eq!(counts, OpcodeCounts { acir: 1, brillig: 1 });
+
+        let counts = circuit_costs.opcode_counts(&path, Span::from(15..40));
+        assert_eq!(counts, OpcodeCounts { acir: 1, brillig: 1 });
+
+        let counts = circuit_costs.opcode_counts(&path, Span::from(36..50));
+        assert!(counts.is_empty());
+
+        let counts = circuit_costs.opcode_counts(&other_path, Span::from(0..50));
+        assert_eq!(counts, OpcodeCounts { acir: 1, brillig: 0 });
+    }
+
+    #[test]
+    async fn test_display_opcode_counts() {
+        let counts = OpcodeCounts { acir: 1, brillig: 0 };
+        assert_eq!(counts.to_string(), "1 ACIR opcode");
+
+        let counts = OpcodeCounts { acir: 3, brillig: 12 };
+        assert_eq!(counts.to_string(), "3 ACIR opcodes, 12 Brillig opcodes");
+    }
+}
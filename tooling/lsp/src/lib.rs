@@ -4,7 +4,7 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies, unused_extern_crates))]
 
 use std::{
-    collections::{BTreeMap, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     future::Future,
     ops::{self, ControlFlow},
     path::{Path, PathBuf},
@@ -22,8 +22,9 @@ use fm::{codespan_files as files, FileManager};
 use fxhash::FxHashSet;
 use lsp_types::{
     request::{
-        CodeActionRequest, Completion, DocumentSymbolRequest, HoverRequest, InlayHintRequest,
-        PrepareRenameRequest, References, Rename, SignatureHelpRequest,
+        CodeActionRequest, Completion, DocumentSymbolRequest, ExecuteCommand, FoldingRangeRequest,
+        HoverRequest, InlayHintRequest, PrepareRenameRequest, References, Rename,
+        SelectionRangeRequest, SignatureHelpRequest,
     },
     CodeLens,
 };
@@ -32,7 +33,9 @@ use nargo::{
     parse_all,
     workspace::Workspace,
 };
-use nargo_toml::{find_file_manifest, resolve_workspace_from_toml, PackageSelection};
+use nargo_toml::{
+    find_file_manifest, resolve_workspace_from_toml, FeatureSelection, PackageSelection,
+};
 use noirc_driver::{file_manager_with_stdlib, prepare_crate, NOIR_ARTIFACT_VERSION_STRING};
 use noirc_frontend::{
     graph::{CrateGraph, CrateId, CrateName},
@@ -53,17 +56,19 @@ use notifications::{
 };
 use requests::{
     on_code_action_request, on_code_lens_request, on_completion_request,
-    on_document_symbol_request, on_formatting, on_goto_declaration_request,
+    on_document_symbol_request, on_execute_command_request, on_expand_macro_request,
+    on_folding_range_request, on_formatting, on_goto_declaration_request,
     on_goto_definition_request, on_goto_type_definition_request, on_hover_request, on_initialize,
-    on_inlay_hint_request, on_prepare_rename_request, on_references_request, on_rename_request,
-    on_shutdown, on_signature_help_request, on_test_run_request, on_tests_request,
-    LspInitializationOptions,
+    on_inlay_hint_request, on_on_type_formatting, on_prepare_rename_request, on_range_formatting,
+    on_references_request, on_rename_request, on_selection_range_request, on_shutdown,
+    on_signature_help_request, on_test_run_request, on_tests_request, LspInitializationOptions,
 };
 use serde_json::Value as JsonValue;
 use thiserror::Error;
 use tower::Service;
 
 mod attribute_reference_finder;
+mod circuit_costs;
 mod modules;
 mod notifications;
 mod requests;
@@ -78,6 +83,7 @@ mod visibility;
 #[cfg(test)]
 mod test_utils;
 
+use circuit_costs::{on_circuit_costs_computed, CircuitCosts, CircuitCostsComputed};
 use solver::WrapperSolver;
 use types::{notification, request, NargoTest, NargoTestId, Position, Range, Url};
 
@@ -101,6 +107,13 @@ pub struct LspState {
     package_cache: HashMap<PathBuf, PackageCacheData>,
     options: LspInitializationOptions,
 
+    // Opcode counts of the compiled entry point, by package root.
+    circuit_costs: HashMap<PathBuf, CircuitCosts>,
+
+    // The latest compilation started for computing circuit costs, by package root, until it ends.
+    pending_circuit_costs: HashMap<PathBuf, u64>,
+    circuit_costs_generation: u64,
+
     // Tracks files that currently have errors, by package root.
     files_with_errors: HashMap<PathBuf, HashSet<Url>>,
 }
@@ -133,6 +146,9 @@ impl LspState {
             package_cache: HashMap::new(),
             open_documents_count: 0,
             options: Default::default(),
+            circuit_costs: HashMap::new(),
+            pending_circuit_costs: HashMap::new(),
+            circuit_costs_generation: 0,
             files_with_errors: HashMap::new(),
         }
     }
@@ -152,10 +168,13 @@ impl NargoLspService {
         router
             .request::<request::Initialize, _>(on_initialize)
             .request::<request::Formatting, _>(on_formatting)
+            .request::<request::RangeFormatting, _>(on_range_formatting)
+            .request::<request::OnTypeFormatting, _>(on_on_type_formatting)
             .request::<request::Shutdown, _>(on_shutdown)
             .request::<request::CodeLens, _>(on_code_lens_request)
             .request::<request::NargoTests, _>(on_tests_request)
             .request::<request::NargoTestRun, _>(on_test_run_request)
+            .request::<request::NargoExpandMacro, _>(on_expand_macro_request)
             .request::<request::GotoDefinition, _>(on_goto_definition_request)
             .request::<request::GotoDeclaration, _>(on_goto_declaration_request)
             .request::<request::GotoTypeDefinition, _>(on_goto_type_definition_request)
@@ -168,13 +187,17 @@ impl NargoLspService {
             .request::<Completion, _>(on_completion_request)
             .request::<SignatureHelpRequest, _>(on_signature_help_request)
             .request::<CodeActionRequest, _>(on_code_action_request)
+            .request::<FoldingRangeRequest, _>(on_folding_range_request)
+            .request::<SelectionRangeRequest, _>(on_selection_range_request)
+            .request::<ExecuteCommand, _>(on_execute_command_request)
             .notification::<notification::Initialized>(on_initialized)
             .notification::<notification::DidChangeConfiguration>(on_did_change_configuration)
             .notification::<notification::DidOpenTextDocument>(on_did_open_text_document)
             .notification::<notification::DidChangeTextDocument>(on_did_change_text_document)
             .notification::<notification::DidCloseTextDocument>(on_did_close_text_document)
             .notification::<notification::DidSaveTextDocument>(on_did_save_text_document)
-            .notification::<notification::Exit>(on_exit);
+            .notification::<notification::Exit>(on_exit)
+            .event::<CircuitCostsComputed>(on_circuit_costs_computed);
         Self { router }
     }
 }
@@ -274,6 +297,8 @@ pub(crate) fn resolve_workspace_for_source_path(file_path: &Path) -> Result<Work
             &toml_path,
             PackageSelection::All,
             Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+            false,
+            FeatureSelection::default(),
         ) {
             Ok(workspace) => return Ok(workspace),
             Err(error) => {
@@ -309,13 +334,19 @@ pub(crate) fn resolve_workspace_for_source_path(file_path: &Path) -> Result<Work
         entry_path: PathBuf::from(file_path),
         name: crate_name,
         dependencies: BTreeMap::new(),
+        dev_dependencies: BTreeMap::new(),
         expression_width: None,
+        lints: Vec::new(),
+        features: BTreeMap::new(),
+        enabled_features: BTreeSet::new(),
     };
     let workspace = Workspace {
         root_dir: PathBuf::from(parent_folder),
         members: vec![assumed_package],
         selected_package_index: Some(0),
         is_assumed: true,
+        profiles: BTreeMap::new(),
+        comptime_inputs: Default::default(),
     };
     Ok(workspace)
 }
@@ -341,6 +372,18 @@ pub(crate) fn prepare_package<'file_manager, 'parsed_files>(
     (context, crate_id)
 }
 
+/// Prepares `package` for listing or running its tests, linking its dev-dependencies.
+pub(crate) fn prepare_package_for_tests<'file_manager, 'parsed_files>(
+    file_manager: &'file_manager FileManager,
+    parsed_files: &'parsed_files ParsedFiles,
+    package: &Package,
+) -> (Context<'file_manager, 'parsed_files>, CrateId) {
+    let (mut context, crate_id) =
+        nargo::prepare_package_for_tests(file_manager, parsed_files, package);
+    context.activate_lsp_mode();
+    (context, crate_id)
+}
+
 /// Prepares a package from a source string
 /// This is useful for situations when we don't need dependencies
 /// and just need to operate on single file.
@@ -427,20 +470,23 @@ pub fn insert_all_files_for_workspace_into_file_manager(
     workspace: &Workspace,
     file_manager: &mut FileManager,
 ) {
-    // Source code for files we cached override those that are read from disk.
-    let mut overrides: HashMap<&Path, &str> = HashMap::new();
-    for (path, source) in &state.input_files {
-        let path = path.strip_prefix("file://").unwrap();
-        overrides.insert(Path::new(path), source);
-    }
-
     nargo::insert_all_files_for_workspace_into_file_manager_with_overrides(
         workspace,
         file_manager,
-        &overrides,
+        &source_overrides(&state.input_files),
     );
 }
 
+/// Source code for files we cached override those that are read from disk.
+fn source_overrides(input_files: &HashMap<String, String>) -> HashMap<&Path, &str> {
+    let mut overrides: HashMap<&Path, &str> = HashMap::new();
+    for (path, source) in input_files {
+        let path = path.strip_prefix("file://").unwrap();
+        overrides.insert(Path::new(path), source);
+    }
+    overrides
+}
+
 #[test]
 fn prepare_package_from_source_string() {
     let source = r#"
@@ -459,3 +505,24 @@ fn prepare_package_from_source_string() {
     let main_func_id = context.get_main_function(&crate_id);
     assert!(main_func_id.is_some());
 }
+
+#[test]
+fn links_dev_dependencies_only_for_tests() {
+    let root_path = std::env::current_dir().unwrap().join("test_programs").join("dev_dependencies");
+    let workspace =
+        resolve_workspace_for_source_path(&root_path.join("src").join("main.nr")).unwrap();
+    let package = &workspace.members[0];
+    let mut file_manager = workspace.new_file_manager();
+    nargo::insert_all_files_for_workspace_into_file_manager(&workspace, &mut file_manager);
+    let parsed_files = nargo::parse_all(&file_manager);
+
+    // Diagnostics don't see the tests, which are the only functions using the dev-dependencies
+    let (mut context, crate_id) = prepare_package(&file_manager, &parsed_files, package);
+    assert!(noirc_driver::check_crate(&mut context, crate_id, &Default::default()).is_ok());
+    assert!(get_package_tests_in_crate(&context, &crate_id, &package.name).is_none());
+
+    let (mut context, crate_id) = prepare_package_for_tests(&file_manager, &parsed_files, package);
+    assert!(noirc_driver::check_crate(&mut context, crate_id, &Default::default()).is_ok());
+    let tests = get_package_tests_in_crate(&context, &crate_id, &package.name).unwrap();
+    assert_eq!(tests.len(), 1);
+}
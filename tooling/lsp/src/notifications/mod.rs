@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::ops::ControlFlow;
 use std::path::PathBuf;
 
+use crate::circuit_costs::{ensure_circuit_costs, refresh_circuit_costs};
 use crate::{
     insert_all_files_for_workspace_into_file_manager, PackageCacheData, WorkspaceCacheData,
 };
@@ -47,6 +48,10 @@ pub(crate) fn on_did_open_text_document(
     let document_uri = params.text_document.uri;
     let output_diagnostics = true;
 
+    if circuit_costs_enabled(state) {
+        ensure_circuit_costs(state, &document_uri);
+    }
+
     match process_workspace_for_noir_document(state, document_uri, output_diagnostics) {
         Ok(_) => {
             state.open_documents_count += 1;
@@ -102,12 +107,21 @@ pub(super) fn on_did_save_text_document(
     let document_uri = params.text_document.uri;
     let output_diagnostics = true;
 
+    // Circuit costs require a full compilation so we only refresh them when a document is saved
+    if circuit_costs_enabled(state) {
+        refresh_circuit_costs(state, &document_uri);
+    }
+
     match process_workspace_for_noir_document(state, document_uri, output_diagnostics) {
         Ok(_) => ControlFlow::Continue(()),
         Err(err) => ControlFlow::Break(Err(err)),
     }
 }
 
+fn circuit_costs_enabled(state: &LspState) -> bool {
+    state.options.inlay_hints.circuit_cost_hints.enabled || state.options.enable_circuit_cost_hover
+}
+
 // Given a Noir document, find the workspace it's contained in (an assumed workspace is created if
 // it's only contained in a package), then type-checks the workspace's packages,
 // caching code lenses and type definitions, and notifying about compilation errors.
@@ -143,8 +157,22 @@ pub(crate) fn process_workspace_for_noir_document(
             Err(errors_and_warnings) => errors_and_warnings,
         };
 
+        // Dev-dependencies are only linked when compiling tests, so the tests of a package which has
+        // some are listed, along with its code lenses, from a compilation of its tests
+        let test_context = (!package.dev_dependencies.is_empty()).then(|| {
+            let (mut test_context, test_crate_id) =
+                crate::prepare_package_for_tests(&workspace_file_manager, &parsed_files, package);
+            let _ = check_crate(&mut test_context, test_crate_id, &Default::default());
+            (test_context, test_crate_id)
+        });
+        let (lens_context, lens_crate_id) = match &test_context {
+            Some((test_context, test_crate_id)) => (test_context, *test_crate_id),
+            None => (&context, crate_id),
+        };
+
         // We don't add test headings for a package if it contains no `#[test]` functions
-        if let Some(tests) = get_package_tests_in_crate(&context, &crate_id, &package.name) {
+        if let Some(tests) = get_package_tests_in_crate(lens_context, &lens_crate_id, &package.name)
+        {
             let _ = state.client.notify::<notification::NargoUpdateTests>(NargoPackageTests {
                 package: package.name.to_string(),
                 tests,
@@ -152,11 +180,12 @@ pub(crate) fn process_workspace_for_noir_document(
         }
 
         let collected_lenses = crate::requests::collect_lenses_for_package(
-            &context,
-            crate_id,
+            lens_context,
+            lens_crate_id,
             &workspace,
             package,
             Some(&file_path),
+            state.options.enable_server_command_lenses,
         );
         state.cached_lenses.insert(document_uri.to_string(), collected_lenses);
         state.package_cache.insert(
@@ -232,7 +261,7 @@ fn publish_diagnostics(
     state.files_with_errors.insert(package_root_dir.clone(), new_files_with_errors);
 }
 
-fn file_diagnostic_to_diagnostic(
+pub(crate) fn file_diagnostic_to_diagnostic(
     file_diagnostic: FileDiagnostic,
     files: &FileMap,
     fm: &FileManager,
@@ -1,14 +1,22 @@
-use std::future::{self, Future};
+use std::{
+    collections::HashSet,
+    future::{self, Future},
+};
 
 use async_lsp::{ErrorCode, ResponseError};
+use lsp_types::{TextDocumentIdentifier, TextDocumentPositionParams, Url};
 
 use nargo::{package::Package, workspace::Workspace};
 use noirc_driver::check_crate;
 use noirc_frontend::hir::FunctionNameMatch;
 
+use super::to_lsp_location;
 use crate::{
     byte_span_to_range, prepare_source, resolve_workspace_for_source_path,
-    types::{CodeLens, CodeLensParams, CodeLensResult, Command},
+    types::{
+        CodeLens, CodeLensParams, CodeLensResult, Command, NargoCommandArguments,
+        NARGO_EXECUTE_COMMAND, NARGO_INFO_COMMAND,
+    },
     LspState,
 };
 
@@ -23,11 +31,22 @@ const EXECUTE_COMMAND: &str = "nargo.execute";
 const EXECUTE_CODELENS_TITLE: &str = "Execute";
 const DEBUG_COMMAND: &str = "nargo.debug.dap";
 const DEBUG_CODELENS_TITLE: &str = "Debug";
+const EXPAND_MACRO_COMMAND: &str = "nargo.expandMacro";
+const EXPAND_MACRO_CODELENS_TITLE: &str = "Expand macro";
 
 fn with_arrow(title: &str) -> String {
     format!("{ARROW} {title}")
 }
 
+/// Arguments for the commands handled by the server through `workspace/executeCommand`.
+fn server_command_args(package: &Package) -> Vec<serde_json::Value> {
+    let Ok(uri) = Url::from_file_path(&package.entry_path) else {
+        return Vec::new();
+    };
+    let arguments = NargoCommandArguments { uri, prover_name: None, test_name: None };
+    vec![serde_json::to_value(arguments).unwrap_or_default()]
+}
+
 fn package_selection_args(workspace: &Workspace, package: &Package) -> Vec<serde_json::Value> {
     vec![
         "--program-dir".into(),
@@ -71,8 +90,14 @@ fn on_code_lens_request_inner(
     // because we can still get the test functions even if compilation fails
     let _ = check_crate(&mut context, crate_id, &Default::default());
 
-    let collected_lenses =
-        collect_lenses_for_package(&context, crate_id, &workspace, package, None);
+    let collected_lenses = collect_lenses_for_package(
+        &context,
+        crate_id,
+        &workspace,
+        package,
+        None,
+        state.options.enable_server_command_lenses,
+    );
 
     if collected_lenses.is_empty() {
         state.cached_lenses.remove(&params.text_document.uri.to_string());
@@ -91,6 +116,7 @@ pub(crate) fn collect_lenses_for_package(
     workspace: &Workspace,
     package: &Package,
     file_path: Option<&std::path::PathBuf>,
+    server_command_lenses: bool,
 ) -> Vec<CodeLens> {
     let mut lenses: Vec<CodeLens> = vec![];
     let fm = &context.file_manager;
@@ -117,7 +143,7 @@ pub(crate) fn collect_lenses_for_package(
             arguments: Some(
                 [
                     package_selection_args(workspace, package),
-                    vec!["--exact".into(), "--show-output".into(), func_name.into()],
+                    vec!["--exact".into(), "--show-output".into(), func_name.clone().into()],
                 ]
                 .concat(),
             ),
@@ -128,6 +154,42 @@ pub(crate) fn collect_lenses_for_package(
         lenses.push(test_lens);
     }
 
+    // Offer to expand code generated by comptime code in this crate (but not in its dependencies)
+    let crate_files: HashSet<_> = context.def_maps[&crate_id]
+        .modules()
+        .iter()
+        .map(|(_, module_data)| module_data.location.file)
+        .collect();
+    for (location, _) in context.def_interner.comptime_expansions() {
+        let file_id = location.file;
+        if !crate_files.contains(&file_id) {
+            continue;
+        }
+
+        if let Some(file_path) = file_path {
+            if fm.path(file_id).expect("file must exist to contain comptime code") != *file_path {
+                continue;
+            }
+        }
+
+        let Some(lsp_location) = to_lsp_location(files, file_id, location.span) else {
+            continue;
+        };
+
+        let params = TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: lsp_location.uri },
+            position: lsp_location.range.start,
+        };
+        let expand_macro_command = Command {
+            title: EXPAND_MACRO_CODELENS_TITLE.to_string(),
+            command: EXPAND_MACRO_COMMAND.into(),
+            arguments: Some(vec![serde_json::to_value(params).unwrap_or_default()]),
+        };
+
+        let range = lsp_location.range;
+        lenses.push(CodeLens { range, command: Some(expand_macro_command), data: None });
+    }
+
     if package.is_binary() {
         if let Some(main_func_id) = context.get_main_function(&crate_id) {
             let location = context.function_meta(&main_func_id).name.location;
@@ -156,21 +218,41 @@ pub(crate) fn collect_lenses_for_package(
 
             lenses.push(compile_lens);
 
-            let internal_command_lenses = [
-                (INFO_CODELENS_TITLE, INFO_COMMAND),
-                (EXECUTE_CODELENS_TITLE, EXECUTE_COMMAND),
-                (DEBUG_CODELENS_TITLE, DEBUG_COMMAND),
-            ]
-            .map(|(title, command)| {
-                let command = Command {
-                    title: title.to_string(),
-                    command: command.into(),
-                    arguments: Some(package_selection_args(workspace, package)),
-                };
-                CodeLens { range, command: Some(command), data: None }
-            });
+            let internal_command_lenses = if server_command_lenses {
+                [
+                    (INFO_CODELENS_TITLE, NARGO_INFO_COMMAND),
+                    (EXECUTE_CODELENS_TITLE, NARGO_EXECUTE_COMMAND),
+                ]
+                .map(|(title, command)| {
+                    let command = Command {
+                        title: title.to_string(),
+                        command: command.into(),
+                        arguments: Some(server_command_args(package)),
+                    };
+                    CodeLens { range, command: Some(command), data: None }
+                })
+            } else {
+                [(INFO_CODELENS_TITLE, INFO_COMMAND), (EXECUTE_CODELENS_TITLE, EXECUTE_COMMAND)]
+                    .map(|(title, command)| {
+                        let command = Command {
+                            title: title.to_string(),
+                            command: command.into(),
+                            arguments: Some(package_selection_args(workspace, package)),
+                        };
+                        CodeLens { range, command: Some(command), data: None }
+                    })
+            };
 
             lenses.append(&mut Vec::from(internal_command_lenses));
+
+            // The client starts debugging sessions, as it runs the debugger as a DAP server
+            let debug_command = Command {
+                title: DEBUG_CODELENS_TITLE.to_string(),
+                command: DEBUG_COMMAND.into(),
+                arguments: Some(package_selection_args(workspace, package)),
+            };
+
+            lenses.push(CodeLens { range, command: Some(debug_command), data: None });
         }
     }
 
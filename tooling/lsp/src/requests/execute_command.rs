@@ -0,0 +1,359 @@
+use std::future::{self, Future};
+
+use async_lsp::{ErrorCode, ResponseError};
+use fm::FileManager;
+use lsp_types::{ExecuteCommandParams, Url};
+use nargo::{
+    constants::PROVER_INPUT_FILE,
+    errors::try_to_diagnose_runtime_error,
+    ops::{execute_program, DefaultForeignCallExecutor},
+    package::Package,
+    workspace::Workspace,
+};
+use noirc_abi::{input_parser::Format, InputMap, MAIN_RETURN_NAME};
+use noirc_driver::{compile_main, CompileOptions, CompiledProgram};
+use serde_json::Value as JsonValue;
+
+use crate::{
+    insert_all_files_for_workspace_into_file_manager,
+    notifications::file_diagnostic_to_diagnostic,
+    parse_diff, resolve_workspace_for_source_path,
+    types::{
+        NargoCommandArguments, NargoDebugConfiguration, NargoDiagnostic, NargoExecuteResult,
+        NargoFunctionInfo, NargoInfoResult, NARGO_DEBUG_CONFIGURATION_COMMAND,
+        NARGO_EXECUTE_COMMAND, NARGO_INFO_COMMAND,
+    },
+    workspace_package_for_file, LspState,
+};
+
+pub(crate) fn on_execute_command_request(
+    state: &mut LspState,
+    params: ExecuteCommandParams,
+) -> impl Future<Output = Result<Option<JsonValue>, ResponseError>> {
+    future::ready(on_execute_command_request_inner(state, params))
+}
+
+fn on_execute_command_request_inner(
+    state: &mut LspState,
+    params: ExecuteCommandParams,
+) -> Result<Option<JsonValue>, ResponseError> {
+    let arguments: NargoCommandArguments = params
+        .arguments
+        .into_iter()
+        .next()
+        .and_then(|argument| serde_json::from_value(argument).ok())
+        .ok_or_else(|| {
+            ResponseError::new(ErrorCode::INVALID_PARAMS, "Missing or invalid command arguments")
+        })?;
+
+    let (workspace, package) = resolve_package(&arguments.uri)?;
+
+    let result = match params.command.as_str() {
+        NARGO_EXECUTE_COMMAND => {
+            let result = execute_package(state, &workspace, &package, &arguments)?;
+            serde_json::to_value(result)
+        }
+        NARGO_INFO_COMMAND => {
+            let result = package_info(state, &workspace, &package)?;
+            serde_json::to_value(result)
+        }
+        NARGO_DEBUG_CONFIGURATION_COMMAND => {
+            let result = debug_configuration(&workspace, &package, arguments);
+            serde_json::to_value(result)
+        }
+        command => {
+            return Err(ResponseError::new(
+                ErrorCode::INVALID_PARAMS,
+                format!("Unknown command: {command}"),
+            ))
+        }
+    };
+
+    result.map(Some).map_err(|err| ResponseError::new(ErrorCode::INTERNAL_ERROR, err.to_string()))
+}
+
+fn resolve_package(uri: &Url) -> Result<(Workspace, Package), ResponseError> {
+    let file_path = uri.to_file_path().map_err(|_| {
+        ResponseError::new(ErrorCode::REQUEST_FAILED, "URI is not a valid file path")
+    })?;
+
+    let workspace = resolve_workspace_for_source_path(&file_path).map_err(|lsp_error| {
+        ResponseError::new(ErrorCode::REQUEST_FAILED, lsp_error.to_string())
+    })?;
+
+    let package = workspace_package_for_file(&workspace, &file_path).cloned().ok_or_else(|| {
+        ResponseError::new(ErrorCode::REQUEST_FAILED, "Could not find package for file")
+    })?;
+
+    Ok((workspace, package))
+}
+
+/// Compiles the package's `main` function, returning the compiled program together with
+/// the file manager that holds the files it was compiled from.
+fn compile_package(
+    state: &mut LspState,
+    workspace: &Workspace,
+    package: &Package,
+) -> Result<(CompiledProgram, FileManager), ResponseError> {
+    if !package.is_binary() {
+        return Err(ResponseError::new(
+            ErrorCode::REQUEST_FAILED,
+            format!("Package `{}` is not a binary package", package.name),
+        ));
+    }
+
+    let mut workspace_file_manager = workspace.new_file_manager();
+    insert_all_files_for_workspace_into_file_manager(state, workspace, &mut workspace_file_manager);
+    let parsed_files = parse_diff(&workspace_file_manager, state);
+
+    let (mut context, crate_id) =
+        nargo::prepare_package(&workspace_file_manager, &parsed_files, package);
+    let (program, _warnings) =
+        compile_main(&mut context, crate_id, &CompileOptions::default(), None).map_err(|_| {
+            ResponseError::new(ErrorCode::REQUEST_FAILED, "The project failed to compile")
+        })?;
+
+    Ok((program, workspace_file_manager))
+}
+
+fn execute_package(
+    state: &mut LspState,
+    workspace: &Workspace,
+    package: &Package,
+    arguments: &NargoCommandArguments,
+) -> Result<NargoExecuteResult, ResponseError> {
+    let (program, file_manager) = compile_package(state, workspace, package)?;
+
+    let prover_name = arguments.prover_name.as_deref().unwrap_or(PROVER_INPUT_FILE);
+    let inputs_map = read_inputs(package, prover_name, &program)?;
+    let initial_witness = program
+        .abi
+        .encode(&inputs_map, None)
+        .map_err(|err| ResponseError::new(ErrorCode::REQUEST_FAILED, err.to_string()))?;
+
+    let mut foreign_call_executor = DefaultForeignCallExecutor::new(
+        false,
+        None,
+        Some(workspace.root_dir.clone()),
+        Some(package.name.to_string()),
+    )
+    .with_captured_output();
+
+    let execution_result = execute_program(
+        &program.program,
+        initial_witness,
+        &state.solver,
+        &mut foreign_call_executor,
+    );
+    let output = foreign_call_executor.take_captured_output().unwrap_or_default();
+    let package_name = package.name.to_string();
+
+    match execution_result {
+        Ok(witness_stack) => {
+            let main_witness = &witness_stack
+                .peek()
+                .expect("Should have at least one witness on the stack")
+                .witness;
+            let values = program.abi.decode(main_witness).ok().and_then(|(mut values, ret)| {
+                if let Some(return_value) = ret {
+                    values.insert(MAIN_RETURN_NAME.to_string(), return_value);
+                }
+                let json = Format::Json.serialize(&values, &program.abi).ok()?;
+                serde_json::from_str(&json).ok()
+            });
+
+            Ok(NargoExecuteResult {
+                package: package_name,
+                success: true,
+                values,
+                output,
+                diagnostic: None,
+            })
+        }
+        Err(err) => {
+            let diagnostic = try_to_diagnose_runtime_error(&err, &program.abi, &program.debug)
+                .and_then(|file_diagnostic| {
+                    let path = file_manager.path(file_diagnostic.file_id)?;
+                    let uri = Url::from_file_path(path).ok()?;
+                    let files = file_manager.as_file_map();
+                    let diagnostic = file_diagnostic_to_diagnostic(
+                        file_diagnostic,
+                        files,
+                        &file_manager,
+                        uri.clone(),
+                    )?;
+                    Some(NargoDiagnostic { uri: Some(uri), diagnostic })
+                })
+                .unwrap_or_else(|| NargoDiagnostic {
+                    uri: None,
+                    diagnostic: lsp_types::Diagnostic {
+                        message: err.to_string(),
+                        severity: Some(lsp_types::DiagnosticSeverity::ERROR),
+                        ..Default::default()
+                    },
+                });
+
+            Ok(NargoExecuteResult {
+                package: package_name,
+                success: false,
+                values: None,
+                output,
+                diagnostic: Some(diagnostic),
+            })
+        }
+    }
+}
+
+fn read_inputs(
+    package: &Package,
+    prover_name: &str,
+    program: &CompiledProgram,
+) -> Result<InputMap, ResponseError> {
+    if program.abi.parameters.is_empty() {
+        return Ok(InputMap::new());
+    }
+
+    let file_path = package.root_dir.join(prover_name).with_extension(Format::Toml.ext());
+    let input_string = std::fs::read_to_string(&file_path).map_err(|_| {
+        ResponseError::new(
+            ErrorCode::REQUEST_FAILED,
+            format!("Could not read program inputs from {}", file_path.display()),
+        )
+    })?;
+
+    let mut inputs_map = Format::Toml
+        .parse(&input_string, &program.abi)
+        .map_err(|err| ResponseError::new(ErrorCode::REQUEST_FAILED, err.to_string()))?;
+    inputs_map.remove(MAIN_RETURN_NAME);
+    Ok(inputs_map)
+}
+
+fn package_info(
+    state: &mut LspState,
+    workspace: &Workspace,
+    package: &Package,
+) -> Result<NargoInfoResult, ResponseError> {
+    let (program, _) = compile_package(state, workspace, package)?;
+
+    let functions = program
+        .program
+        .functions
+        .iter()
+        .zip(&program.names)
+        .map(|(function, name)| NargoFunctionInfo {
+            name: name.clone(),
+            opcodes: function.opcodes.len(),
+        })
+        .collect();
+    let unconstrained_functions = program
+        .program
+        .unconstrained_functions
+        .iter()
+        .zip(&program.brillig_names)
+        .map(|(function, name)| NargoFunctionInfo {
+            name: name.clone(),
+            opcodes: function.bytecode.len(),
+        })
+        .collect();
+
+    Ok(NargoInfoResult { package: package.name.to_string(), functions, unconstrained_functions })
+}
+
+fn debug_configuration(
+    workspace: &Workspace,
+    package: &Package,
+    arguments: NargoCommandArguments,
+) -> NargoDebugConfiguration {
+    let name = match &arguments.test_name {
+        Some(test_name) => format!("Debug test {test_name}"),
+        None => format!("Debug {}", package.name),
+    };
+
+    NargoDebugConfiguration {
+        debug_type: "noir".to_string(),
+        request: "launch".to_string(),
+        name,
+        project_folder: workspace.root_dir.display().to_string(),
+        package: package.name.to_string(),
+        prover_name: arguments.prover_name.unwrap_or_else(|| PROVER_INPUT_FILE.to_string()),
+        test_name: arguments.test_name,
+    }
+}
+
+#[cfg(test)]
+mod execute_command_tests {
+    use lsp_types::WorkDoneProgressParams;
+    use tokio::test;
+
+    use crate::test_utils;
+
+    use super::*;
+
+    async fn execute_command(command: &str, arguments: JsonValue) -> JsonValue {
+        let (mut state, _) = test_utils::init_lsp_server("execute_command").await;
+        let params = ExecuteCommandParams {
+            command: command.to_string(),
+            arguments: vec![arguments],
+            work_done_progress_params: WorkDoneProgressParams { work_done_token: None },
+        };
+
+        on_execute_command_request(&mut state, params)
+            .await
+            .expect("Could not execute command")
+            .expect("Expected a command result")
+    }
+
+    fn main_uri() -> Url {
+        let path = std::env::current_dir()
+            .unwrap()
+            .join("test_programs/execute_command/src/main.nr")
+            .canonicalize()
+            .unwrap();
+        Url::from_file_path(path).unwrap()
+    }
+
+    #[test]
+    async fn test_execute_command_runs_main() {
+        let arguments = serde_json::json!({ "uri": main_uri() });
+        let result = execute_command(NARGO_EXECUTE_COMMAND, arguments).await;
+        let result: NargoExecuteResult = serde_json::from_value(result).unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.output, "executing main\n");
+        assert_eq!(result.values.unwrap()["return"], serde_json::json!("0x06"));
+    }
+
+    #[test]
+    async fn test_execute_command_reports_failing_assertion() {
+        let arguments = serde_json::json!({ "uri": main_uri(), "proverName": "Failing" });
+        let result = execute_command(NARGO_EXECUTE_COMMAND, arguments).await;
+        let result: NargoExecuteResult = serde_json::from_value(result).unwrap();
+
+        assert!(!result.success);
+        let diagnostic = result.diagnostic.unwrap();
+        assert_eq!(diagnostic.uri, Some(main_uri()));
+        assert_eq!(diagnostic.diagnostic.range.start.line, 2);
+    }
+
+    #[test]
+    async fn test_info_command_counts_opcodes() {
+        let arguments = serde_json::json!({ "uri": main_uri() });
+        let result = execute_command(NARGO_INFO_COMMAND, arguments).await;
+        let result: NargoInfoResult = serde_json::from_value(result).unwrap();
+
+        assert_eq!(result.package, "execute_command");
+        assert_eq!(result.functions[0].name, "main");
+        assert!(result.functions[0].opcodes > 0);
+    }
+
+    #[test]
+    async fn test_debug_configuration_command_returns_launch_configuration() {
+        let arguments = serde_json::json!({ "uri": main_uri(), "testName": "test_double" });
+        let result = execute_command(NARGO_DEBUG_CONFIGURATION_COMMAND, arguments).await;
+
+        assert_eq!(result["type"], "noir");
+        assert_eq!(result["request"], "launch");
+        assert_eq!(result["package"], "execute_command");
+        assert_eq!(result["testName"], "test_double");
+    }
+}
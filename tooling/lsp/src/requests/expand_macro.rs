@@ -0,0 +1,251 @@
+use std::future::{self, Future};
+
+use async_lsp::ResponseError;
+use fm::FileMap;
+use lsp_types::{
+    Location, Position, Range, TextDocumentIdentifier, TextDocumentPositionParams, Url,
+};
+use noirc_frontend::node_interner::{NodeInterner, ReferenceId};
+
+use crate::{
+    types::{NargoExpandMacroResult, NARGO_EXPANSION_SCHEME},
+    LspState,
+};
+
+use super::{process_request, to_lsp_location};
+
+pub(crate) fn on_expand_macro_request(
+    state: &mut LspState,
+    params: TextDocumentPositionParams,
+) -> impl Future<Output = Result<Option<NargoExpandMacroResult>, ResponseError>> {
+    // The client might ask for the contents of an expansion URI we previously returned
+    // (for example as the result of a "go to definition" request).
+    let params = from_expansion_uri(&params.text_document.uri).unwrap_or(params);
+
+    let result = process_request(state, params, |args| {
+        let (location, code) = find_expansion(args.interner, args.location)?;
+        let lsp_location = to_lsp_location(args.files, location.file, location.span)?;
+        let uri = expansion_uri(&lsp_location.uri, lsp_location.range.start)?;
+        Some(NargoExpandMacroResult { uri, range: lsp_location.range, contents: code })
+    });
+    future::ready(result)
+}
+
+/// Finds the innermost location that generated code through comptime code
+/// and that contains the given location, together with the generated code.
+pub(crate) fn find_expansion(
+    interner: &NodeInterner,
+    location: noirc_errors::Location,
+) -> Option<(noirc_errors::Location, String)> {
+    interner
+        .comptime_expansions()
+        .filter(|(expansion_location, _)| expansion_location.contains(&location))
+        .min_by_key(|(expansion_location, _)| {
+            expansion_location.span.end() - expansion_location.span.start()
+        })
+        .map(|(expansion_location, code)| (*expansion_location, code.join("\n\n")))
+}
+
+/// If `definition` was generated by comptime code, returns an LSP location pointing
+/// to its declaration inside the rendered expansion instead of to the code that generated it.
+pub(crate) fn definition_location_in_expansion(
+    interner: &NodeInterner,
+    files: &FileMap,
+    definition: ReferenceId,
+) -> Option<Location> {
+    let definition_location = interner.reference_location(definition);
+    let (location, code) = find_expansion(interner, definition_location)?;
+    let lsp_location = to_lsp_location(files, location.file, location.span)?;
+    let uri = expansion_uri(&lsp_location.uri, lsp_location.range.start)?;
+    let range = find_declaration_in_code(interner, &code, definition)?;
+    Some(Location { uri, range })
+}
+
+/// Returns a URI representing the code generated at the given position of the given file.
+/// Clients can fetch the contents of these URIs by sending a `nargo/expandMacro` request.
+///
+/// The path of the file is only there for clients to display. The file's URI is carried as is
+/// in the query, so that it doesn't have to be rebuilt from the (already percent-encoded) path.
+pub(crate) fn expansion_uri(file_uri: &Url, position: Position) -> Option<Url> {
+    let mut uri = Url::parse(&format!("{}:{}", NARGO_EXPANSION_SCHEME, file_uri.path())).ok()?;
+    uri.query_pairs_mut()
+        .append_pair("uri", file_uri.as_str())
+        .append_pair("line", &position.line.to_string())
+        .append_pair("character", &position.character.to_string());
+    Some(uri)
+}
+
+fn from_expansion_uri(uri: &Url) -> Option<TextDocumentPositionParams> {
+    if uri.scheme() != NARGO_EXPANSION_SCHEME {
+        return None;
+    }
+
+    let mut file_uri = None;
+    let mut line = None;
+    let mut character = None;
+    for (key, value) in uri.query_pairs() {
+        match key.as_ref() {
+            "uri" => file_uri = Url::parse(&value).ok(),
+            "line" => line = value.parse().ok(),
+            "character" => character = value.parse().ok(),
+            _ => (),
+        }
+    }
+
+    let position = Position { line: line?, character: character? };
+    let text_document = TextDocumentIdentifier { uri: file_uri? };
+    Some(TextDocumentPositionParams { text_document, position })
+}
+
+/// Finds where `definition` is declared in the rendered code of an expansion.
+///
+/// Generated code carries the location of the code that generated it, so declarations are
+/// looked up by the kind and name of the definition (`fn foo`, `struct Foo`, etc.).
+/// Local variables can't be told apart this way, so they aren't looked up.
+fn find_declaration_in_code(
+    interner: &NodeInterner,
+    code: &str,
+    definition: ReferenceId,
+) -> Option<Range> {
+    let (index, name) = match definition {
+        ReferenceId::Module(id) => {
+            let name = interner.module_attributes(&id).name.clone();
+            (find_declaration(code, "mod", &name)?, name)
+        }
+        ReferenceId::Struct(id) => {
+            let name = interner.get_struct(id).borrow().name.to_string();
+            (find_declaration(code, "struct", &name)?, name)
+        }
+        ReferenceId::StructMember(id, field_index) => {
+            let struct_type = interner.get_struct(id);
+            let struct_type = struct_type.borrow();
+            let struct_index = find_declaration(code, "struct", &struct_type.name.to_string())?;
+            let name = struct_type.field_at(field_index).name.to_string();
+            let index = find_word(code, struct_index, &name, |_, after| after.starts_with(':'))?;
+            (index, name)
+        }
+        ReferenceId::Trait(id) => {
+            let name = interner.get_trait(id).name.to_string();
+            (find_declaration(code, "trait", &name)?, name)
+        }
+        ReferenceId::Global(id) => {
+            // Generated globals are rendered as `let` statements
+            let name = interner.get_global(id).ident.to_string();
+            (find_declaration(code, "let", &name)?, name)
+        }
+        ReferenceId::Function(id) => {
+            let name = interner.function_name(&id).to_string();
+            (find_declaration(code, "fn", &name)?, name)
+        }
+        ReferenceId::Alias(id) => {
+            let name = interner.get_type_alias(id).borrow().name.to_string();
+            (find_declaration(code, "type", &name)?, name)
+        }
+        ReferenceId::Local(_) | ReferenceId::Reference(..) => return None,
+    };
+
+    let before = &code[..index];
+    let line = before.matches('\n').count() as u32;
+    let line_start = before.rfind('\n').map_or(0, |newline_index| newline_index + 1);
+    let character = code[line_start..index].encode_utf16().count() as u32;
+    let end_character = character + name.encode_utf16().count() as u32;
+    Some(Range {
+        start: Position { line, character },
+        end: Position { line, character: end_character },
+    })
+}
+
+/// Returns the byte index of `name` in `keyword name`, the first time it's declared in `code`.
+fn find_declaration(code: &str, keyword: &str, name: &str) -> Option<usize> {
+    find_word(code, 0, name, |before, _| {
+        before
+            .strip_suffix(' ')
+            .and_then(|before| before.strip_suffix(keyword))
+            .map_or(false, |before| !before.ends_with(is_ident_char))
+    })
+}
+
+/// Returns the byte index of the first occurrence of `name` as a whole word in `code`,
+/// starting at `start`, for which `matches` returns true given the code before and after it.
+fn find_word(
+    code: &str,
+    start: usize,
+    name: &str,
+    matches: impl Fn(&str, &str) -> bool,
+) -> Option<usize> {
+    if name.is_empty() {
+        return None;
+    }
+
+    code[start..].match_indices(name).map(|(index, _)| start + index).find(|index| {
+        let before = &code[..*index];
+        let after = &code[index + name.len()..];
+        !before.ends_with(is_ident_char)
+            && !after.starts_with(is_ident_char)
+            && matches(before, after)
+    })
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod expand_macro_tests {
+    use crate::test_utils;
+
+    use super::*;
+    use tokio::test;
+
+    async fn expand_macro_at(line: u32, character: u32) -> Option<NargoExpandMacroResult> {
+        let (mut state, noir_text_document) = test_utils::init_lsp_server("expand_macro").await;
+
+        let params = TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: noir_text_document },
+            position: Position { line, character },
+        };
+
+        on_expand_macro_request(&mut state, params)
+            .await
+            .expect("Could execute on_expand_macro_request")
+    }
+
+    #[test]
+    async fn test_expand_attribute() {
+        let result = expand_macro_at(0, 4).await.expect("Expected an expansion");
+        assert_eq!(result.uri.scheme(), NARGO_EXPANSION_SCHEME);
+        assert!(result.contents.contains("fn generated_function()"));
+        assert!(result.contents.contains("fn generated_helper() -> Field"));
+        assert!(!result.contents.contains("resolved"));
+    }
+
+    #[test]
+    async fn test_no_expansion_outside_of_macros() {
+        assert!(expand_macro_at(22, 4).await.is_none());
+    }
+
+    #[test]
+    async fn test_expansion_uri_round_trip() {
+        let position = Position { line: 3, character: 4 };
+        for path in ["/tmp/main.nr", "/tmp/my project/façade/main.nr"] {
+            let file_uri = Url::from_file_path(path).unwrap();
+            let uri = expansion_uri(&file_uri, position).unwrap();
+
+            let params = from_expansion_uri(&uri).unwrap();
+            assert_eq!(params.text_document.uri, file_uri);
+            assert_eq!(params.position, position);
+        }
+
+        // A Windows path, as sent by clients
+        let file_uri = Url::parse("file:///c%3A/Users/me/main.nr").unwrap();
+        let uri = expansion_uri(&file_uri, position).unwrap();
+        assert_eq!(from_expansion_uri(&uri).unwrap().text_document.uri, file_uri);
+    }
+
+    #[test]
+    async fn test_find_declaration() {
+        let code = "fn foo_bar() {\n    foo();\n}\n\nfn foo() {}";
+        assert_eq!(find_declaration(code, "fn", "foo"), code.rfind("foo"));
+        assert_eq!(find_declaration(code, "struct", "foo"), None);
+    }
+}
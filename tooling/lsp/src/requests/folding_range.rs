@@ -0,0 +1,305 @@
+use std::future::{self, Future};
+
+use async_lsp::ResponseError;
+use fm::codespan_files::SimpleFile;
+use lsp_types::{FoldingRange, FoldingRangeKind, FoldingRangeParams};
+use noirc_errors::Span;
+use noirc_frontend::{
+    ast::{BlockExpression, NoirFunction, UseTree, Visitor},
+    lexer::Lexer,
+    parser::{Item, ItemKind, ParsedSubModule},
+    token::Token,
+    ParsedModule,
+};
+
+use crate::{byte_span_to_range, LspState};
+
+use super::parse_document;
+
+pub(crate) fn on_folding_range_request(
+    state: &mut LspState,
+    params: FoldingRangeParams,
+) -> impl Future<Output = Result<Option<Vec<FoldingRange>>, ResponseError>> {
+    let result = parse_document(state, &params.text_document.uri).map(|(source, parsed_module)| {
+        let mut collector = FoldingRangeCollector::new(&source);
+        collector.collect(&parsed_module)
+    });
+    future::ready(Ok(result))
+}
+
+struct FoldingRangeCollector<'a> {
+    source: &'a str,
+    file: SimpleFile<&'a str, &'a str>,
+    ranges: Vec<FoldingRange>,
+}
+
+impl<'a> FoldingRangeCollector<'a> {
+    fn new(source: &'a str) -> Self {
+        Self { source, file: SimpleFile::new("", source), ranges: Vec::new() }
+    }
+
+    fn collect(&mut self, parsed_module: &ParsedModule) -> Vec<FoldingRange> {
+        self.collect_comments();
+        parsed_module.accept(self);
+
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.sort_by_key(|range| (range.start_line, range.end_line));
+        ranges.dedup_by_key(|range| (range.start_line, range.end_line));
+        ranges
+    }
+
+    /// Adds a folding range for runs of line comments on consecutive lines and for multi-line
+    /// block comments.
+    fn collect_comments(&mut self) {
+        let mut line_comments_span: Option<Span> = None;
+
+        for token in Lexer::new(self.source).skip_comments(false) {
+            let Ok(token) = token else {
+                break;
+            };
+            let span = token.to_span();
+
+            match token.token() {
+                Token::LineComment(..) => {
+                    line_comments_span = match line_comments_span {
+                        Some(run) if self.is_next_line(run, span) => Some(run.merge(span)),
+                        Some(run) => {
+                            self.add_range(run, Some(FoldingRangeKind::Comment));
+                            Some(span)
+                        }
+                        None => Some(span),
+                    };
+                }
+                Token::BlockComment(..) => {
+                    self.add_range(span, Some(FoldingRangeKind::Comment));
+                }
+                Token::EOF => break,
+                _ => {
+                    if let Some(run) = line_comments_span.take() {
+                        self.add_range(run, Some(FoldingRangeKind::Comment));
+                    }
+                }
+            }
+        }
+
+        if let Some(run) = line_comments_span {
+            self.add_range(run, Some(FoldingRangeKind::Comment));
+        }
+    }
+
+    /// Adds a folding range for each group of two or more `use` items separated only by whitespace.
+    fn collect_import_groups(&mut self, items: &[Item]) {
+        let mut groups: Vec<(Span, usize)> = Vec::new();
+        let mut last_was_import = false;
+
+        for item in items {
+            let is_import = matches!(item.kind, ItemKind::Import(..));
+            if is_import {
+                match groups.last_mut() {
+                    Some((span, count))
+                        if last_was_import && self.only_whitespace_between(*span, item.span) =>
+                    {
+                        *span = span.merge(item.span);
+                        *count += 1;
+                    }
+                    _ => groups.push((item.span, 1)),
+                }
+            }
+            last_was_import = is_import;
+        }
+
+        for (span, count) in groups {
+            if count > 1 {
+                self.add_range(span, Some(FoldingRangeKind::Imports));
+            }
+        }
+    }
+
+    fn is_next_line(&self, previous: Span, next: Span) -> bool {
+        self.source.get(previous.end() as usize..next.start() as usize).map_or(false, |between| {
+            between.trim().is_empty() && between.matches('\n').count() == 1
+        })
+    }
+
+    fn only_whitespace_between(&self, previous: Span, next: Span) -> bool {
+        self.source
+            .get(previous.end() as usize..next.start() as usize)
+            .map_or(false, |between| between.trim().is_empty())
+    }
+
+    fn add_range(&mut self, span: Span, kind: Option<FoldingRangeKind>) {
+        let Some(range) = byte_span_to_range(&self.file, (), span.into()) else {
+            return;
+        };
+
+        // Keep a closing delimiter visible when folding
+        let mut end_line = range.end.line;
+        let ends_with_delimiter = span.end() > 0
+            && matches!(self.source.as_bytes().get(span.end() as usize - 1), Some(b'}' | b')'));
+        if ends_with_delimiter && kind.is_none() {
+            end_line = end_line.saturating_sub(1);
+        }
+
+        if end_line <= range.start.line {
+            return;
+        }
+
+        self.ranges.push(FoldingRange {
+            start_line: range.start.line,
+            start_character: None,
+            end_line,
+            end_character: None,
+            kind,
+            collapsed_text: None,
+        });
+    }
+}
+
+impl<'a> Visitor for FoldingRangeCollector<'a> {
+    fn visit_parsed_module(&mut self, parsed_module: &ParsedModule) -> bool {
+        self.collect_import_groups(&parsed_module.items);
+        true
+    }
+
+    fn visit_item(&mut self, item: &Item) -> bool {
+        if !matches!(item.kind, ItemKind::Import(..)) {
+            self.add_range(item.span, None);
+        }
+        true
+    }
+
+    fn visit_parsed_submodule(&mut self, _: &ParsedSubModule, span: Span) -> bool {
+        self.add_range(span, None);
+        true
+    }
+
+    fn visit_noir_function(&mut self, _: &NoirFunction, span: Span) -> bool {
+        self.add_range(span, None);
+        true
+    }
+
+    fn visit_use_tree_list(&mut self, use_tree: &UseTree, _: &[UseTree]) -> bool {
+        self.add_range(use_tree.span, None);
+        true
+    }
+
+    fn visit_block_expression(&mut self, _: &BlockExpression, span: Option<Span>) -> bool {
+        if let Some(span) = span {
+            self.add_range(span, None);
+        }
+        true
+    }
+
+    fn visit_comptime_expression(&mut self, _: &BlockExpression, span: Span) -> bool {
+        self.add_range(span, None);
+        true
+    }
+
+    fn visit_unsafe(&mut self, _: &BlockExpression, span: Span) -> bool {
+        self.add_range(span, None);
+        true
+    }
+}
+
+#[cfg(test)]
+mod folding_range_tests {
+    use lsp_types::{FoldingRange, FoldingRangeKind};
+
+    use super::FoldingRangeCollector;
+
+    fn get_folding_ranges(src: &str) -> Vec<FoldingRange> {
+        let (parsed_module, errors) = noirc_frontend::parse_program(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+
+        let mut collector = FoldingRangeCollector::new(src);
+        collector.collect(&parsed_module)
+    }
+
+    fn folding_range(
+        start_line: u32,
+        end_line: u32,
+        kind: Option<FoldingRangeKind>,
+    ) -> FoldingRange {
+        FoldingRange {
+            start_line,
+            start_character: None,
+            end_line,
+            end_character: None,
+            kind,
+            collapsed_text: None,
+        }
+    }
+
+    #[test]
+    fn test_folds_functions_and_blocks() {
+        let src = "fn main() {
+    let x = unsafe {
+        foo()
+    };
+    if x {
+        bar();
+    }
+}
+
+unconstrained fn foo() -> bool {
+    true
+}
+";
+        let ranges = get_folding_ranges(src);
+        assert_eq!(
+            ranges,
+            vec![
+                folding_range(0, 6, None),
+                folding_range(1, 2, None),
+                folding_range(4, 5, None),
+                folding_range(9, 10, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_folds_comments_and_imports() {
+        let src = "// one
+// two
+// three
+use std::hash::Hash;
+use std::hash::Hasher;
+
+/* a
+   block */
+use std::collections::{
+    map::HashMap,
+    umap::UHashMap,
+};
+";
+        let ranges = get_folding_ranges(src);
+        assert_eq!(
+            ranges,
+            vec![
+                folding_range(0, 2, Some(FoldingRangeKind::Comment)),
+                folding_range(3, 4, Some(FoldingRangeKind::Imports)),
+                folding_range(6, 7, Some(FoldingRangeKind::Comment)),
+                folding_range(8, 10, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_folds_modules_and_impls() {
+        let src = "mod foo {
+    struct Foo {
+        x: Field,
+    }
+
+    impl Foo {
+        fn one() {}
+    }
+}
+";
+        let ranges = get_folding_ranges(src);
+        assert_eq!(
+            ranges,
+            vec![folding_range(0, 7, None), folding_range(1, 2, None), folding_range(5, 6, None),]
+        );
+    }
+}
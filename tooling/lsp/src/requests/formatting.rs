@@ -0,0 +1,223 @@
+use std::future::{self, Future};
+
+use async_lsp::ResponseError;
+use lsp_types::{
+    DocumentFormattingParams, DocumentOnTypeFormattingParams, DocumentRangeFormattingParams,
+    Position, Range, TextEdit, Url,
+};
+use nargo_fmt::Config;
+use nargo_toml::find_file_manifest;
+use noirc_errors::Span;
+
+use crate::{utils, LspState};
+
+pub(crate) fn on_formatting(
+    state: &mut LspState,
+    params: DocumentFormattingParams,
+) -> impl Future<Output = Result<Option<Vec<TextEdit>>, ResponseError>> {
+    future::ready(on_formatting_inner(state, params))
+}
+
+fn on_formatting_inner(
+    state: &LspState,
+    params: DocumentFormattingParams,
+) -> Result<Option<Vec<TextEdit>>, ResponseError> {
+    let path = params.text_document.uri.to_string();
+
+    if let Some(source) = state.input_files.get(&path) {
+        let (module, errors) = noirc_frontend::parse_program(source);
+        if !errors.is_empty() {
+            return Ok(None);
+        }
+
+        let config = formatter_config(&params.text_document.uri);
+        let new_text = nargo_fmt::format(source, module, &config);
+
+        let start_position = Position { line: 0, character: 0 };
+        let end_position = Position {
+            line: source.lines().count() as u32,
+            character: source.chars().count() as u32,
+        };
+
+        Ok(Some(vec![TextEdit { range: Range::new(start_position, end_position), new_text }]))
+    } else {
+        Ok(None)
+    }
+}
+
+pub(crate) fn on_range_formatting(
+    state: &mut LspState,
+    params: DocumentRangeFormattingParams,
+) -> impl Future<Output = Result<Option<Vec<TextEdit>>, ResponseError>> {
+    let result =
+        format_range(state, &params.text_document.uri, params.range.start, params.range.end);
+    future::ready(Ok(result))
+}
+
+pub(crate) fn on_on_type_formatting(
+    state: &mut LspState,
+    params: DocumentOnTypeFormattingParams,
+) -> impl Future<Output = Result<Option<Vec<TextEdit>>, ResponseError>> {
+    let text_document_position = params.text_document_position;
+    let uri = &text_document_position.text_document.uri;
+    let position = text_document_position.position;
+    let result = format_range(state, uri, position, position);
+    future::ready(Ok(result))
+}
+
+/// Formats the statements or items that overlap with the range between `start` and `end`,
+/// returning a single edit that replaces them.
+fn format_range(
+    state: &LspState,
+    uri: &Url,
+    start: Position,
+    end: Position,
+) -> Option<Vec<TextEdit>> {
+    let source = state.input_files.get(&uri.to_string())?;
+
+    let (module, errors) = noirc_frontend::parse_program(source);
+    if !errors.is_empty() {
+        return None;
+    }
+
+    let start = position_to_byte_index(source, start)?;
+    let end = position_to_byte_index(source, end)?;
+    let span = Span::from(start as u32..end as u32);
+
+    let config = formatter_config(uri);
+    let (span, new_text) = nargo_fmt::format_range(source, module, &config, span)?;
+
+    let start = byte_index_to_position(source, span.start() as usize);
+    let end = byte_index_to_position(source, span.end() as usize);
+    let range = Range::new(start, end);
+
+    // Avoid sending an edit if the text is already formatted
+    if source.get(span.start() as usize..span.end() as usize) == Some(new_text.as_str()) {
+        return Some(Vec::new());
+    }
+
+    Some(vec![TextEdit { range, new_text }])
+}
+
+/// Reads the formatter configuration (`noirfmt.toml`) next to the manifest of the package
+/// the given document belongs to, falling back to the default configuration.
+fn formatter_config(uri: &Url) -> Config {
+    uri.to_file_path()
+        .ok()
+        .and_then(|path| find_file_manifest(&path))
+        .and_then(|manifest_path| {
+            let package_dir = manifest_path.parent()?;
+            Config::read(package_dir).ok()
+        })
+        .unwrap_or_default()
+}
+
+fn position_to_byte_index(source: &str, position: Position) -> Option<usize> {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        line_start += source.get(line_start..)?.find('\n')? + 1;
+    }
+
+    let line = source.get(line_start..)?;
+    let line = line.split('\n').next().unwrap_or(line);
+    let offset = utils::character_to_line_offset(line, position.character)?;
+    Some(line_start + offset)
+}
+
+fn byte_index_to_position(source: &str, byte_index: usize) -> Position {
+    let before = &source[..byte_index];
+    let line = before.matches('\n').count() as u32;
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    let character = before[line_start..].encode_utf16().count() as u32;
+    Position { line, character }
+}
+
+#[cfg(test)]
+mod formatting_tests {
+    use acvm::blackbox_solver::StubbedBlackBoxSolver;
+    use async_lsp::ClientSocket;
+    use lsp_types::{FormattingOptions, TextDocumentIdentifier, TextDocumentPositionParams};
+    use tokio::test;
+
+    use super::*;
+
+    fn state_with_source(source: &str) -> (LspState, Url) {
+        let client = ClientSocket::new_closed();
+        let mut state = LspState::new(&client, StubbedBlackBoxSolver);
+        let uri = Url::parse("file:///does/not/exist/src/main.nr").unwrap();
+        state.input_files.insert(uri.to_string(), source.to_string());
+        (state, uri)
+    }
+
+    #[test]
+    async fn test_range_formatting_only_touches_selected_item() {
+        let source = "fn  one ( ) { }\nfn two ( ) {  let  x = 1 ; }\nfn  three ( ) { }\n";
+        let (mut state, uri) = state_with_source(source);
+
+        let params = DocumentRangeFormattingParams {
+            text_document: TextDocumentIdentifier { uri },
+            range: Range::new(Position::new(1, 3), Position::new(1, 6)),
+            options: FormattingOptions::default(),
+            work_done_progress_params: Default::default(),
+        };
+
+        let edits = on_range_formatting(&mut state, params).await.unwrap().unwrap();
+        assert_eq!(
+            edits,
+            vec![TextEdit {
+                range: Range::new(Position::new(1, 0), Position::new(1, 28)),
+                new_text: "fn two() {\n    let x = 1;\n}".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    async fn test_range_formatting_only_touches_selected_statement() {
+        let source = "fn two ( ) {  let  x = 1 ; let  y = 2 ; }\n";
+        let (mut state, uri) = state_with_source(source);
+
+        let params = DocumentRangeFormattingParams {
+            text_document: TextDocumentIdentifier { uri },
+            range: Range::new(Position::new(0, 15), Position::new(0, 20)),
+            options: FormattingOptions::default(),
+            work_done_progress_params: Default::default(),
+        };
+
+        let edits = on_range_formatting(&mut state, params).await.unwrap().unwrap();
+        assert_eq!(
+            edits,
+            vec![TextEdit {
+                range: Range::new(Position::new(0, 14), Position::new(0, 26)),
+                new_text: "let x = 1;".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    async fn test_on_type_formatting_after_closing_brace() {
+        let source = "fn  one ( ) { }\nfn two ( ) {  let  x = 1 ; }\n";
+        let (mut state, uri) = state_with_source(source);
+
+        let params = DocumentOnTypeFormattingParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position: Position::new(1, 28),
+            },
+            ch: "}".to_string(),
+            options: FormattingOptions::default(),
+        };
+
+        let edits = on_on_type_formatting(&mut state, params).await.unwrap().unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range, Range::new(Position::new(1, 0), Position::new(1, 28)));
+    }
+
+    #[test]
+    async fn test_position_and_byte_index_conversions() {
+        let source = "fn main() {\n    let 黑 = 1;\n}\n";
+        let position = Position::new(1, 9);
+        let byte_index = position_to_byte_index(source, position).unwrap();
+        assert_eq!(&source[byte_index..byte_index + 1], " ");
+        assert_eq!(byte_index_to_position(source, byte_index), position);
+    }
+}
@@ -9,6 +9,7 @@ use fm::PathString;
 use lsp_types::request::GotoTypeDefinitionParams;
 use lsp_types::{GotoDefinitionParams, GotoDefinitionResponse};
 
+use super::expand_macro::definition_location_in_expansion;
 use super::{process_request, to_lsp_location};
 
 pub(crate) fn on_goto_definition_request(
@@ -36,20 +37,18 @@ fn on_goto_definition_inner(
     let position = params.text_document_position_params.position;
     process_request(state, params.text_document_position_params, |args| {
         let path = PathString::from_path(uri.to_file_path().unwrap());
-        let reference_id = args.files.get_file_id(&path).and_then(|file_id| {
-            utils::position_to_byte_index(args.files, file_id, &position).and_then(|byte_index| {
-                let file = args.files.get_file(file_id).unwrap();
-                let source = file.source();
-                let (parsed_module, _errors) = noirc_frontend::parse_program(source);
-
-                let mut finder = AttributeReferenceFinder::new(
-                    file_id,
-                    byte_index,
-                    args.crate_id,
-                    args.def_maps,
-                );
-                finder.find(&parsed_module)
-            })
+        let file_and_byte_index = args.files.get_file_id(&path).and_then(|file_id| {
+            let byte_index = utils::position_to_byte_index(args.files, file_id, &position)?;
+            Some((file_id, byte_index))
+        });
+        let reference_id = file_and_byte_index.and_then(|(file_id, byte_index)| {
+            let file = args.files.get_file(file_id).unwrap();
+            let source = file.source();
+            let (parsed_module, _errors) = noirc_frontend::parse_program(source);
+
+            let mut finder =
+                AttributeReferenceFinder::new(file_id, byte_index, args.crate_id, args.def_maps);
+            finder.find(&parsed_module)
         });
         let location = if let Some(reference_id) = reference_id {
             Some(args.interner.reference_location(reference_id))
@@ -63,6 +62,23 @@ fn on_goto_definition_inner(
                 })
         };
         location.and_then(|found_location| {
+            // If the definition was generated by comptime code, point to it inside the expansion.
+            // All generated definitions share the location of the code that generated them,
+            // so the definition is found through the reference under the cursor instead.
+            if reference_id.is_none() {
+                let definition = if return_type_location_instead {
+                    args.interner.reference_at_location(found_location)
+                } else {
+                    args.interner.find_referenced(args.location)
+                };
+                let expansion_location = definition.and_then(|definition| {
+                    definition_location_in_expansion(args.interner, args.files, definition)
+                });
+                if let Some(expansion_location) = expansion_location {
+                    return Some(GotoDefinitionResponse::from(expansion_location));
+                }
+            }
+
             let file_id = found_location.file;
             let definition_position = to_lsp_location(args.files, file_id, found_location.span)?;
             let response = GotoDefinitionResponse::from(definition_position).to_owned();
@@ -75,8 +91,12 @@ fn on_goto_definition_inner(
 mod goto_definition_tests {
     use std::panic;
 
-    use crate::test_utils::{self, search_in_file};
-    use lsp_types::{Position, Range};
+    use crate::{
+        requests::on_expand_macro_request,
+        test_utils::{self, search_in_file},
+        types::NARGO_EXPANSION_SCHEME,
+    };
+    use lsp_types::{Position, Range, TextDocumentIdentifier, TextDocumentPositionParams};
     use tokio::test;
 
     use super::*;
@@ -157,6 +177,45 @@ mod goto_definition_tests {
         expect_goto_for_all_references("go_to_definition", "another_function", 0).await;
     }
 
+    #[test]
+    async fn goto_declaration_inside_expansion() {
+        let (mut state, noir_text_document) = test_utils::init_lsp_server("expand_macro").await;
+
+        // `generated_helper` is called in the expansion before it's declared
+        let params = GotoDefinitionParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: noir_text_document },
+                position: Position { line: 3, character: 12 }, // `generated_helper` in `main`
+            },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+
+        let response = on_goto_definition_request(&mut state, params)
+            .await
+            .expect("Could execute on_goto_definition_request")
+            .expect("Didn't get a goto definition response");
+        let GotoDefinitionResponse::Scalar(location) = response else {
+            panic!("Expected a scalar response");
+        };
+        assert_eq!(location.uri.scheme(), NARGO_EXPANSION_SCHEME);
+
+        let params = TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: location.uri },
+            position: location.range.start,
+        };
+        let expansion = on_expand_macro_request(&mut state, params)
+            .await
+            .expect("Could execute on_expand_macro_request")
+            .expect("Expected an expansion");
+
+        let line = expansion.contents.lines().nth(location.range.start.line as usize).unwrap();
+        assert!(line.starts_with("fn generated_helper()"));
+        let start = location.range.start.character as usize;
+        let end = location.range.end.character as usize;
+        assert_eq!(&line[start..end], "generated_helper");
+    }
+
     #[test]
     async fn goto_from_use_as() {
         expect_goto(
@@ -1,7 +1,10 @@
-use std::future::{self, Future};
+use std::{
+    future::{self, Future},
+    path::PathBuf,
+};
 
 use async_lsp::ResponseError;
-use fm::{FileMap, PathString};
+use fm::{codespan_files::Files, FileMap, PathString};
 use lsp_types::{Hover, HoverContents, HoverParams, MarkupContent, MarkupKind};
 use noirc_frontend::{
     ast::{ItemVisibility, Visibility},
@@ -32,6 +35,7 @@ pub(crate) fn on_hover_request(
 ) -> impl Future<Output = Result<Option<Hover>, ResponseError>> {
     let uri = params.text_document_position_params.text_document.uri.clone();
     let position = params.text_document_position_params.position;
+
     let result = process_request(state, params.text_document_position_params, |args| {
         let path = PathString::from_path(uri.to_file_path().unwrap());
         args.files
@@ -440,6 +444,7 @@ fn format_function(id: FuncId, args: &ProcessRequestCallbackArgs) -> String {
     string.push_str(&go_to_type_links(return_type, args.interner, args.files));
 
     append_doc_comments(args.interner, ReferenceId::Function(id), &mut string);
+    append_circuit_costs(id, args, &mut string);
 
     string
 }
@@ -748,6 +753,29 @@ fn format_link(name: String, location: lsp_types::Location) -> String {
     )
 }
 
+fn append_circuit_costs(id: FuncId, args: &ProcessRequestCallbackArgs, string: &mut String) {
+    let Some(circuit_costs) = args.circuit_costs else {
+        return;
+    };
+
+    let func_meta = args.interner.function_meta(&id);
+    let Some(body) = args.interner.function(&id).try_as_expr() else {
+        return;
+    };
+
+    let location = func_meta.location;
+    let span = location.span.merge(args.interner.expr_span(&body));
+    let Ok(path) = args.files.name(location.file) else {
+        return;
+    };
+
+    let counts = circuit_costs.opcode_counts(&PathBuf::from(path.to_string()), span);
+    if !counts.is_empty() {
+        string.push_str("\n\n---\n\n");
+        string.push_str(&format!("Compiles to {}\n", counts));
+    }
+}
+
 fn append_doc_comments(interner: &NodeInterner, id: ReferenceId, string: &mut String) {
     if let Some(doc_comments) = interner.doc_comments(id) {
         string.push_str("\n\n---\n\n");
@@ -760,7 +788,7 @@ fn append_doc_comments(interner: &NodeInterner, id: ReferenceId, string: &mut St
 
 #[cfg(test)]
 mod hover_tests {
-    use crate::test_utils;
+    use crate::{circuit_costs::compute_circuit_costs, test_utils};
 
     use super::*;
     use lsp_types::{
@@ -1111,4 +1139,31 @@ mod hover_tests {
     pub fn bar_stuff(self)"
         ));
     }
+
+    #[test]
+    async fn hover_on_function_shows_circuit_costs() {
+        let (mut state, noir_text_document) = test_utils::init_lsp_server("circuit_costs").await;
+        state.options.enable_circuit_cost_hover = true;
+        compute_circuit_costs(&mut state, &noir_text_document);
+
+        let hover = on_hover_request(
+            &mut state,
+            HoverParams {
+                text_document_position_params: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier { uri: noir_text_document },
+                    position: Position { line: 3, character: 22 },
+                },
+                work_done_progress_params: WorkDoneProgressParams { work_done_token: None },
+            },
+        )
+        .await
+        .expect("Could not execute hover")
+        .unwrap();
+
+        let HoverContents::Markup(markup) = hover.contents else {
+            panic!("Expected hover contents to be Markup");
+        };
+        assert!(markup.value.contains("unconstrained fn double(x: Field) -> Field"));
+        assert!(markup.value.contains("Brillig opcodes"));
+    }
 }
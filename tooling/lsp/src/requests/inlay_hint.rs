@@ -1,4 +1,7 @@
-use std::future::{self, Future};
+use std::{
+    future::{self, Future},
+    path::PathBuf,
+};
 
 use async_lsp::ResponseError;
 use fm::{FileId, FileMap, PathString};
@@ -20,7 +23,7 @@ use noirc_frontend::{
     Kind, Type, TypeBinding, TypeVariable,
 };
 
-use crate::{utils, LspState};
+use crate::{circuit_costs::CircuitCosts, utils, LspState};
 
 use super::{process_request, to_lsp_location, InlayHintsOptions};
 
@@ -36,7 +39,8 @@ pub(crate) fn on_inlay_hint_request(
     let options = state.options.inlay_hints;
 
     let result = process_request(state, text_document_position_params, |args| {
-        let path = PathString::from_path(params.text_document.uri.to_file_path().unwrap());
+        let file_path = params.text_document.uri.to_file_path().unwrap();
+        let path = PathString::from_path(file_path.clone());
         args.files.get_file_id(&path).map(|file_id| {
             let file = args.files.get_file(file_id).unwrap();
             let source = file.source();
@@ -47,6 +51,7 @@ pub(crate) fn on_inlay_hint_request(
 
             let mut collector =
                 InlayHintCollector::new(args.files, file_id, args.interner, span, options);
+            collector.circuit_costs = args.circuit_costs.map(|costs| (costs, file_path));
             parsed_moduled.accept(&mut collector);
             collector.inlay_hints
         })
@@ -60,6 +65,8 @@ pub(crate) struct InlayHintCollector<'a> {
     interner: &'a NodeInterner,
     span: Option<Span>,
     options: InlayHintsOptions,
+    /// The circuit costs of the package, together with the path of the file being visited.
+    circuit_costs: Option<(&'a CircuitCosts, PathBuf)>,
     inlay_hints: Vec<InlayHint>,
 }
 
@@ -71,7 +78,15 @@ impl<'a> InlayHintCollector<'a> {
         span: Option<Span>,
         options: InlayHintsOptions,
     ) -> InlayHintCollector<'a> {
-        InlayHintCollector { files, file_id, interner, span, options, inlay_hints: Vec::new() }
+        InlayHintCollector {
+            files,
+            file_id,
+            interner,
+            span,
+            options,
+            circuit_costs: None,
+            inlay_hints: Vec::new(),
+        }
     }
 
     fn collect_in_ident(&mut self, ident: &Ident, editable: bool) {
@@ -252,6 +267,34 @@ impl<'a> InlayHintCollector<'a> {
         self.span.map_or(true, |span| span.intersects(&other_span))
     }
 
+    fn show_circuit_cost_hint(&mut self, span: Span) {
+        if !self.options.circuit_cost_hints.enabled {
+            return;
+        }
+
+        let Some((circuit_costs, file_path)) = &self.circuit_costs else {
+            return;
+        };
+
+        let counts = circuit_costs.opcode_counts(file_path, span);
+        if counts.is_empty() {
+            return;
+        }
+
+        if let Some(lsp_location) = to_lsp_location(self.files, self.file_id, span) {
+            self.inlay_hints.push(InlayHint {
+                position: lsp_location.range.end,
+                label: InlayHintLabel::String(counts.to_string()),
+                kind: None,
+                text_edits: None,
+                tooltip: None,
+                padding_left: Some(true),
+                padding_right: None,
+                data: None,
+            });
+        }
+    }
+
     fn show_closing_brace_hint<F>(&mut self, span: Span, f: F)
     where
         F: FnOnce() -> String,
@@ -300,12 +343,19 @@ impl<'a> Visitor for InlayHintCollector<'a> {
 
     fn visit_noir_function(&mut self, noir_function: &NoirFunction, span: Span) -> bool {
         self.show_closing_brace_hint(span, || format!(" fn {}", noir_function.def.name));
+        self.show_circuit_cost_hint(span);
 
         true
     }
 
     fn visit_statement(&mut self, statement: &Statement) -> bool {
-        self.intersects_span(statement.span)
+        if !self.intersects_span(statement.span) {
+            return false;
+        }
+
+        self.show_circuit_cost_hint(statement.span);
+
+        true
     }
 
     fn visit_let_statement(&mut self, let_statement: &LetStatement) -> bool {
@@ -523,6 +573,7 @@ fn get_expression_name(expression: &Expression) -> Option<String> {
         | ExpressionKind::Index(..)
         | ExpressionKind::Block(..)
         | ExpressionKind::If(..)
+        | ExpressionKind::Match(..)
         | ExpressionKind::Lambda(..)
         | ExpressionKind::Tuple(..)
         | ExpressionKind::Quote(..)
@@ -540,7 +591,11 @@ fn get_expression_name(expression: &Expression) -> Option<String> {
 #[cfg(test)]
 mod inlay_hints_tests {
     use crate::{
-        requests::{ClosingBraceHintsOptions, ParameterHintsOptions, TypeHintsOptions},
+        circuit_costs::compute_circuit_costs,
+        requests::{
+            CircuitCostHintsOptions, ClosingBraceHintsOptions, ParameterHintsOptions,
+            TypeHintsOptions,
+        },
         test_utils,
     };
 
@@ -577,6 +632,7 @@ mod inlay_hints_tests {
             type_hints: TypeHintsOptions { enabled: false },
             parameter_hints: ParameterHintsOptions { enabled: false },
             closing_brace_hints: ClosingBraceHintsOptions { enabled: false, min_lines: 25 },
+            circuit_cost_hints: CircuitCostHintsOptions { enabled: false },
         }
     }
 
@@ -585,6 +641,7 @@ mod inlay_hints_tests {
             type_hints: TypeHintsOptions { enabled: true },
             parameter_hints: ParameterHintsOptions { enabled: false },
             closing_brace_hints: ClosingBraceHintsOptions { enabled: false, min_lines: 25 },
+            circuit_cost_hints: CircuitCostHintsOptions { enabled: false },
         }
     }
 
@@ -593,6 +650,7 @@ mod inlay_hints_tests {
             type_hints: TypeHintsOptions { enabled: false },
             parameter_hints: ParameterHintsOptions { enabled: true },
             closing_brace_hints: ClosingBraceHintsOptions { enabled: false, min_lines: 25 },
+            circuit_cost_hints: CircuitCostHintsOptions { enabled: false },
         }
     }
 
@@ -601,6 +659,7 @@ mod inlay_hints_tests {
             type_hints: TypeHintsOptions { enabled: false },
             parameter_hints: ParameterHintsOptions { enabled: false },
             closing_brace_hints: ClosingBraceHintsOptions { enabled: true, min_lines },
+            circuit_cost_hints: CircuitCostHintsOptions { enabled: false },
         }
     }
 
@@ -955,4 +1014,43 @@ mod inlay_hints_tests {
             panic!("Expected InlayHintLabel::String, got {:?}", inlay_hint.label);
         }
     }
+
+    #[test]
+    async fn test_shows_circuit_cost_inlay_hints() {
+        let (mut state, noir_text_document) = test_utils::init_lsp_server("circuit_costs").await;
+        let mut options = no_hints();
+        options.circuit_cost_hints.enabled = true;
+        state.options.inlay_hints = options;
+        compute_circuit_costs(&mut state, &noir_text_document);
+
+        let inlay_hints = on_inlay_hint_request(
+            &mut state,
+            InlayHintParams {
+                work_done_progress_params: WorkDoneProgressParams { work_done_token: None },
+                text_document: TextDocumentIdentifier { uri: noir_text_document },
+                range: Range {
+                    start: Position { line: 0, character: 0 },
+                    end: Position { line: 11, character: 0 },
+                },
+            },
+        )
+        .await
+        .expect("Could not execute on_inlay_hint_request")
+        .unwrap();
+
+        let labels: Vec<_> = inlay_hints
+            .iter()
+            .map(|inlay_hint| match &inlay_hint.label {
+                InlayHintLabel::String(label) => (inlay_hint.position.line, label.clone()),
+                InlayHintLabel::LabelParts(_) => panic!("Expected InlayHintLabel::String"),
+            })
+            .collect();
+
+        // `main` and the statements in it produce ACIR opcodes
+        assert!(labels.iter().any(|(line, label)| *line == 5 && label.contains("ACIR")));
+        assert!(labels.iter().any(|(line, label)| *line == 2 && label.contains("ACIR")));
+
+        // The unconstrained function produces Brillig opcodes
+        assert!(labels.iter().any(|(line, label)| *line == 9 && label.contains("Brillig")));
+    }
 }
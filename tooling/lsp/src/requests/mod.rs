@@ -2,6 +2,7 @@ use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::{collections::HashMap, future::Future};
 
+use crate::circuit_costs::CircuitCosts;
 use crate::{insert_all_files_for_workspace_into_file_manager, parse_diff, PackageCacheData};
 use crate::{
     resolve_workspace_for_source_path,
@@ -10,20 +11,23 @@ use crate::{
 use async_lsp::{ErrorCode, ResponseError};
 use fm::{codespan_files::Error, FileMap, PathString};
 use lsp_types::{
-    CodeActionKind, DeclarationCapability, Location, Position, TextDocumentPositionParams,
-    TextDocumentSyncCapability, TextDocumentSyncKind, TypeDefinitionProviderCapability, Url,
-    WorkDoneProgressOptions,
+    CodeActionKind, DeclarationCapability, FoldingRangeProviderCapability, Location, Position,
+    SelectionRangeProviderCapability, TextDocumentPositionParams, TextDocumentSyncCapability,
+    TextDocumentSyncKind, TypeDefinitionProviderCapability, Url, WorkDoneProgressOptions,
 };
-use nargo_fmt::Config;
 
 use noirc_frontend::graph::CrateId;
 use noirc_frontend::hir::def_map::CrateDefMap;
 use noirc_frontend::usage_tracker::UsageTracker;
+use noirc_frontend::ParsedModule;
 use noirc_frontend::{graph::Dependency, node_interner::NodeInterner};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    types::{InitializeResult, NargoCapability, NargoTestsOptions, ServerCapabilities},
+    types::{
+        InitializeResult, NargoCapability, NargoTestsOptions, ServerCapabilities,
+        NARGO_DEBUG_CONFIGURATION_COMMAND, NARGO_EXECUTE_COMMAND, NARGO_INFO_COMMAND,
+    },
     LspState,
 };
 
@@ -41,12 +45,17 @@ mod code_action;
 mod code_lens_request;
 mod completion;
 mod document_symbol;
+mod execute_command;
+mod expand_macro;
+mod folding_range;
+mod formatting;
 mod goto_declaration;
 mod goto_definition;
 mod hover;
 mod inlay_hint;
 mod references;
 mod rename;
+mod selection_range;
 mod signature_help;
 mod test_run;
 mod tests;
@@ -54,12 +63,15 @@ mod tests;
 pub(crate) use {
     code_action::on_code_action_request, code_lens_request::collect_lenses_for_package,
     code_lens_request::on_code_lens_request, completion::on_completion_request,
-    document_symbol::on_document_symbol_request, goto_declaration::on_goto_declaration_request,
-    goto_definition::on_goto_definition_request, goto_definition::on_goto_type_definition_request,
-    hover::on_hover_request, inlay_hint::on_inlay_hint_request, references::on_references_request,
+    document_symbol::on_document_symbol_request, execute_command::on_execute_command_request,
+    expand_macro::on_expand_macro_request, folding_range::on_folding_range_request,
+    formatting::on_formatting, formatting::on_on_type_formatting, formatting::on_range_formatting,
+    goto_declaration::on_goto_declaration_request, goto_definition::on_goto_definition_request,
+    goto_definition::on_goto_type_definition_request, hover::on_hover_request,
+    inlay_hint::on_inlay_hint_request, references::on_references_request,
     rename::on_prepare_rename_request, rename::on_rename_request,
-    signature_help::on_signature_help_request, test_run::on_test_run_request,
-    tests::on_tests_request,
+    selection_range::on_selection_range_request, signature_help::on_signature_help_request,
+    test_run::on_test_run_request, tests::on_tests_request,
 };
 
 /// LSP client will send initialization request after the server has started.
@@ -76,6 +88,20 @@ pub(crate) struct LspInitializationOptions {
 
     #[serde(rename = "inlayHints", default = "default_inlay_hints")]
     pub(crate) inlay_hints: InlayHintsOptions,
+
+    /// Controls whether hovering a function shows the number of opcodes it compiles to.
+    /// By default this will be set to false (disabled) as it requires compiling the package.
+    #[serde(rename = "enableCircuitCostHover", default = "default_enable_circuit_cost_hover")]
+    pub(crate) enable_circuit_cost_hover: bool,
+
+    /// Controls whether the "Info", "Execute" and "Debug" code lenses invoke commands handled
+    /// by the server through `workspace/executeCommand` instead of commands run by the client.
+    /// By default this will be set to false (disabled) to keep existing clients working.
+    #[serde(
+        rename = "enableServerCommandLenses",
+        default = "default_enable_server_command_lenses"
+    )]
+    pub(crate) enable_server_command_lenses: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize, Copy, Clone)]
@@ -88,6 +114,9 @@ pub(crate) struct InlayHintsOptions {
 
     #[serde(rename = "closingBraceHints", default = "default_closing_brace_hints")]
     pub(crate) closing_brace_hints: ClosingBraceHintsOptions,
+
+    #[serde(rename = "circuitCostHints", default = "default_circuit_cost_hints")]
+    pub(crate) circuit_cost_hints: CircuitCostHintsOptions,
 }
 
 #[derive(Debug, Deserialize, Serialize, Copy, Clone)]
@@ -111,6 +140,12 @@ pub(crate) struct ClosingBraceHintsOptions {
     pub(crate) min_lines: u32,
 }
 
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub(crate) struct CircuitCostHintsOptions {
+    #[serde(rename = "enabled", default = "default_circuit_cost_hints_enabled")]
+    pub(crate) enabled: bool,
+}
+
 fn default_enable_code_lens() -> bool {
     true
 }
@@ -119,11 +154,20 @@ fn default_enable_parsing_cache() -> bool {
     true
 }
 
+fn default_enable_circuit_cost_hover() -> bool {
+    false
+}
+
+fn default_enable_server_command_lenses() -> bool {
+    false
+}
+
 fn default_inlay_hints() -> InlayHintsOptions {
     InlayHintsOptions {
         type_hints: default_type_hints(),
         parameter_hints: default_parameter_hints(),
         closing_brace_hints: default_closing_brace_hints(),
+        circuit_cost_hints: default_circuit_cost_hints(),
     }
 }
 
@@ -158,12 +202,22 @@ fn default_closing_brace_min_lines() -> u32 {
     25
 }
 
+fn default_circuit_cost_hints() -> CircuitCostHintsOptions {
+    CircuitCostHintsOptions { enabled: default_circuit_cost_hints_enabled() }
+}
+
+fn default_circuit_cost_hints_enabled() -> bool {
+    false
+}
+
 impl Default for LspInitializationOptions {
     fn default() -> Self {
         Self {
             enable_code_lens: default_enable_code_lens(),
             enable_parsing_cache: default_enable_parsing_cache(),
             inlay_hints: default_inlay_hints(),
+            enable_circuit_cost_hover: default_enable_circuit_cost_hover(),
+            enable_server_command_lenses: default_enable_server_command_lenses(),
         }
     }
 }
@@ -194,13 +248,33 @@ pub(crate) fn on_initialize(
                 run: Some(true),
                 update: Some(true),
             }),
+            expand_macro: Some(true),
         };
 
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 text_document_sync: Some(text_document_sync),
                 code_lens_provider: code_lens,
+                execute_command_provider: Some(lsp_types::ExecuteCommandOptions {
+                    commands: vec![
+                        NARGO_EXECUTE_COMMAND.to_string(),
+                        NARGO_INFO_COMMAND.to_string(),
+                        NARGO_DEBUG_CONFIGURATION_COMMAND.to_string(),
+                    ],
+                    work_done_progress_options: WorkDoneProgressOptions {
+                        work_done_progress: None,
+                    },
+                }),
                 document_formatting_provider: true,
+                document_range_formatting_provider: Some(lsp_types::OneOf::Left(true)),
+                document_on_type_formatting_provider: Some(
+                    lsp_types::DocumentOnTypeFormattingOptions {
+                        first_trigger_character: "}".to_string(),
+                        more_trigger_character: Some(vec![";".to_string()]),
+                    },
+                ),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
                 nargo: Some(nargo),
                 definition_provider: Some(lsp_types::OneOf::Left(true)),
                 declaration_provider: Some(DeclarationCapability::Simple(true)),
@@ -270,40 +344,30 @@ pub(crate) fn on_initialize(
     }
 }
 
-pub(crate) fn on_formatting(
-    state: &mut LspState,
-    params: lsp_types::DocumentFormattingParams,
-) -> impl Future<Output = Result<Option<Vec<lsp_types::TextEdit>>, ResponseError>> {
-    std::future::ready(on_formatting_inner(state, params))
-}
-
-fn on_formatting_inner(
-    state: &LspState,
-    params: lsp_types::DocumentFormattingParams,
-) -> Result<Option<Vec<lsp_types::TextEdit>>, ResponseError> {
-    let path = params.text_document.uri.to_string();
+/// Returns the source of the given document together with its parsed module.
+/// The source of open documents is preferred over the one on disk, and
+/// the parsing cache is used if the source didn't change since it was last parsed.
+pub(crate) fn parse_document(state: &mut LspState, uri: &Url) -> Option<(String, ParsedModule)> {
+    let file_path = uri.to_file_path().ok()?;
+    let source = match state.input_files.get(&uri.to_string()) {
+        Some(source) => source.clone(),
+        None => std::fs::read_to_string(&file_path).ok()?,
+    };
 
-    if let Some(source) = state.input_files.get(&path) {
-        let (module, errors) = noirc_frontend::parse_program(source);
-        if !errors.is_empty() {
-            return Ok(None);
+    let hash = fxhash::hash(source.as_str());
+    if let Some((cached_hash, (parsed_module, _errors))) = state.cached_parsed_files.get(&file_path)
+    {
+        if *cached_hash == hash {
+            return Some((source, parsed_module.clone()));
         }
+    }
 
-        let new_text = nargo_fmt::format(source, module, &Config::default());
-
-        let start_position = Position { line: 0, character: 0 };
-        let end_position = Position {
-            line: source.lines().count() as u32,
-            character: source.chars().count() as u32,
-        };
-
-        Ok(Some(vec![lsp_types::TextEdit {
-            range: lsp_types::Range::new(start_position, end_position),
-            new_text,
-        }]))
-    } else {
-        Ok(None)
+    let parse_results = noirc_frontend::parse_program(&source);
+    let parsed_module = parse_results.0.clone();
+    if state.options.enable_parsing_cache {
+        state.cached_parsed_files.insert(file_path, (hash, parse_results));
     }
+    Some((source, parsed_module))
 }
 
 pub(crate) fn position_to_byte_index<'a, F>(
@@ -416,6 +480,7 @@ pub(crate) struct ProcessRequestCallbackArgs<'a> {
     dependencies: &'a Vec<Dependency>,
     def_maps: &'a BTreeMap<CrateId, CrateDefMap>,
     usage_tracker: &'a UsageTracker,
+    circuit_costs: Option<&'a CircuitCosts>,
 }
 
 pub(crate) fn process_request<F, T>(
@@ -474,6 +539,7 @@ where
         dependencies: &crate_graph[crate_id].dependencies,
         def_maps,
         usage_tracker,
+        circuit_costs: state.circuit_costs.get(&package.root_dir),
     }))
 }
 
@@ -539,6 +605,7 @@ where
         dependencies: &context.crate_graph[crate_id].dependencies,
         def_maps,
         usage_tracker,
+        circuit_costs: state.circuit_costs.get(&package.root_dir),
     }))
 }
 
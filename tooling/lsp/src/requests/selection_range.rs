@@ -0,0 +1,192 @@
+use std::future::{self, Future};
+
+use async_lsp::ResponseError;
+use fm::codespan_files::SimpleFile;
+use lsp_types::{Position, SelectionRange, SelectionRangeParams};
+use noirc_errors::Span;
+use noirc_frontend::{
+    ast::{
+        BlockExpression, Expression, NoirFunction, Path, Pattern, Statement, UnresolvedType,
+        UseTree, Visitor,
+    },
+    parser::{Item, ParsedSubModule},
+    ParsedModule,
+};
+
+use crate::{byte_span_to_range, LspState};
+
+use super::{parse_document, position_to_byte_index};
+
+pub(crate) fn on_selection_range_request(
+    state: &mut LspState,
+    params: SelectionRangeParams,
+) -> impl Future<Output = Result<Option<Vec<SelectionRange>>, ResponseError>> {
+    let result = parse_document(state, &params.text_document.uri).map(|(source, parsed_module)| {
+        params
+            .positions
+            .iter()
+            .map(|position| selection_range(&source, &parsed_module, *position))
+            .collect()
+    });
+    future::ready(Ok(result))
+}
+
+/// Builds the chain of syntactic ranges around `position`, from the innermost node
+/// up to the whole document.
+fn selection_range(
+    source: &str,
+    parsed_module: &ParsedModule,
+    position: Position,
+) -> SelectionRange {
+    let file = SimpleFile::new("", source);
+    let document_span = Span::from(0..source.len() as u32);
+
+    let mut spans = match position_to_byte_index(&file, (), &position) {
+        Ok(byte_index) => {
+            let mut finder = SelectionRangeFinder::new(byte_index as u32);
+            parsed_module.accept(&mut finder);
+            finder.spans
+        }
+        Err(_) => Vec::new(),
+    };
+    spans.push(document_span);
+
+    // Order spans from the outermost to the innermost one
+    spans.sort_by_key(|span| (span.start(), std::cmp::Reverse(span.end())));
+    spans.dedup();
+
+    let mut selection_range: Option<SelectionRange> = None;
+    for span in spans {
+        let Some(range) = byte_span_to_range(&file, (), span.into()) else {
+            continue;
+        };
+        let parent = selection_range.take().map(Box::new);
+        selection_range = Some(SelectionRange { range, parent });
+    }
+
+    selection_range.unwrap_or_else(|| SelectionRange {
+        range: lsp_types::Range::new(position, position),
+        parent: None,
+    })
+}
+
+/// Collects the spans of every node that contains a given byte index.
+struct SelectionRangeFinder {
+    byte_index: u32,
+    spans: Vec<Span>,
+}
+
+impl SelectionRangeFinder {
+    fn new(byte_index: u32) -> Self {
+        Self { byte_index, spans: Vec::new() }
+    }
+
+    /// Records `span` if it contains the byte index, returning whether it does so
+    /// that children of nodes that don't contain it are not visited.
+    fn includes(&mut self, span: Span) -> bool {
+        let contains = span.start() <= self.byte_index && self.byte_index <= span.end();
+        if contains {
+            self.spans.push(span);
+        }
+        contains
+    }
+}
+
+impl Visitor for SelectionRangeFinder {
+    fn visit_item(&mut self, item: &Item) -> bool {
+        self.includes(item.span)
+    }
+
+    fn visit_parsed_submodule(&mut self, _: &ParsedSubModule, span: Span) -> bool {
+        self.includes(span)
+    }
+
+    fn visit_noir_function(&mut self, _: &NoirFunction, span: Span) -> bool {
+        self.includes(span)
+    }
+
+    fn visit_use_tree(&mut self, use_tree: &UseTree) -> bool {
+        self.includes(use_tree.span)
+    }
+
+    fn visit_expression(&mut self, expression: &Expression) -> bool {
+        self.includes(expression.span)
+    }
+
+    fn visit_block_expression(&mut self, _: &BlockExpression, span: Option<Span>) -> bool {
+        span.map_or(true, |span| self.includes(span))
+    }
+
+    fn visit_statement(&mut self, statement: &Statement) -> bool {
+        self.includes(statement.span)
+    }
+
+    fn visit_unresolved_type(&mut self, typ: &UnresolvedType) -> bool {
+        self.includes(typ.span)
+    }
+
+    fn visit_pattern(&mut self, pattern: &Pattern) -> bool {
+        self.includes(pattern.span())
+    }
+
+    fn visit_path(&mut self, path: &Path) {
+        self.includes(path.span);
+    }
+}
+
+#[cfg(test)]
+mod selection_range_tests {
+    use lsp_types::{Position, Range};
+
+    use super::selection_range;
+
+    fn get_selection_ranges(src: &str, position: Position) -> Vec<Range> {
+        let (parsed_module, errors) = noirc_frontend::parse_program(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+
+        let mut ranges = Vec::new();
+        let mut selection_range = Some(selection_range(src, &parsed_module, position));
+        while let Some(current) = selection_range {
+            ranges.push(current.range);
+            selection_range = current.parent.map(|parent| *parent);
+        }
+        ranges
+    }
+
+    fn range(start_line: u32, start_character: u32, end_line: u32, end_character: u32) -> Range {
+        Range::new(
+            Position::new(start_line, start_character),
+            Position::new(end_line, end_character),
+        )
+    }
+
+    #[test]
+    fn test_selection_ranges_expand_outwards() {
+        let src = "fn main() {
+    let x = foo(1 + 2);
+}
+";
+        let ranges = get_selection_ranges(src, Position::new(1, 16));
+        assert_eq!(&ranges[..3], &[range(1, 16, 1, 17), range(1, 16, 1, 21), range(1, 12, 1, 22)]);
+        assert!(ranges.contains(&range(0, 10, 2, 1)));
+        assert_eq!(ranges.last(), Some(&range(0, 0, 3, 0)));
+    }
+
+    #[test]
+    fn test_selection_ranges_are_nested() {
+        let src = "mod foo {
+    fn bar(x: Field) -> Field {
+        x
+    }
+}
+";
+        let ranges = get_selection_ranges(src, Position::new(1, 14));
+        for pair in ranges.windows(2) {
+            let (inner, outer) = (pair[0], pair[1]);
+            assert!(outer.start <= inner.start && inner.end <= outer.end);
+            assert_ne!(inner, outer);
+        }
+        assert_eq!(ranges.first(), Some(&range(1, 14, 1, 19)));
+        assert_eq!(ranges.last(), Some(&range(0, 0, 5, 0)));
+    }
+}
@@ -3,7 +3,9 @@ use std::future::{self, Future};
 use crate::insert_all_files_for_workspace_into_file_manager;
 use async_lsp::{ErrorCode, ResponseError};
 use nargo::ops::{run_test, TestStatus};
-use nargo_toml::{find_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use nargo_toml::{
+    find_package_manifest, resolve_workspace_from_toml, FeatureSelection, PackageSelection,
+};
 use noirc_driver::{check_crate, CompileOptions, NOIR_ARTIFACT_VERSION_STRING};
 use noirc_frontend::hir::FunctionNameMatch;
 
@@ -40,6 +42,8 @@ fn on_test_run_request_inner(
         &toml_path,
         PackageSelection::Selected(crate_name.clone()),
         Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+        false,
+        FeatureSelection::default(),
     )
     .map_err(|err| {
         // If we found a manifest, but the workspace is invalid, we raise an error about it
@@ -58,7 +62,7 @@ fn on_test_run_request_inner(
     match workspace.into_iter().next() {
         Some(package) => {
             let (mut context, crate_id) =
-                crate::prepare_package(&workspace_file_manager, &parsed_files, package);
+                crate::prepare_package_for_tests(&workspace_file_manager, &parsed_files, package);
             if check_crate(&mut context, crate_id, &Default::default()).is_err() {
                 let result = NargoTestRunResult {
                     id: params.id.clone(),
@@ -3,7 +3,9 @@ use std::future::{self, Future};
 use crate::insert_all_files_for_workspace_into_file_manager;
 use async_lsp::{ErrorCode, LanguageClient, ResponseError};
 use lsp_types::{LogMessageParams, MessageType};
-use nargo_toml::{find_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use nargo_toml::{
+    find_package_manifest, resolve_workspace_from_toml, FeatureSelection, PackageSelection,
+};
 use noirc_driver::{check_crate, NOIR_ARTIFACT_VERSION_STRING};
 
 use crate::{
@@ -44,6 +46,8 @@ fn on_tests_request_inner(
         &toml_path,
         PackageSelection::All,
         Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+        false,
+        FeatureSelection::default(),
     )
     .map_err(|err| {
         // If we found a manifest, but the workspace is invalid, we raise an error about it
@@ -62,7 +66,7 @@ fn on_tests_request_inner(
         .into_iter()
         .filter_map(|package| {
             let (mut context, crate_id) =
-                crate::prepare_package(&workspace_file_manager, &parsed_files, package);
+                crate::prepare_package_for_tests(&workspace_file_manager, &parsed_files, package);
             // We ignore the warnings and errors produced by compilation for producing tests
             // because we can still get the test functions even if compilation fails
             let _ = check_crate(&mut context, crate_id, &Default::default());
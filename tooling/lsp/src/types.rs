@@ -1,7 +1,9 @@
 use lsp_types::{
     CodeActionOptions, CompletionOptions, DeclarationCapability, DefinitionOptions,
-    DocumentSymbolOptions, HoverOptions, InlayHintOptions, OneOf, ReferencesOptions, RenameOptions,
-    SignatureHelpOptions, TypeDefinitionProviderCapability,
+    DocumentOnTypeFormattingOptions, DocumentRangeFormattingOptions, DocumentSymbolOptions,
+    ExecuteCommandOptions, FoldingRangeProviderCapability, HoverOptions, InlayHintOptions, OneOf,
+    ReferencesOptions, RenameOptions, SelectionRangeProviderCapability, SignatureHelpOptions,
+    TypeDefinitionProviderCapability,
 };
 use noirc_frontend::graph::CrateName;
 use serde::{Deserialize, Serialize};
@@ -18,14 +20,14 @@ pub(crate) mod request {
     use lsp_types::{request::Request, InitializeParams};
 
     use super::{
-        InitializeResult, NargoTestRunParams, NargoTestRunResult, NargoTestsParams,
-        NargoTestsResult,
+        InitializeResult, NargoExpandMacroResult, NargoTestRunParams, NargoTestRunResult,
+        NargoTestsParams, NargoTestsResult,
     };
 
     // Re-providing lsp_types that we don't need to override
     pub(crate) use lsp_types::request::{
         CodeLensRequest as CodeLens, Formatting, GotoDeclaration, GotoDefinition,
-        GotoTypeDefinition, Shutdown,
+        GotoTypeDefinition, OnTypeFormatting, RangeFormatting, Shutdown,
     };
 
     #[derive(Debug)]
@@ -51,6 +53,14 @@ pub(crate) mod request {
         type Result = NargoTestsResult;
         const METHOD: &'static str = "nargo/tests";
     }
+
+    #[derive(Debug)]
+    pub(crate) struct NargoExpandMacro;
+    impl Request for NargoExpandMacro {
+        type Params = lsp_types::TextDocumentPositionParams;
+        type Result = Option<NargoExpandMacroResult>;
+        const METHOD: &'static str = "nargo/expandMacro";
+    }
 }
 
 pub(crate) mod notification {
@@ -93,6 +103,10 @@ pub(crate) struct NargoCapability {
     /// The server will provide various features related to testing within Nargo.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) tests: Option<NargoTestsOptions>,
+
+    /// The server can show the code generated by comptime code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) expand_macro: Option<bool>,
 }
 
 #[derive(Debug, PartialEq, Clone, Default, Deserialize, Serialize)]
@@ -118,9 +132,30 @@ pub(crate) struct ServerCapabilities {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) code_lens_provider: Option<CodeLensOptions>,
 
+    /// The server provides execute command support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) execute_command_provider: Option<ExecuteCommandOptions>,
+
     /// The server provides document formatting.
     pub(crate) document_formatting_provider: bool,
 
+    /// The server provides document range formatting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) document_range_formatting_provider:
+        Option<OneOf<bool, DocumentRangeFormattingOptions>>,
+
+    /// The server provides document formatting on typing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) document_on_type_formatting_provider: Option<DocumentOnTypeFormattingOptions>,
+
+    /// The server provides folding ranges.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) folding_range_provider: Option<FoldingRangeProviderCapability>,
+
+    /// The server provides selection ranges.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) selection_range_provider: Option<SelectionRangeProviderCapability>,
+
     /// The server handles and provides custom nargo messages.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) nargo: Option<NargoCapability>,
@@ -241,6 +276,89 @@ pub(crate) struct NargoTestRunResult {
     pub(crate) message: Option<String>,
 }
 
+/// The URI scheme used for documents holding code generated by comptime code.
+pub(crate) const NARGO_EXPANSION_SCHEME: &str = "noir-expansion";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct NargoExpandMacroResult {
+    /// A URI identifying this expansion. Its scheme is [NARGO_EXPANSION_SCHEME].
+    pub(crate) uri: Url,
+    /// The range of the code that generated this expansion.
+    pub(crate) range: Range,
+    /// The generated code.
+    pub(crate) contents: String,
+}
+
+/// Runs the package's `main` function with the inputs in its `Prover.toml` file.
+pub(crate) const NARGO_EXECUTE_COMMAND: &str = "nargo.lsp.execute";
+/// Reports the number of opcodes of each function in the package's program.
+pub(crate) const NARGO_INFO_COMMAND: &str = "nargo.lsp.info";
+/// Returns the launch configuration a client passes to the Noir debugger to debug the package's
+/// `main` function or one of its tests. The server doesn't start the debugger itself.
+pub(crate) const NARGO_DEBUG_CONFIGURATION_COMMAND: &str = "nargo.lsp.debugConfiguration";
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NargoCommandArguments {
+    /// A document in the package the command applies to.
+    pub(crate) uri: Url,
+    /// The name of the inputs file, without extension. Defaults to `Prover`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) prover_name: Option<String>,
+    /// The fully-qualified name of the test to debug, instead of the `main` function.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) test_name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NargoExecuteResult {
+    pub(crate) package: String,
+    /// Whether the circuit witness was successfully solved.
+    pub(crate) success: bool,
+    /// The inputs and the return value of `main`, as JSON values.
+    pub(crate) values: Option<serde_json::Value>,
+    /// Everything printed during execution.
+    pub(crate) output: String,
+    /// Why execution failed, pointing at the failing assertion if possible.
+    pub(crate) diagnostic: Option<NargoDiagnostic>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct NargoDiagnostic {
+    pub(crate) uri: Option<Url>,
+    pub(crate) diagnostic: Diagnostic,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NargoInfoResult {
+    pub(crate) package: String,
+    pub(crate) functions: Vec<NargoFunctionInfo>,
+    pub(crate) unconstrained_functions: Vec<NargoFunctionInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct NargoFunctionInfo {
+    pub(crate) name: String,
+    pub(crate) opcodes: usize,
+}
+
+/// A launch configuration the client can use to start a debug session through `nargo dap`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NargoDebugConfiguration {
+    #[serde(rename = "type")]
+    pub(crate) debug_type: String,
+    pub(crate) request: String,
+    pub(crate) name: String,
+    pub(crate) project_folder: String,
+    pub(crate) package: String,
+    pub(crate) prover_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) test_name: Option<String>,
+}
+
 pub(crate) type CodeLensResult = Option<Vec<CodeLens>>;
 pub(crate) type GotoDefinitionResult = Option<lsp_types::GotoDefinitionResponse>;
 pub(crate) type GotoDeclarationResult = Option<lsp_types::request::GotoDeclarationResponse>;
@@ -0,0 +1,129 @@
+use super::{markdown, CrateDocs, ItemDocs, ModuleDocs};
+
+/// A test built from a code block in the documentation of a crate.
+#[derive(Debug, Clone)]
+pub struct DocTest {
+    /// The path of the documented item, followed by the index of the code block.
+    pub name: String,
+    /// The source of a Noir file defining the test function [DOC_TEST_FUNCTION], in a crate
+    /// depending on the documented crate.
+    pub source: String,
+}
+
+/// Name of the test function defined by each doc test.
+pub const DOC_TEST_FUNCTION: &str = "doc_test";
+
+/// Returns a test for each Noir code block in the documentation of `docs`.
+///
+/// Code blocks marked `ignore` or with another language than `noir` are skipped. Blocks
+/// marked `should_fail` are expected to fail.
+pub fn doc_tests(docs: &CrateDocs) -> Vec<DocTest> {
+    let mut tests = Vec::new();
+    collect_module_tests(&docs.root, &mut tests);
+    tests
+}
+
+fn collect_module_tests(module: &ModuleDocs, tests: &mut Vec<DocTest>) {
+    let path = module.path.join("::");
+    collect_tests(&path, &module.doc, tests);
+    for item in &module.items {
+        collect_item_tests(&format!("{path}::{}", item.name), item, tests);
+    }
+    for module in &module.modules {
+        collect_module_tests(module, tests);
+    }
+}
+
+fn collect_item_tests(path: &str, item: &ItemDocs, tests: &mut Vec<DocTest>) {
+    collect_tests(path, &item.doc, tests);
+    for field in &item.fields {
+        collect_tests(&format!("{path}::{}", field.name), &field.doc, tests);
+    }
+    for method in &item.methods {
+        collect_item_tests(&format!("{path}::{}", method.name), method, tests);
+    }
+}
+
+fn collect_tests(path: &str, doc: &str, tests: &mut Vec<DocTest>) {
+    for (index, block) in markdown::code_blocks(doc).into_iter().enumerate() {
+        let attributes: Vec<_> =
+            block.info.split(|char: char| char == ',' || char.is_whitespace()).collect();
+        let is_noir = attributes
+            .iter()
+            .all(|attribute| matches!(*attribute, "" | "noir" | "rust" | "should_fail" | "ignore"));
+        if !is_noir || attributes.contains(&"ignore") {
+            continue;
+        }
+
+        let should_fail = attributes.contains(&"should_fail");
+        let name = format!("{path} (doc test {})", index + 1);
+        tests.push(DocTest { name, source: test_source(&block.lines, should_fail) });
+    }
+}
+
+/// Wraps the `lines` of a code block in a test function. Imports are kept at the top level, and
+/// a block defining a `main` function is tested by calling it.
+fn test_source(lines: &[String], should_fail: bool) -> String {
+    // Lines hidden from the documentation are still part of the test
+    let lines = lines.iter().map(|line| {
+        if markdown::is_hidden_line(line) {
+            line.trim_start().trim_start_matches('#').strip_prefix(' ').unwrap_or_default()
+        } else {
+            line.as_str()
+        }
+    });
+    let (imports, body): (Vec<_>, Vec<_>) =
+        lines.partition(|line| line.starts_with("use ") && line.trim_end().ends_with(';'));
+
+    let test_attribute = if should_fail { "#[test(should_fail)]" } else { "#[test]" };
+    let mut source = String::new();
+    for import in imports {
+        source.push_str(import);
+        source.push('\n');
+    }
+
+    if body.iter().any(|line| line.trim_start().starts_with("fn main()")) {
+        for line in body {
+            source.push_str(line);
+            source.push('\n');
+        }
+        source.push_str(&format!(
+            "\n{test_attribute}\nfn {DOC_TEST_FUNCTION}() {{\n    main();\n}}\n"
+        ));
+    } else {
+        source.push_str(&format!("\n{test_attribute}\nfn {DOC_TEST_FUNCTION}() {{\n"));
+        for line in body {
+            source.push_str("    ");
+            source.push_str(line);
+            source.push('\n');
+        }
+        source.push_str("}\n");
+    }
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_source;
+
+    #[test]
+    fn wraps_code_blocks_in_test_functions() {
+        let lines: Vec<String> = ["use lib::add;", "# let x = 1;", "assert(add(x, 1) == 2);"]
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(
+            test_source(&lines, false),
+            "use lib::add;\n\n#[test]\nfn doc_test() {\n    let x = 1;\n    assert(add(x, 1) == 2);\n}\n"
+        );
+
+        let lines: Vec<String> = ["fn main() {", "    assert(false);", "}"]
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(
+            test_source(&lines, true),
+            "fn main() {\n    assert(false);\n}\n\n#[test(should_fail)]\nfn doc_test() {\n    main();\n}\n"
+        );
+    }
+}
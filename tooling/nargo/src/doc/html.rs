@@ -0,0 +1,288 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use super::{
+    markdown::{escape, render, render_summary},
+    CrateDocs, ItemDocs, ItemKind, ItemLink, ModuleDocs, Signature,
+};
+
+const STYLE: &str = "
+body { font-family: sans-serif; max-width: 60em; margin: 2em auto; padding: 0 1em; line-height: 1.5; }
+pre, code { font-family: monospace; background: #f4f4f4; }
+pre { padding: 0.5em; overflow-x: auto; }
+.signature { display: block; padding: 0.5em; }
+.item, .member { margin-left: 1em; }
+nav a { margin-right: 0.25em; }
+a { color: #3060a0; text-decoration: none; }
+";
+
+/// Renders `crates` as HTML pages, returning the path of each page relative to the output
+/// directory along with its contents.
+///
+/// Each module gets a page at `<crate>/<module>/.../index.html`, on which each of its items
+/// can be linked to with an anchor such as `#struct.Name`.
+pub fn render_html(crates: &[CrateDocs]) -> Vec<(PathBuf, String)> {
+    let mut pages = Vec::new();
+
+    let mut index = String::from("<h1>Crates</h1>\n<ul>\n");
+    for crate_docs in crates {
+        index.push_str(&format!(
+            "<li><a href=\"{}/index.html\">{}</a></li>\n",
+            crate_docs.name,
+            escape(&crate_docs.name)
+        ));
+        render_module(&crate_docs.root, &mut pages);
+    }
+    index.push_str("</ul>\n");
+    pages.push((PathBuf::from("index.html"), page("Crates", &index)));
+
+    pages
+}
+
+fn render_module(module: &ModuleDocs, pages: &mut Vec<(PathBuf, String)>) {
+    let depth = module.path.len();
+    let title = module.path.join("::");
+
+    let mut body = String::new();
+    body.push_str(&breadcrumbs(&module.path));
+    let kind = if depth == 1 { "Crate" } else { "Module" };
+    body.push_str(&format!("<h1>{kind} <code>{}</code></h1>\n", escape(&title)));
+    body.push_str(&render(&module.doc, 1));
+
+    if !module.modules.is_empty() {
+        body.push_str("<h2>Modules</h2>\n<ul>\n");
+        for child in &module.modules {
+            let name = child.path.last().expect("modules have a name");
+            body.push_str(&format!(
+                "<li><a href=\"{name}/index.html\"><code>{}</code></a> {}</li>\n",
+                escape(name),
+                render_summary(&child.doc)
+            ));
+        }
+        body.push_str("</ul>\n");
+    }
+
+    for (kind, items) in items_by_kind(module) {
+        body.push_str(&format!("<h2>{}</h2>\n", section_title(kind)));
+        for item in items {
+            render_item(item, depth, &mut body);
+        }
+    }
+
+    let mut path: PathBuf = module.path.iter().collect();
+    path.push("index.html");
+    pages.push((path, page(&title, &body)));
+
+    for child in &module.modules {
+        render_module(child, pages);
+    }
+}
+
+fn render_item(item: &ItemDocs, depth: usize, body: &mut String) {
+    body.push_str(&format!(
+        "<div class=\"item\" id=\"{}\">\n<h3><code class=\"signature\">{}</code></h3>\n",
+        anchor(item.kind, &item.name),
+        render_signature(&item.signature, depth)
+    ));
+    body.push_str(&render(&item.doc, 3));
+
+    if !item.fields.is_empty() {
+        let title = if item.kind == ItemKind::Enum { "Variants" } else { "Fields" };
+        body.push_str(&format!("<h4>{title}</h4>\n"));
+        for field in &item.fields {
+            body.push_str(&format!(
+                "<div class=\"member\"><code class=\"signature\">{}</code>\n{}</div>\n",
+                render_signature(&field.signature, depth),
+                render(&field.doc, 4)
+            ));
+        }
+    }
+
+    if !item.methods.is_empty() {
+        body.push_str("<h4>Methods</h4>\n");
+        for method in &item.methods {
+            body.push_str(&format!(
+                "<div class=\"member\"><code class=\"signature\">{}</code>\n{}</div>\n",
+                render_signature(&method.signature, depth),
+                render(&method.doc, 4)
+            ));
+        }
+    }
+
+    if !item.implementations.is_empty() {
+        let title =
+            if item.kind == ItemKind::Trait { "Implementors" } else { "Trait implementations" };
+        body.push_str(&format!("<h4>{title}</h4>\n<ul>\n"));
+        for implementation in &item.implementations {
+            body.push_str(&format!(
+                "<li><code>{}</code></li>\n",
+                render_signature(implementation, depth)
+            ));
+        }
+        body.push_str("</ul>\n");
+    }
+
+    body.push_str("</div>\n");
+}
+
+/// Renders `signature`, on a page `depth` directories below the output directory.
+fn render_signature(signature: &Signature, depth: usize) -> String {
+    let mut html = String::new();
+    for part in &signature.0 {
+        match &part.link {
+            Some(link) => html.push_str(&format!(
+                "<a href=\"{}\">{}</a>",
+                link_href(link, depth),
+                escape(&part.text)
+            )),
+            None => html.push_str(&escape(&part.text)),
+        }
+    }
+    html
+}
+
+fn link_href(link: &ItemLink, depth: usize) -> String {
+    format!(
+        "{}{}/index.html#{}",
+        "../".repeat(depth),
+        link.module.join("/"),
+        anchor(link.kind, &link.name)
+    )
+}
+
+fn anchor(kind: ItemKind, name: &str) -> String {
+    format!("{}.{name}", kind.keyword())
+}
+
+fn breadcrumbs(path: &[String]) -> String {
+    let mut html = format!("<nav><a href=\"{}index.html\">Crates</a>", "../".repeat(path.len()));
+    for (index, name) in path.iter().enumerate() {
+        let up = "../".repeat(path.len() - index - 1);
+        html.push_str(&format!(" :: <a href=\"{up}index.html\">{}</a>", escape(name)));
+    }
+    html.push_str("</nav>\n");
+    html
+}
+
+fn section_title(kind: ItemKind) -> &'static str {
+    match kind {
+        ItemKind::Struct => "Structs",
+        ItemKind::Enum => "Enums",
+        ItemKind::Trait => "Traits",
+        ItemKind::TypeAlias => "Type aliases",
+        ItemKind::Global => "Globals",
+        ItemKind::Function => "Functions",
+    }
+}
+
+/// Groups the items of `module` by their kind, in the order they are listed in.
+fn items_by_kind(module: &ModuleDocs) -> BTreeMap<ItemKind, Vec<&ItemDocs>> {
+    let mut items: BTreeMap<ItemKind, Vec<&ItemDocs>> = BTreeMap::new();
+    for item in &module.items {
+        items.entry(item.kind).or_default().push(item);
+    }
+    items
+}
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        escape(title)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{link_href, render_html};
+    use crate::doc::{
+        CrateDocs, ItemDocs, ItemKind, ItemLink, ModuleDocs, Signature, SignaturePart,
+    };
+
+    fn text(text: &str) -> SignaturePart {
+        SignaturePart { text: text.to_string(), link: None }
+    }
+
+    fn foo_link() -> ItemLink {
+        ItemLink {
+            module: vec!["lib".to_string()],
+            kind: ItemKind::Struct,
+            name: "Foo".to_string(),
+        }
+    }
+
+    fn item(kind: ItemKind, name: &str, doc: &str, signature: Vec<SignaturePart>) -> ItemDocs {
+        ItemDocs {
+            kind,
+            name: name.to_string(),
+            doc: doc.to_string(),
+            signature: Signature(signature),
+            fields: Vec::new(),
+            methods: Vec::new(),
+            implementations: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn links_relative_to_the_page_depth() {
+        let link = ItemLink {
+            module: vec!["lib".to_string(), "inner".to_string()],
+            kind: ItemKind::Function,
+            name: "nested".to_string(),
+        };
+        assert_eq!(link_href(&link, 0), "lib/inner/index.html#fn.nested");
+        assert_eq!(link_href(&link, 2), "../../lib/inner/index.html#fn.nested");
+        assert_eq!(link_href(&foo_link(), 1), "../lib/index.html#struct.Foo");
+    }
+
+    #[test]
+    fn renders_a_page_per_module_with_escaped_docs() {
+        let foo = item(
+            ItemKind::Struct,
+            "Foo",
+            "A <b>struct</b> & `Vec<T>`",
+            vec![text("pub struct Foo<T>")],
+        );
+        let nested = item(
+            ItemKind::Function,
+            "nested",
+            "",
+            vec![
+                text("pub fn nested() -> "),
+                SignaturePart { text: "Foo".to_string(), link: Some(foo_link()) },
+            ],
+        );
+        let inner = ModuleDocs {
+            path: vec!["lib".to_string(), "inner".to_string()],
+            doc: "Inner module.".to_string(),
+            modules: Vec::new(),
+            items: vec![nested],
+        };
+        let root = ModuleDocs {
+            path: vec!["lib".to_string()],
+            doc: String::new(),
+            modules: vec![inner],
+            items: vec![foo],
+        };
+        let pages = render_html(&[CrateDocs { name: "lib".to_string(), root }]);
+
+        let paths: Vec<_> = pages.iter().map(|(path, _)| path.clone()).collect();
+        let expected_paths = ["lib/index.html", "lib/inner/index.html", "index.html"];
+        assert_eq!(paths, expected_paths.map(PathBuf::from));
+
+        let lib_page = &pages[0].1;
+        assert!(lib_page.contains("<div class=\"item\" id=\"struct.Foo\">"));
+        assert!(lib_page.contains("<code class=\"signature\">pub struct Foo&lt;T&gt;</code>"));
+        assert!(
+            lib_page.contains("<p>A &lt;b&gt;struct&lt;/b&gt; &amp; <code>Vec&lt;T&gt;</code></p>")
+        );
+        assert!(
+            lib_page.contains("<a href=\"inner/index.html\"><code>inner</code></a> Inner module.")
+        );
+
+        let inner_page = &pages[1].1;
+        assert!(inner_page
+            .contains("pub fn nested() -&gt; <a href=\"../../lib/index.html#struct.Foo\">Foo</a>"));
+        assert!(inner_page.contains("<nav><a href=\"../../index.html\">Crates</a> :: <a href=\"../index.html\">lib</a> :: <a href=\"index.html\">inner</a></nav>"));
+    }
+}
@@ -0,0 +1,244 @@
+//! Rendering of the subset of Markdown used in doc comments: paragraphs, headings, lists,
+//! fenced code blocks, inline code and emphasis.
+
+/// A fenced code block in a doc comment.
+pub(super) struct CodeBlock {
+    /// The info string following the opening fence, e.g. `noir` or `ignore`.
+    pub(super) info: String,
+    pub(super) lines: Vec<String>,
+}
+
+enum Block<'a> {
+    Paragraph(Vec<&'a str>),
+    Heading(usize, &'a str),
+    List(Vec<&'a str>),
+    Code(CodeBlock),
+}
+
+fn parse_blocks(markdown: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut lines = markdown.lines().peekable();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some(info) = trimmed.strip_prefix("```") {
+            let mut code = Vec::new();
+            for line in lines.by_ref() {
+                if line.trim().starts_with("```") {
+                    break;
+                }
+                code.push(line.to_string());
+            }
+            blocks.push(Block::Code(CodeBlock { info: info.trim().to_string(), lines: code }));
+        } else if let Some(level) = heading_level(trimmed) {
+            blocks.push(Block::Heading(level, trimmed[level..].trim()));
+        } else if let Some(item) = list_item(trimmed) {
+            let mut items = vec![item];
+            while let Some(item) = lines.peek().and_then(|line| list_item(line.trim())) {
+                items.push(item);
+                lines.next();
+            }
+            blocks.push(Block::List(items));
+        } else {
+            let mut paragraph = vec![trimmed];
+            while let Some(line) = lines.peek().map(|line| line.trim()) {
+                if line.is_empty()
+                    || line.starts_with("```")
+                    || heading_level(line).is_some()
+                    || list_item(line).is_some()
+                {
+                    break;
+                }
+                paragraph.push(line);
+                lines.next();
+            }
+            blocks.push(Block::Paragraph(paragraph));
+        }
+    }
+    blocks
+}
+
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|char| *char == '#').count();
+    ((1..=6).contains(&level) && line[level..].starts_with(' ')).then_some(level)
+}
+
+fn list_item(line: &str) -> Option<&str> {
+    line.strip_prefix("- ").or_else(|| line.strip_prefix("* "))
+}
+
+/// Returns the fenced code blocks of `markdown`.
+pub(super) fn code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    parse_blocks(markdown)
+        .into_iter()
+        .filter_map(|block| match block {
+            Block::Code(code) => Some(code),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Renders `markdown` as HTML. Headings are shifted down by `heading_offset` levels so that
+/// they nest under the heading of the item they document.
+pub(super) fn render(markdown: &str, heading_offset: usize) -> String {
+    let mut html = String::new();
+    for block in parse_blocks(markdown) {
+        match block {
+            Block::Paragraph(lines) => {
+                html.push_str(&format!("<p>{}</p>\n", render_inline(&lines.join(" "))));
+            }
+            Block::Heading(level, text) => {
+                let level = (level + heading_offset).min(6);
+                html.push_str(&format!("<h{level}>{}</h{level}>\n", render_inline(text)));
+            }
+            Block::List(items) => {
+                html.push_str("<ul>\n");
+                for item in items {
+                    html.push_str(&format!("<li>{}</li>\n", render_inline(item)));
+                }
+                html.push_str("</ul>\n");
+            }
+            Block::Code(code) => {
+                // Lines starting with `# ` are only part of the doc test, as in rustdoc
+                let lines: Vec<_> = code
+                    .lines
+                    .iter()
+                    .filter(|line| !is_hidden_line(line))
+                    .map(|line| escape(line))
+                    .collect();
+                html.push_str(&format!("<pre><code>{}</code></pre>\n", lines.join("\n")));
+            }
+        }
+    }
+    html
+}
+
+/// Renders the first paragraph of `markdown` as HTML, for listing an item along with its summary.
+pub(super) fn render_summary(markdown: &str) -> String {
+    parse_blocks(markdown)
+        .into_iter()
+        .find_map(|block| match block {
+            Block::Paragraph(lines) => Some(render_inline(&lines.join(" "))),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Returns whether `line` of a code block is hidden in the documentation.
+pub(super) fn is_hidden_line(line: &str) -> bool {
+    let line = line.trim_start();
+    line == "#" || line.starts_with("# ")
+}
+
+/// Renders inline code spans and `*`/`**` emphasis, escaping everything else.
+fn render_inline(text: &str) -> String {
+    let mut html = String::new();
+    for (index, segment) in text.split('`').enumerate() {
+        // An odd number of backticks leaves the last segment outside of any code span
+        if index % 2 == 1 && index < text.matches('`').count() {
+            html.push_str(&format!("<code>{}</code>", escape(segment)));
+        } else {
+            if index % 2 == 1 {
+                html.push('`');
+            }
+            html.push_str(&render_emphasis(&escape(segment)));
+        }
+    }
+    html
+}
+
+fn render_emphasis(text: &str) -> String {
+    let mut html = text.to_string();
+    for (marker, tag) in [("**", "strong"), ("*", "em")] {
+        let segments: Vec<_> = html.split(marker).collect();
+        let mut rendered = String::new();
+        for (index, segment) in segments.iter().enumerate() {
+            // An unmatched marker is kept as is
+            if index % 2 == 1 && index < segments.len() - 1 {
+                rendered.push_str(&format!("<{tag}>{segment}</{tag}>"));
+            } else {
+                if index % 2 == 1 {
+                    rendered.push_str(marker);
+                }
+                rendered.push_str(segment);
+            }
+        }
+        html = rendered;
+    }
+    html
+}
+
+/// Escapes `text` for use in HTML.
+pub(super) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{code_blocks, escape, is_hidden_line, render, render_summary};
+
+    const MARKDOWN: &str = "# Title
+
+Text with *emphasis*
+and **strong** words.
+
+- one
+* `two`
+
+```noir
+let x = 1;
+# let hidden = 2;
+assert(x < 2);
+```";
+
+    #[test]
+    fn renders_blocks_with_shifted_headings() {
+        assert_eq!(
+            render(MARKDOWN, 1),
+            "<h2>Title</h2>
+<p>Text with <em>emphasis</em> and <strong>strong</strong> words.</p>
+<ul>
+<li>one</li>
+<li><code>two</code></li>
+</ul>
+<pre><code>let x = 1;
+assert(x &lt; 2);</code></pre>
+"
+        );
+        assert_eq!(render("###### Deep", 3), "<h6>Deep</h6>\n");
+        assert_eq!(render("#hashtag", 0), "<p>#hashtag</p>\n");
+    }
+
+    #[test]
+    fn keeps_hidden_lines_in_code_blocks() {
+        let blocks = code_blocks(MARKDOWN);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].info, "noir");
+        assert_eq!(blocks[0].lines, vec!["let x = 1;", "# let hidden = 2;", "assert(x < 2);"]);
+
+        assert!(is_hidden_line("    # let x = 1;"));
+        assert!(is_hidden_line("#"));
+        assert!(!is_hidden_line("#[test]"));
+    }
+
+    #[test]
+    fn renders_summary_from_first_paragraph() {
+        assert_eq!(
+            render_summary(MARKDOWN),
+            "Text with <em>emphasis</em> and <strong>strong</strong> words."
+        );
+        assert_eq!(render_summary("```\ncode\n```"), "");
+    }
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(escape("<a href=\"x\">&</a>"), "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;");
+        assert_eq!(
+            render("A <b> in `Vec<u8>`, and a lone ` and *", 0),
+            "<p>A &lt;b&gt; in <code>Vec&lt;u8&gt;</code>, and a lone ` and *</p>\n"
+        );
+    }
+}
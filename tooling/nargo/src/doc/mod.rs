@@ -0,0 +1,764 @@
+//! Documentation of Noir crates, built from their elaborated definitions.
+//!
+//! `nargo doc` renders it as HTML and JSON, and `nargo test --doc` runs the code blocks in it.
+
+use std::collections::HashMap;
+
+use noirc_frontend::{
+    ast::{ItemVisibility, Visibility},
+    graph::CrateId,
+    hir::{
+        def_map::{ModuleDefId, ModuleId},
+        Context,
+    },
+    hir_def::{stmt::HirPattern, traits::TraitConstraint},
+    node_interner::{FuncId, GlobalId, ReferenceId, StructId, TraitId, TypeAliasId},
+    Kind, ResolvedGeneric, Type,
+};
+use serde::Serialize;
+
+mod doc_tests;
+mod html;
+mod markdown;
+
+pub use doc_tests::{doc_tests, DocTest, DOC_TEST_FUNCTION};
+pub use html::render_html;
+
+/// The documentation of a crate.
+#[derive(Debug, Clone, Serialize)]
+pub struct CrateDocs {
+    pub name: String,
+    pub root: ModuleDocs,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleDocs {
+    /// The path of the module, starting with the name of its crate.
+    pub path: Vec<String>,
+    pub doc: String,
+    pub modules: Vec<ModuleDocs>,
+    pub items: Vec<ItemDocs>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemKind {
+    Struct,
+    Enum,
+    Trait,
+    TypeAlias,
+    Global,
+    Function,
+}
+
+impl ItemKind {
+    /// The keyword declaring an item of this kind.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            ItemKind::Struct => "struct",
+            ItemKind::Enum => "enum",
+            ItemKind::Trait => "trait",
+            ItemKind::TypeAlias => "type",
+            ItemKind::Global => "global",
+            ItemKind::Function => "fn",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemDocs {
+    pub kind: ItemKind,
+    pub name: String,
+    pub doc: String,
+    pub signature: Signature,
+    /// The fields of a struct, or the variants of an enum.
+    pub fields: Vec<FieldDocs>,
+    /// The methods of a struct or a trait.
+    pub methods: Vec<ItemDocs>,
+    /// The trait implementations of a struct, or the implementations of a trait.
+    pub implementations: Vec<Signature>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldDocs {
+    pub name: String,
+    pub doc: String,
+    pub signature: Signature,
+}
+
+/// The signature of an item, split into parts some of which link to the items they name.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Signature(pub Vec<SignaturePart>);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SignaturePart {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<ItemLink>,
+}
+
+/// A documented item, as it is linked to from signatures.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ItemLink {
+    /// The path of the module defining the item, starting with the name of its crate.
+    pub module: Vec<String>,
+    pub kind: ItemKind,
+    pub name: String,
+}
+
+impl Signature {
+    fn push_str(&mut self, text: &str) {
+        match self.0.last_mut() {
+            Some(SignaturePart { text: last, link: None }) => last.push_str(text),
+            _ => self.0.push(SignaturePart { text: text.to_string(), link: None }),
+        }
+    }
+
+    fn push_link(&mut self, text: &str, link: Option<&ItemLink>) {
+        match link {
+            Some(link) => {
+                self.0.push(SignaturePart { text: text.to_string(), link: Some(link.clone()) });
+            }
+            None => self.push_str(text),
+        }
+    }
+}
+
+impl std::fmt::Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for part in &self.0 {
+            write!(f, "{}", part.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// Documents the crate `crate_id` of an elaborated `context` under the name `crate_name`, followed
+/// by its dependencies other than the standard library if `include_dependencies` is set.
+///
+/// Only public items are documented, unless `include_private_items` is set.
+pub fn document_crates(
+    context: &Context,
+    crate_id: CrateId,
+    crate_name: &str,
+    include_dependencies: bool,
+    include_private_items: bool,
+) -> Vec<CrateDocs> {
+    let mut crates = vec![(crate_id, crate_name.to_string())];
+    if include_dependencies {
+        collect_dependencies(context, crate_id, &mut crates);
+    }
+
+    let mut builder = DocBuilder {
+        context,
+        include_private_items,
+        links: HashMap::new(),
+        implementations: HashMap::new(),
+    };
+    for (crate_id, name) in &crates {
+        builder.collect_links(builder.root_module(*crate_id), vec![name.clone()]);
+    }
+    builder.collect_trait_implementations();
+
+    crates
+        .into_iter()
+        .map(|(crate_id, name)| {
+            let root = builder.document_module(builder.root_module(crate_id), vec![name.clone()]);
+            CrateDocs { name, root }
+        })
+        .collect()
+}
+
+/// Collects the dependencies of `crate_id`, other than the standard library, under the name
+/// they were first depended on with.
+fn collect_dependencies(context: &Context, crate_id: CrateId, crates: &mut Vec<(CrateId, String)>) {
+    for dependency in &context.crate_graph[crate_id].dependencies {
+        if dependency.crate_id.is_stdlib()
+            || crates.iter().any(|(id, _)| *id == dependency.crate_id)
+        {
+            continue;
+        }
+        crates.push((dependency.crate_id, dependency.name.to_string()));
+        collect_dependencies(context, dependency.crate_id, crates);
+    }
+}
+
+struct DocBuilder<'a, 'file_manager, 'parsed_files> {
+    context: &'a Context<'file_manager, 'parsed_files>,
+    include_private_items: bool,
+    /// The documented items which signatures can link to.
+    links: HashMap<ModuleDefId, ItemLink>,
+    /// The implementations of each documented trait by documented structs.
+    implementations: HashMap<TraitId, Vec<Signature>>,
+}
+
+impl DocBuilder<'_, '_, '_> {
+    fn root_module(&self, crate_id: CrateId) -> ModuleId {
+        ModuleId { krate: crate_id, local_id: self.context.def_maps[&crate_id].root() }
+    }
+
+    fn is_documented(&self, visibility: ItemVisibility) -> bool {
+        self.include_private_items || visibility == ItemVisibility::Public
+    }
+
+    /// Returns the documented items defined directly in `module_id`, sorted by name,
+    /// along with their visibility.
+    fn module_items(&self, module_id: ModuleId) -> Vec<(String, ModuleDefId, ItemVisibility)> {
+        let module = &self.context.def_maps[&module_id.krate][module_id.local_id];
+        let definitions = module.definitions();
+
+        let mut items: Vec<_> = definitions
+            .types()
+            .iter()
+            .chain(definitions.values())
+            .filter_map(|(name, scope)| {
+                // Items keyed by a trait are the methods of that trait
+                let (id, visibility, _) = scope.get(&None)?;
+                self.is_documented(*visibility).then(|| (name.to_string(), *id, *visibility))
+            })
+            .collect();
+        items.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+        items
+    }
+
+    fn collect_links(&mut self, module_id: ModuleId, path: Vec<String>) {
+        for (name, id, _) in self.module_items(module_id) {
+            let kind = match id {
+                ModuleDefId::ModuleId(child_id) => {
+                    let mut child_path = path.clone();
+                    child_path.push(name);
+                    self.collect_links(child_id, child_path);
+                    continue;
+                }
+                ModuleDefId::TypeId(struct_id) => {
+                    if self.context.def_interner.get_struct(struct_id).borrow().is_enum() {
+                        ItemKind::Enum
+                    } else {
+                        ItemKind::Struct
+                    }
+                }
+                ModuleDefId::TraitId(_) => ItemKind::Trait,
+                ModuleDefId::TypeAliasId(_) => ItemKind::TypeAlias,
+                ModuleDefId::GlobalId(_) => ItemKind::Global,
+                ModuleDefId::FunctionId(_) => ItemKind::Function,
+            };
+            self.links.insert(id, ItemLink { module: path.clone(), kind, name });
+        }
+    }
+
+    fn collect_trait_implementations(&mut self) {
+        let mut struct_ids: Vec<StructId> = self
+            .links
+            .keys()
+            .filter_map(|id| match id {
+                ModuleDefId::TypeId(struct_id) => Some(*struct_id),
+                _ => None,
+            })
+            .collect();
+        struct_ids.sort();
+
+        for struct_id in struct_ids {
+            for (trait_id, implementation) in self.struct_trait_implementations(struct_id) {
+                self.implementations.entry(trait_id).or_default().push(implementation);
+            }
+        }
+    }
+
+    /// Returns each trait implemented by the struct `struct_id`, along with the signature of its implementation.
+    fn struct_trait_implementations(&self, struct_id: StructId) -> Vec<(TraitId, Signature)> {
+        let interner = &self.context.def_interner;
+        let mut impl_ids = Vec::new();
+        for methods in interner.get_struct_methods(struct_id).into_iter().flat_map(|m| m.values()) {
+            for method in &methods.trait_impl_methods {
+                if let Some(impl_id) = interner.function_meta(&method.method).trait_impl {
+                    if !impl_ids.contains(&impl_id) {
+                        impl_ids.push(impl_id);
+                    }
+                }
+            }
+        }
+
+        let mut implementations: Vec<_> = impl_ids
+            .into_iter()
+            .map(|impl_id| {
+                let trait_impl = interner.get_trait_implementation(impl_id);
+                let trait_impl = trait_impl.borrow();
+                let trait_ = interner.get_trait(trait_impl.trait_id);
+
+                let mut signature = Signature::default();
+                signature.push_str("impl ");
+                signature.push_link(
+                    &trait_.name.to_string(),
+                    self.links.get(&ModuleDefId::TraitId(trait_impl.trait_id)),
+                );
+                self.push_type_arguments(&trait_impl.trait_generics, &mut signature);
+                signature.push_str(" for ");
+                self.push_type(&trait_impl.typ, &mut signature);
+                self.push_where_clause(&trait_impl.where_clause, &mut signature);
+                (trait_impl.trait_id, signature)
+            })
+            .collect();
+        implementations.sort_by_key(|(_, signature)| signature.to_string());
+        implementations
+    }
+
+    fn document_module(&self, module_id: ModuleId, path: Vec<String>) -> ModuleDocs {
+        let mut modules = Vec::new();
+        let mut items = Vec::new();
+        for (name, id, visibility) in self.module_items(module_id) {
+            match id {
+                ModuleDefId::ModuleId(child_id) => {
+                    let mut child_path = path.clone();
+                    child_path.push(name);
+                    modules.push(self.document_module(child_id, child_path));
+                }
+                ModuleDefId::TypeId(struct_id) => {
+                    items.push(self.document_struct(struct_id, visibility));
+                }
+                ModuleDefId::TraitId(trait_id) => {
+                    items.push(self.document_trait(trait_id, visibility));
+                }
+                ModuleDefId::TypeAliasId(alias_id) => {
+                    items.push(self.document_type_alias(alias_id, visibility));
+                }
+                ModuleDefId::GlobalId(global_id) => {
+                    items.push(self.document_global(global_id, &name, visibility));
+                }
+                ModuleDefId::FunctionId(func_id) => items.push(self.document_function(func_id)),
+            }
+        }
+        items.sort_by_key(|item| item.kind);
+
+        let doc = self.doc_comments(ReferenceId::Module(module_id));
+        ModuleDocs { path, doc, modules, items }
+    }
+
+    fn document_struct(&self, struct_id: StructId, visibility: ItemVisibility) -> ItemDocs {
+        let interner = &self.context.def_interner;
+        let struct_type = interner.get_struct(struct_id);
+        let struct_type = struct_type.borrow();
+        let kind = if struct_type.is_enum() { ItemKind::Enum } else { ItemKind::Struct };
+        let name = struct_type.name.to_string();
+
+        let mut signature = Signature::default();
+        push_visibility(visibility, &mut signature);
+        signature.push_str(&format!("{} {name}", kind.keyword()));
+        push_generics(&struct_type.generics, &mut signature);
+
+        let fields = if struct_type.is_enum() {
+            // Enum variants have no doc comments of their own
+            let variants = struct_type.get_variants_as_written().into_iter();
+            variants
+                .map(|variant| {
+                    let mut signature = Signature::default();
+                    signature.push_str(&variant.name.to_string());
+                    if !variant.params.is_empty() {
+                        signature.push_str("(");
+                        self.push_types(&variant.params, &mut signature);
+                        signature.push_str(")");
+                    }
+                    FieldDocs { name: variant.name.to_string(), doc: String::new(), signature }
+                })
+                .collect()
+        } else {
+            let fields = struct_type.get_fields_as_written().into_iter().enumerate();
+            fields
+                .filter(|(_, field)| self.is_documented(field.visibility))
+                .map(|(index, field)| {
+                    let mut signature = Signature::default();
+                    push_visibility(field.visibility, &mut signature);
+                    signature.push_str(&format!("{}: ", field.name));
+                    self.push_type(&field.typ, &mut signature);
+                    let doc = self.doc_comments(ReferenceId::StructMember(struct_id, index));
+                    FieldDocs { name: field.name.to_string(), doc, signature }
+                })
+                .collect()
+        };
+
+        let mut methods: Vec<ItemDocs> = interner
+            .get_struct_methods(struct_id)
+            .into_iter()
+            .flat_map(|methods| methods.values())
+            .flat_map(|methods| methods.direct.iter())
+            .filter(|func_id| self.is_documented(interner.function_visibility(**func_id)))
+            .map(|func_id| self.document_function(*func_id))
+            .collect();
+        methods.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let implementations = self
+            .struct_trait_implementations(struct_id)
+            .into_iter()
+            .map(|(_, signature)| signature)
+            .collect();
+
+        let doc = self.doc_comments(ReferenceId::Struct(struct_id));
+        ItemDocs { kind, name, doc, signature, fields, methods, implementations }
+    }
+
+    fn document_trait(&self, trait_id: TraitId, visibility: ItemVisibility) -> ItemDocs {
+        let trait_ = self.context.def_interner.get_trait(trait_id);
+        let name = trait_.name.to_string();
+
+        let mut signature = Signature::default();
+        push_visibility(visibility, &mut signature);
+        signature.push_str(&format!("trait {name}"));
+        push_generics(&trait_.generics, &mut signature);
+        for (index, bound) in trait_.trait_bounds.iter().enumerate() {
+            signature.push_str(if index == 0 { ": " } else { " + " });
+            let bound_trait = self.context.def_interner.get_trait(bound.trait_id);
+            signature.push_link(
+                &bound_trait.name.to_string(),
+                self.links.get(&ModuleDefId::TraitId(bound.trait_id)),
+            );
+            signature.push_str(&bound.trait_generics.to_string());
+        }
+        self.push_where_clause(&trait_.where_clause, &mut signature);
+
+        let methods = trait_
+            .methods
+            .iter()
+            .filter_map(|method| trait_.method_ids.get(&method.name.to_string()))
+            .map(|func_id| self.document_function(*func_id))
+            .collect();
+        let implementations = self.implementations.get(&trait_id).cloned().unwrap_or_default();
+
+        let doc = self.doc_comments(ReferenceId::Trait(trait_id));
+        ItemDocs {
+            kind: ItemKind::Trait,
+            name,
+            doc,
+            signature,
+            fields: Vec::new(),
+            methods,
+            implementations,
+        }
+    }
+
+    fn document_type_alias(&self, alias_id: TypeAliasId, visibility: ItemVisibility) -> ItemDocs {
+        let type_alias = self.context.def_interner.get_type_alias(alias_id);
+        let type_alias = type_alias.borrow();
+        let name = type_alias.name.to_string();
+
+        let mut signature = Signature::default();
+        push_visibility(visibility, &mut signature);
+        signature.push_str(&format!("type {name}"));
+        push_generics(&type_alias.generics, &mut signature);
+        signature.push_str(" = ");
+        self.push_type(&type_alias.typ, &mut signature);
+
+        let doc = self.doc_comments(ReferenceId::Alias(alias_id));
+        ItemDocs::without_members(ItemKind::TypeAlias, name, doc, signature)
+    }
+
+    fn document_global(
+        &self,
+        global_id: GlobalId,
+        name: &str,
+        visibility: ItemVisibility,
+    ) -> ItemDocs {
+        let interner = &self.context.def_interner;
+        let global = interner.get_global(global_id);
+
+        let mut signature = Signature::default();
+        push_visibility(visibility, &mut signature);
+        signature.push_str(&format!("global {name}: "));
+        self.push_type(&interner.definition_type(global.definition_id), &mut signature);
+
+        let doc = self.doc_comments(ReferenceId::Global(global_id));
+        ItemDocs::without_members(ItemKind::Global, name.to_string(), doc, signature)
+    }
+
+    fn document_function(&self, func_id: FuncId) -> ItemDocs {
+        let interner = &self.context.def_interner;
+        let func_meta = interner.function_meta(&func_id);
+        let modifiers = interner.function_modifiers(&func_id);
+        let name = modifiers.name.clone();
+
+        let mut signature = Signature::default();
+        // Trait methods are as visible as their trait
+        if func_meta.trait_id.is_none() {
+            push_visibility(modifiers.visibility, &mut signature);
+        }
+        if modifiers.is_unconstrained {
+            signature.push_str("unconstrained ");
+        }
+        if modifiers.is_comptime {
+            signature.push_str("comptime ");
+        }
+        signature.push_str(&format!("fn {name}"));
+        push_generics(&func_meta.direct_generics, &mut signature);
+
+        signature.push_str("(");
+        for (index, (pattern, typ, visibility)) in func_meta.parameters.iter().enumerate() {
+            if index > 0 {
+                signature.push_str(", ");
+            }
+            let pattern_name = self.pattern_name(pattern);
+            signature.push_str(&pattern_name);
+            if pattern_name != "self" && pattern_name != "mut self" {
+                signature.push_str(": ");
+                if *visibility == Visibility::Public {
+                    signature.push_str("pub ");
+                }
+                self.push_type(typ, &mut signature);
+            }
+        }
+        signature.push_str(")");
+
+        let return_type = func_meta.return_type();
+        if *return_type != Type::Unit {
+            signature.push_str(" -> ");
+            self.push_type(return_type, &mut signature);
+        }
+        self.push_where_clause(&func_meta.trait_constraints, &mut signature);
+
+        let doc = self.doc_comments(ReferenceId::Function(func_id));
+        ItemDocs::without_members(ItemKind::Function, name, doc, signature)
+    }
+
+    fn pattern_name(&self, pattern: &HirPattern) -> String {
+        match pattern {
+            HirPattern::Identifier(ident) => {
+                self.context.def_interner.definition(ident.id).name.clone()
+            }
+            HirPattern::Mutable(pattern, _) => format!("mut {}", self.pattern_name(pattern)),
+            HirPattern::Tuple(..) | HirPattern::Struct(..) => "_".to_string(),
+        }
+    }
+
+    fn push_where_clause(&self, constraints: &[TraitConstraint], signature: &mut Signature) {
+        for (index, constraint) in constraints.iter().enumerate() {
+            signature.push_str(if index == 0 { " where " } else { ", " });
+            self.push_type(&constraint.typ, signature);
+            signature.push_str(": ");
+            let trait_id = constraint.trait_bound.trait_id;
+            let trait_ = self.context.def_interner.get_trait(trait_id);
+            signature.push_link(
+                &trait_.name.to_string(),
+                self.links.get(&ModuleDefId::TraitId(trait_id)),
+            );
+            signature.push_str(&constraint.trait_bound.trait_generics.to_string());
+        }
+    }
+
+    /// Writes `typ` to `signature`, linking the documented structs, aliases and traits it refers to.
+    fn push_type(&self, typ: &Type, signature: &mut Signature) {
+        match typ {
+            Type::Struct(struct_type, generics) => {
+                let struct_type = struct_type.borrow();
+                let link = self.links.get(&ModuleDefId::TypeId(struct_type.id));
+                signature.push_link(&struct_type.name.to_string(), link);
+                self.push_type_arguments(generics, signature);
+            }
+            Type::Alias(type_alias, generics) => {
+                let type_alias = type_alias.borrow();
+                let link = self.links.get(&ModuleDefId::TypeAliasId(type_alias.id));
+                signature.push_link(&type_alias.name.to_string(), link);
+                self.push_type_arguments(generics, signature);
+            }
+            Type::TraitAsType(trait_id, name, generics) => {
+                signature.push_str("impl ");
+                signature.push_link(name, self.links.get(&ModuleDefId::TraitId(*trait_id)));
+                signature.push_str(&generics.to_string());
+            }
+            Type::Array(length, element) => {
+                signature.push_str("[");
+                self.push_type(element, signature);
+                signature.push_str(&format!("; {length}]"));
+            }
+            Type::Slice(element) => {
+                signature.push_str("[");
+                self.push_type(element, signature);
+                signature.push_str("]");
+            }
+            Type::Tuple(elements) => {
+                signature.push_str("(");
+                self.push_types(elements, signature);
+                signature.push_str(")");
+            }
+            Type::MutableReference(element) => {
+                signature.push_str("&mut ");
+                self.push_type(element, signature);
+            }
+            _ => signature.push_str(&typ.to_string()),
+        }
+    }
+
+    fn push_types(&self, types: &[Type], signature: &mut Signature) {
+        for (index, typ) in types.iter().enumerate() {
+            if index > 0 {
+                signature.push_str(", ");
+            }
+            self.push_type(typ, signature);
+        }
+    }
+
+    fn push_type_arguments(&self, types: &[Type], signature: &mut Signature) {
+        if !types.is_empty() {
+            signature.push_str("<");
+            self.push_types(types, signature);
+            signature.push_str(">");
+        }
+    }
+
+    /// Returns the doc comments of `id`, without the space usually following `///` or `//!`.
+    fn doc_comments(&self, id: ReferenceId) -> String {
+        let Some(comments) = self.context.def_interner.doc_comments(id) else {
+            return String::new();
+        };
+        let lines = comments.iter().flat_map(|comment| comment.lines());
+        let lines: Vec<_> = lines.map(|line| line.strip_prefix(' ').unwrap_or(line)).collect();
+        lines.join("\n")
+    }
+}
+
+impl ItemDocs {
+    fn without_members(kind: ItemKind, name: String, doc: String, signature: Signature) -> Self {
+        ItemDocs {
+            kind,
+            name,
+            doc,
+            signature,
+            fields: Vec::new(),
+            methods: Vec::new(),
+            implementations: Vec::new(),
+        }
+    }
+}
+
+fn push_visibility(visibility: ItemVisibility, signature: &mut Signature) {
+    if visibility != ItemVisibility::Private {
+        signature.push_str(&format!("{visibility} "));
+    }
+}
+
+fn push_generics(generics: &[ResolvedGeneric], signature: &mut Signature) {
+    if generics.is_empty() {
+        return;
+    }
+
+    let generics: Vec<_> = generics
+        .iter()
+        .map(|generic| match generic.kind() {
+            Kind::Any | Kind::Normal => generic.name.to_string(),
+            Kind::IntegerOrField | Kind::Integer => format!("let {}: u32", generic.name),
+            Kind::Numeric(typ) => format!("let {}: {typ}", generic.name),
+        })
+        .collect();
+    signature.push_str(&format!("<{}>", generics.join(", ")));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use noirc_driver::{check_crate, file_manager_with_stdlib, prepare_crate, CompileOptions};
+    use noirc_frontend::hir::Context;
+
+    use super::{document_crates, CrateDocs, ItemDocs};
+
+    const SOURCE: &str = "//! The crate.
+
+/// A public struct.
+pub struct Foo {
+    /// A public field.
+    pub x: Field,
+    y: Field,
+}
+
+impl Foo {
+    pub fn new() -> Self {
+        Foo { x: 1, y: 2 }
+    }
+
+    fn secret(self) -> Field {
+        self.y
+    }
+}
+
+struct Hidden {}
+
+pub fn make(foo: Foo) -> Foo {
+    foo
+}
+
+fn private_function() {}
+
+pub mod inner {
+    pub fn nested() {}
+}
+
+mod hidden_module {
+    pub fn function() {}
+}
+";
+
+    fn document(include_private_items: bool) -> CrateDocs {
+        let mut file_manager = file_manager_with_stdlib(Path::new("/lib"));
+        file_manager.add_file_with_source(Path::new("src/lib.nr"), SOURCE.to_string());
+        let parsed_files = crate::parse_all(&file_manager);
+
+        let mut context = Context::from_ref_file_manager(&file_manager, &parsed_files);
+        let crate_id = prepare_crate(&mut context, Path::new("/lib/src/lib.nr"));
+        check_crate(&mut context, crate_id, &CompileOptions::default()).expect("crate has errors");
+        document_crates(&context, crate_id, "lib", false, include_private_items).remove(0)
+    }
+
+    fn names<'a>(items: impl IntoIterator<Item = &'a ItemDocs>) -> Vec<&'a str> {
+        items.into_iter().map(|item| item.name.as_str()).collect()
+    }
+
+    #[test]
+    fn documents_public_items() {
+        let docs = document(false);
+        assert_eq!(docs.name, "lib");
+        assert_eq!(docs.root.doc, "The crate.");
+
+        let modules: Vec<_> = docs.root.modules.iter().map(|module| module.path.clone()).collect();
+        assert_eq!(modules, vec![vec!["lib".to_string(), "inner".to_string()]]);
+        assert_eq!(names(&docs.root.items), vec!["Foo", "make"]);
+
+        let foo = &docs.root.items[0];
+        assert_eq!(foo.doc, "A public struct.");
+        assert_eq!(foo.signature.to_string(), "pub struct Foo");
+        let fields: Vec<_> = foo.fields.iter().map(|field| field.signature.to_string()).collect();
+        assert_eq!(fields, vec!["pub x: Field"]);
+        assert_eq!(foo.fields[0].doc, "A public field.");
+        assert_eq!(names(&foo.methods), vec!["new"]);
+    }
+
+    #[test]
+    fn documents_private_items_when_included() {
+        let docs = document(true);
+
+        let modules: Vec<_> = docs.root.modules.iter().map(|module| module.path.clone()).collect();
+        let module_path = |name: &str| vec!["lib".to_string(), name.to_string()];
+        assert_eq!(modules, vec![module_path("hidden_module"), module_path("inner")]);
+        assert_eq!(names(&docs.root.items), vec!["Foo", "Hidden", "make", "private_function"]);
+
+        let foo = &docs.root.items[0];
+        let fields: Vec<_> = foo.fields.iter().map(|field| field.signature.to_string()).collect();
+        assert_eq!(fields, vec!["pub x: Field", "y: Field"]);
+        assert_eq!(names(&foo.methods), vec!["new", "secret"]);
+    }
+
+    #[test]
+    fn serializes_signature_links_to_json() {
+        let docs = document(false);
+        let json = serde_json::to_value(&docs).unwrap();
+
+        let make = &json["root"]["items"][1];
+        assert_eq!(make["kind"], "function");
+        assert_eq!(make["name"], "make");
+        assert_eq!(
+            make["signature"],
+            serde_json::json!([
+                { "text": "pub fn make(foo: " },
+                { "text": "Foo", "link": { "module": ["lib"], "kind": "struct", "name": "Foo" } },
+                { "text": ") -> " },
+                { "text": "Foo", "link": { "module": ["lib"], "kind": "struct", "name": "Foo" } },
+            ])
+        );
+        assert_eq!(json["root"]["modules"][0]["items"][0]["name"], "nested");
+    }
+}
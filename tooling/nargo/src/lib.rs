@@ -8,6 +8,7 @@
 //! Noir Package Manager abbreviated is npm, which is already taken.
 
 pub mod constants;
+pub mod doc;
 pub mod errors;
 pub mod ops;
 pub mod package;
@@ -19,11 +20,12 @@ use std::{
 };
 
 use fm::{FileManager, FILE_EXTENSION};
-use noirc_driver::{add_dep, prepare_crate, prepare_dependency};
+use noirc_driver::{add_dep, prepare_crate, prepare_dependency, CompileOptions};
 use noirc_frontend::{
     graph::{CrateId, CrateName},
     hir::{def_map::parse_file, Context, ParsedFiles},
 };
+use ops::RpcComptimeOracleResolver;
 use package::{Dependency, Package};
 use rayon::prelude::*;
 use walkdir::WalkDir;
@@ -37,8 +39,9 @@ pub fn prepare_dependencies(
 ) {
     for (dep_name, dep) in dependencies.iter() {
         match dep {
-            Dependency::Remote { package } | Dependency::Local { package } => {
+            Dependency::Remote { package, .. } | Dependency::Local { package, .. } => {
                 let crate_id = prepare_dependency(context, &package.entry_path);
+                apply_package_settings(context, crate_id, package);
                 add_dep(context, parent_crate, crate_id, dep_name.clone());
                 prepare_dependencies(context, crate_id, &package.dependencies);
             }
@@ -46,6 +49,14 @@ pub fn prepare_dependencies(
     }
 }
 
+/// Applies the settings `package` declares in its manifest to `crate_id`, the crate of its entry point
+/// or of one of its doc tests.
+fn apply_package_settings(context: &mut Context, crate_id: CrateId, package: &Package) {
+    context.set_package_root(crate_id, package.root_dir.clone());
+    context.set_lint_levels(crate_id, package.lints.clone());
+    context.enable_features(crate_id, package.enabled_features.clone());
+}
+
 pub fn insert_all_files_for_workspace_into_file_manager(
     workspace: &workspace::Workspace,
     file_manager: &mut FileManager,
@@ -141,9 +152,9 @@ fn insert_all_files_for_packages_dependencies_into_file_manager(
     overrides: &HashMap<&std::path::Path, &str>,
     processed_entry_paths: &mut HashSet<PathBuf>,
 ) {
-    for (_, dep) in package.dependencies.iter() {
+    for (_, dep) in package.dependencies.iter().chain(&package.dev_dependencies) {
         match dep {
-            Dependency::Local { package } | Dependency::Remote { package } => {
+            Dependency::Local { package, .. } | Dependency::Remote { package, .. } => {
                 insert_all_files_for_package_into_file_manager(
                     package,
                     file_manager,
@@ -174,12 +185,82 @@ pub fn prepare_package<'file_manager, 'parsed_files>(
     file_manager: &'file_manager FileManager,
     parsed_files: &'parsed_files ParsedFiles,
     package: &Package,
+) -> (Context<'file_manager, 'parsed_files>, CrateId) {
+    prepare_package_with_dev_dependencies(file_manager, parsed_files, package, false)
+}
+
+/// Prepares `package` for running its tests, linking its dev-dependencies as well as its dependencies.
+///
+/// When a package has dev-dependencies, its `#[test]` functions are only compiled in this case,
+/// as they may use them.
+pub fn prepare_package_for_tests<'file_manager, 'parsed_files>(
+    file_manager: &'file_manager FileManager,
+    parsed_files: &'parsed_files ParsedFiles,
+    package: &Package,
+) -> (Context<'file_manager, 'parsed_files>, CrateId) {
+    prepare_package_with_dev_dependencies(file_manager, parsed_files, package, true)
+}
+
+fn prepare_package_with_dev_dependencies<'file_manager, 'parsed_files>(
+    file_manager: &'file_manager FileManager,
+    parsed_files: &'parsed_files ParsedFiles,
+    package: &Package,
+    include_dev_dependencies: bool,
 ) -> (Context<'file_manager, 'parsed_files>, CrateId) {
     let mut context = Context::from_ref_file_manager(file_manager, parsed_files);
 
     let crate_id = prepare_crate(&mut context, &package.entry_path);
+    apply_package_settings(&mut context, crate_id, package);
 
     prepare_dependencies(&mut context, crate_id, &package.dependencies);
+    if include_dev_dependencies {
+        prepare_dependencies(&mut context, crate_id, &package.dev_dependencies);
+    } else if !package.dev_dependencies.is_empty() {
+        context.skip_test_functions(crate_id);
+    }
 
     (context, crate_id)
 }
+
+/// Prepares the doc test at `test_path`, which must already be in the file manager, as a crate
+/// depending on `package` under its own name along with its dev-dependencies.
+///
+/// `package` is compiled as it is by [prepare_package], and the doc test itself with the lint
+/// levels and features of `package`.
+pub fn prepare_doc_test<'file_manager, 'parsed_files>(
+    file_manager: &'file_manager FileManager,
+    parsed_files: &'parsed_files ParsedFiles,
+    package: &Package,
+    test_path: &std::path::Path,
+) -> (Context<'file_manager, 'parsed_files>, CrateId) {
+    let mut context = Context::from_ref_file_manager(file_manager, parsed_files);
+
+    let crate_id = prepare_crate(&mut context, test_path);
+    apply_package_settings(&mut context, crate_id, package);
+
+    let package_crate_id = prepare_dependency(&mut context, &package.entry_path);
+    apply_package_settings(&mut context, package_crate_id, package);
+    if !package.dev_dependencies.is_empty() {
+        context.skip_test_functions(package_crate_id);
+    }
+    add_dep(&mut context, crate_id, package_crate_id, package.name.clone());
+    prepare_dependencies(&mut context, package_crate_id, &package.dependencies);
+    prepare_dependencies(&mut context, crate_id, &package.dev_dependencies);
+
+    (context, crate_id)
+}
+
+/// Lets comptime code in `package` call oracles if a JSON RPC resolver for them was
+/// passed with `--comptime-oracle-resolver`. Otherwise such calls are compilation errors.
+pub fn prepare_comptime_oracle_resolver(
+    context: &mut Context,
+    package: &Package,
+    compile_options: &CompileOptions,
+) {
+    if let Some(resolver_url) = &compile_options.comptime_oracle_resolver {
+        let root_path = Some(package.root_dir.clone());
+        let package_name = Some(package.name.to_string());
+        let resolver = RpcComptimeOracleResolver::new(resolver_url, root_path, package_name);
+        context.set_comptime_oracle_resolver(Box::new(resolver));
+    }
+}
@@ -1,13 +1,15 @@
-use fm::FileManager;
+use fm::{FileId, FileManager};
 use noirc_driver::{
-    link_to_debug_crate, CompilationResult, CompileOptions, CompiledContract, CompiledProgram,
+    check_crate, compile_no_check, link_to_debug_crate, CompilationResult, CompileOptions,
+    CompiledContract, CompiledProgram,
 };
+use noirc_errors::{CustomDiagnostic, FileDiagnostic};
 use noirc_frontend::debug::DebugInstrumenter;
-use noirc_frontend::hir::ParsedFiles;
+use noirc_frontend::hir::{FunctionNameMatch, ParsedFiles};
 
 use crate::errors::CompileError;
-use crate::prepare_package;
 use crate::{package::Package, workspace::Workspace};
+use crate::{prepare_comptime_oracle_resolver, prepare_package, prepare_package_for_tests};
 
 use rayon::prelude::*;
 
@@ -37,7 +39,9 @@ pub fn compile_workspace(
         .collect();
     let contract_results: Vec<CompilationResult<CompiledContract>> = contract_packages
         .par_iter()
-        .map(|package| compile_contract(file_manager, parsed_files, package, compile_options))
+        .map(|package| {
+            compile_contract(file_manager, parsed_files, workspace, package, compile_options)
+        })
         .collect();
 
     // Collate any warnings/errors which were encountered during compilation.
@@ -85,21 +89,68 @@ pub fn compile_program_with_debug_instrumenter(
     debug_instrumenter: DebugInstrumenter,
 ) -> CompilationResult<CompiledProgram> {
     let (mut context, crate_id) = prepare_package(file_manager, parsed_files, package);
+    prepare_comptime_oracle_resolver(&mut context, package, compile_options);
+    link_to_debug_crate(&mut context, crate_id);
+    context.debug_instrumenter = debug_instrumenter;
+    context.package_build_path = workspace.package_build_path(package);
+
+    let result =
+        noirc_driver::compile_main(&mut context, crate_id, compile_options, cached_program);
+    workspace.record_comptime_inputs(context.comptime_file_inputs());
+    result
+}
+
+/// Compiles the test function named `test_name` as if it was the entry point of the package,
+/// so that it can be executed on its own (for example by the debugger).
+pub fn compile_test_with_debug_instrumenter(
+    file_manager: &FileManager,
+    parsed_files: &ParsedFiles,
+    workspace: &Workspace,
+    package: &Package,
+    test_name: &str,
+    compile_options: &CompileOptions,
+    debug_instrumenter: DebugInstrumenter,
+) -> CompilationResult<CompiledProgram> {
+    let (mut context, crate_id) = prepare_package_for_tests(file_manager, parsed_files, package);
+    prepare_comptime_oracle_resolver(&mut context, package, compile_options);
     link_to_debug_crate(&mut context, crate_id);
     context.debug_instrumenter = debug_instrumenter;
     context.package_build_path = workspace.package_build_path(package);
 
-    noirc_driver::compile_main(&mut context, crate_id, compile_options, cached_program)
+    let result = check_crate(&mut context, crate_id, compile_options);
+    workspace.record_comptime_inputs(context.comptime_file_inputs());
+    let (_, warnings) = result?;
+
+    let test_functions = context
+        .get_all_test_functions_in_crate_matching(&crate_id, FunctionNameMatch::Exact(test_name));
+    let Some((_, test_function)) = test_functions.into_iter().next() else {
+        let err = CustomDiagnostic::from_message(&format!(
+            "cannot find a test function named `{test_name}` in package `{}`",
+            package.name
+        ))
+        .in_file(FileId::default());
+        return Err(vec![err]);
+    };
+
+    let program =
+        compile_no_check(&mut context, compile_options, test_function.get_id(), None, false)
+            .map_err(|error| vec![FileDiagnostic::from(error)])?;
+
+    Ok((program, warnings))
 }
 
 pub fn compile_contract(
     file_manager: &FileManager,
     parsed_files: &ParsedFiles,
+    workspace: &Workspace,
     package: &Package,
     compile_options: &CompileOptions,
 ) -> CompilationResult<CompiledContract> {
     let (mut context, crate_id) = prepare_package(file_manager, parsed_files, package);
-    noirc_driver::compile_contract(&mut context, crate_id, compile_options)
+    prepare_comptime_oracle_resolver(&mut context, package, compile_options);
+    let result = noirc_driver::compile_contract(&mut context, crate_id, compile_options);
+    workspace.record_comptime_inputs(context.comptime_file_inputs());
+    result
 }
 
 /// Constructs a single `CompilationResult` for a collection of `CompilationResult`s, merging the set of warnings/errors.
@@ -3,9 +3,10 @@ use std::path::PathBuf;
 use acvm::{
     acir::brillig::{ForeignCallParam, ForeignCallResult},
     pwg::ForeignCallWaitInfo,
-    AcirField,
+    AcirField, FieldElement,
 };
 use jsonrpc::{arg as build_json_rpc_arg, minreq_http::Builder, Client};
+use noirc_frontend::hir::comptime::ComptimeOracleResolver;
 use noirc_printable_type::{decode_string_value, ForeignCallError, PrintableValueDisplay};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
@@ -112,6 +113,8 @@ pub struct DefaultForeignCallExecutor<F> {
     mocked_responses: Vec<MockedCall<F>>,
     /// Whether to print [`ForeignCall::Print`] output.
     show_output: bool,
+    /// If set, [`ForeignCall::Print`] output is collected here instead of being printed.
+    captured_output: Option<String>,
     /// JSON RPC client to resolve foreign calls
     external_resolver: Option<Client>,
     /// Root path to the program or workspace in execution.
@@ -162,6 +165,7 @@ impl<F> DefaultForeignCallExecutor<F> {
         });
         DefaultForeignCallExecutor {
             show_output,
+            captured_output: None,
             external_resolver: oracle_resolver,
             id: rand::thread_rng().gen(),
             mocked_responses: Vec::new(),
@@ -170,6 +174,18 @@ impl<F> DefaultForeignCallExecutor<F> {
             package_name,
         }
     }
+
+    /// Collects [`ForeignCall::Print`] output instead of printing it to stdout.
+    /// The output can later be retrieved with [`DefaultForeignCallExecutor::take_captured_output`].
+    pub fn with_captured_output(mut self) -> Self {
+        self.captured_output = Some(String::new());
+        self
+    }
+
+    /// Returns the output collected so far, if output is being captured.
+    pub fn take_captured_output(&mut self) -> Option<String> {
+        self.captured_output.as_mut().map(std::mem::take)
+    }
 }
 
 impl<F: AcirField> DefaultForeignCallExecutor<F> {
@@ -197,16 +213,14 @@ impl<F: AcirField> DefaultForeignCallExecutor<F> {
         decode_string_value(&fields)
     }
 
-    fn execute_print(foreign_call_inputs: &[ForeignCallParam<F>]) -> Result<(), ForeignCallError> {
+    fn format_print(
+        foreign_call_inputs: &[ForeignCallParam<F>],
+    ) -> Result<String, ForeignCallError> {
         let skip_newline = foreign_call_inputs[0].unwrap_field().is_zero();
 
         let foreign_call_inputs =
             foreign_call_inputs.split_first().ok_or(ForeignCallError::MissingForeignCallInputs)?.1;
-        let display_string = Self::format_printable_value(foreign_call_inputs, skip_newline)?;
-
-        print!("{display_string}");
-
-        Ok(())
+        Self::format_printable_value(foreign_call_inputs, skip_newline)
     }
 
     fn format_printable_value(
@@ -231,8 +245,10 @@ impl<F: AcirField + Serialize + for<'a> Deserialize<'a>> ForeignCallExecutor<F>
         let foreign_call_name = foreign_call.function.as_str();
         match ForeignCall::lookup(foreign_call_name) {
             Some(ForeignCall::Print) => {
-                if self.show_output {
-                    Self::execute_print(&foreign_call.inputs)?;
+                if let Some(output) = &mut self.captured_output {
+                    output.push_str(&Self::format_print(&foreign_call.inputs)?);
+                } else if self.show_output {
+                    print!("{}", Self::format_print(&foreign_call.inputs)?);
                 }
                 Ok(ForeignCallResult::default())
             }
@@ -352,6 +368,33 @@ impl<F: AcirField + Serialize + for<'a> Deserialize<'a>> ForeignCallExecutor<F>
     }
 }
 
+/// Resolves oracle calls made from comptime code with an external JSON RPC resolver,
+/// using the same protocol as [`DefaultForeignCallExecutor`] does at runtime.
+pub struct RpcComptimeOracleResolver {
+    executor: DefaultForeignCallExecutor<FieldElement>,
+}
+
+impl RpcComptimeOracleResolver {
+    pub fn new(
+        resolver_url: &str,
+        root_path: Option<PathBuf>,
+        package_name: Option<String>,
+    ) -> Self {
+        let executor =
+            DefaultForeignCallExecutor::new(false, Some(resolver_url), root_path, package_name);
+        RpcComptimeOracleResolver { executor }
+    }
+}
+
+impl ComptimeOracleResolver for RpcComptimeOracleResolver {
+    fn resolve(
+        &mut self,
+        foreign_call: &ForeignCallWaitInfo<FieldElement>,
+    ) -> Result<ForeignCallResult<FieldElement>, ForeignCallError> {
+        self.executor.execute(foreign_call)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use acvm::{
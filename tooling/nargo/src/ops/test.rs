@@ -1,18 +1,26 @@
-use std::path::PathBuf;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    path::PathBuf,
+};
 
 use acvm::{
     acir::native_types::{WitnessMap, WitnessStack},
     BlackBoxFunctionSolver, FieldElement,
 };
 use noirc_abi::Abi;
-use noirc_driver::{compile_no_check, CompileError, CompileOptions};
+use noirc_driver::{compile_monomorphized, CompileError, CompileOptions};
 use noirc_errors::{debug_info::DebugInfo, FileDiagnostic};
-use noirc_frontend::hir::{def_map::TestFunction, Context};
+use noirc_frontend::{
+    hir::{def_map::TestFunction, Context},
+    monomorphization::{ast::Program, monomorphize, monomorphize_debug},
+};
 
 use crate::{errors::try_to_diagnose_runtime_error, NargoError};
 
 use super::{execute_program, DefaultForeignCallExecutor};
 
+#[derive(Clone)]
 pub enum TestStatus {
     Pass,
     Fail { message: String, error_diagnostic: Option<FileDiagnostic> },
@@ -35,6 +43,33 @@ pub fn run_test<B: BlackBoxFunctionSolver<FieldElement>>(
     root_path: Option<PathBuf>,
     package_name: Option<String>,
     config: &CompileOptions,
+) -> TestStatus {
+    let program = monomorphize_test(context, test_function, config);
+    run_monomorphized_test(
+        blackbox_solver,
+        context,
+        test_function,
+        program,
+        show_output,
+        foreign_call_resolver_url,
+        root_path,
+        package_name,
+        config,
+    )
+}
+
+/// Runs `test_function` as [run_test] does, given the result of [monomorphize_test] for it.
+#[allow(clippy::too_many_arguments)]
+pub fn run_monomorphized_test<B: BlackBoxFunctionSolver<FieldElement>>(
+    blackbox_solver: &B,
+    context: &mut Context,
+    test_function: &TestFunction,
+    program: Result<Program, CompileError>,
+    show_output: bool,
+    foreign_call_resolver_url: Option<&str>,
+    root_path: Option<PathBuf>,
+    package_name: Option<String>,
+    config: &CompileOptions,
 ) -> TestStatus {
     let test_function_has_no_arguments = context
         .def_interner
@@ -43,7 +78,10 @@ pub fn run_test<B: BlackBoxFunctionSolver<FieldElement>>(
         .0
         .is_empty();
 
-    match compile_no_check(context, config, test_function.get_id(), None, false) {
+    let compiled_program = program.and_then(|program| {
+        compile_monomorphized(context, config, test_function.get_id(), program, None, false)
+    });
+    match compiled_program {
         Ok(compiled_program) => {
             if test_function_has_no_arguments {
                 // Run the backend to ensure the PWG evaluates functions like std::hash::pedersen,
@@ -117,6 +155,39 @@ pub fn run_test<B: BlackBoxFunctionSolver<FieldElement>>(
     }
 }
 
+/// Monomorphizes `test_function` with the options it's compiled with.
+pub fn monomorphize_test(
+    context: &mut Context,
+    test_function: &TestFunction,
+    config: &CompileOptions,
+) -> Result<Program, CompileError> {
+    let program = if config.instrument_debug {
+        monomorphize_debug(
+            test_function.get_id(),
+            &mut context.def_interner,
+            &context.debug_instrumenter,
+        )?
+    } else {
+        monomorphize(test_function.get_id(), &mut context.def_interner)?
+    };
+    Ok(program)
+}
+
+/// Returns a hash of everything the result of `test_function` depends on: its monomorphized
+/// `program`, its expected failure and the options it's compiled with.
+pub fn test_program_hash(
+    program: &Program,
+    test_function: &TestFunction,
+    config: &CompileOptions,
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    program.hash(&mut hasher);
+    test_function.should_fail().hash(&mut hasher);
+    test_function.failure_reason().hash(&mut hasher);
+    format!("{config:?}").hash(&mut hasher);
+    hasher.finish()
+}
+
 /// Test function failed to compile
 ///
 /// Note: This could be because the compiler was able to deduce
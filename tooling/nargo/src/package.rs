@@ -1,7 +1,12 @@
-use std::{collections::BTreeMap, fmt::Display, path::PathBuf};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    path::PathBuf,
+};
 
 use acvm::acir::circuit::ExpressionWidth;
 pub use noirc_driver::CrateName;
+use noirc_frontend::hir::lint_levels::{Lint, LintLevel};
 
 use crate::constants::PROVER_INPUT_FILE;
 
@@ -24,20 +29,50 @@ impl Display for PackageType {
 
 #[derive(Clone)]
 pub enum Dependency {
-    Local { package: Package },
-    Remote { package: Package },
+    Local {
+        package: Package,
+        /// The semver requirement on the dependency's version set in the dependent's `Nargo.toml`
+        version_req: Option<String>,
+    },
+    Remote {
+        package: Package,
+        git: String,
+        tag: String,
+        /// The semver requirement on the dependency's version set in the dependent's `Nargo.toml`
+        version_req: Option<String>,
+    },
 }
 
 impl Dependency {
     pub fn is_binary(&self) -> bool {
+        self.package().is_binary()
+    }
+
+    pub fn package_name(&self) -> &CrateName {
+        &self.package().name
+    }
+
+    pub fn package(&self) -> &Package {
         match self {
-            Self::Local { package } | Self::Remote { package } => package.is_binary(),
+            Self::Local { package, .. } | Self::Remote { package, .. } => package,
         }
     }
 
-    pub fn package_name(&self) -> &CrateName {
+    pub fn version_req(&self) -> Option<&str> {
+        match self {
+            Self::Local { version_req, .. } | Self::Remote { version_req, .. } => {
+                version_req.as_deref()
+            }
+        }
+    }
+}
+
+/// Formats where the dependency was resolved from: its directory, or its git repository and tag.
+impl Display for Dependency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Local { package } | Self::Remote { package } => &package.name,
+            Self::Local { package, .. } => write!(f, "{}", package.root_dir.display()),
+            Self::Remote { git, tag, .. } => write!(f, "{git}#{tag}"),
         }
     }
 }
@@ -52,7 +87,18 @@ pub struct Package {
     pub entry_path: PathBuf,
     pub name: CrateName,
     pub dependencies: BTreeMap<CrateName, Dependency>,
+    /// Dependencies from the `[dev-dependencies]` table, which are only linked when compiling tests.
+    /// These are only resolved for workspace members, never for the dependencies of a package.
+    pub dev_dependencies: BTreeMap<CrateName, Dependency>,
     pub expression_width: Option<ExpressionWidth>,
+    /// The default level of each lint set in the `[lints]` table of the package's `Nargo.toml`
+    pub lints: Vec<(Lint, LintLevel)>,
+    /// The features in the `[features]` table of the package's `Nargo.toml`, each with
+    /// the features it enables in turn.
+    pub features: BTreeMap<String, Vec<String>>,
+    /// The features enabled when compiling the package, against which its `#[cfg(...)]`
+    /// attributes are checked.
+    pub enabled_features: BTreeSet<String>,
 }
 
 impl Package {
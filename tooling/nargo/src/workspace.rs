@@ -4,13 +4,15 @@
 // - library will be default
 
 use std::{
+    collections::{BTreeMap, BTreeSet},
     iter::{once, Once},
-    path::PathBuf,
+    path::{Path, PathBuf},
     slice,
+    sync::{Arc, Mutex},
 };
 
 use fm::FileManager;
-use noirc_driver::file_manager_with_stdlib;
+use noirc_driver::{file_manager_with_stdlib, CompileOptions, CompileProfile, DEFAULT_PROFILE};
 
 use crate::{
     constants::{CONTRACT_DIR, EXPORT_DIR, PROOFS_DIR, TARGET_DIR},
@@ -25,6 +27,11 @@ pub struct Workspace {
     pub selected_package_index: Option<usize>,
     /// If we could not resolve the workspace we would inform the user we have assumed it (ie. from lsp file path given)
     pub is_assumed: bool,
+    /// The build profiles declared in the root `Nargo.toml` of the workspace, along with the builtin ones.
+    pub profiles: BTreeMap<String, CompileProfile>,
+    /// The files read by comptime code while compiling the packages of the workspace, shared by its
+    /// clones. See [Workspace::record_comptime_inputs].
+    pub comptime_inputs: Arc<Mutex<BTreeSet<PathBuf>>>,
 }
 
 impl Workspace {
@@ -46,6 +53,24 @@ impl Workspace {
         self.root_dir.join(TARGET_DIR)
     }
 
+    /// Returns the profile selected by the `--profile` flag of `compile_options`, or the default
+    /// profile if it isn't set. Returns `None` if the workspace has no such profile.
+    pub fn profile(&self, compile_options: &CompileOptions) -> Option<&CompileProfile> {
+        self.profiles.get(compile_options.profile.as_deref().unwrap_or(DEFAULT_PROFILE))
+    }
+
+    /// Records `paths` as files read by comptime code while compiling a package of the workspace.
+    /// The compiled packages depend on their contents as much as on their source files.
+    pub fn record_comptime_inputs<'a>(&self, paths: impl IntoIterator<Item = &'a Path>) {
+        let mut comptime_inputs = self.comptime_inputs.lock().expect("comptime inputs lock");
+        comptime_inputs.extend(paths.into_iter().map(Path::to_path_buf));
+    }
+
+    /// Returns the files recorded by [Workspace::record_comptime_inputs] so far.
+    pub fn comptime_inputs(&self) -> BTreeSet<PathBuf> {
+        self.comptime_inputs.lock().expect("comptime inputs lock").clone()
+    }
+
     pub fn export_directory_path(&self) -> PathBuf {
         self.root_dir.join(EXPORT_DIR)
     }
@@ -42,6 +42,8 @@ fn read_compiled_programs_and_inputs(
         &toml_path,
         nargo_toml::PackageSelection::All,
         Some(noirc_driver::NOIR_ARTIFACT_VERSION_STRING.to_string()),
+        false,
+        nargo_toml::FeatureSelection::default(),
     )
     .expect("failed to resolve workspace");
 
@@ -0,0 +1,54 @@
+use clap::Args;
+use nargo::package::CrateName;
+use nargo_toml::{add_dependency, find_package_root, find_package_to_edit, DependencySource};
+
+use crate::errors::CliError;
+
+use super::NargoConfig;
+
+/// Add a dependency to the `Nargo.toml` of a package
+#[derive(Debug, Clone, Args)]
+pub(crate) struct AddCommand {
+    /// The name of the dependency
+    name: CrateName,
+
+    /// The url of the git repository of the dependency
+    #[clap(long, requires = "tag", conflicts_with = "path")]
+    git: Option<String>,
+
+    /// The tag of the git repository to depend on
+    #[clap(long, requires = "git")]
+    tag: Option<String>,
+
+    /// The subdirectory of the git repository containing the dependency
+    #[clap(long, requires = "git")]
+    directory: Option<String>,
+
+    /// The directory of the dependency, relative to the package depending on it
+    #[clap(long, required_unless_present = "git")]
+    path: Option<String>,
+
+    /// Add the dependency to `[dev-dependencies]` instead of `[dependencies]`
+    #[clap(long)]
+    dev: bool,
+
+    /// The name of the workspace member to add the dependency to
+    #[clap(long)]
+    package: Option<CrateName>,
+}
+
+pub(crate) fn run(args: AddCommand, config: NargoConfig) -> Result<(), CliError> {
+    let workspace_root = find_package_root(&config.program_dir)?;
+    let toml_path =
+        find_package_to_edit(&workspace_root, &config.program_dir, args.package.as_ref())?;
+
+    let source = match (args.git, args.tag, args.path) {
+        (Some(git), Some(tag), _) => DependencySource::Git { git, tag, directory: args.directory },
+        (_, _, Some(path)) => DependencySource::Path { path },
+        _ => unreachable!("clap requires either `--git` and `--tag` or `--path`"),
+    };
+    add_dependency(&toml_path, &workspace_root, &args.name, source, args.dev, config.offline)?;
+
+    println!("Added {} to {}", args.name, toml_path.display());
+    Ok(())
+}
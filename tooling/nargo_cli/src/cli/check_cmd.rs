@@ -5,10 +5,10 @@ use fm::FileManager;
 use iter_extended::btree_map;
 use nargo::{
     errors::CompileError,
-    insert_all_files_for_workspace_into_file_manager,
     ops::report_errors,
     package::{CrateName, Package},
-    parse_all, prepare_package,
+    prepare_comptime_oracle_resolver, prepare_package,
+    workspace::Workspace,
 };
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_abi::{AbiParameter, AbiType, MAIN_RETURN_NAME};
@@ -17,7 +17,9 @@ use noirc_driver::{
 };
 use noirc_frontend::hir::{Context, ParsedFiles};
 
+use super::compile_cmd::apply_profile;
 use super::fs::write_to_file;
+use super::watch::run_or_watch_workspace;
 use super::NargoConfig;
 
 /// Checks the constraint system for errors
@@ -38,29 +40,51 @@ pub(crate) struct CheckCommand {
 
     #[clap(flatten)]
     compile_options: CompileOptions,
+
+    /// Watch the workspace and check it again whenever a file changes
+    #[clap(long)]
+    watch: bool,
 }
 
 pub(crate) fn run(args: CheckCommand, config: NargoConfig) -> Result<(), CliError> {
     let toml_path = get_package_manifest(&config.program_dir)?;
     let default_selection =
         if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
-    let selection = args.package.map_or(default_selection, PackageSelection::Selected);
-    let workspace = resolve_workspace_from_toml(
-        &toml_path,
-        selection,
-        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
-    )?;
-
-    let mut workspace_file_manager = workspace.new_file_manager();
-    insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
-    let parsed_files = parse_all(&workspace_file_manager);
-
-    for package in &workspace {
+    let selection = args.package.clone().map_or(default_selection, PackageSelection::Selected);
+    let resolve_workspace = || -> Result<Workspace, CliError> {
+        Ok(resolve_workspace_from_toml(
+            &toml_path,
+            selection.clone(),
+            Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+            config.offline,
+            config.feature_selection(),
+        )?)
+    };
+
+    run_or_watch_workspace(
+        args.watch,
+        resolve_workspace,
+        |workspace, file_manager, parsed_files| {
+            check_workspace(workspace, file_manager, parsed_files, &args)
+        },
+    )
+}
+
+fn check_workspace(
+    workspace: &Workspace,
+    file_manager: &FileManager,
+    parsed_files: &ParsedFiles,
+    args: &CheckCommand,
+) -> Result<(), CliError> {
+    let compile_options = apply_profile(workspace, &args.compile_options)?;
+
+    for package in workspace {
         let any_file_written = check_package(
-            &workspace_file_manager,
-            &parsed_files,
+            file_manager,
+            parsed_files,
+            workspace,
             package,
-            &args.compile_options,
+            &compile_options,
             args.allow_overwrite,
         )?;
         if any_file_written {
@@ -75,12 +99,16 @@ pub(crate) fn run(args: CheckCommand, config: NargoConfig) -> Result<(), CliErro
 fn check_package(
     file_manager: &FileManager,
     parsed_files: &ParsedFiles,
+    workspace: &Workspace,
     package: &Package,
     compile_options: &CompileOptions,
     allow_overwrite: bool,
 ) -> Result<bool, CompileError> {
     let (mut context, crate_id) = prepare_package(file_manager, parsed_files, package);
-    check_crate_and_report_errors(&mut context, crate_id, compile_options)?;
+    prepare_comptime_oracle_resolver(&mut context, package, compile_options);
+    let result = check_crate_and_report_errors(&mut context, crate_id, compile_options);
+    workspace.record_comptime_inputs(context.comptime_file_inputs());
+    result?;
 
     if package.is_library() || package.is_contract() {
         // Libraries do not have ABIs while contracts have many, so we cannot generate a `Prover.toml` file.
@@ -1,6 +1,4 @@
-use std::io::Write;
 use std::path::Path;
-use std::time::Duration;
 
 use acvm::acir::circuit::ExpressionWidth;
 use fm::FileManager;
@@ -11,16 +9,16 @@ use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_driver::DEFAULT_EXPRESSION_WIDTH;
 use noirc_driver::NOIR_ARTIFACT_VERSION_STRING;
-use noirc_driver::{CompilationResult, CompileOptions, CompiledContract};
+use noirc_driver::{CompilationResult, CompileOptions};
 
 use clap::Args;
+use noirc_artifacts::{contract::ContractArtifact, program::ProgramArtifact};
 use noirc_frontend::hir::ParsedFiles;
-use notify::{EventKind, RecursiveMode, Watcher};
-use notify_debouncer_full::new_debouncer;
 
 use crate::errors::CliError;
 
 use super::fs::program::{read_program_from_file, save_contract_to_file, save_program_to_file};
+use super::watch::run_or_watch_workspace;
 use super::NargoConfig;
 use rayon::prelude::*;
 
@@ -38,8 +36,8 @@ pub(crate) struct CompileCommand {
     #[clap(flatten)]
     compile_options: CompileOptions,
 
-    /// Watch workspace and recompile on changes.
-    #[clap(long, hide = true)]
+    /// Watch the workspace and compile it again whenever a file changes
+    #[clap(long)]
     watch: bool,
 }
 
@@ -48,64 +46,29 @@ pub(crate) fn run(args: CompileCommand, config: NargoConfig) -> Result<(), CliEr
     let default_selection =
         if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
     let selection = args.package.map_or(default_selection, PackageSelection::Selected);
+    let resolve_workspace = || -> Result<Workspace, CliError> {
+        Ok(resolve_workspace_from_toml(
+            &toml_path,
+            selection.clone(),
+            Some(NOIR_ARTIFACT_VERSION_STRING.to_owned()),
+            config.offline,
+            config.feature_selection(),
+        )?)
+    };
 
-    let workspace = resolve_workspace_from_toml(
-        &toml_path,
-        selection,
-        Some(NOIR_ARTIFACT_VERSION_STRING.to_owned()),
-    )?;
-
-    if args.watch {
-        watch_workspace(&workspace, &args.compile_options)
-            .map_err(|err| CliError::Generic(err.to_string()))?;
-    } else {
-        compile_workspace_full(&workspace, &args.compile_options)?;
-    }
-
-    Ok(())
-}
-
-fn watch_workspace(workspace: &Workspace, compile_options: &CompileOptions) -> notify::Result<()> {
-    let (tx, rx) = std::sync::mpsc::channel();
-
-    // No specific tickrate, max debounce time 1 seconds
-    let mut debouncer = new_debouncer(Duration::from_secs(1), None, tx)?;
-
-    // Add a path to be watched. All files and directories at that path and
-    // below will be monitored for changes.
-    debouncer.watcher().watch(&workspace.root_dir, RecursiveMode::Recursive)?;
-
-    let mut screen = std::io::stdout();
-    write!(screen, "{}", termion::cursor::Save).unwrap();
-    screen.flush().unwrap();
-    let _ = compile_workspace_full(workspace, compile_options);
-    for res in rx {
-        let debounced_events = res.map_err(|mut err| err.remove(0))?;
-
-        // We only want to trigger a rebuild if a noir source file has been modified.
-        let noir_files_modified = debounced_events.iter().any(|event| {
-            let mut event_paths = event.event.paths.iter();
-            let event_affects_noir_file =
-                event_paths.any(|path| path.extension().map_or(false, |ext| ext == "nr"));
-
-            let is_relevant_event_kind = matches!(
-                event.kind,
-                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
-            );
-
-            is_relevant_event_kind && event_affects_noir_file
-        });
-
-        if noir_files_modified {
-            write!(screen, "{}{}", termion::cursor::Restore, termion::clear::AfterCursor).unwrap();
-            screen.flush().unwrap();
-            let _ = compile_workspace_full(workspace, compile_options);
-        }
-    }
-
-    screen.flush().unwrap();
-
-    Ok(())
+    run_or_watch_workspace(
+        args.watch,
+        resolve_workspace,
+        |workspace, file_manager, parsed_files| {
+            let compile_options = apply_profile(workspace, &args.compile_options)?;
+            compile_workspace_and_report_errors(
+                file_manager,
+                parsed_files,
+                workspace,
+                &compile_options,
+            )
+        },
+    )
 }
 
 pub(super) fn compile_workspace_full(
@@ -116,12 +79,26 @@ pub(super) fn compile_workspace_full(
     insert_all_files_for_workspace_into_file_manager(workspace, &mut workspace_file_manager);
     let parsed_files = parse_all(&workspace_file_manager);
 
+    compile_workspace_and_report_errors(
+        &workspace_file_manager,
+        &parsed_files,
+        workspace,
+        compile_options,
+    )
+}
+
+pub(super) fn compile_workspace_and_report_errors(
+    file_manager: &FileManager,
+    parsed_files: &ParsedFiles,
+    workspace: &Workspace,
+    compile_options: &CompileOptions,
+) -> Result<(), CliError> {
     let compiled_workspace =
-        compile_workspace(&workspace_file_manager, &parsed_files, workspace, compile_options);
+        compile_workspace(file_manager, parsed_files, workspace, compile_options);
 
     report_errors(
         compiled_workspace,
-        &workspace_file_manager,
+        file_manager,
         compile_options.deny_warnings,
         compile_options.silence_warnings,
     )?;
@@ -147,9 +124,9 @@ fn compile_workspace(
     let contract_warnings_or_errors: CompilationResult<()> = compiled_contracts(
         file_manager,
         parsed_files,
+        workspace,
         &contract_packages,
         compile_options,
-        &workspace.target_directory_path(),
     );
 
     match (program_warnings_or_errors, contract_warnings_or_errors) {
@@ -171,11 +148,16 @@ fn compile_programs(
     binary_packages: &[Package],
     compile_options: &CompileOptions,
 ) -> CompilationResult<()> {
+    let profile = workspace.profile(compile_options).cloned();
+
     let load_cached_program = |package| {
         let program_artifact_path = workspace.package_build_path(package);
+        // The files read by comptime code don't need to be checked: the cached program is only
+        // reused if the program elaborated from them has the same hash.
+        // Programs compiled with another profile may have been compiled with other options
         read_program_from_file(program_artifact_path)
             .ok()
-            .filter(|p| p.noir_version == NOIR_ARTIFACT_VERSION_STRING)
+            .filter(|p| p.noir_version == NOIR_ARTIFACT_VERSION_STRING && p.profile == profile)
             .map(|p| p.into())
     };
 
@@ -193,7 +175,9 @@ fn compile_programs(
             get_target_width(package.expression_width, compile_options.expression_width);
         let program = nargo::ops::transform_program(program, target_width);
         nargo::ops::check_program(&program)?;
-        save_program_to_file(&program.into(), &package.name, workspace.target_directory_path());
+        let artifact =
+            ProgramArtifact { profile: profile.clone(), ..ProgramArtifact::from(program) };
+        save_program_to_file(&artifact, &package.name, workspace.target_directory_path());
 
         Ok(((), warnings))
     };
@@ -211,19 +195,23 @@ fn compile_programs(
 fn compiled_contracts(
     file_manager: &FileManager,
     parsed_files: &ParsedFiles,
+    workspace: &Workspace,
     contract_packages: &[Package],
     compile_options: &CompileOptions,
-    target_dir: &Path,
 ) -> CompilationResult<()> {
+    let target_dir = &workspace.target_directory_path();
+    let profile = workspace.profile(compile_options);
     let contract_results: Vec<CompilationResult<()>> = contract_packages
         .par_iter()
         .map(|package| {
             let (contract, warnings) =
-                compile_contract(file_manager, parsed_files, package, compile_options)?;
+                compile_contract(file_manager, parsed_files, workspace, package, compile_options)?;
             let target_width =
                 get_target_width(package.expression_width, compile_options.expression_width);
             let contract = nargo::ops::transform_contract(contract, target_width);
-            save_contract(contract, package, target_dir, compile_options.show_artifact_paths);
+            let artifact =
+                ContractArtifact { profile: profile.cloned(), ..ContractArtifact::from(contract) };
+            save_contract(artifact, package, target_dir, compile_options.show_artifact_paths);
             Ok(((), warnings))
         })
         .collect();
@@ -233,15 +221,14 @@ fn compiled_contracts(
 }
 
 fn save_contract(
-    contract: CompiledContract,
+    contract: ContractArtifact,
     package: &Package,
     target_dir: &Path,
     show_artifact_paths: bool,
 ) {
-    let contract_name = contract.name.clone();
     let artifact_path = save_contract_to_file(
-        &contract.into(),
-        &format!("{}-{}", package.name, contract_name),
+        &contract,
+        &format!("{}-{}", package.name, contract.name),
         target_dir,
     );
     if show_artifact_paths {
@@ -249,6 +236,19 @@ fn save_contract(
     }
 }
 
+/// Returns `compile_options` with the settings of the profile selected by its `--profile` flag applied.
+pub(crate) fn apply_profile(
+    workspace: &Workspace,
+    compile_options: &CompileOptions,
+) -> Result<CompileOptions, CliError> {
+    match workspace.profile(compile_options) {
+        Some(profile) => Ok(profile.apply(compile_options)),
+        None => Err(CliError::UnknownProfile(
+            compile_options.profile.clone().expect("the default profile is always defined"),
+        )),
+    }
+}
+
 /// If a target width was not specified in the CLI we can safely override the default.
 pub(crate) fn get_target_width(
     package_default_width: Option<ExpressionWidth>,
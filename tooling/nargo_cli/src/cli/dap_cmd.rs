@@ -5,7 +5,9 @@ use bn254_blackbox_solver::Bn254BlackBoxSolver;
 use clap::Args;
 use nargo::constants::PROVER_INPUT_FILE;
 use nargo::workspace::Workspace;
-use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use nargo_toml::{
+    get_package_manifest, resolve_workspace_from_toml, FeatureSelection, PackageSelection,
+};
 use noirc_abi::input_parser::Format;
 use noirc_driver::{CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING};
 use noirc_frontend::graph::CrateName;
@@ -19,7 +21,7 @@ use dap::server::Server;
 use dap::types::Capabilities;
 use serde_json::Value;
 
-use super::debug_cmd::compile_bin_package_for_debugging;
+use super::debug_cmd::{compile_bin_package_for_debugging, compile_test_for_debugging};
 use super::fs::inputs::read_inputs_from_file;
 use crate::errors::CliError;
 
@@ -50,6 +52,9 @@ pub(crate) struct DapCommand {
 
     #[clap(long)]
     preflight_skip_instrumentation: bool,
+
+    #[clap(long)]
+    preflight_test_name: Option<String>,
 }
 
 fn parse_expression_width(input: &str) -> Result<ExpressionWidth, std::io::Error> {
@@ -76,6 +81,8 @@ fn find_workspace(project_folder: &str, package: Option<&str>) -> Option<Workspa
         &toml_path,
         selection,
         Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+        false,
+        FeatureSelection::default(),
     ) {
         Ok(workspace) => Some(workspace),
         Err(err) => {
@@ -102,9 +109,21 @@ fn load_and_compile_project(
     expression_width: ExpressionWidth,
     acir_mode: bool,
     skip_instrumentation: bool,
+    test_name: Option<&str>,
 ) -> Result<(CompiledProgram, WitnessMap<FieldElement>), LoadError> {
     let workspace = find_workspace(project_folder, package)
         .ok_or(LoadError::Generic(workspace_not_found_error_msg(project_folder, package)))?;
+
+    if let Some(test_name) = test_name {
+        return load_and_compile_test(
+            &workspace,
+            test_name,
+            expression_width,
+            acir_mode,
+            skip_instrumentation,
+        );
+    }
+
     let package = workspace
         .into_iter()
         .find(|p| p.is_binary())
@@ -134,6 +153,41 @@ fn load_and_compile_project(
     Ok((compiled_program, initial_witness))
 }
 
+fn load_and_compile_test(
+    workspace: &Workspace,
+    test_name: &str,
+    expression_width: ExpressionWidth,
+    acir_mode: bool,
+    skip_instrumentation: bool,
+) -> Result<(CompiledProgram, WitnessMap<FieldElement>), LoadError> {
+    let package = workspace
+        .into_iter()
+        .next()
+        .ok_or(LoadError::Generic("No matching packages found in workspace".into()))?;
+
+    let compiled_program = compile_test_for_debugging(
+        workspace,
+        package,
+        test_name,
+        acir_mode,
+        skip_instrumentation,
+        CompileOptions::default(),
+    )
+    .map_err(|_| LoadError::Generic(format!("Failed to compile test {}", test_name)))?;
+
+    // Tests with arguments are fuzzed, so there are no inputs we could debug them with
+    if !compiled_program.abi.parameters.is_empty() {
+        return Err(LoadError::Generic(format!(
+            "Test {} takes arguments and can't be debugged",
+            test_name
+        )));
+    }
+
+    let compiled_program = nargo::ops::transform_program(compiled_program, expression_width);
+
+    Ok((compiled_program, WitnessMap::new()))
+}
+
 fn loop_uninitialized_dap<R: Read, W: Write>(
     mut server: Server<R, W>,
     expression_width: ExpressionWidth,
@@ -179,10 +233,14 @@ fn loop_uninitialized_dap<R: Read, W: Write>(
                     .get("skipInstrumentation")
                     .and_then(|v| v.as_bool())
                     .unwrap_or(generate_acir);
+                let test_name = additional_data.get("testName").and_then(|v| v.as_str());
 
                 eprintln!("Project folder: {}", project_folder);
                 eprintln!("Package: {}", package.unwrap_or("(default)"));
                 eprintln!("Prover name: {}", prover_name);
+                if let Some(test_name) = test_name {
+                    eprintln!("Test name: {}", test_name);
+                }
 
                 match load_and_compile_project(
                     project_folder,
@@ -191,6 +249,7 @@ fn loop_uninitialized_dap<R: Read, W: Write>(
                     expression_width,
                     generate_acir,
                     skip_instrumentation,
+                    test_name,
                 ) {
                     Ok((compiled_program, initial_witness)) => {
                         server.respond(req.ack()?)?;
@@ -243,6 +302,7 @@ fn run_preflight_check(
         expression_width,
         args.preflight_generate_acir,
         args.preflight_skip_instrumentation,
+        args.preflight_test_name.as_deref(),
     )?;
 
     Ok(())
@@ -8,11 +8,18 @@ use clap::Args;
 use fm::FileManager;
 use nargo::constants::PROVER_INPUT_FILE;
 use nargo::errors::CompileError;
-use nargo::ops::{compile_program, compile_program_with_debug_instrumenter, report_errors};
+use nargo::ops::{
+    compile_program, compile_program_with_debug_instrumenter, compile_test_with_debug_instrumenter,
+    report_errors,
+};
 use nargo::package::{CrateName, Package};
 use nargo::workspace::Workspace;
-use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
+use nargo::{
+    insert_all_files_for_workspace_into_file_manager, parse_all, prepare_comptime_oracle_resolver,
+    prepare_package,
+};
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noir_debugger::ComptimeReplDebugger;
 use noirc_abi::input_parser::{Format, InputValue};
 use noirc_abi::InputMap;
 use noirc_driver::{
@@ -21,7 +28,8 @@ use noirc_driver::{
 use noirc_frontend::debug::DebugInstrumenter;
 use noirc_frontend::hir::ParsedFiles;
 
-use super::compile_cmd::get_target_width;
+use super::check_cmd::check_crate_and_report_errors;
+use super::compile_cmd::{apply_profile, get_target_width};
 use super::fs::{inputs::read_inputs_from_file, witness::save_witness_to_dir};
 use super::NargoConfig;
 use crate::errors::CliError;
@@ -50,9 +58,13 @@ pub(crate) struct DebugCommand {
     /// Disable vars debug instrumentation (enabled by default)
     #[clap(long)]
     skip_instrumentation: Option<bool>,
+
+    /// Debug the comptime code evaluated while compiling the package instead of executing it
+    #[clap(long)]
+    comptime: bool,
 }
 
-pub(crate) fn run(args: DebugCommand, config: NargoConfig) -> Result<(), CliError> {
+pub(crate) fn run(mut args: DebugCommand, config: NargoConfig) -> Result<(), CliError> {
     let acir_mode = args.acir_mode;
     let skip_instrumentation = args.skip_instrumentation.unwrap_or(acir_mode);
 
@@ -62,7 +74,10 @@ pub(crate) fn run(args: DebugCommand, config: NargoConfig) -> Result<(), CliErro
         &toml_path,
         selection,
         Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+        config.offline,
+        config.feature_selection(),
     )?;
+    args.compile_options = apply_profile(&workspace, &args.compile_options)?;
     let target_dir = &workspace.target_directory_path();
 
     let Some(package) = workspace.into_iter().find(|p| p.is_binary()) else {
@@ -72,6 +87,11 @@ pub(crate) fn run(args: DebugCommand, config: NargoConfig) -> Result<(), CliErro
         return Ok(());
     };
 
+    if args.comptime {
+        println!("[{}] Starting comptime debugger", package.name);
+        return debug_comptime(&workspace, package, &args.compile_options);
+    }
+
     let compiled_program = compile_bin_package_for_debugging(
         &workspace,
         package,
@@ -137,6 +157,69 @@ pub(crate) fn compile_bin_package_for_debugging(
     )
 }
 
+/// Type checks `package` while stepping through the comptime code it evaluates in the debugger.
+fn debug_comptime(
+    workspace: &Workspace,
+    package: &Package,
+    compile_options: &CompileOptions,
+) -> Result<(), CliError> {
+    let mut workspace_file_manager = file_manager_with_stdlib(std::path::Path::new(""));
+    insert_all_files_for_workspace_into_file_manager(workspace, &mut workspace_file_manager);
+    let parsed_files = parse_all(&workspace_file_manager);
+
+    let debugger = ComptimeReplDebugger::new(&workspace_file_manager, &package.root_dir);
+    let (mut context, crate_id) = prepare_package(&workspace_file_manager, &parsed_files, package);
+    prepare_comptime_oracle_resolver(&mut context, package, compile_options);
+    context.set_comptime_debugger(Box::new(debugger));
+
+    check_crate_and_report_errors(&mut context, crate_id, compile_options)?;
+    println!("[{}] Comptime evaluation finished", package.name);
+    Ok(())
+}
+
+/// Compiles the test function named `test_name` in `package` so that it can be debugged.
+pub(crate) fn compile_test_for_debugging(
+    workspace: &Workspace,
+    package: &Package,
+    test_name: &str,
+    acir_mode: bool,
+    skip_instrumentation: bool,
+    compile_options: CompileOptions,
+) -> Result<CompiledProgram, CompileError> {
+    let mut workspace_file_manager = file_manager_with_stdlib(std::path::Path::new(""));
+    insert_all_files_for_workspace_into_file_manager(workspace, &mut workspace_file_manager);
+    let mut parsed_files = parse_all(&workspace_file_manager);
+
+    let compile_options = CompileOptions {
+        instrument_debug: !skip_instrumentation,
+        force_brillig: !acir_mode,
+        ..compile_options
+    };
+
+    let debug_state = if skip_instrumentation {
+        DebugInstrumenter::default()
+    } else {
+        instrument_package_files(&mut parsed_files, &workspace_file_manager, package)
+    };
+
+    let compilation_result = compile_test_with_debug_instrumenter(
+        &workspace_file_manager,
+        &parsed_files,
+        workspace,
+        package,
+        test_name,
+        &compile_options,
+        debug_state,
+    );
+
+    report_errors(
+        compilation_result,
+        &workspace_file_manager,
+        compile_options.deny_warnings,
+        compile_options.silence_warnings,
+    )
+}
+
 /// Add debugging instrumentation to all parsed files belonging to the package
 /// being compiled
 fn instrument_package_files(
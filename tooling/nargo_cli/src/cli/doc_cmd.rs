@@ -0,0 +1,96 @@
+use clap::Args;
+use nargo::{
+    doc::{document_crates, render_html, CrateDocs},
+    insert_all_files_for_workspace_into_file_manager,
+    package::CrateName,
+    parse_all, prepare_comptime_oracle_resolver, prepare_package,
+};
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_driver::{CompileOptions, NOIR_ARTIFACT_VERSION_STRING};
+
+use crate::errors::CliError;
+
+use super::{
+    check_cmd::check_crate_and_report_errors,
+    compile_cmd::apply_profile,
+    fs::{create_named_dir, write_to_file},
+    NargoConfig,
+};
+
+/// Generate the documentation of a package and its dependencies
+#[derive(Debug, Clone, Args)]
+pub(crate) struct DocCommand {
+    /// The name of the package to document
+    #[clap(long, conflicts_with = "workspace")]
+    package: Option<CrateName>,
+
+    /// Document all packages in the workspace
+    #[clap(long, conflicts_with = "package")]
+    workspace: bool,
+
+    /// Document private items as well as public ones
+    #[clap(long)]
+    document_private_items: bool,
+
+    /// Don't document the dependencies of the packages
+    #[clap(long)]
+    no_deps: bool,
+
+    #[clap(flatten)]
+    compile_options: CompileOptions,
+}
+
+pub(crate) fn run(mut args: DocCommand, config: NargoConfig) -> Result<(), CliError> {
+    let toml_path = get_package_manifest(&config.program_dir)?;
+    let default_selection =
+        if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
+    let selection = args.package.map_or(default_selection, PackageSelection::Selected);
+    let workspace = resolve_workspace_from_toml(
+        &toml_path,
+        selection,
+        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+        config.offline,
+        config.feature_selection(),
+    )?;
+    args.compile_options = apply_profile(&workspace, &args.compile_options)?;
+
+    let mut workspace_file_manager = workspace.new_file_manager();
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
+    let parsed_files = parse_all(&workspace_file_manager);
+
+    let mut crates: Vec<CrateDocs> = Vec::new();
+    for package in &workspace {
+        let (mut context, crate_id) =
+            prepare_package(&workspace_file_manager, &parsed_files, package);
+        prepare_comptime_oracle_resolver(&mut context, package, &args.compile_options);
+        check_crate_and_report_errors(&mut context, crate_id, &args.compile_options)?;
+
+        let package_crates = document_crates(
+            &context,
+            crate_id,
+            &package.name.to_string(),
+            !args.no_deps,
+            args.document_private_items,
+        );
+        // Dependencies shared by several packages are only documented once
+        for crate_docs in package_crates {
+            if !crates.iter().any(|other| other.name == crate_docs.name) {
+                crates.push(crate_docs);
+            }
+        }
+    }
+
+    let doc_dir = workspace.target_directory_path().join("doc");
+    for (path, html) in render_html(&crates) {
+        let path = doc_dir.join(path);
+        create_named_dir(path.parent().expect("pages are in the doc directory"), "doc");
+        write_to_file(html.as_bytes(), &path);
+    }
+    for crate_docs in &crates {
+        let json = serde_json::to_string_pretty(crate_docs).expect("docs are serializable");
+        write_to_file(json.as_bytes(), &doc_dir.join(&crate_docs.name).with_extension("json"));
+    }
+
+    println!("Documentation written to {}", doc_dir.join("index.html").display());
+    Ok(())
+}
@@ -4,19 +4,23 @@ use acvm::acir::native_types::WitnessStack;
 use acvm::FieldElement;
 use bn254_blackbox_solver::Bn254BlackBoxSolver;
 use clap::Args;
+use fm::FileManager;
 
 use nargo::constants::PROVER_INPUT_FILE;
 use nargo::errors::try_to_diagnose_runtime_error;
 use nargo::ops::DefaultForeignCallExecutor;
 use nargo::package::{CrateName, Package};
+use nargo::workspace::Workspace;
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_abi::input_parser::{Format, InputValue};
 use noirc_abi::InputMap;
 use noirc_artifacts::debug::DebugArtifact;
 use noirc_driver::{CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING};
+use noirc_frontend::hir::ParsedFiles;
 
-use super::compile_cmd::compile_workspace_full;
+use super::compile_cmd::{apply_profile, compile_workspace_and_report_errors};
 use super::fs::{inputs::read_inputs_from_file, witness::save_witness_to_dir};
+use super::watch::run_or_watch_workspace;
 use super::NargoConfig;
 use crate::cli::fs::program::read_program_from_file;
 use crate::errors::CliError;
@@ -48,22 +52,47 @@ pub(crate) struct ExecuteCommand {
     /// JSON RPC url to solve oracle calls
     #[clap(long)]
     oracle_resolver: Option<String>,
+
+    /// Watch the workspace and execute it again whenever a file changes
+    #[clap(long)]
+    watch: bool,
 }
 
 pub(crate) fn run(args: ExecuteCommand, config: NargoConfig) -> Result<(), CliError> {
     let toml_path = get_package_manifest(&config.program_dir)?;
     let default_selection =
         if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
-    let selection = args.package.map_or(default_selection, PackageSelection::Selected);
-    let workspace = resolve_workspace_from_toml(
-        &toml_path,
-        selection,
-        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
-    )?;
+    let selection = args.package.clone().map_or(default_selection, PackageSelection::Selected);
+    let resolve_workspace = || -> Result<Workspace, CliError> {
+        Ok(resolve_workspace_from_toml(
+            &toml_path,
+            selection.clone(),
+            Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+            config.offline,
+            config.feature_selection(),
+        )?)
+    };
+
+    run_or_watch_workspace(
+        args.watch,
+        resolve_workspace,
+        |workspace, file_manager, parsed_files| {
+            execute_workspace(workspace, file_manager, parsed_files, &args)
+        },
+    )
+}
+
+fn execute_workspace(
+    workspace: &Workspace,
+    file_manager: &FileManager,
+    parsed_files: &ParsedFiles,
+    args: &ExecuteCommand,
+) -> Result<(), CliError> {
+    let compile_options = apply_profile(workspace, &args.compile_options)?;
     let target_dir = &workspace.target_directory_path();
 
     // Compile the full workspace in order to generate any build artifacts.
-    compile_workspace_full(&workspace, &args.compile_options)?;
+    compile_workspace_and_report_errors(file_manager, parsed_files, workspace, &compile_options)?;
 
     let binary_packages = workspace.into_iter().filter(|package| package.is_binary());
     for package in binary_packages {
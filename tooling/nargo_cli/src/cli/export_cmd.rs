@@ -1,5 +1,6 @@
 use nargo::errors::CompileError;
 use nargo::ops::report_errors;
+use noirc_artifacts::program::ProgramArtifact;
 use noirc_errors::FileDiagnostic;
 use noirc_frontend::hir::ParsedFiles;
 use rayon::prelude::*;
@@ -7,9 +8,9 @@ use rayon::prelude::*;
 use fm::FileManager;
 use iter_extended::try_vecmap;
 use nargo::package::Package;
-use nargo::prepare_package;
 use nargo::workspace::Workspace;
 use nargo::{insert_all_files_for_workspace_into_file_manager, parse_all};
+use nargo::{prepare_comptime_oracle_resolver, prepare_package};
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_driver::{
     compile_no_check, CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING,
@@ -22,6 +23,7 @@ use clap::Args;
 use crate::errors::CliError;
 
 use super::check_cmd::check_crate_and_report_errors;
+use super::compile_cmd::apply_profile;
 
 use super::fs::program::save_program_to_file;
 use super::NargoConfig;
@@ -41,7 +43,7 @@ pub(crate) struct ExportCommand {
     compile_options: CompileOptions,
 }
 
-pub(crate) fn run(args: ExportCommand, config: NargoConfig) -> Result<(), CliError> {
+pub(crate) fn run(mut args: ExportCommand, config: NargoConfig) -> Result<(), CliError> {
     let toml_path = get_package_manifest(&config.program_dir)?;
     let default_selection =
         if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
@@ -51,7 +53,10 @@ pub(crate) fn run(args: ExportCommand, config: NargoConfig) -> Result<(), CliErr
         &toml_path,
         selection,
         Some(NOIR_ARTIFACT_VERSION_STRING.to_owned()),
+        config.offline,
+        config.feature_selection(),
     )?;
+    args.compile_options = apply_profile(&workspace, &args.compile_options)?;
 
     let mut workspace_file_manager = workspace.new_file_manager();
     insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
@@ -82,6 +87,7 @@ fn compile_exported_functions(
     compile_options: &CompileOptions,
 ) -> Result<(), CliError> {
     let (mut context, crate_id) = prepare_package(file_manager, parsed_files, package);
+    prepare_comptime_oracle_resolver(&mut context, package, compile_options);
     check_crate_and_report_errors(&mut context, crate_id, compile_options)?;
 
     let exported_functions = context.get_all_exported_functions_in_crate(&crate_id);
@@ -105,8 +111,11 @@ fn compile_exported_functions(
     )?;
 
     let export_dir = workspace.export_directory_path();
+    let profile = workspace.profile(compile_options).cloned();
     for (function_name, program) in exported_programs {
-        save_program_to_file(&program.into(), &function_name.parse().unwrap(), &export_dir);
+        let artifact =
+            ProgramArtifact { profile: profile.clone(), ..ProgramArtifact::from(program) };
+        save_program_to_file(&artifact, &function_name.parse().unwrap(), &export_dir);
     }
     Ok(())
 }
@@ -27,6 +27,8 @@ pub(crate) fn run(args: FormatCommand, config: NargoConfig) -> Result<(), CliErr
         &toml_path,
         PackageSelection::All,
         Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+        config.offline,
+        config.feature_selection(),
     )?;
 
     let mut workspace_file_manager = workspace.new_file_manager();
@@ -18,7 +18,7 @@ use serde::Serialize;
 use crate::{cli::fs::inputs::read_inputs_from_file, errors::CliError};
 
 use super::{
-    compile_cmd::{compile_workspace_full, get_target_width},
+    compile_cmd::{apply_profile, compile_workspace_full, get_target_width},
     fs::program::read_program_from_file,
     NargoConfig,
 };
@@ -63,7 +63,10 @@ pub(crate) fn run(mut args: InfoCommand, config: NargoConfig) -> Result<(), CliE
         &toml_path,
         selection,
         Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+        config.offline,
+        config.feature_selection(),
     )?;
+    args.compile_options = apply_profile(&workspace, &args.compile_options)?;
 
     if args.profile_execution {
         // Execution profiling is only relevant with the Brillig VM
@@ -1,17 +1,20 @@
 use clap::{Args, Parser, Subcommand};
 use const_format::formatcp;
-use nargo_toml::find_package_root;
+use nargo_toml::{find_package_root, FeatureSelection};
 use noirc_driver::NOIR_ARTIFACT_VERSION_STRING;
 use std::path::PathBuf;
 
 use color_eyre::eyre;
 
 mod fs;
+mod watch;
 
+mod add_cmd;
 mod check_cmd;
 mod compile_cmd;
 mod dap_cmd;
 mod debug_cmd;
+mod doc_cmd;
 mod execute_cmd;
 mod export_cmd;
 mod fmt_cmd;
@@ -20,7 +23,11 @@ mod info_cmd;
 mod init_cmd;
 mod lsp_cmd;
 mod new_cmd;
+mod remove_cmd;
 mod test_cmd;
+mod tree_cmd;
+mod update_cmd;
+mod vendor_cmd;
 
 const GIT_HASH: &str = env!("GIT_COMMIT");
 const IS_DIRTY: &str = env!("GIT_DIRTY");
@@ -50,6 +57,28 @@ pub(crate) struct NargoConfig {
     // REMINDER: Also change this flag in the LSP test lens if renamed
     #[arg(long, hide = true, global = true, default_value = "./")]
     program_dir: PathBuf,
+
+    /// Fail instead of fetching git dependencies over the network
+    #[arg(long, global = true)]
+    offline: bool,
+
+    /// Comma-separated list of features to enable, besides the default ones
+    #[arg(long, global = true, value_delimiter = ',')]
+    features: Vec<String>,
+
+    /// Don't enable the `default` feature of the selected packages
+    #[arg(long, global = true)]
+    no_default_features: bool,
+}
+
+impl NargoConfig {
+    /// The features to enable in the workspace members, as set by `--features` and `--no-default-features`.
+    fn feature_selection(&self) -> FeatureSelection {
+        FeatureSelection {
+            features: self.features.clone(),
+            default_features: !self.no_default_features,
+        }
+    }
 }
 
 #[non_exhaustive]
@@ -66,7 +95,13 @@ enum NargoCommand {
     Export(export_cmd::ExportCommand),
     Debug(debug_cmd::DebugCommand),
     Test(test_cmd::TestCommand),
+    Doc(doc_cmd::DocCommand),
     Info(info_cmd::InfoCommand),
+    Tree(tree_cmd::TreeCommand),
+    Update(update_cmd::UpdateCommand),
+    Add(add_cmd::AddCommand),
+    Remove(remove_cmd::RemoveCommand),
+    Vendor(vendor_cmd::VendorCommand),
     Lsp(lsp_cmd::LspCommand),
     #[command(hide = true)]
     Dap(dap_cmd::DapCommand),
@@ -91,11 +126,17 @@ pub(crate) fn start_cli() -> eyre::Result<()> {
         | NargoCommand::Export(..)
         | NargoCommand::Debug(..)
         | NargoCommand::Test(..)
-        | NargoCommand::Info(..) => {
+        | NargoCommand::Doc(..)
+        | NargoCommand::Info(..)
+        | NargoCommand::Tree(..)
+        | NargoCommand::Update(..)
+        | NargoCommand::Vendor(..) => {
             config.program_dir = find_package_root(&config.program_dir)?;
         }
         NargoCommand::New(..)
         | NargoCommand::Init(..)
+        | NargoCommand::Add(..)
+        | NargoCommand::Remove(..)
         | NargoCommand::Lsp(..)
         | NargoCommand::Dap(..)
         | NargoCommand::GenerateCompletionScript(..) => (),
@@ -110,7 +151,13 @@ pub(crate) fn start_cli() -> eyre::Result<()> {
         NargoCommand::Execute(args) => execute_cmd::run(args, config),
         NargoCommand::Export(args) => export_cmd::run(args, config),
         NargoCommand::Test(args) => test_cmd::run(args, config),
+        NargoCommand::Doc(args) => doc_cmd::run(args, config),
         NargoCommand::Info(args) => info_cmd::run(args, config),
+        NargoCommand::Tree(args) => tree_cmd::run(args, config),
+        NargoCommand::Update(args) => update_cmd::run(args, config),
+        NargoCommand::Vendor(args) => vendor_cmd::run(args, config),
+        NargoCommand::Add(args) => add_cmd::run(args, config),
+        NargoCommand::Remove(args) => remove_cmd::run(args, config),
         NargoCommand::Lsp(args) => lsp_cmd::run(args, config),
         NargoCommand::Dap(args) => dap_cmd::run(args, config),
         NargoCommand::Fmt(args) => fmt_cmd::run(args, config),
@@ -0,0 +1,28 @@
+use clap::Args;
+use nargo::package::CrateName;
+use nargo_toml::{find_package_root, find_package_to_edit, remove_dependency};
+
+use crate::errors::CliError;
+
+use super::NargoConfig;
+
+/// Remove a dependency from the `Nargo.toml` of a package
+#[derive(Debug, Clone, Args)]
+pub(crate) struct RemoveCommand {
+    /// The name of the dependency
+    name: CrateName,
+
+    /// The name of the workspace member to remove the dependency from
+    #[clap(long)]
+    package: Option<CrateName>,
+}
+
+pub(crate) fn run(args: RemoveCommand, config: NargoConfig) -> Result<(), CliError> {
+    let workspace_root = find_package_root(&config.program_dir)?;
+    let toml_path =
+        find_package_to_edit(&workspace_root, &config.program_dir, args.package.as_ref())?;
+    remove_dependency(&toml_path, &args.name)?;
+
+    println!("Removed {} from {}", args.name, toml_path.display());
+    Ok(())
+}
@@ -1,24 +1,30 @@
-use std::{io::Write, path::PathBuf};
+use std::{
+    collections::HashMap,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
 
 use acvm::{BlackBoxFunctionSolver, FieldElement};
 use bn254_blackbox_solver::Bn254BlackBoxSolver;
 use clap::Args;
 use fm::FileManager;
 use nargo::{
-    insert_all_files_for_workspace_into_file_manager,
+    doc::{doc_tests, document_crates, DocTest, DOC_TEST_FUNCTION},
     ops::TestStatus,
     package::{CrateName, Package},
-    parse_all, prepare_package,
+    prepare_comptime_oracle_resolver, prepare_doc_test, prepare_package, prepare_package_for_tests,
+    workspace::Workspace,
 };
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_driver::{check_crate, CompileOptions, NOIR_ARTIFACT_VERSION_STRING};
-use noirc_frontend::hir::{FunctionNameMatch, ParsedFiles};
+use noirc_frontend::hir::{def_map::parse_file, FunctionNameMatch, ParsedFiles};
 use rayon::prelude::{IntoParallelIterator, ParallelBridge, ParallelIterator};
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
 use crate::{cli::check_cmd::check_crate_and_report_errors, errors::CliError};
 
-use super::NargoConfig;
+use super::{compile_cmd::apply_profile, watch::run_or_watch_workspace, NargoConfig};
 
 /// Run the tests for this program
 #[derive(Debug, Clone, Args)]
@@ -49,22 +55,55 @@ pub(crate) struct TestCommand {
     /// JSON RPC url to solve oracle calls
     #[clap(long)]
     oracle_resolver: Option<String>,
+
+    /// Only run the code blocks in the documentation of library packages as tests
+    #[arg(long)]
+    doc: bool,
+
+    /// Watch the workspace and run the tests affected by each change to its files
+    #[clap(long)]
+    watch: bool,
 }
 
+/// The result of each test run in watch mode, keyed by the names of its package and function,
+/// along with the hash of everything it depends on.
+type TestResults = HashMap<(String, String), (u64, TestStatus)>;
+
 pub(crate) fn run(args: TestCommand, config: NargoConfig) -> Result<(), CliError> {
     let toml_path = get_package_manifest(&config.program_dir)?;
     let default_selection =
         if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
-    let selection = args.package.map_or(default_selection, PackageSelection::Selected);
-    let workspace = resolve_workspace_from_toml(
-        &toml_path,
-        selection,
-        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
-    )?;
+    let selection = args.package.clone().map_or(default_selection, PackageSelection::Selected);
+    let resolve_workspace = || -> Result<Workspace, CliError> {
+        Ok(resolve_workspace_from_toml(
+            &toml_path,
+            selection.clone(),
+            Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+            config.offline,
+            config.feature_selection(),
+        )?)
+    };
+
+    // In watch mode, the tests which don't depend on any changed code aren't run again
+    let results = Mutex::new(TestResults::new());
+    run_or_watch_workspace(
+        args.watch,
+        resolve_workspace,
+        |workspace, file_manager, parsed_files| {
+            let results = args.watch.then_some(&results);
+            test_workspace(workspace, file_manager, parsed_files, &args, results)
+        },
+    )
+}
 
-    let mut workspace_file_manager = workspace.new_file_manager();
-    insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
-    let parsed_files = parse_all(&workspace_file_manager);
+fn test_workspace(
+    workspace: &Workspace,
+    file_manager: &FileManager,
+    parsed_files: &ParsedFiles,
+    args: &TestCommand,
+    results: Option<&Mutex<TestResults>>,
+) -> Result<(), CliError> {
+    let compile_options = apply_profile(workspace, &args.compile_options)?;
 
     let pattern = match &args.test_name {
         Some(name) => {
@@ -80,27 +119,75 @@ pub(crate) fn run(args: TestCommand, config: NargoConfig) -> Result<(), CliError
     // Configure a thread pool with a larger stack size to prevent overflowing stack in large programs.
     // Default is 2MB.
     let pool = rayon::ThreadPoolBuilder::new().stack_size(4 * 1024 * 1024).build().unwrap();
+
+    if args.doc {
+        // Doc tests are compiled as crates of their own, which are added to the file manager upfront
+        let mut file_manager = file_manager.clone();
+        let mut parsed_files = parsed_files.clone();
+        let package_doc_tests = workspace
+            .into_iter()
+            .filter(|package| package.is_library())
+            .map(|package| {
+                let tests = add_doc_tests_in_package(
+                    workspace,
+                    &mut file_manager,
+                    &mut parsed_files,
+                    package,
+                    pattern,
+                    &compile_options,
+                )?;
+                Ok((package, tests))
+            })
+            .collect::<Result<Vec<_>, CliError>>()?;
+
+        let test_reports: Vec<Vec<(String, TestStatus)>> = pool.install(|| {
+            package_doc_tests
+                .into_par_iter()
+                .map(|(package, tests)| {
+                    run_doc_tests::<Bn254BlackBoxSolver>(
+                        &file_manager,
+                        &parsed_files,
+                        package,
+                        tests,
+                        args.show_output,
+                        args.oracle_resolver.as_deref(),
+                        Some(workspace.root_dir.clone()),
+                        &compile_options,
+                    )
+                })
+                .collect::<Result<_, _>>()
+        })?;
+        return check_test_report(test_reports.into_iter().flatten().collect(), pattern);
+    }
+
     let test_reports: Vec<Vec<(String, TestStatus)>> = pool.install(|| {
         workspace
             .into_iter()
             .par_bridge()
             .map(|package| {
                 run_tests::<Bn254BlackBoxSolver>(
-                    &workspace_file_manager,
-                    &parsed_files,
+                    file_manager,
+                    parsed_files,
+                    workspace,
                     package,
                     pattern,
                     args.show_output,
                     args.oracle_resolver.as_deref(),
-                    Some(workspace.root_dir.clone()),
                     Some(package.name.to_string()),
-                    &args.compile_options,
+                    &compile_options,
+                    results,
                 )
             })
             .collect::<Result<_, _>>()
     })?;
-    let test_report: Vec<(String, TestStatus)> = test_reports.into_iter().flatten().collect();
+    check_test_report(test_reports.into_iter().flatten().collect(), pattern)
+}
 
+/// Returns an error if any test failed, or if no test matched a given test name.
+fn check_test_report(
+    test_report: Vec<(String, TestStatus)>,
+    pattern: FunctionNameMatch,
+) -> Result<(), CliError> {
     if test_report.is_empty() {
         match &pattern {
             FunctionNameMatch::Exact(pattern) => {
@@ -127,26 +214,34 @@ pub(crate) fn run(args: TestCommand, config: NargoConfig) -> Result<(), CliError
 fn run_tests<S: BlackBoxFunctionSolver<FieldElement> + Default>(
     file_manager: &FileManager,
     parsed_files: &ParsedFiles,
+    workspace: &Workspace,
     package: &Package,
     fn_name: FunctionNameMatch,
     show_output: bool,
     foreign_call_resolver_url: Option<&str>,
-    root_path: Option<PathBuf>,
     package_name: Option<String>,
     compile_options: &CompileOptions,
+    results: Option<&Mutex<TestResults>>,
 ) -> Result<Vec<(String, TestStatus)>, CliError> {
-    let test_functions =
-        get_tests_in_package(file_manager, parsed_files, package, fn_name, compile_options)?;
+    let test_functions = get_tests_in_package(
+        file_manager,
+        parsed_files,
+        workspace,
+        package,
+        fn_name,
+        compile_options,
+    )?;
+    let root_path = Some(workspace.root_dir.clone());
 
     let count_all = test_functions.len();
 
     let plural = if count_all == 1 { "" } else { "s" };
     println!("[{}] Running {count_all} test function{plural}", package.name);
 
-    let test_report: Vec<(String, TestStatus)> = test_functions
+    let test_report: Vec<(String, TestStatus, bool)> = test_functions
         .into_par_iter()
         .map(|test_name| {
-            let status = run_test::<S>(
+            let (status, is_unchanged) = run_test::<S>(
                 file_manager,
                 parsed_files,
                 package,
@@ -156,13 +251,25 @@ fn run_tests<S: BlackBoxFunctionSolver<FieldElement> + Default>(
                 root_path.clone(),
                 package_name.clone(),
                 compile_options,
+                results,
             );
 
-            (test_name, status)
+            (test_name, status, is_unchanged)
         })
         .collect();
 
+    let count_unchanged = test_report.iter().filter(|(_, _, is_unchanged)| *is_unchanged).count();
+    let test_report: Vec<_> =
+        test_report.into_iter().map(|(test_name, status, _)| (test_name, status)).collect();
+
     display_test_report(file_manager, package, compile_options, &test_report)?;
+    if count_unchanged != 0 {
+        let plural = if count_unchanged == 1 { "" } else { "s" };
+        println!(
+            "[{}] {count_unchanged} unchanged test{plural} reported from the previous run",
+            package.name
+        );
+    }
     Ok(test_report)
 }
 
@@ -177,11 +284,13 @@ fn run_test<S: BlackBoxFunctionSolver<FieldElement> + Default>(
     root_path: Option<PathBuf>,
     package_name: Option<String>,
     compile_options: &CompileOptions,
-) -> TestStatus {
+    results: Option<&Mutex<TestResults>>,
+) -> (TestStatus, bool) {
     // This is really hacky but we can't share `Context` or `S` across threads.
     // We then need to construct a separate copy for each test.
 
-    let (mut context, crate_id) = prepare_package(file_manager, parsed_files, package);
+    let (mut context, crate_id) = prepare_package_for_tests(file_manager, parsed_files, package);
+    prepare_comptime_oracle_resolver(&mut context, package, compile_options);
     check_crate(&mut context, crate_id, compile_options)
         .expect("Any errors should have occurred when collecting test functions");
 
@@ -189,29 +298,177 @@ fn run_test<S: BlackBoxFunctionSolver<FieldElement> + Default>(
         .get_all_test_functions_in_crate_matching(&crate_id, FunctionNameMatch::Exact(fn_name));
     let (_, test_function) = test_functions.first().expect("Test function should exist");
 
+    // A test whose program and options didn't change since its last run has the same result.
+    // A test which fails to monomorphize is always run to report the error.
+    let program = nargo::ops::monomorphize_test(&mut context, test_function, compile_options);
+    let key = (package.name.to_string(), fn_name.to_string());
+    let hash = results
+        .and(program.as_ref().ok())
+        .map(|program| nargo::ops::test_program_hash(program, test_function, compile_options));
+    if let (Some(results), Some(hash)) = (results, hash) {
+        if let Some((previous_hash, status)) = results.lock().unwrap().get(&key) {
+            if *previous_hash == hash {
+                return (status.clone(), true);
+            }
+        }
+    }
+
     let blackbox_solver = S::default();
 
-    nargo::ops::run_test(
+    let status = nargo::ops::run_monomorphized_test(
         &blackbox_solver,
         &mut context,
         test_function,
+        program,
         show_output,
         foreign_call_resolver_url,
         root_path,
         package_name,
         compile_options,
+    );
+    if let (Some(results), Some(hash)) = (results, hash) {
+        results.lock().unwrap().insert(key, (hash, status.clone()));
+    }
+    (status, false)
+}
+
+/// Adds the doc tests of `package` whose names match `fn_name` to the file manager and the parsed
+/// files, returning their names and paths.
+fn add_doc_tests_in_package(
+    workspace: &Workspace,
+    file_manager: &mut FileManager,
+    parsed_files: &mut ParsedFiles,
+    package: &Package,
+    fn_name: FunctionNameMatch,
+    options: &CompileOptions,
+) -> Result<Vec<(String, PathBuf)>, CliError> {
+    let package_name = package.name.to_string();
+    let tests: Vec<DocTest> = {
+        let (mut context, crate_id) = prepare_package(file_manager, parsed_files, package);
+        prepare_comptime_oracle_resolver(&mut context, package, options);
+        let result = check_crate_and_report_errors(&mut context, crate_id, options);
+        workspace.record_comptime_inputs(context.comptime_file_inputs());
+        result?;
+
+        // Doc tests can only use the public items of the package
+        let docs = document_crates(&context, crate_id, &package_name, false, false);
+        docs.iter()
+            .flat_map(doc_tests)
+            .filter(|test| match fn_name {
+                FunctionNameMatch::Anything => true,
+                FunctionNameMatch::Exact(name) => test.name == name,
+                FunctionNameMatch::Contains(name) => test.name.contains(name),
+            })
+            .collect()
+    };
+
+    let tests_dir = workspace.target_directory_path().join("doc_tests").join(&package_name);
+    Ok(tests
+        .into_iter()
+        .enumerate()
+        .map(|(index, test)| {
+            let path = tests_dir.join(format!("doc_test_{index}.nr"));
+            let file_id = file_manager
+                .add_file_with_source(&path, test.source)
+                .expect("Doc test paths should be unique");
+            parsed_files.insert(file_id, parse_file(file_manager, file_id));
+            (test.name, path)
+        })
+        .collect())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_doc_tests<S: BlackBoxFunctionSolver<FieldElement> + Default>(
+    file_manager: &FileManager,
+    parsed_files: &ParsedFiles,
+    package: &Package,
+    tests: Vec<(String, PathBuf)>,
+    show_output: bool,
+    foreign_call_resolver_url: Option<&str>,
+    root_path: Option<PathBuf>,
+    compile_options: &CompileOptions,
+) -> Result<Vec<(String, TestStatus)>, CliError> {
+    let count_all = tests.len();
+    let plural = if count_all == 1 { "" } else { "s" };
+    println!("[{}] Running {count_all} doc test{plural}", package.name);
+
+    let test_report: Vec<(String, TestStatus)> = tests
+        .into_par_iter()
+        .map(|(test_name, path)| {
+            let status = run_doc_test::<S>(
+                file_manager,
+                parsed_files,
+                package,
+                &path,
+                show_output,
+                foreign_call_resolver_url,
+                root_path.clone(),
+                compile_options,
+            );
+
+            (test_name, status)
+        })
+        .collect();
+
+    display_test_report(file_manager, package, compile_options, &test_report)?;
+    Ok(test_report)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_doc_test<S: BlackBoxFunctionSolver<FieldElement> + Default>(
+    file_manager: &FileManager,
+    parsed_files: &ParsedFiles,
+    package: &Package,
+    path: &Path,
+    show_output: bool,
+    foreign_call_resolver_url: Option<&str>,
+    root_path: Option<PathBuf>,
+    compile_options: &CompileOptions,
+) -> TestStatus {
+    let (mut context, crate_id) = prepare_doc_test(file_manager, parsed_files, package, path);
+    prepare_comptime_oracle_resolver(&mut context, package, compile_options);
+
+    // Unlike the package's own tests, a doc test may not compile
+    if let Err(errors) = check_crate(&mut context, crate_id, compile_options) {
+        let error = errors.into_iter().find(|error| error.diagnostic.is_error());
+        if let Some(error) = error {
+            return TestStatus::CompileError(error);
+        }
+    }
+
+    let test_functions = context.get_all_test_functions_in_crate_matching(
+        &crate_id,
+        FunctionNameMatch::Exact(DOC_TEST_FUNCTION),
+    );
+    let (_, test_function) = test_functions.first().expect("Doc test function should exist");
+
+    let blackbox_solver = S::default();
+
+    nargo::ops::run_test(
+        &blackbox_solver,
+        &mut context,
+        test_function,
+        show_output,
+        foreign_call_resolver_url,
+        root_path,
+        Some(package.name.to_string()),
+        compile_options,
     )
 }
 
 fn get_tests_in_package(
     file_manager: &FileManager,
     parsed_files: &ParsedFiles,
+    workspace: &Workspace,
     package: &Package,
     fn_name: FunctionNameMatch,
     options: &CompileOptions,
 ) -> Result<Vec<String>, CliError> {
-    let (mut context, crate_id) = prepare_package(file_manager, parsed_files, package);
-    check_crate_and_report_errors(&mut context, crate_id, options)?;
+    let (mut context, crate_id) = prepare_package_for_tests(file_manager, parsed_files, package);
+    prepare_comptime_oracle_resolver(&mut context, package, options);
+    let result = check_crate_and_report_errors(&mut context, crate_id, options);
+    workspace.record_comptime_inputs(context.comptime_file_inputs());
+    result?;
 
     Ok(context
         .get_all_test_functions_in_crate_matching(&crate_id, fn_name)
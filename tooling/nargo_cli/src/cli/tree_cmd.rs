@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+
+use clap::Args;
+use nargo::{
+    package::{CrateName, Dependency, Package},
+    workspace::Workspace,
+};
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_driver::NOIR_ARTIFACT_VERSION_STRING;
+
+use crate::errors::CliError;
+
+use super::NargoConfig;
+
+/// Print the resolved dependency graph of the workspace, with the version and source of each dependency
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TreeCommand {
+    /// The name of the package to print the dependencies of
+    #[clap(long, conflicts_with = "workspace")]
+    package: Option<CrateName>,
+
+    /// Print the dependencies of all packages in the workspace
+    #[clap(long, conflicts_with = "package")]
+    workspace: bool,
+}
+
+pub(crate) fn run(args: TreeCommand, config: NargoConfig) -> Result<(), CliError> {
+    let toml_path = get_package_manifest(&config.program_dir)?;
+    let default_selection =
+        if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
+    let selection = args.package.map_or(default_selection, PackageSelection::Selected);
+    let workspace = resolve_workspace_from_toml(
+        &toml_path,
+        selection,
+        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+        config.offline,
+        config.feature_selection(),
+    )?;
+
+    print!("{}", format_tree(&workspace));
+    Ok(())
+}
+
+/// Formats the dependencies of each package of the `workspace`, followed by its dev-dependencies.
+fn format_tree(workspace: &Workspace) -> String {
+    let mut output = String::new();
+    for package in workspace {
+        output.push_str(&format!(
+            "{} ({})\n",
+            package_with_version(package),
+            package.root_dir.display()
+        ));
+        format_dependencies(&package.dependencies, "", &mut output);
+        if !package.dev_dependencies.is_empty() {
+            output.push_str("[dev-dependencies]\n");
+            format_dependencies(&package.dev_dependencies, "", &mut output);
+        }
+    }
+    output
+}
+
+fn format_dependencies(
+    dependencies: &BTreeMap<CrateName, Dependency>,
+    prefix: &str,
+    output: &mut String,
+) {
+    let count = dependencies.len();
+    for (index, dependency) in dependencies.values().enumerate() {
+        let is_last = index + 1 == count;
+        let branch = if is_last { "└── " } else { "├── " };
+        output.push_str(&format!(
+            "{prefix}{branch}{} ({dependency})\n",
+            package_with_version(dependency.package())
+        ));
+
+        let child_prefix = format!("{prefix}{}", if is_last { "    " } else { "│   " });
+        format_dependencies(&dependency.package().dependencies, &child_prefix, output);
+    }
+}
+
+fn package_with_version(package: &Package) -> String {
+    match &package.version {
+        Some(version) => format!("{} v{version}", package.name),
+        None => package.name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, path::PathBuf, str::FromStr};
+
+    use nargo::{
+        package::{CrateName, Dependency, Package, PackageType},
+        workspace::Workspace,
+    };
+
+    use super::format_tree;
+
+    fn library(name: &str, version: Option<&str>) -> Package {
+        let root_dir = PathBuf::from(format!("/{name}"));
+        Package {
+            version: version.map(ToString::to_string),
+            compiler_required_version: None,
+            entry_path: root_dir.join("src/lib.nr"),
+            root_dir,
+            package_type: PackageType::Library,
+            name: CrateName::from_str(name).unwrap(),
+            dependencies: BTreeMap::new(),
+            dev_dependencies: BTreeMap::new(),
+            expression_width: None,
+            lints: Vec::new(),
+            features: BTreeMap::new(),
+            enabled_features: Default::default(),
+        }
+    }
+
+    fn depend_on(package: &mut Package, dependency: Dependency, dev: bool) {
+        let name = dependency.package_name().clone();
+        let dependencies =
+            if dev { &mut package.dev_dependencies } else { &mut package.dependencies };
+        dependencies.insert(name, dependency);
+    }
+
+    #[test]
+    fn formats_the_dependency_tree_of_each_member() {
+        let remote = |package, tag: &str| Dependency::Remote {
+            package,
+            git: "https://github.com/noir-lang/remote".to_string(),
+            tag: tag.to_string(),
+            version_req: None,
+        };
+        let local = |package| Dependency::Local { package, version_req: None };
+
+        let mut intermediate = library("intermediate", None);
+        depend_on(&mut intermediate, remote(library("remote", Some("1.2.0")), "v1.2.0"), false);
+        depend_on(&mut intermediate, local(library("nested", None)), false);
+        let mut member = library("member", Some("0.1.0"));
+        depend_on(&mut member, local(intermediate), false);
+        depend_on(&mut member, local(library("sibling", None)), false);
+        depend_on(&mut member, local(library("mocks", None)), true);
+        let other = library("other", None);
+
+        let workspace = Workspace {
+            root_dir: PathBuf::from("/"),
+            members: vec![member, other],
+            selected_package_index: None,
+            is_assumed: false,
+            profiles: BTreeMap::new(),
+            comptime_inputs: Default::default(),
+        };
+
+        let expected = "\
+member v0.1.0 (/member)
+├── intermediate (/intermediate)
+│   ├── nested (/nested)
+│   └── remote v1.2.0 (https://github.com/noir-lang/remote#v1.2.0)
+└── sibling (/sibling)
+[dev-dependencies]
+└── mocks (/mocks)
+other (/other)
+";
+        assert_eq!(format_tree(&workspace), expected);
+    }
+}
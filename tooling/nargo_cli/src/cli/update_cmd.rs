@@ -0,0 +1,18 @@
+use clap::Args;
+use nargo_toml::{get_package_manifest, update_lockfile};
+
+use crate::errors::CliError;
+
+use super::NargoConfig;
+
+/// Fetch the git dependencies of the workspace again and update `Nargo.lock`
+#[derive(Debug, Clone, Args)]
+pub(crate) struct UpdateCommand;
+
+pub(crate) fn run(_args: UpdateCommand, config: NargoConfig) -> Result<(), CliError> {
+    let toml_path = get_package_manifest(&config.program_dir)?;
+    update_lockfile(&toml_path, config.offline)?;
+
+    println!("Updated {}", config.program_dir.join("Nargo.lock").display());
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use clap::Args;
+use nargo_toml::{get_package_manifest, vendor_dependencies};
+
+use crate::errors::CliError;
+
+use super::NargoConfig;
+
+/// Copy the git dependencies of the workspace into its `vendor` directory so it can be built offline
+#[derive(Debug, Clone, Args)]
+pub(crate) struct VendorCommand;
+
+pub(crate) fn run(_args: VendorCommand, config: NargoConfig) -> Result<(), CliError> {
+    let toml_path = get_package_manifest(&config.program_dir)?;
+    let vendor_dir = vendor_dependencies(&toml_path, config.offline)?;
+
+    println!("Vendored git dependencies into {}", vendor_dir.display());
+    Ok(())
+}
@@ -0,0 +1,261 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use fm::FileManager;
+use nargo::{
+    insert_all_files_for_workspace_into_file_manager,
+    package::{Dependency, Package},
+    parse_all,
+    workspace::Workspace,
+};
+use noirc_frontend::hir::ParsedFiles;
+use notify::{EventKind, RecursiveMode, Watcher};
+use notify_debouncer_full::new_debouncer;
+
+use crate::errors::CliError;
+
+/// How long to wait for further changes after a file changed, before running the command again.
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Calls `run` on the workspace returned by `resolve_workspace` once, or whenever one of its
+/// files changes if `watch` is set.
+pub(super) fn run_or_watch_workspace(
+    watch: bool,
+    resolve_workspace: impl Fn() -> Result<Workspace, CliError>,
+    mut run: impl FnMut(&Workspace, &FileManager, &ParsedFiles) -> Result<(), CliError>,
+) -> Result<(), CliError> {
+    if watch {
+        return watch_workspace(resolve_workspace, run);
+    }
+
+    let workspace = resolve_workspace()?;
+    let mut workspace_file_manager = workspace.new_file_manager();
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
+    let parsed_files = parse_all(&workspace_file_manager);
+    run(&workspace, &workspace_file_manager, &parsed_files)
+}
+
+/// Calls `run` on the workspace returned by `resolve_workspace`, then again whenever one of its
+/// files changes, clearing the output of the previous run.
+///
+/// The watched files are the Noir files of the workspace's file manager along with any Noir file
+/// created in the directory of a package, the `.toml` files at the root of each package, such as
+/// `Nargo.toml` and `Prover.toml`, and the files read by comptime code during the last run.
+/// The workspace is resolved again on each change so that edits to the manifests apply.
+fn watch_workspace(
+    resolve_workspace: impl Fn() -> Result<Workspace, CliError>,
+    mut run: impl FnMut(&Workspace, &FileManager, &ParsedFiles) -> Result<(), CliError>,
+) -> Result<(), CliError> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut debouncer = new_debouncer(DEBOUNCE_TIMEOUT, None, tx).map_err(watch_error)?;
+
+    let mut watched: Option<WatchedFiles> = None;
+
+    let mut screen = std::io::stdout();
+    write!(screen, "{}", termion::cursor::Save).unwrap();
+    loop {
+        write!(screen, "{}{}", termion::cursor::Restore, termion::clear::AfterCursor).unwrap();
+        screen.flush().unwrap();
+
+        match resolve_workspace() {
+            Ok(workspace) => {
+                let mut file_manager = workspace.new_file_manager();
+                insert_all_files_for_workspace_into_file_manager(&workspace, &mut file_manager);
+                let parsed_files = parse_all(&file_manager);
+                if let Err(error) = run(&workspace, &file_manager, &parsed_files) {
+                    report_error(error);
+                }
+
+                // Files written by `run`, such as a generated `Prover.toml`, are part of the snapshot
+                // so that they don't trigger another run
+                let files = WatchedFiles::new(&workspace, &file_manager);
+                let previous_directories =
+                    watched.take().map(|watched| watched.directories).unwrap_or_default();
+                for (directory, mode) in &previous_directories {
+                    if files.directories.get(directory) != Some(mode) {
+                        let _ = debouncer.watcher().unwatch(directory);
+                    }
+                }
+                for (directory, mode) in &files.directories {
+                    if previous_directories.get(directory) != Some(mode) {
+                        debouncer.watcher().watch(directory, *mode).map_err(watch_error)?;
+                    }
+                }
+                watched = Some(files);
+            }
+            // The manifests can still be fixed while the files of the last workspace are watched
+            Err(error) if watched.is_some() => report_error(error),
+            Err(error) => return Err(error),
+        }
+        screen.flush().unwrap();
+
+        let files = watched.as_ref().expect("files are watched after the first run");
+        loop {
+            let Ok(result) = rx.recv() else {
+                return Ok(());
+            };
+            let debounced_events = result.map_err(|mut errors| watch_error(errors.remove(0)))?;
+
+            let any_file_changed = debounced_events.iter().any(|event| {
+                let is_relevant_event_kind = matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                );
+                is_relevant_event_kind && event.event.paths.iter().any(|path| files.changed(path))
+            });
+            if any_file_changed {
+                break;
+            }
+        }
+    }
+}
+
+/// The files watched by [watch_workspace], along with their contents at the end of the last run,
+/// or `None` for the files comptime code couldn't find.
+///
+/// Their directories are watched rather than the files themselves, as editors often save a file by
+/// replacing it. The directories of the workspace and of its packages are watched recursively so
+/// that Noir files created in new subdirectories are noticed, while the directories of other files,
+/// such as those read by comptime code, are watched on their own.
+struct WatchedFiles {
+    directories: HashMap<PathBuf, RecursiveMode>,
+    package_directories: HashSet<PathBuf>,
+    target_directory: PathBuf,
+    contents: HashMap<PathBuf, Option<Vec<u8>>>,
+}
+
+impl WatchedFiles {
+    fn new(workspace: &Workspace, file_manager: &FileManager) -> Self {
+        let mut packages = Vec::new();
+        for package in workspace.members.iter() {
+            collect_packages(package, &mut packages);
+        }
+        let package_directories: HashSet<PathBuf> = std::iter::once(&workspace.root_dir)
+            .chain(packages.iter().map(|package| &package.root_dir))
+            .filter(|directory| directory.is_dir())
+            .cloned()
+            .collect();
+
+        let mut files = WatchedFiles {
+            directories: HashMap::new(),
+            package_directories,
+            target_directory: workspace.target_directory_path(),
+            contents: HashMap::new(),
+        };
+
+        // A package nested in another one, such as a workspace member, is watched along with it
+        for directory in &files.package_directories {
+            let is_nested = files
+                .package_directories
+                .iter()
+                .any(|other| other != directory && directory.starts_with(other));
+            if !is_nested {
+                files.directories.insert(directory.clone(), RecursiveMode::Recursive);
+            }
+        }
+
+        for directory in &files.package_directories {
+            let Ok(entries) = std::fs::read_dir(directory) else {
+                continue;
+            };
+            for path in entries.flatten().map(|entry| entry.path()) {
+                if is_manifest(&path) {
+                    if let Ok(source) = std::fs::read(&path) {
+                        files.contents.insert(path, Some(source));
+                    }
+                }
+            }
+        }
+
+        // The files of the standard library are embedded in Nargo, so they have no directory on disk
+        for file_id in file_manager.as_file_map().all_file_ids() {
+            let path = file_manager.path(*file_id).expect("expected file to exist");
+            if path.is_absolute() {
+                let source = file_manager.fetch_file(*file_id).expect("expected file to exist");
+                files.watch_parent(path);
+                files.contents.insert(path.to_path_buf(), Some(source.as_bytes().to_vec()));
+            }
+        }
+
+        for path in workspace.comptime_inputs() {
+            files.watch_parent(&path);
+            let contents = std::fs::read(&path).ok();
+            files.contents.insert(path, contents);
+        }
+        files
+    }
+
+    /// Watches the directory of `path` if it exists and isn't already watched recursively.
+    fn watch_parent(&mut self, path: &Path) {
+        let Some(directory) = path.parent().filter(|directory| directory.is_dir()) else {
+            return;
+        };
+        let is_watched_recursively = self
+            .directories
+            .iter()
+            .any(|(other, mode)| *mode == RecursiveMode::Recursive && directory.starts_with(other));
+        if !is_watched_recursively {
+            self.directories.insert(directory.to_path_buf(), RecursiveMode::NonRecursive);
+        }
+    }
+
+    /// Returns whether `path`, which an event was reported for, was created, removed or modified
+    /// since the end of the last run.
+    fn changed(&self, path: &Path) -> bool {
+        let previous_contents = match self.contents.get(path) {
+            Some(contents) => contents.as_ref(),
+            None if self.is_new_watched_file(path) => None,
+            None => return false,
+        };
+        std::fs::read(path).ok().as_ref() != previous_contents
+    }
+
+    /// Returns whether `path` is a new Noir file in the directory of a package, or a new manifest
+    /// at the root of one. The files written to the target directory, such as the artifacts of
+    /// the last run, are ignored.
+    fn is_new_watched_file(&self, path: &Path) -> bool {
+        if path.starts_with(&self.target_directory) {
+            return false;
+        }
+        if path.extension().map_or(false, |extension| extension == "nr") {
+            return self.package_directories.iter().any(|directory| path.starts_with(directory));
+        }
+        is_manifest(path)
+            && path.parent().map_or(false, |directory| self.package_directories.contains(directory))
+    }
+}
+
+fn is_manifest(path: &Path) -> bool {
+    path.extension().map_or(false, |extension| extension == "toml")
+}
+
+/// Adds `package` and all its dependencies to `packages`.
+fn collect_packages<'a>(package: &'a Package, packages: &mut Vec<&'a Package>) {
+    if packages.iter().any(|other| other.root_dir == package.root_dir) {
+        return;
+    }
+    packages.push(package);
+    for dependency in package.dependencies.values().chain(package.dev_dependencies.values()) {
+        match dependency {
+            Dependency::Local { package, .. } | Dependency::Remote { package, .. } => {
+                collect_packages(package, packages);
+            }
+        }
+    }
+}
+
+fn report_error(error: CliError) {
+    // Failing tests are reported as an empty error, as the report was already printed
+    let message = error.to_string();
+    if !message.is_empty() {
+        eprintln!("{message}");
+    }
+}
+
+fn watch_error(error: notify::Error) -> CliError {
+    CliError::Generic(error.to_string())
+}
@@ -35,6 +35,9 @@ pub(crate) enum CliError {
     #[error("Error: destination {} already exists", .0.display())]
     DestinationAlreadyExists(PathBuf),
 
+    #[error("Profile `{0}` is not defined in Nargo.toml")]
+    UnknownProfile(String),
+
     #[error("Invalid package name {0}. Did you mean to use `--name`?")]
     InvalidPackageName(String),
 
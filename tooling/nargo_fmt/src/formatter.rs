@@ -14,6 +14,7 @@ mod attribute;
 mod buffer;
 mod comments_and_whitespace;
 mod doc_comments;
+mod enums;
 mod expression;
 mod function;
 mod generics;
@@ -120,7 +121,8 @@ impl<'a> Formatter<'a> {
     pub(crate) fn write_identifier(&mut self, ident: Ident) {
         self.skip_comments_and_whitespace();
 
-        let Token::Ident(..) = self.token else {
+        // Functions and methods can be named `match`
+        let (Token::Ident(..) | Token::Keyword(Keyword::Match)) = self.token else {
             panic!("Expected identifier, got {:?}", self.token);
         };
         self.write(&ident.0.contents);
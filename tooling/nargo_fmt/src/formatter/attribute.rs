@@ -1,5 +1,6 @@
 use noirc_frontend::token::{
-    Attribute, Attributes, FunctionAttribute, MetaAttribute, SecondaryAttribute, TestScope, Token,
+    Attribute, Attributes, CfgPredicate, FunctionAttribute, MetaAttribute, SecondaryAttribute,
+    TestScope, Token,
 };
 
 use crate::chunks::ChunkGroup;
@@ -79,7 +80,9 @@ impl<'a> Formatter<'a> {
             }
             SecondaryAttribute::Field(_)
             | SecondaryAttribute::Abi(_)
-            | SecondaryAttribute::Allow(_) => {
+            | SecondaryAttribute::Allow(_)
+            | SecondaryAttribute::Warn(_)
+            | SecondaryAttribute::Deny(_) => {
                 self.format_one_arg_attribute();
             }
             SecondaryAttribute::Tag(custom_attribute) => {
@@ -88,6 +91,9 @@ impl<'a> Formatter<'a> {
             SecondaryAttribute::Meta(meta_attribute) => {
                 self.format_meta_attribute(meta_attribute);
             }
+            SecondaryAttribute::Cfg(predicate) => {
+                self.format_cfg_attribute(predicate);
+            }
         }
 
         self.write_line();
@@ -137,6 +143,51 @@ impl<'a> Formatter<'a> {
         self.write_right_bracket(); // ]
     }
 
+    fn format_cfg_attribute(&mut self, predicate: CfgPredicate) {
+        self.write_current_token_and_bump(); // #[
+        self.skip_comments_and_whitespace();
+        self.write_current_token_and_bump(); // cfg
+        self.write_left_paren(); // (
+        self.format_cfg_predicate(predicate);
+        self.write_right_paren(); // )
+        self.write_right_bracket(); // ]
+    }
+
+    fn format_cfg_predicate(&mut self, predicate: CfgPredicate) {
+        self.skip_comments_and_whitespace();
+        self.write_current_token_and_bump(); // feature, not, all or any
+
+        match predicate {
+            CfgPredicate::Feature(..) => {
+                self.write_space();
+                self.write_token(Token::Assign);
+                self.write_space();
+                self.skip_comments_and_whitespace();
+                self.write_current_token_and_bump(); // "name"
+            }
+            CfgPredicate::Not(predicate) => {
+                self.write_left_paren(); // (
+                self.format_cfg_predicate(*predicate);
+                self.write_right_paren(); // )
+            }
+            CfgPredicate::All(predicates) | CfgPredicate::Any(predicates) => {
+                self.write_left_paren(); // (
+                for (index, predicate) in predicates.into_iter().enumerate() {
+                    if index > 0 {
+                        self.write_comma();
+                        self.write_space();
+                    }
+                    self.format_cfg_predicate(predicate);
+                }
+                self.skip_comments_and_whitespace();
+                if self.is_at(Token::Comma) {
+                    self.bump();
+                }
+                self.write_right_paren(); // )
+            }
+        }
+    }
+
     fn format_meta_attribute(&mut self, meta_attribute: MetaAttribute) {
         self.write_current_token_and_bump(); // #[
         self.skip_comments_and_whitespace();
@@ -250,6 +301,20 @@ mod tests {
         assert_format_attribute(src, expected);
     }
 
+    #[test]
+    fn format_cfg_attribute() {
+        let src = "  #[ cfg ( all ( feature  =  \"foo\" , not ( feature=\"bar\" ) , ) ) ] ";
+        let expected = "#[cfg(all(feature = \"foo\", not(feature = \"bar\")))]";
+        assert_format_attribute(src, expected);
+    }
+
+    #[test]
+    fn format_cfg_attribute_on_import() {
+        let src = "  #[ cfg ( feature = \"foo\" ) ]  use  foo ; use bar;";
+        let expected = "#[cfg(feature = \"foo\")]\nuse foo;\nuse bar;\n";
+        assert_format(src, expected);
+    }
+
     #[test]
     fn format_field_attribute() {
         let src = "  #[ field ( bn256 ) ] ";
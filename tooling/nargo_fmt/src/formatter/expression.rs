@@ -2,8 +2,8 @@ use noirc_frontend::{
     ast::{
         ArrayLiteral, BinaryOpKind, BlockExpression, CallExpression, CastExpression,
         ConstructorExpression, Expression, ExpressionKind, IfExpression, IndexExpression,
-        InfixExpression, Lambda, Literal, MemberAccessExpression, MethodCallExpression,
-        PrefixExpression, TypePath, UnaryOp, UnresolvedTypeData,
+        InfixExpression, Lambda, Literal, MatchExpression, MemberAccessExpression,
+        MethodCallExpression, PrefixExpression, TypePath, UnaryOp, UnresolvedTypeData,
     },
     token::{Keyword, Token},
 };
@@ -85,6 +85,9 @@ impl<'a, 'b> ChunkFormatter<'a, 'b> {
                     false, // force multiple lines
                 ));
             }
+            ExpressionKind::Match(match_expression) => {
+                group.group(self.format_match_expression(*match_expression));
+            }
             ExpressionKind::AsTraitPath(as_trait_path) => {
                 group.text(self.chunk(|formatter| formatter.format_as_trait_path(as_trait_path)));
             }
@@ -376,6 +379,66 @@ impl<'a, 'b> ChunkFormatter<'a, 'b> {
         group
     }
 
+    pub(super) fn format_match_expression(
+        &mut self,
+        match_expression: MatchExpression,
+    ) -> ChunkGroup {
+        let mut group = ChunkGroup::new();
+        group.force_multiple_lines = true;
+
+        group.text(self.chunk(|formatter| {
+            formatter.write_keyword(Keyword::Match);
+            formatter.write_space();
+        }));
+        self.format_expression(match_expression.expression, &mut group);
+        group.space(self);
+        group.text(self.chunk(|formatter| {
+            formatter.write_left_brace();
+        }));
+
+        if match_expression.rules.is_empty() {
+            if let Some(block_group) = self.empty_block_contents_chunk() {
+                group.chunks.extend(block_group.chunks);
+            }
+        } else {
+            group.increase_indentation();
+
+            for (pattern, branch) in match_expression.rules {
+                group.line();
+                self.format_expression(pattern, &mut group);
+                group.text(self.chunk(|formatter| {
+                    formatter.write_space();
+                    formatter.write_token(Token::FatArrow);
+                    formatter.write_space();
+                }));
+
+                // Like in Rust, a comma is only needed after branches that aren't blocks
+                let is_block = matches!(branch.kind, ExpressionKind::Block(..));
+                self.format_expression(branch, &mut group);
+                group.text(self.chunk(|formatter| {
+                    formatter.skip_whitespace();
+                    if formatter.is_at(Token::Comma) {
+                        formatter.bump();
+                    }
+                    if !is_block {
+                        formatter.write(",");
+                    }
+                }));
+            }
+
+            group.text(self.chunk(|formatter| {
+                formatter.skip_comments_and_whitespace();
+            }));
+            group.decrease_indentation();
+            group.line();
+        }
+
+        group.text(self.chunk(|formatter| {
+            formatter.write_right_brace();
+        }));
+        group
+    }
+
     pub(super) fn format_type_path(&mut self, type_path: TypePath) -> ChunkGroup {
         let mut group = ChunkGroup::new();
         group.text(self.chunk(|formatter| {
@@ -2060,6 +2123,21 @@ global y = 1;
         assert_format_with_config(src, expected, config);
     }
 
+    #[test]
+    fn format_match() {
+        let src = "fn foo() { match  x  {  Foo::A ( a ) => a ,  Foo::B  =>  { 1 } ,
+        _ => 2 } }";
+        let expected = "fn foo() {
+    match x {
+        Foo::A(a) => a,
+        Foo::B => { 1 }
+        _ => 2,
+    }
+}
+";
+        assert_format(src, expected);
+    }
+
     #[test]
     fn format_quote() {
         let src = "global x = quote { 1  2  3 $four $(five) };";
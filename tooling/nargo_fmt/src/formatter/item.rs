@@ -2,6 +2,7 @@ use noirc_frontend::{
     ast::{ItemVisibility, UseTree},
     hir::resolution::errors::Span,
     parser::{Item, ItemKind},
+    token::SecondaryAttribute,
 };
 
 use crate::config::ImportsGranularity;
@@ -54,12 +55,24 @@ impl<'a> Formatter<'a> {
             return;
         }
 
+        // These items don't keep their attributes, so their `cfg` attributes are
+        // formatted from the ones collected for the item.
+        if let ItemKind::Import(..)
+        | ItemKind::Impl(..)
+        | ItemKind::TraitImpl(..)
+        | ItemKind::TypeAlias(..) = item.kind
+        {
+            let cfg_attributes = item.cfg.into_iter().map(SecondaryAttribute::Cfg).collect();
+            self.format_secondary_attributes(cfg_attributes);
+        }
+
         match item.kind {
             ItemKind::Import(use_tree, item_visibility) => {
                 self.format_import(use_tree, item_visibility);
             }
             ItemKind::Function(noir_function) => self.format_function(noir_function),
             ItemKind::Struct(noir_struct) => self.format_struct(noir_struct),
+            ItemKind::Enum(noir_enum) => self.format_enum(noir_enum),
             ItemKind::Trait(noir_trait) => self.format_trait(noir_trait),
             ItemKind::TraitImpl(noir_trait_impl) => self.format_trait_impl(noir_trait_impl),
             ItemKind::Impl(type_impl) => self.format_impl(type_impl),
@@ -102,6 +115,11 @@ impl<'a> Formatter<'a> {
             return None;
         };
 
+        // Imports with `cfg` attributes are formatted on their own
+        if !item.cfg.is_empty() {
+            return None;
+        }
+
         let item = items.pop().unwrap();
         let ItemKind::Import(use_tree, visibility) = item.kind else {
             panic!("Expected import, got {:?}", item.kind);
@@ -134,7 +152,7 @@ impl<'a> Formatter<'a> {
                 break;
             };
 
-            if visibility != *next_visibility {
+            if visibility != *next_visibility || !item.cfg.is_empty() {
                 break;
             }
 
@@ -1,10 +1,10 @@
 use noirc_frontend::{
     ast::{
         AssignStatement, ConstrainKind, ConstrainStatement, Expression, ExpressionKind,
-        ForLoopStatement, ForRange, LetStatement, Pattern, Statement, StatementKind,
-        UnresolvedType, UnresolvedTypeData,
+        ForLoopStatement, ForRange, LetStatement, LoopStatement, Pattern, Statement, StatementKind,
+        UnresolvedType, UnresolvedTypeData, WhileStatement,
     },
-    token::{Keyword, SecondaryAttribute, Token},
+    token::{CfgPredicate, Keyword, SecondaryAttribute, Token},
 };
 
 use crate::chunks::{ChunkFormatter, ChunkGroup, GroupKind};
@@ -65,6 +65,12 @@ impl<'a, 'b> ChunkFormatter<'a, 'b> {
             StatementKind::For(for_loop_statement) => {
                 group.group(self.format_for_loop(for_loop_statement));
             }
+            StatementKind::While(while_statement) => {
+                group.group(self.format_while_loop(while_statement));
+            }
+            StatementKind::Loop(loop_statement) => {
+                group.group(self.format_loop(loop_statement));
+            }
             StatementKind::Break => {
                 group.text(self.chunk(|formatter| {
                     formatter.write_keyword(Keyword::Break);
@@ -77,9 +83,15 @@ impl<'a, 'b> ChunkFormatter<'a, 'b> {
                     formatter.write_semicolon();
                 }));
             }
+            StatementKind::Return(expression) => {
+                group.group(self.format_return_statement(expression));
+            }
             StatementKind::Comptime(statement) => {
                 group.group(self.format_comptime_statement(*statement));
             }
+            StatementKind::Cfg(predicate, statement) => {
+                group.group(self.format_cfg_statement(predicate, *statement));
+            }
             StatementKind::Semi(expression) => {
                 group.group(self.format_semi_statement(expression));
             }
@@ -247,9 +259,39 @@ impl<'a, 'b> ChunkFormatter<'a, 'b> {
         }
 
         group.space(self);
+        self.format_loop_body(for_loop.block, &mut group);
+        group
+    }
+
+    fn format_while_loop(&mut self, while_loop: WhileStatement) -> ChunkGroup {
+        let mut group = ChunkGroup::new();
+
+        group.text(self.chunk(|formatter| {
+            formatter.write_keyword(Keyword::While);
+            formatter.write_space();
+        }));
+
+        self.format_expression(while_loop.condition, &mut group);
+        group.space(self);
+        self.format_loop_body(while_loop.block, &mut group);
+        group
+    }
 
-        let ExpressionKind::Block(block) = for_loop.block.kind else {
-            panic!("Expected a block expression for for loop body");
+    fn format_loop(&mut self, loop_statement: LoopStatement) -> ChunkGroup {
+        let mut group = ChunkGroup::new();
+
+        group.text(self.chunk(|formatter| {
+            formatter.write_keyword(Keyword::Loop);
+            formatter.write_space();
+        }));
+
+        self.format_loop_body(loop_statement.block, &mut group);
+        group
+    }
+
+    fn format_loop_body(&mut self, body: Expression, group: &mut ChunkGroup) {
+        let ExpressionKind::Block(block) = body.kind else {
+            panic!("Expected a block expression for loop body");
         };
 
         group.group(self.format_block_expression(
@@ -263,8 +305,6 @@ impl<'a, 'b> ChunkFormatter<'a, 'b> {
                 formatter.bump();
             }
         }));
-
-        group
     }
 
     fn format_comptime_statement(&mut self, statement: Statement) -> ChunkGroup {
@@ -284,6 +324,44 @@ impl<'a, 'b> ChunkFormatter<'a, 'b> {
         group
     }
 
+    fn format_cfg_statement(
+        &mut self,
+        predicate: CfgPredicate,
+        statement: Statement,
+    ) -> ChunkGroup {
+        let mut group = ChunkGroup::new();
+
+        // The attribute always goes in its own line
+        group.force_multiple_lines = true;
+
+        group.text(self.chunk(|formatter| {
+            formatter.format_secondary_attribute(SecondaryAttribute::Cfg(predicate));
+        }));
+        group.line();
+        self.format_statement(
+            statement, &mut group, false, // ignore next
+        );
+        group
+    }
+
+    fn format_return_statement(&mut self, expression: Option<Expression>) -> ChunkGroup {
+        let mut group = ChunkGroup::new();
+
+        group.text(self.chunk(|formatter| {
+            formatter.write_keyword(Keyword::Return);
+        }));
+
+        if let Some(expression) = expression {
+            group.text(self.chunk(|formatter| {
+                formatter.write_space();
+            }));
+            self.format_expression(expression, &mut group);
+        }
+
+        group.semicolon(self);
+        group
+    }
+
     fn format_semi_statement(&mut self, expression: Expression) -> ChunkGroup {
         let mut group = ChunkGroup::new();
 
@@ -333,6 +411,26 @@ mod tests {
         assert_format(src, expected);
     }
 
+    #[test]
+    fn format_return_statement() {
+        let src = " fn foo() { return  1  +  2 ; } ";
+        let expected = "fn foo() {
+    return 1 + 2;
+}
+";
+        assert_format(src, expected);
+    }
+
+    #[test]
+    fn format_return_statement_without_value() {
+        let src = " fn foo() { return  ; } ";
+        let expected = "fn foo() {
+    return;
+}
+";
+        assert_format(src, expected);
+    }
+
     #[test]
     fn format_continue_statement() {
         let src = " fn foo() { continue  ; } ";
@@ -374,6 +472,17 @@ mod tests {
         assert_format(src, expected);
     }
 
+    #[test]
+    fn format_cfg_statement() {
+        let src = " fn foo() {   #[cfg( feature = \"foo\" )]  x  =  1 ; } ";
+        let expected = "fn foo() {
+    #[cfg(feature = \"foo\")]
+    x = 1;
+}
+";
+        assert_format(src, expected);
+    }
+
     #[test]
     fn format_assign() {
         let src = " fn foo() { x  =  2 ; } ";
@@ -592,6 +701,42 @@ mod tests {
         assert_format(src, expected);
     }
 
+    #[test]
+    fn format_while() {
+        let src = " fn foo() {  while  x  <  10  {  x  +=  1;  } } ";
+        let expected = "fn foo() {
+    while x < 10 {
+        x += 1;
+    }
+}
+";
+        assert_format(src, expected);
+    }
+
+    #[test]
+    fn format_while_trailing_semicolon() {
+        let src = " fn foo() {  while  true  {  1  } ; } ";
+        let expected = "fn foo() {
+    while true {
+        1
+    }
+}
+";
+        assert_format(src, expected);
+    }
+
+    #[test]
+    fn format_loop() {
+        let src = " fn foo() {  loop  {  break ;  } } ";
+        let expected = "fn foo() {
+    loop {
+        break;
+    }
+}
+";
+        assert_format(src, expected);
+    }
+
     #[test]
     fn format_assert() {
         let src = r#" fn foo() {  assert ( true , "hello" ) ;  } "#;
@@ -40,7 +40,12 @@ pub mod errors;
 mod formatter;
 
 use formatter::Formatter;
-use noirc_frontend::ParsedModule;
+use noirc_frontend::{
+    ast::{BlockExpression, NoirTrait, NoirTraitImpl, TypeImpl, Visitor},
+    hir::resolution::errors::Span,
+    parser::{self, Item, ItemKind, ParsedSubModule},
+    ParsedModule,
+};
 
 pub use config::Config;
 
@@ -50,6 +55,172 @@ pub fn format(source: &str, parsed_module: ParsedModule, config: &Config) -> Str
     formatter.buffer.contents()
 }
 
+/// Formats only the code that overlaps with the given span, leaving the rest of `source` untouched.
+///
+/// If the span lies inside the statements of a block, only the statements of the innermost such
+/// block that overlap with the span are formatted. Otherwise the items that overlap with the span
+/// are formatted.
+///
+/// Returns the span of `source` that needs to be replaced together with its formatted contents,
+/// or `None` if nothing overlaps with the given span (or if that code can't be formatted).
+pub fn format_range(
+    source: &str,
+    parsed_module: ParsedModule,
+    config: &Config,
+    span: Span,
+) -> Option<(Span, String)> {
+    let mut finder = StatementsFinder::new(span);
+    parsed_module.accept(&mut finder);
+    if let Some((statements_span, indentation)) = finder.statements {
+        let formatted = format_statements(source, config, statements_span, indentation)?;
+        return Some((statements_span, formatted));
+    }
+
+    let (items_span, indentation) = find_items_span(&parsed_module.items, span, 0)?;
+    let items_source = source.get(items_span.start() as usize..items_span.end() as usize)?;
+
+    let (items_module, errors) = parser::parse_program(items_source);
+    if !errors.is_empty() {
+        return None;
+    }
+
+    let mut formatter = Formatter::new(items_source, config);
+    formatter.indentation = indentation;
+    formatter.format_program(items_module);
+
+    // The replaced span starts right at the first item (after its indentation)
+    // and ends right at the end of the last item (before any newline).
+    let formatted = formatter.buffer.contents();
+    Some((items_span, formatted.trim().to_string()))
+}
+
+/// Formats the statements in the given span of `source`, which are indented at `indentation`.
+/// The replaced span starts right at the first statement and ends right at the end of the last one.
+fn format_statements(
+    source: &str,
+    config: &Config,
+    statements_span: Span,
+    indentation: i32,
+) -> Option<String> {
+    let statements_source =
+        source.get(statements_span.start() as usize..statements_span.end() as usize)?;
+
+    // Statements can only be parsed and formatted inside a function, so one is put around them
+    let function_header = "fn statements() {";
+    let function_source = format!("{function_header}\n{statements_source}\n}}");
+    let (function_module, errors) = parser::parse_program(&function_source);
+    if !errors.is_empty() {
+        return None;
+    }
+
+    let mut formatter = Formatter::new(&function_source, config);
+    formatter.indentation = indentation - 1;
+    formatter.format_program(function_module);
+
+    let formatted = formatter.buffer.contents();
+    let statements = formatted.trim().strip_prefix(function_header)?.strip_suffix('}')?;
+    Some(statements.trim().to_string())
+}
+
+/// Finds the innermost block whose statements surround a span, together with the span
+/// of the statements in that block that overlap with it and their indentation.
+struct StatementsFinder {
+    span: Span,
+    indentation: i32,
+    statements: Option<(Span, i32)>,
+}
+
+impl StatementsFinder {
+    fn new(span: Span) -> Self {
+        Self { span, indentation: 0, statements: None }
+    }
+
+    /// Increases the indentation if `span` lies inside the given node.
+    /// Nodes are visited from the outermost to the innermost one, so once the innermost
+    /// block is reached the indentation matches its statements.
+    fn visit_indented(&mut self, node_span: Span) -> bool {
+        if span_surrounds(node_span, self.span) {
+            self.indentation += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Visitor for StatementsFinder {
+    fn visit_parsed_submodule(&mut self, _: &ParsedSubModule, span: Span) -> bool {
+        self.visit_indented(span)
+    }
+
+    fn visit_type_impl(&mut self, _: &TypeImpl, span: Span) -> bool {
+        self.visit_indented(span)
+    }
+
+    fn visit_noir_trait_impl(&mut self, _: &NoirTraitImpl, span: Span) -> bool {
+        self.visit_indented(span)
+    }
+
+    fn visit_noir_trait(&mut self, _: &NoirTrait, span: Span) -> bool {
+        self.visit_indented(span)
+    }
+
+    fn visit_block_expression(&mut self, block: &BlockExpression, _: Option<Span>) -> bool {
+        let (Some(first), Some(last)) = (block.statements.first(), block.statements.last()) else {
+            return false;
+        };
+
+        if !self.visit_indented(Span::from(first.span.start()..last.span.end())) {
+            return false;
+        }
+
+        let mut overlapping_statements =
+            block.statements.iter().filter(|statement| span_overlaps(statement.span, self.span));
+        if let Some(first) = overlapping_statements.next() {
+            let last = overlapping_statements.last().unwrap_or(first);
+            let statements_span = Span::from(first.span.start()..last.span.end());
+            self.statements = Some((statements_span, self.indentation));
+        }
+        true
+    }
+}
+
+/// Returns the span that covers all items that overlap with `span`, together with their indentation.
+/// If `span` lies inside a single `mod { ... }`, the search continues inside that module.
+fn find_items_span(items: &[Item], span: Span, indentation: i32) -> Option<(Span, i32)> {
+    let overlapping_items: Vec<&Item> =
+        items.iter().filter(|item| span_overlaps(item.span, span)).collect();
+
+    if let [item] = overlapping_items.as_slice() {
+        if let ItemKind::Submodules(submodule) = &item.kind {
+            if !span.contains(&item.span) {
+                let items = &submodule.contents.items;
+                if let Some(result) = find_items_span(items, span, indentation + 1) {
+                    return Some(result);
+                }
+            }
+        }
+    }
+
+    let first_item = overlapping_items.first()?;
+    let last_item = overlapping_items.last()?;
+    Some((Span::from(first_item.span.start()..last_item.span.end()), indentation))
+}
+
+/// An empty span (a cursor position) overlaps with an item span if it touches it.
+fn span_overlaps(item_span: Span, span: Span) -> bool {
+    if span.start() == span.end() {
+        item_span.start() <= span.start() && span.start() <= item_span.end()
+    } else {
+        item_span.intersects(&span)
+    }
+}
+
+/// Returns true if `span` lies inside `node_span` (or touches it, if `span` is empty).
+fn span_surrounds(node_span: Span, span: Span) -> bool {
+    node_span.start() <= span.start() && span.end() <= node_span.end()
+}
+
 #[cfg(test)]
 pub(crate) fn assert_format(src: &str, expected: &str) {
     assert_format_with_config(src, expected, Config::default());
@@ -87,3 +258,136 @@ pub(crate) fn assert_format_with_config(src: &str, expected: &str, config: Confi
     }
     similar_asserts::assert_eq!(result, expected, "idempotent check failed");
 }
+
+#[cfg(test)]
+mod format_range_tests {
+    use noirc_frontend::{hir::resolution::errors::Span, parser};
+
+    use crate::{format_range, Config};
+
+    fn assert_format_range(src: &str, selected: &str, expected: &str) {
+        let start = src.find(selected).expect("Expected to find selected text") as u32;
+        let span = Span::from(start..start + selected.len() as u32);
+
+        let (parsed_module, errors) = parser::parse_program(src);
+        if !errors.is_empty() {
+            panic!("Expected no errors, got: {:?}", errors);
+        }
+
+        let (span, formatted) = format_range(src, parsed_module, &Config::default(), span)
+            .expect("Expected a formatted range");
+        let mut result = src.to_string();
+        result.replace_range(span.start() as usize..span.end() as usize, &formatted);
+        similar_asserts::assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn formats_only_selected_item() {
+        let src = "fn  one ( ) { }
+
+fn two ( ) {  let  x = 1 ; }
+
+fn  three ( ) { }
+";
+        let expected = "fn  one ( ) { }
+
+fn two() {
+    let x = 1;
+}
+
+fn  three ( ) { }
+";
+        assert_format_range(src, "two ( )", expected);
+    }
+
+    #[test]
+    fn formats_only_selected_statements() {
+        let src = "fn one ( ) {
+    let  x = 1 ;
+    let  y = 2 ;
+    let  z = 3 ;
+}
+";
+        let expected = "fn one ( ) {
+    let x = 1;
+    let y = 2;
+    let  z = 3 ;
+}
+";
+        assert_format_range(src, "x = 1 ;\n    let  y", expected);
+    }
+
+    #[test]
+    fn formats_statements_in_innermost_block() {
+        let src = "mod foo {
+    impl Foo {
+        fn one ( ) {
+            if true {
+                let  x = [ 1 , 2 ] ;
+            }
+        }
+    }
+}
+";
+        let expected = "mod foo {
+    impl Foo {
+        fn one ( ) {
+            if true {
+                let x = [1, 2];
+            }
+        }
+    }
+}
+";
+        assert_format_range(src, "x = [", expected);
+    }
+
+    #[test]
+    fn formats_all_overlapping_items() {
+        let src = "fn  one ( ) { }
+fn two ( ) { }
+fn  three ( ) { }
+";
+        let expected = "fn  one ( ) { }
+fn two() {}
+fn three() {}
+";
+        assert_format_range(src, "two ( ) { }\nfn", expected);
+    }
+
+    #[test]
+    fn formats_item_inside_module() {
+        let src = "mod  foo {
+    fn  one ( ) { }
+    fn two ( ) {  let  x = 1 ; }
+}
+";
+        let expected = "mod  foo {
+    fn  one ( ) { }
+    fn two() {
+        let x = 1;
+    }
+}
+";
+        assert_format_range(src, "two ( )", expected);
+    }
+
+    #[test]
+    fn formats_item_at_cursor_after_closing_brace() {
+        let src = "fn  one ( ) { }
+fn two ( ) {  let  x = 1 ; }
+";
+        let expected = "fn  one ( ) { }
+fn two() {
+    let x = 1;
+}
+";
+        let (parsed_module, _) = parser::parse_program(src);
+        let position = src.rfind('}').unwrap() as u32 + 1;
+        let (span, formatted) =
+            format_range(src, parsed_module, &Config::default(), Span::empty(position)).unwrap();
+        let mut result = src.to_string();
+        result.replace_range(span.start() as usize..span.end() as usize, &formatted);
+        similar_asserts::assert_eq!(result, expected);
+    }
+}
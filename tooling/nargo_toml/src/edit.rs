@@ -0,0 +1,80 @@
+use std::path::Path;
+
+use toml_edit::{Document, InlineTable};
+
+use crate::{errors::ManifestError, DependencySource};
+
+/// Name of the table holding the dependencies of a package.
+pub(crate) const DEPENDENCIES_TABLE: &str = "dependencies";
+/// Name of the table holding the dependencies of a package which are only linked in tests.
+pub(crate) const DEV_DEPENDENCIES_TABLE: &str = "dev-dependencies";
+
+/// Sets `name` to `source` in the `table` of the manifest at `toml_path`, creating the table if
+/// needed. The rest of the manifest, including its comments and formatting, is left untouched.
+pub(crate) fn insert_dependency(
+    toml_path: &Path,
+    table: &str,
+    name: &str,
+    source: &DependencySource,
+) -> Result<(), ManifestError> {
+    let mut document = read_document(toml_path)?;
+
+    let mut dependency = InlineTable::new();
+    match source {
+        DependencySource::Git { git, tag, directory } => {
+            dependency.insert("tag", tag.into());
+            dependency.insert("git", git.into());
+            if let Some(directory) = directory {
+                dependency.insert("directory", directory.into());
+            }
+        }
+        DependencySource::Path { path } => {
+            dependency.insert("path", path.into());
+        }
+    }
+
+    let dependencies =
+        document.entry(table).or_insert(toml_edit::table()).as_table_like_mut().ok_or_else(
+            || ManifestError::EditFailed {
+                toml: toml_path.to_path_buf(),
+                message: format!("`{table}` is not a table"),
+            },
+        )?;
+    dependencies.insert(name, toml_edit::value(dependency));
+
+    write_document(toml_path, &document)
+}
+
+/// Removes `name` from the dependency tables of the manifest at `toml_path`, leaving the rest
+/// of the manifest untouched. Returns whether it was found in any of them.
+pub(crate) fn remove_dependency(toml_path: &Path, name: &str) -> Result<bool, ManifestError> {
+    let mut document = read_document(toml_path)?;
+
+    let mut removed = false;
+    for table in [DEPENDENCIES_TABLE, DEV_DEPENDENCIES_TABLE] {
+        if let Some(dependencies) =
+            document.get_mut(table).and_then(|item| item.as_table_like_mut())
+        {
+            removed |= dependencies.remove(name).is_some();
+        }
+    }
+
+    if removed {
+        write_document(toml_path, &document)?;
+    }
+    Ok(removed)
+}
+
+fn read_document(toml_path: &Path) -> Result<Document, ManifestError> {
+    let contents = std::fs::read_to_string(toml_path)
+        .map_err(|_| ManifestError::ReadFailed(toml_path.to_path_buf()))?;
+    contents.parse().map_err(|err: toml_edit::TomlError| ManifestError::EditFailed {
+        toml: toml_path.to_path_buf(),
+        message: err.to_string(),
+    })
+}
+
+fn write_document(toml_path: &Path, document: &Document) -> Result<(), ManifestError> {
+    std::fs::write(toml_path, document.to_string())
+        .map_err(|_| ManifestError::WriteFailed(toml_path.to_path_buf()))
+}
@@ -15,6 +15,12 @@ pub enum ManifestError {
     #[error("Cannot read file {0} - does it exist?")]
     ReadFailed(PathBuf),
 
+    #[error("Cannot write file {0}")]
+    WriteFailed(PathBuf),
+
+    #[error("{0} wasn't vendored by nargo. Move it out of the vendor directory to vendor dependencies there")]
+    UnknownVendoredFile(PathBuf),
+
     #[error("Nargo.toml is missing a parent directory")]
     MissingParent,
 
@@ -45,6 +51,20 @@ pub enum ManifestError {
     #[error("{} found in {toml}", if name.is_empty() { "Empty dependency name".into() } else { format!("Invalid dependency name `{name}`") })]
     InvalidDependencyName { toml: PathBuf, name: String },
 
+    #[error("`{name}` is both a dependency and a dev-dependency in {toml}")]
+    DuplicateDevDependency { toml: PathBuf, name: CrateName },
+
+    #[error("Dependency `{name}` was not found in {toml}")]
+    MissingDependency { toml: PathBuf, name: CrateName },
+
+    #[error(
+        "{0} is a workspace without a `default-member`. Select one of its members with `--package`"
+    )]
+    UnselectedWorkspaceMember(PathBuf),
+
+    #[error("Cannot edit {toml}: {message}")]
+    EditFailed { toml: PathBuf, message: String },
+
     #[error("Invalid directory path {directory} in {toml}: It must point to a subdirectory")]
     InvalidDirectory { toml: PathBuf, directory: PathBuf },
 
@@ -75,6 +95,43 @@ pub enum ManifestError {
 
     #[error("Failed to parse expression width with the following error: {0}")]
     ParseExpressionWidth(String),
+
+    #[error("Profile `{name}` in {toml} can't set both `deny-warnings` and `silence-warnings`")]
+    ConflictingProfileOptions { toml: PathBuf, name: String },
+
+    #[error("Unknown lint `{name}` found in {toml}")]
+    UnknownLint { toml: PathBuf, name: String },
+
+    #[error(
+        "Invalid level `{level}` for lint `{name}` in {toml}. Expected `allow`, `warn` or `deny`"
+    )]
+    InvalidLintLevel { toml: PathBuf, name: String, level: String },
+
+    #[error("Feature `{name}` is not declared in the `[features]` table of {toml}")]
+    UnknownFeature { toml: PathBuf, name: String },
+
+    #[error("None of the packages in the workspace declare the selected feature `{0}`")]
+    UnknownSelectedFeature(String),
+
+    #[error("{lockfile} is badly formed, could not parse.\n\n {message}")]
+    MalformedLockfile { lockfile: PathBuf, message: String },
+
+    #[error("Unsupported version {version} in {lockfile}")]
+    UnsupportedLockfileVersion { lockfile: PathBuf, version: u32 },
+
+    #[error("Dependency {git} at tag `{tag}` resolved to commit {found} but Nargo.lock expects commit {expected}. If the tag was moved intentionally, run `nargo update` to lock the new commit")]
+    LockedRevisionMismatch { git: String, tag: String, expected: String, found: String },
+
+    #[error("The contents of dependency {git} at tag `{tag}` in {dir} do not match the checksum in Nargo.lock. Run `nargo update` to fetch the dependency again, or `nargo vendor` if it is vendored")]
+    LockedChecksumMismatch { git: String, tag: String, dir: PathBuf },
+
+    #[error(
+        "Dependency {git} at tag `{tag}` is missing from Nargo.lock. Run `nargo update` to lock it"
+    )]
+    UnlockedDependency { git: String, tag: String },
+
+    #[error("Dependency {git} at tag `{tag}` is vendored but missing from Nargo.lock. Run `nargo vendor` to vendor it again")]
+    UnlockedVendoredDependency { git: String, tag: String },
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -90,4 +147,25 @@ pub enum SemverError {
     CouldNotParseRequiredVersion { package_name: String, error: String },
     #[error("Could not parse the package version for package {package_name} in Nargo.toml. Error: {error}")]
     CouldNotParsePackageVersion { package_name: String, error: String },
+    #[error("Could not parse the version requirement on dependency {dependency_name} of package {package_name} in Nargo.toml. Error: {error}")]
+    CouldNotParseDependencyVersion {
+        package_name: CrateName,
+        dependency_name: CrateName,
+        error: String,
+    },
+    #[error("Package {package_name} requires version {required_version} of dependency {dependency_name} but version {version_found} was resolved")]
+    IncompatibleDependencyVersion {
+        package_name: CrateName,
+        dependency_name: CrateName,
+        required_version: String,
+        version_found: String,
+    },
+    #[error("Package {package_name} requires version {required_version} of dependency {dependency_name} but it doesn't declare a version in its Nargo.toml")]
+    MissingDependencyVersion {
+        package_name: CrateName,
+        dependency_name: CrateName,
+        required_version: String,
+    },
+    #[error("Dependency {dependency_name} is resolved to incompatible versions: {versions}.\n Depend on the same tag of {dependency_name} everywhere, or on semver compatible versions of it")]
+    ConflictingDependencyVersions { dependency_name: CrateName, versions: String },
 }
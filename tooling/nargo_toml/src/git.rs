@@ -1,4 +1,5 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
 /// Creates a unique folder name for a GitHub repo
 /// by using its URL and tag
@@ -9,14 +10,16 @@ fn resolve_folder_name(base: &url::Url, tag: &str) -> String {
     folder_name
 }
 
-fn nargo_crates() -> PathBuf {
+/// Returns the directory git dependencies are cloned into.
+pub(crate) fn nargo_crates() -> PathBuf {
     dirs::home_dir().unwrap().join("nargo")
 }
 
-fn git_dep_location(base: &url::Url, tag: &str) -> PathBuf {
-    let folder_name = resolve_folder_name(base, tag);
-
-    nargo_crates().join(folder_name)
+/// Returns where the git repo at `url` and `tag` is stored within `dir`, which is either the
+/// directory git dependencies are cloned into or a `vendor` directory.
+pub(crate) fn git_dep_location(dir: &Path, url: &str, tag: &str) -> Result<PathBuf, String> {
+    let base = url::Url::parse(url).map_err(|err| err.to_string())?;
+    Ok(dir.join(resolve_folder_name(&base, tag)))
 }
 
 /// XXX: I'd prefer to use a GitHub library however, there
@@ -25,19 +28,38 @@ fn git_dep_location(base: &url::Url, tag: &str) -> PathBuf {
 /// github-rs looks promising, however it seems to require an API token
 ///
 /// One advantage of using "git clone" is that there is effectively no rate limit
-pub(crate) fn clone_git_repo(url: &str, tag: &str) -> Result<PathBuf, String> {
-    use std::process::Command;
-
+///
+/// The repo is cloned into `checkouts_dir`. A previously cloned repo is reused unless `refresh` is
+/// set, in which case it is cloned again to pick up any change to the commit `tag` points to. When
+/// `offline` is set, any attempt to clone the repo is an error.
+pub(crate) fn clone_git_repo(
+    checkouts_dir: &Path,
+    url: &str,
+    tag: &str,
+    refresh: bool,
+    offline: bool,
+) -> Result<PathBuf, String> {
     let base = match url::Url::parse(url) {
         Ok(base) => base,
         Err(err) => return Err(err.to_string()),
     };
 
-    let loc = git_dep_location(&base, tag);
-    if loc.exists() {
+    let loc = checkouts_dir.join(resolve_folder_name(&base, tag));
+    if loc.exists() && !refresh {
         return Ok(loc);
     }
 
+    if offline {
+        return Err(format!(
+            "Cannot fetch {url} at tag `{tag}` while offline. Run `nargo vendor` while online to make it available offline"
+        ));
+    }
+
+    if loc.exists() {
+        std::fs::remove_dir_all(&loc)
+            .map_err(|err| format!("Failed to remove {}: {err}", loc.display()))?;
+    }
+
     Command::new("git")
         .arg("-c")
         .arg("advice.detachedHead=false")
@@ -53,3 +75,19 @@ pub(crate) fn clone_git_repo(url: &str, tag: &str) -> Result<PathBuf, String> {
 
     Ok(loc)
 }
+
+/// Returns the hash of the commit checked out in the git repo at `dir`.
+pub(crate) fn head_revision(dir: &Path) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .arg("rev-parse")
+        .arg("HEAD")
+        .output()
+        .map_err(|err| format!("git rev-parse command failed to start: {err}"))?;
+
+    if !output.status.success() {
+        return Err(format!("Could not read the checked out commit of {}", dir.display()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
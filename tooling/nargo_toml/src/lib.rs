@@ -4,7 +4,7 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies, unused_extern_crates))]
 
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     path::{Component, Path, PathBuf},
 };
 
@@ -14,16 +14,19 @@ use nargo::{
     package::{Dependency, Package, PackageType},
     workspace::Workspace,
 };
-use noirc_driver::parse_expression_width;
+use noirc_driver::{parse_expression_width, CompileProfile, ProfileOptions, BUILTIN_PROFILES};
 use noirc_frontend::graph::CrateName;
 use serde::Deserialize;
 
+mod edit;
 mod errors;
 mod git;
+mod lock;
 mod semver;
+mod vendor;
 
 pub use errors::ManifestError;
-use git::clone_git_repo;
+use lock::{DependencyLock, LockMode};
 
 /// Searches for a `Nargo.toml` file in the current directory and all parent directories.
 /// For example, if the current directory is `/workspace/package/src`, then this function
@@ -118,13 +121,29 @@ struct PackageConfig {
     package: PackageMetadata,
     #[serde(default)]
     dependencies: BTreeMap<String, DependencyConfig>,
+    #[serde(default, rename = "dev-dependencies")]
+    dev_dependencies: BTreeMap<String, DependencyConfig>,
+    #[serde(default)]
+    lints: BTreeMap<String, String>,
+    #[serde(default)]
+    features: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    profile: BTreeMap<String, ProfileOptions>,
 }
 
 impl PackageConfig {
+    /// Resolves this config into a [Package]. Its dev-dependencies are only resolved if
+    /// `is_member` is set, as they are never needed when the package is itself a dependency.
+    ///
+    /// The `features` selected for a member may include features it doesn't declare, as they
+    /// apply to every member of the workspace, but a dependency must declare all of them.
     fn resolve_to_package(
         &self,
         root_dir: &Path,
         processed: &mut Vec<String>,
+        lock: &mut DependencyLock,
+        is_member: bool,
+        features: &FeatureSelection,
     ) -> Result<Package, ManifestError> {
         let name: CrateName = if let Some(name) = &self.package.name {
             name.parse().map_err(|_| ManifestError::InvalidPackageName {
@@ -141,11 +160,31 @@ impl PackageConfig {
                 toml: root_dir.join("Nargo.toml"),
                 name: name.into(),
             })?;
-            let resolved_dep = dep_config.resolve_to_dependency(root_dir, processed)?;
+            let resolved_dep = dep_config.resolve_to_dependency(root_dir, processed, lock)?;
 
             dependencies.insert(name, resolved_dep);
         }
 
+        let mut dev_dependencies: BTreeMap<CrateName, Dependency> = BTreeMap::new();
+        if is_member {
+            for (name, dep_config) in self.dev_dependencies.iter() {
+                let name: CrateName =
+                    name.parse().map_err(|_| ManifestError::InvalidDependencyName {
+                        toml: root_dir.join("Nargo.toml"),
+                        name: name.into(),
+                    })?;
+                if dependencies.contains_key(&name) {
+                    return Err(ManifestError::DuplicateDevDependency {
+                        toml: root_dir.join("Nargo.toml"),
+                        name,
+                    });
+                }
+                let resolved_dep = dep_config.resolve_to_dependency(root_dir, processed, lock)?;
+
+                dev_dependencies.insert(name, resolved_dep);
+            }
+        }
+
         let package_type = match self.package.package_type.as_deref() {
             Some("lib") => PackageType::Library,
             Some("bin") => PackageType::Binary,
@@ -210,6 +249,46 @@ impl PackageConfig {
             })
             .map_or(Ok(None), |res| res.map(Some))?;
 
+        let mut lints = Vec::new();
+        for (name, level) in &self.lints {
+            let lint = name.parse().map_err(|_| ManifestError::UnknownLint {
+                toml: root_dir.join("Nargo.toml"),
+                name: name.clone(),
+            })?;
+            let level = level.parse().map_err(|_| ManifestError::InvalidLintLevel {
+                toml: root_dir.join("Nargo.toml"),
+                name: name.clone(),
+                level: level.clone(),
+            })?;
+            lints.push((lint, level));
+        }
+
+        for implied_features in self.features.values() {
+            for implied_feature in implied_features {
+                if !self.features.contains_key(implied_feature) {
+                    return Err(ManifestError::UnknownFeature {
+                        toml: root_dir.join("Nargo.toml"),
+                        name: implied_feature.clone(),
+                    });
+                }
+            }
+        }
+
+        let features = if is_member {
+            let selected = features.features.iter();
+            FeatureSelection {
+                features: selected
+                    .filter(|name| self.features.contains_key(*name))
+                    .cloned()
+                    .collect(),
+                default_features: features.default_features,
+            }
+        } else {
+            features.clone()
+        };
+        let enabled_features =
+            resolve_enabled_features(&self.features, &features, &root_dir.join("Nargo.toml"))?;
+
         Ok(Package {
             version: self.package.version.clone(),
             compiler_required_version: self.package.compiler_version.clone(),
@@ -218,11 +297,42 @@ impl PackageConfig {
             package_type,
             name,
             dependencies,
+            dev_dependencies,
             expression_width,
+            lints,
+            features: self.features.clone(),
+            enabled_features,
         })
     }
 }
 
+/// The name of the feature enabled unless default features are turned off.
+const DEFAULT_FEATURE: &str = "default";
+
+/// Returns the features enabled by `selection`, following the features each of them enables
+/// in turn in the `declared` features of a package.
+fn resolve_enabled_features(
+    declared: &BTreeMap<String, Vec<String>>,
+    selection: &FeatureSelection,
+    toml: &Path,
+) -> Result<BTreeSet<String>, ManifestError> {
+    let mut pending = selection.features.clone();
+    if selection.default_features && declared.contains_key(DEFAULT_FEATURE) {
+        pending.push(DEFAULT_FEATURE.to_string());
+    }
+
+    let mut enabled = BTreeSet::new();
+    while let Some(feature) = pending.pop() {
+        let Some(implied_features) = declared.get(&feature) else {
+            return Err(ManifestError::UnknownFeature { toml: toml.to_path_buf(), name: feature });
+        };
+        if enabled.insert(feature) {
+            pending.extend(implied_features.iter().cloned());
+        }
+    }
+    Ok(enabled)
+}
+
 /// Contains all the information about a package, as loaded from a `Nargo.toml`.
 #[derive(Debug, Deserialize, Clone)]
 #[serde(untagged)]
@@ -236,9 +346,32 @@ enum Config {
     Workspace {
         #[serde(alias = "workspace")]
         workspace_config: WorkspaceConfig,
+        #[serde(default)]
+        profile: BTreeMap<String, ProfileOptions>,
     },
 }
 
+/// Resolves the profiles declared in the `Nargo.toml` at `toml`, along with the builtin
+/// profiles which it doesn't declare.
+fn resolve_profiles(
+    toml: &Path,
+    profiles: &BTreeMap<String, ProfileOptions>,
+) -> Result<BTreeMap<String, CompileProfile>, ManifestError> {
+    let mut resolved: BTreeMap<_, _> =
+        BUILTIN_PROFILES.iter().map(|name| (name.to_string(), CompileProfile::new(name))).collect();
+    for (name, options) in profiles {
+        if options.deny_warnings == Some(true) && options.silence_warnings == Some(true) {
+            return Err(ManifestError::ConflictingProfileOptions {
+                toml: toml.to_path_buf(),
+                name: name.to_string(),
+            });
+        }
+        resolved
+            .insert(name.clone(), CompileProfile { name: name.clone(), options: options.clone() });
+    }
+    Ok(resolved)
+}
+
 impl TryFrom<String> for Config {
     type Error = toml::de::Error;
 
@@ -295,8 +428,28 @@ struct PackageMetadata {
 /// Enum representing the different types of ways to
 /// supply a source for the dependency
 enum DependencyConfig {
-    Github { git: String, tag: String, directory: Option<String> },
-    Path { path: String },
+    Github {
+        git: String,
+        tag: String,
+        directory: Option<String>,
+        version: Option<String>,
+        #[serde(default)]
+        features: Vec<String>,
+        #[serde(default = "default_features_enabled", rename = "default-features")]
+        default_features: bool,
+    },
+    Path {
+        path: String,
+        version: Option<String>,
+        #[serde(default)]
+        features: Vec<String>,
+        #[serde(default = "default_features_enabled", rename = "default-features")]
+        default_features: bool,
+    },
+}
+
+fn default_features_enabled() -> bool {
+    true
 }
 
 impl DependencyConfig {
@@ -304,10 +457,11 @@ impl DependencyConfig {
         &self,
         pkg_root: &Path,
         processed: &mut Vec<String>,
+        lock: &mut DependencyLock,
     ) -> Result<Dependency, ManifestError> {
         let dep = match self {
-            Self::Github { git, tag, directory } => {
-                let dir_path = clone_git_repo(git, tag).map_err(ManifestError::GitError)?;
+            Self::Github { git, tag, directory, version, features, default_features } => {
+                let dir_path = lock.resolve_git_dependency(git, tag)?;
                 let project_path = if let Some(directory) = directory {
                     let internal_path = dir_path.join(directory).normalize();
                     if !internal_path.starts_with(&dir_path) {
@@ -320,15 +474,31 @@ impl DependencyConfig {
                 } else {
                     dir_path
                 };
+                lock.check_git_package(git, tag, directory.as_deref(), &project_path)?;
                 let toml_path = project_path.join("Nargo.toml");
-                let package = resolve_package_from_toml(&toml_path, processed)?;
-                Dependency::Remote { package }
+                let features = FeatureSelection {
+                    features: features.clone(),
+                    default_features: *default_features,
+                };
+                let package =
+                    resolve_package_from_toml(&toml_path, processed, lock, false, &features)?;
+                Dependency::Remote {
+                    package,
+                    git: git.clone(),
+                    tag: tag.clone(),
+                    version_req: version.clone(),
+                }
             }
-            Self::Path { path } => {
+            Self::Path { path, version, features, default_features } => {
                 let dir_path = pkg_root.join(path);
                 let toml_path = dir_path.join("Nargo.toml");
-                let package = resolve_package_from_toml(&toml_path, processed)?;
-                Dependency::Local { package }
+                let features = FeatureSelection {
+                    features: features.clone(),
+                    default_features: *default_features,
+                };
+                let package =
+                    resolve_package_from_toml(&toml_path, processed, lock, false, &features)?;
+                Dependency::Local { package, version_req: version.clone() }
             }
         };
 
@@ -342,14 +512,60 @@ impl DependencyConfig {
     }
 }
 
+/// Where a dependency added by [add_dependency] is fetched from.
+#[derive(Debug, Clone)]
+pub enum DependencySource {
+    /// A `tag` of a git repository, optionally in one of its subdirectories.
+    Git { git: String, tag: String, directory: Option<String> },
+    /// A directory, relative to the package depending on it.
+    Path { path: String },
+}
+
+impl From<DependencySource> for DependencyConfig {
+    fn from(source: DependencySource) -> Self {
+        match source {
+            DependencySource::Git { git, tag, directory } => DependencyConfig::Github {
+                git,
+                tag,
+                directory,
+                version: None,
+                features: Vec::new(),
+                default_features: true,
+            },
+            DependencySource::Path { path } => DependencyConfig::Path {
+                path,
+                version: None,
+                features: Vec::new(),
+                default_features: true,
+            },
+        }
+    }
+}
+
 fn toml_to_workspace(
     nargo_toml: NargoToml,
     package_selection: PackageSelection,
+    feature_selection: &FeatureSelection,
+    lock: &mut DependencyLock,
 ) -> Result<Workspace, ManifestError> {
+    // Only the profiles of the root `Nargo.toml` apply, as they are shared by all members
+    let root_toml = nargo_toml.root_dir.join("Nargo.toml");
+    let profiles = match &nargo_toml.config {
+        Config::Package { package_config } => &package_config.profile,
+        Config::Workspace { profile, .. } => profile,
+    };
+    let profiles = resolve_profiles(&root_toml, profiles)?;
+
     let mut resolved = Vec::new();
-    let workspace = match nargo_toml.config {
+    let mut workspace = match nargo_toml.config {
         Config::Package { package_config } => {
-            let member = package_config.resolve_to_package(&nargo_toml.root_dir, &mut resolved)?;
+            let member = package_config.resolve_to_package(
+                &nargo_toml.root_dir,
+                &mut resolved,
+                lock,
+                true,
+                feature_selection,
+            )?;
             match &package_selection {
                 PackageSelection::Selected(selected_name) if selected_name != &member.name => {
                     return Err(ManifestError::MissingSelectedPackage(member.name))
@@ -359,16 +575,24 @@ fn toml_to_workspace(
                     selected_package_index: Some(0),
                     members: vec![member],
                     is_assumed: false,
+                    profiles,
+                    comptime_inputs: Default::default(),
                 },
             }
         }
-        Config::Workspace { workspace_config } => {
+        Config::Workspace { workspace_config, .. } => {
             let mut members = Vec::new();
             let mut selected_package_index = None;
             for (index, member_path) in workspace_config.members.into_iter().enumerate() {
                 let package_root_dir = nargo_toml.root_dir.join(&member_path);
                 let package_toml_path = package_root_dir.join("Nargo.toml");
-                let member = resolve_package_from_toml(&package_toml_path, &mut resolved)?;
+                let member = resolve_package_from_toml(
+                    &package_toml_path,
+                    &mut resolved,
+                    lock,
+                    true,
+                    feature_selection,
+                )?;
 
                 match &package_selection {
                     PackageSelection::Selected(selected_name) => {
@@ -411,10 +635,20 @@ fn toml_to_workspace(
                 members,
                 selected_package_index,
                 is_assumed: false,
+                profiles,
+                comptime_inputs: Default::default(),
             }
         }
     };
 
+    for feature in &feature_selection.features {
+        if !workspace.members.iter().any(|member| member.features.contains_key(feature)) {
+            return Err(ManifestError::UnknownSelectedFeature(feature.clone()));
+        }
+    }
+
+    semver::resolve_dependency_versions(&mut workspace).map_err(ManifestError::SemverError)?;
+
     Ok(workspace)
 }
 
@@ -433,6 +667,9 @@ fn read_toml(toml_path: &Path) -> Result<NargoToml, ManifestError> {
 fn resolve_package_from_toml(
     toml_path: &Path,
     processed: &mut Vec<String>,
+    lock: &mut DependencyLock,
+    is_member: bool,
+    features: &FeatureSelection,
 ) -> Result<Package, ManifestError> {
     // Checks for cyclic dependencies
     let str_path = toml_path.to_str().expect("ICE - path is empty");
@@ -456,9 +693,13 @@ fn resolve_package_from_toml(
     let nargo_toml = read_toml(toml_path)?;
 
     let result = match nargo_toml.config {
-        Config::Package { package_config } => {
-            package_config.resolve_to_package(&nargo_toml.root_dir, processed)
-        }
+        Config::Package { package_config } => package_config.resolve_to_package(
+            &nargo_toml.root_dir,
+            processed,
+            lock,
+            is_member,
+            features,
+        ),
         Config::Workspace { .. } => {
             Err(ManifestError::UnexpectedWorkspace(toml_path.to_path_buf()))
         }
@@ -469,27 +710,198 @@ fn resolve_package_from_toml(
     result
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PackageSelection {
     Selected(CrateName),
     DefaultOrAll,
     All,
 }
 
+/// The features to enable in the members of a workspace.
+///
+/// These apply to every member that declares them, but each of them must be declared
+/// by at least one member.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureSelection {
+    /// Features to enable besides the default ones.
+    pub features: Vec<String>,
+    /// Whether the `default` feature of each member is enabled.
+    pub default_features: bool,
+}
+
+impl Default for FeatureSelection {
+    fn default() -> Self {
+        Self { features: Vec::new(), default_features: true }
+    }
+}
+
 /// Resolves a Nargo.toml file into a `Workspace` struct as defined by our `nargo` core.
+///
+/// Git dependencies are checked against the `Nargo.lock` at the root of the workspace, which is
+/// never changed: dependencies missing from it are an error until `nargo update` locks them.
+/// Vendored git dependencies are used instead of fetching them, and if `offline` is set any
+/// attempt to fetch a git dependency is an error.
+///
+/// The members are compiled with the features in `feature_selection` enabled.
 pub fn resolve_workspace_from_toml(
     toml_path: &Path,
     package_selection: PackageSelection,
     current_compiler_version: Option<String>,
+    offline: bool,
+    feature_selection: FeatureSelection,
 ) -> Result<Workspace, ManifestError> {
     let nargo_toml = read_toml(toml_path)?;
-    let workspace = toml_to_workspace(nargo_toml, package_selection)?;
+    let mut lock = DependencyLock::load(&nargo_toml.root_dir, LockMode::Locked, offline)?;
+    let workspace =
+        toml_to_workspace(nargo_toml, package_selection, &feature_selection, &mut lock)?;
     if let Some(current_compiler_version) = current_compiler_version {
         semver::semver_check_workspace(&workspace, current_compiler_version)?;
     }
     Ok(workspace)
 }
 
+/// Fetches every git dependency of the workspace in `toml_path` again and rewrites its `Nargo.lock`
+/// with the commits they now resolve to.
+pub fn update_lockfile(toml_path: &Path, offline: bool) -> Result<Workspace, ManifestError> {
+    let nargo_toml = read_toml(toml_path)?;
+    let mut lock = DependencyLock::load(&nargo_toml.root_dir, LockMode::Update, offline)?;
+    let workspace = toml_to_workspace(
+        nargo_toml,
+        PackageSelection::All,
+        &FeatureSelection::default(),
+        &mut lock,
+    )?;
+    lock.save()?;
+    Ok(workspace)
+}
+
+/// Copies every git dependency of the workspace in `toml_path`, including transitive ones, into
+/// the `vendor` directory at its root. Vendored dependencies are then used instead of fetching them.
+/// Dependencies missing from the `Nargo.lock` of the workspace are added to it.
+///
+/// Returns the path of the `vendor` directory.
+pub fn vendor_dependencies(toml_path: &Path, offline: bool) -> Result<PathBuf, ManifestError> {
+    let nargo_toml = read_toml(toml_path)?;
+    let vendor_dir = nargo_toml.root_dir.join(vendor::VENDOR_DIR);
+    let mut lock = DependencyLock::load(&nargo_toml.root_dir, LockMode::AddMissing, offline)?;
+    lock.ignore_vendored();
+    toml_to_workspace(nargo_toml, PackageSelection::All, &FeatureSelection::default(), &mut lock)?;
+    vendor::vendor_git_dependencies(&vendor_dir, lock.resolved_git_dependencies())?;
+    lock.save()?;
+    Ok(vendor_dir)
+}
+
+/// Returns the `Nargo.toml` of the package whose dependencies are edited by [add_dependency] and
+/// [remove_dependency].
+///
+/// If a `package` is given, it is looked up in the workspace at `workspace_root`. Otherwise this is
+/// the package `current_path` is in, or the `default-member` of the workspace it is in.
+pub fn find_package_to_edit(
+    workspace_root: &Path,
+    current_path: &Path,
+    package: Option<&CrateName>,
+) -> Result<PathBuf, ManifestError> {
+    let toml_path = match package {
+        Some(_) => get_package_manifest(workspace_root)?,
+        None => find_file_manifest(current_path)
+            .ok_or_else(|| ManifestError::MissingFile(current_path.to_path_buf()))?,
+    };
+    let nargo_toml = read_toml(&toml_path)?;
+
+    match nargo_toml.config {
+        Config::Package { package_config } => match package {
+            Some(selected_name)
+                if package_config.package.name.as_deref() != Some(&selected_name.to_string()) =>
+            {
+                Err(ManifestError::MissingSelectedPackage(selected_name.clone()))
+            }
+            _ => Ok(toml_path),
+        },
+        Config::Workspace { workspace_config, .. } => match package {
+            Some(selected_name) => {
+                for member_path in workspace_config.members {
+                    let member_toml_path = nargo_toml.root_dir.join(member_path).join("Nargo.toml");
+                    if let Config::Package { package_config } = read_toml(&member_toml_path)?.config
+                    {
+                        if package_config.package.name.as_deref()
+                            == Some(&selected_name.to_string())
+                        {
+                            return Ok(member_toml_path);
+                        }
+                    }
+                }
+                Err(ManifestError::MissingSelectedPackage(selected_name.clone()))
+            }
+            None => match workspace_config.default_member {
+                Some(default_member) => {
+                    Ok(nargo_toml.root_dir.join(default_member).join("Nargo.toml"))
+                }
+                None => Err(ManifestError::UnselectedWorkspaceMember(toml_path)),
+            },
+        },
+    }
+}
+
+/// Adds the dependency `name` to the package whose `Nargo.toml` is at `toml_path`, in its
+/// `[dev-dependencies]` if `dev` is set or its `[dependencies]` otherwise, after checking that the
+/// dependency resolves. Comments and formatting of the manifest are kept.
+///
+/// Git dependencies are fetched and locked in the `Nargo.lock` at `workspace_root`.
+pub fn add_dependency(
+    toml_path: &Path,
+    workspace_root: &Path,
+    name: &CrateName,
+    source: DependencySource,
+    dev: bool,
+    offline: bool,
+) -> Result<(), ManifestError> {
+    let nargo_toml = read_toml(toml_path)?;
+    let Config::Package { package_config } = nargo_toml.config else {
+        return Err(ManifestError::UnexpectedWorkspace(toml_path.to_path_buf()));
+    };
+
+    let (table, other_dependencies) = if dev {
+        (edit::DEV_DEPENDENCIES_TABLE, &package_config.dependencies)
+    } else {
+        (edit::DEPENDENCIES_TABLE, &package_config.dev_dependencies)
+    };
+    if other_dependencies.contains_key(&name.to_string()) {
+        return Err(ManifestError::DuplicateDevDependency {
+            toml: toml_path.to_path_buf(),
+            name: name.clone(),
+        });
+    }
+
+    let mut lock = DependencyLock::load(workspace_root, LockMode::AddMissing, offline)?;
+    DependencyConfig::from(source.clone()).resolve_to_dependency(
+        &nargo_toml.root_dir,
+        &mut Vec::new(),
+        &mut lock,
+    )?;
+
+    edit::insert_dependency(toml_path, table, &name.to_string(), &source)?;
+    lock.save()
+}
+
+/// Removes the dependency `name` from the package whose `Nargo.toml` is at `toml_path`, keeping
+/// the comments and formatting of the rest of the manifest.
+pub fn remove_dependency(toml_path: &Path, name: &CrateName) -> Result<(), ManifestError> {
+    if edit::remove_dependency(toml_path, &name.to_string())? {
+        Ok(())
+    } else {
+        Err(ManifestError::MissingDependency { toml: toml_path.to_path_buf(), name: name.clone() })
+    }
+}
+
+/// Writes a package with an empty `src/lib.nr` and the given `Nargo.toml` in `dir_name` in `dir`.
+#[cfg(test)]
+fn write_package(dir: &Path, dir_name: &str, manifest: &str) {
+    let package_dir = dir.join(dir_name);
+    std::fs::create_dir_all(package_dir.join("src")).unwrap();
+    std::fs::write(package_dir.join("src").join("lib.nr"), "").unwrap();
+    std::fs::write(package_dir.join("Nargo.toml"), manifest).unwrap();
+}
+
 #[test]
 fn parse_standard_toml() {
     let src = r#"
@@ -559,3 +971,463 @@ fn parse_package_expression_width_toml() {
     assert!(Config::try_from(String::from(src)).is_ok());
     assert!(Config::try_from(src).is_ok());
 }
+
+#[test]
+fn parse_package_lints_toml() {
+    let src = r#"
+    [package]
+    name = "test"
+    type = "bin"
+    authors = [""]
+
+    [lints]
+    unused_variables = "deny"
+    dead_code = "allow"
+    "#;
+
+    let Config::Package { package_config } = Config::try_from(src).unwrap() else {
+        panic!("Expected a package config");
+    };
+    assert_eq!(package_config.lints["unused_variables"], "deny");
+    assert_eq!(package_config.lints["dead_code"], "allow");
+}
+
+#[test]
+fn resolves_package_lints() {
+    use noirc_frontend::hir::lint_levels::{Lint, LintLevel};
+
+    let dir = tempfile::tempdir().unwrap();
+    let resolve = |lints: &str| {
+        let manifest = format!("[package]\nname = \"test\"\ntype = \"lib\"\n\n[lints]\n{lints}");
+        write_package(dir.path(), "test", &manifest);
+        let toml_path = dir.path().join("test").join("Nargo.toml");
+        let selection = FeatureSelection::default();
+        resolve_workspace_from_toml(&toml_path, PackageSelection::All, None, false, selection)
+    };
+
+    let workspace = resolve("unused_variables = \"deny\"\ndead_code = \"allow\"").unwrap();
+    assert_eq!(
+        workspace.members[0].lints,
+        vec![(Lint::DeadCode, LintLevel::Allow), (Lint::UnusedVariables, LintLevel::Deny)]
+    );
+
+    assert!(matches!(
+        resolve("unused_apples = \"deny\""),
+        Err(ManifestError::UnknownLint { name, .. }) if name == "unused_apples"
+    ));
+    assert!(matches!(
+        resolve("dead_code = \"forbid\""),
+        Err(ManifestError::InvalidLintLevel { name, level, .. })
+            if name == "dead_code" && level == "forbid"
+    ));
+}
+
+#[test]
+fn parse_package_dev_dependencies_toml() {
+    let src = r#"
+    [package]
+    name = "test"
+    type = "lib"
+    authors = [""]
+
+    [dependencies]
+    hello = { path = "./hello" }
+
+    [dev-dependencies]
+    mocks = { path = "./mocks" }
+    "#;
+
+    let Config::Package { package_config } = Config::try_from(src).unwrap() else {
+        panic!("Expected a package config");
+    };
+    assert!(package_config.dependencies.contains_key("hello"));
+    assert!(package_config.dev_dependencies.contains_key("mocks"));
+    assert!(!package_config.dependencies.contains_key("mocks"));
+}
+
+#[test]
+fn resolves_dev_dependencies_of_members_only() {
+    let dir = tempfile::tempdir().unwrap();
+    write_package(
+        dir.path(),
+        "member",
+        r#"
+        [package]
+        name = "member"
+        type = "lib"
+
+        [dependencies]
+        dep = { path = "../dep" }
+
+        [dev-dependencies]
+        mocks = { path = "../mocks" }
+        "#,
+    );
+    // The dev-dependency of `dep` doesn't exist, which is fine as it is never resolved.
+    write_package(
+        dir.path(),
+        "dep",
+        r#"
+        [package]
+        name = "dep"
+        type = "lib"
+
+        [dev-dependencies]
+        missing = { path = "../missing" }
+        "#,
+    );
+    write_package(
+        dir.path(),
+        "mocks",
+        r#"
+        [package]
+        name = "mocks"
+        type = "lib"
+        "#,
+    );
+
+    let toml_path = dir.path().join("member").join("Nargo.toml");
+    let workspace = resolve_workspace_from_toml(
+        &toml_path,
+        PackageSelection::All,
+        None,
+        false,
+        FeatureSelection::default(),
+    )
+    .unwrap();
+    let member = &workspace.members[0];
+    let dep_name: CrateName = "dep".parse().unwrap();
+    let mocks_name: CrateName = "mocks".parse().unwrap();
+    assert!(member.dependencies.contains_key(&dep_name));
+    assert!(!member.dependencies.contains_key(&mocks_name));
+    assert!(member.dev_dependencies.contains_key(&mocks_name));
+
+    let (Dependency::Local { package: dep, .. } | Dependency::Remote { package: dep, .. }) =
+        &member.dependencies[&dep_name];
+    assert!(dep.dev_dependencies.is_empty());
+}
+
+#[test]
+fn parse_package_features_toml() {
+    let src = r#"
+    [package]
+    name = "test"
+    type = "lib"
+    authors = [""]
+
+    [features]
+    default = ["bn254"]
+    bn254 = []
+    debug = ["bn254"]
+
+    [dependencies]
+    hello = { path = "./hello", features = ["fast"], default-features = false }
+    world = { path = "./world" }
+    "#;
+
+    let Config::Package { package_config } = Config::try_from(src).unwrap() else {
+        panic!("Expected a package config");
+    };
+    assert_eq!(package_config.features["default"], vec!["bn254".to_string()]);
+    assert_eq!(package_config.features["debug"], vec!["bn254".to_string()]);
+
+    let DependencyConfig::Path { features, default_features, .. } =
+        &package_config.dependencies["hello"]
+    else {
+        panic!("Expected a path dependency");
+    };
+    assert_eq!(features, &vec!["fast".to_string()]);
+    assert!(!default_features);
+
+    let DependencyConfig::Path { features, default_features, .. } =
+        &package_config.dependencies["world"]
+    else {
+        panic!("Expected a path dependency");
+    };
+    assert!(features.is_empty());
+    assert!(default_features);
+}
+
+#[test]
+fn parse_package_profiles_toml() {
+    let src = r#"
+    [package]
+    name = "test"
+    type = "bin"
+    authors = [""]
+
+    [profile.release]
+    expression-width = "4"
+    inliner-aggressiveness = 0
+
+    [profile.ci]
+    deny-warnings = true
+    force-brillig = true
+    "#;
+
+    let Config::Package { package_config } = Config::try_from(src).unwrap() else {
+        panic!("Expected a package config");
+    };
+    let profiles = resolve_profiles(Path::new("Nargo.toml"), &package_config.profile).unwrap();
+
+    assert_eq!(profiles["dev"], CompileProfile::new("dev"));
+    let release = &profiles["release"];
+    assert_eq!(release.options.expression_width, parse_expression_width("4").ok());
+    assert_eq!(release.options.inliner_aggressiveness, Some(0));
+    assert_eq!(release.options.force_brillig, None);
+    assert_eq!(profiles["ci"].options.deny_warnings, Some(true));
+    assert_eq!(profiles["ci"].options.force_brillig, Some(true));
+
+    let src = r#"
+    [workspace]
+    members = ["a"]
+
+    [profile.release]
+    skip-underconstrained-check = true
+    "#;
+    let Config::Workspace { profile, .. } = Config::try_from(src).unwrap() else {
+        panic!("Expected a workspace config");
+    };
+    assert_eq!(profile["release"].skip_underconstrained_check, Some(true));
+
+    // Unknown options are rejected rather than silently ignored
+    let src = r#"
+    [package]
+    name = "test"
+    type = "bin"
+    authors = [""]
+
+    [profile.release]
+    optimize = true
+    "#;
+    assert!(Config::try_from(src).is_err());
+}
+
+#[test]
+fn resolves_enabled_features() {
+    let declared: BTreeMap<String, Vec<String>> =
+        [("default", vec!["a"]), ("a", vec!["b"]), ("b", vec![]), ("c", vec!["a"]), ("d", vec![])]
+            .into_iter()
+            .map(|(name, implied)| {
+                (name.to_string(), implied.into_iter().map(String::from).collect())
+            })
+            .collect();
+    let toml = Path::new("Nargo.toml");
+    let enabled = |features: &[&str], default_features| {
+        let features = features.iter().map(ToString::to_string).collect();
+        let selection = FeatureSelection { features, default_features };
+        let enabled = resolve_enabled_features(&declared, &selection, toml).unwrap();
+        enabled.into_iter().collect::<Vec<_>>()
+    };
+
+    assert_eq!(enabled(&[], true), vec!["a", "b", "default"]);
+    assert_eq!(enabled(&[], false), Vec::<String>::new());
+    assert_eq!(enabled(&["c"], false), vec!["a", "b", "c"]);
+    assert_eq!(enabled(&["d"], true), vec!["a", "b", "d", "default"]);
+
+    let selection = FeatureSelection { features: vec!["e".to_string()], default_features: true };
+    assert!(matches!(
+        resolve_enabled_features(&declared, &selection, toml),
+        Err(ManifestError::UnknownFeature { name, .. }) if name == "e"
+    ));
+}
+
+#[test]
+fn resolves_features_of_members_and_dependencies() {
+    let dir = tempfile::tempdir().unwrap();
+    write_package(
+        dir.path(),
+        "member",
+        r#"
+        [package]
+        name = "member"
+        type = "lib"
+
+        [features]
+        default = ["std"]
+        std = []
+        extra = []
+
+        [dependencies]
+        dep = { path = "../dep", features = ["fast"], default-features = false }
+        "#,
+    );
+    write_package(
+        dir.path(),
+        "dep",
+        r#"
+        [package]
+        name = "dep"
+        type = "lib"
+
+        [features]
+        default = ["slow"]
+        slow = []
+        fast = []
+        "#,
+    );
+
+    let toml_path = dir.path().join("member").join("Nargo.toml");
+    let resolve = |features: &[&str], default_features| {
+        let features = features.iter().map(ToString::to_string).collect();
+        let selection = FeatureSelection { features, default_features };
+        resolve_workspace_from_toml(&toml_path, PackageSelection::All, None, false, selection)
+    };
+
+    let workspace = resolve(&["extra"], false).unwrap();
+    let member = &workspace.members[0];
+    assert_eq!(member.enabled_features, BTreeSet::from(["extra".to_string()]));
+
+    let dep_name: CrateName = "dep".parse().unwrap();
+    let (Dependency::Local { package: dep, .. } | Dependency::Remote { package: dep, .. }) =
+        &member.dependencies[&dep_name];
+    assert_eq!(dep.enabled_features, BTreeSet::from(["fast".to_string()]));
+
+    let workspace = resolve(&[], true).unwrap();
+    let expected = BTreeSet::from(["default".to_string(), "std".to_string()]);
+    assert_eq!(workspace.members[0].enabled_features, expected);
+
+    assert!(matches!(
+        resolve(&["missing"], true),
+        Err(ManifestError::UnknownSelectedFeature(name)) if name == "missing"
+    ));
+}
+
+#[test]
+fn resolves_dependency_versions() {
+    let dir = tempfile::tempdir().unwrap();
+    let member_dir = dir.path().join("member");
+    let vendor_dir = member_dir.join(vendor::VENDOR_DIR);
+    let git = "https://github.com/noir-lang/shared";
+    let tags = [("v1.0.0", "1.0.0"), ("v1.2.0", "1.2.0"), ("v2.0.0", "2.0.0")];
+    // The tags are vendored, so that they are resolved without fetching them
+    for (tag, version) in tags {
+        let manifest =
+            format!("[package]\nname = \"shared\"\ntype = \"lib\"\nversion = \"{version}\"");
+        let vendored = git::git_dep_location(&vendor_dir, git, tag).unwrap();
+        let vendored = vendored.strip_prefix(&vendor_dir).unwrap();
+        write_package(&vendor_dir, vendored.to_str().unwrap(), &manifest);
+    }
+    lock::lock_vendored_dependencies(&member_dir, &tags.map(|(tag, _)| (git, tag)));
+
+    let write_packages = |member_shared: &str, other_shared: &str| {
+        write_package(
+            dir.path(),
+            "member",
+            &format!(
+                "[package]\nname = \"member\"\ntype = \"lib\"\n\n[dependencies]\nshared = {member_shared}\nother = {{ path = \"../other\" }}"
+            ),
+        );
+        write_package(
+            dir.path(),
+            "other",
+            &format!("[package]\nname = \"other\"\ntype = \"lib\"\n\n[dependencies]\nshared = {other_shared}"),
+        );
+    };
+
+    let toml_path = member_dir.join("Nargo.toml");
+    let resolve = || {
+        let selection = FeatureSelection::default();
+        resolve_workspace_from_toml(&toml_path, PackageSelection::All, None, true, selection)
+    };
+    let shared_name: CrateName = "shared".parse().unwrap();
+
+    // Compatible tags of the same repository are unified to the highest version
+    write_packages(
+        &format!(r#"{{ git = "{git}", tag = "v1.0.0", version = "1.0" }}"#),
+        &format!(r#"{{ git = "{git}", tag = "v1.2.0" }}"#),
+    );
+    let workspace = resolve().unwrap();
+    let shared = &workspace.members[0].dependencies[&shared_name];
+    assert_eq!(shared.package().version.as_deref(), Some("1.2.0"));
+    assert_eq!(shared.to_string(), format!("{git}#v1.2.0"));
+    assert_eq!(shared.version_req(), Some("1.0"));
+
+    // Version requirements must be satisfied by the unified version
+    write_packages(
+        &format!(r#"{{ git = "{git}", tag = "v1.0.0", version = "=1.0.0" }}"#),
+        &format!(r#"{{ git = "{git}", tag = "v1.2.0" }}"#),
+    );
+    assert!(matches!(
+        resolve(),
+        Err(ManifestError::SemverError(SemverError::IncompatibleDependencyVersion { version_found, .. }))
+            if version_found == "1.2.0"
+    ));
+
+    // Incompatible versions are an error
+    write_packages(
+        &format!(r#"{{ git = "{git}", tag = "v1.0.0" }}"#),
+        &format!(r#"{{ git = "{git}", tag = "v2.0.0" }}"#),
+    );
+    assert!(matches!(
+        resolve(),
+        Err(ManifestError::SemverError(SemverError::ConflictingDependencyVersions { dependency_name, .. }))
+            if dependency_name == shared_name
+    ));
+}
+
+#[test]
+fn adds_and_removes_dependencies() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("Nargo.toml"),
+        "[workspace]\nmembers = [\"member\", \"helper\"]\n",
+    )
+    .unwrap();
+    write_package(
+        dir.path(),
+        "member",
+        "# The member package\n[package]\nname = \"member\"\ntype = \"lib\"\n\n[dependencies]\n",
+    );
+    write_package(dir.path(), "helper", "[package]\nname = \"helper\"\ntype = \"lib\"\n");
+    write_package(dir.path(), "binary", "[package]\nname = \"binary\"\ntype = \"bin\"\n");
+
+    let member_dir = dir.path().join("member");
+    let toml_path = member_dir.join("Nargo.toml");
+    let helper_name: CrateName = "helper".parse().unwrap();
+    let member_name: CrateName = "member".parse().unwrap();
+
+    assert!(matches!(
+        find_package_to_edit(dir.path(), dir.path(), None),
+        Err(ManifestError::UnselectedWorkspaceMember(_))
+    ));
+    assert_eq!(
+        find_package_to_edit(dir.path(), dir.path(), Some(&member_name)).unwrap(),
+        toml_path
+    );
+    assert_eq!(find_package_to_edit(dir.path(), &member_dir.join("src"), None).unwrap(), toml_path);
+
+    let helper = DependencySource::Path { path: "../helper".to_string() };
+    add_dependency(&toml_path, dir.path(), &helper_name, helper, false, false).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&toml_path).unwrap(),
+        "# The member package\n[package]\nname = \"member\"\ntype = \"lib\"\n\n[dependencies]\nhelper = { path = \"../helper\" }\n",
+    );
+    let workspace = resolve_workspace_from_toml(
+        &dir.path().join("Nargo.toml"),
+        PackageSelection::Selected(member_name),
+        None,
+        false,
+        FeatureSelection::default(),
+    )
+    .unwrap();
+    assert!(workspace.members[0].dependencies.contains_key(&helper_name));
+
+    // Dependencies which don't resolve to a library are not added
+    let binary_name: CrateName = "binary".parse().unwrap();
+    let binary = DependencySource::Path { path: "../binary".to_string() };
+    assert!(matches!(
+        add_dependency(&toml_path, dir.path(), &binary_name, binary, true, false),
+        Err(ManifestError::BinaryDependency(_))
+    ));
+
+    remove_dependency(&toml_path, &helper_name).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&toml_path).unwrap(),
+        "# The member package\n[package]\nname = \"member\"\ntype = \"lib\"\n\n[dependencies]\n",
+    );
+    assert!(matches!(
+        remove_dependency(&toml_path, &helper_name),
+        Err(ManifestError::MissingDependency { .. })
+    ));
+}
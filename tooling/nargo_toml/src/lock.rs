@@ -0,0 +1,557 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    time::SystemTime,
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    errors::ManifestError,
+    git::{clone_git_repo, git_dep_location, head_revision, nargo_crates},
+    vendor::VENDOR_DIR,
+};
+
+/// Name of the lockfile written at the root of a workspace.
+pub(crate) const LOCKFILE_NAME: &str = "Nargo.lock";
+
+const LOCKFILE_VERSION: u32 = 1;
+
+const LOCKFILE_HEADER: &str =
+    "# This file is automatically generated by nargo.\n# It is not intended for manual editing.\n";
+
+/// Contents of a `Nargo.lock` file.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+struct Lockfile {
+    version: u32,
+    #[serde(default, rename = "package")]
+    packages: Vec<LockedDependency>,
+}
+
+impl Default for Lockfile {
+    fn default() -> Self {
+        Lockfile { version: LOCKFILE_VERSION, packages: Vec::new() }
+    }
+}
+
+/// A git dependency pinned to the commit and contents it resolved to.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+struct LockedDependency {
+    git: String,
+    tag: String,
+    /// Directory of the repository the package is in, if it isn't at its root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    directory: Option<String>,
+    /// Hash of the commit checked out for `tag`.
+    rev: String,
+    /// SHA-256 of every file in the package directory, excluding `.git` directories.
+    checksum: String,
+}
+
+/// How git dependencies which are missing from the lockfile are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LockMode {
+    /// Every git dependency must already be locked and the lockfile is never written.
+    Locked,
+    /// Git dependencies which are missing from the lockfile are added to it.
+    AddMissing,
+    /// Every git dependency is fetched again and the lockfile is rewritten.
+    Update,
+}
+
+/// A git dependency fetched, or found vendored, while resolving the workspace.
+struct Checkout {
+    dir: PathBuf,
+    /// Hash of the commit checked out, which is unknown for vendored dependencies.
+    rev: Option<String>,
+}
+
+/// Resolves git dependencies against the `Nargo.lock` of a workspace.
+///
+/// Dependencies which are already locked must resolve to the same commit and contents. Depending
+/// on the [LockMode], dependencies missing from the lockfile are either an error or added to it.
+///
+/// Dependencies which have been vendored into the workspace are used instead of being fetched.
+pub(crate) struct DependencyLock {
+    path: PathBuf,
+    lockfile: Lockfile,
+    vendor_dir: Option<PathBuf>,
+    checkouts_dir: PathBuf,
+    mode: LockMode,
+    offline: bool,
+    changed: bool,
+    /// Git dependencies resolved so far, keyed by url and tag.
+    resolved: BTreeMap<(String, String), Checkout>,
+}
+
+impl DependencyLock {
+    /// Reads the lockfile in `root_dir`, if there is one.
+    pub(crate) fn load(
+        root_dir: &Path,
+        mode: LockMode,
+        offline: bool,
+    ) -> Result<Self, ManifestError> {
+        let update = mode == LockMode::Update;
+        let path = root_dir.join(LOCKFILE_NAME);
+        let lockfile = if update || !path.exists() {
+            Lockfile::default()
+        } else {
+            let contents = std::fs::read_to_string(&path)
+                .map_err(|_| ManifestError::ReadFailed(path.clone()))?;
+            let lockfile: Lockfile =
+                toml::from_str(&contents).map_err(|err| ManifestError::MalformedLockfile {
+                    lockfile: path.clone(),
+                    message: err.to_string(),
+                })?;
+            if lockfile.version != LOCKFILE_VERSION {
+                return Err(ManifestError::UnsupportedLockfileVersion {
+                    lockfile: path,
+                    version: lockfile.version,
+                });
+            }
+            lockfile
+        };
+
+        // Vendored dependencies are only updated by vendoring them again
+        let vendor_dir = (!update).then(|| root_dir.join(VENDOR_DIR));
+
+        Ok(DependencyLock {
+            path,
+            lockfile,
+            vendor_dir,
+            checkouts_dir: nargo_crates(),
+            mode,
+            offline,
+            changed: update,
+            resolved: BTreeMap::new(),
+        })
+    }
+
+    /// Fetches git dependencies even if they have been vendored, so that they can be vendored again.
+    pub(crate) fn ignore_vendored(&mut self) {
+        self.vendor_dir = None;
+    }
+
+    /// Returns the url, tag and directory of every git dependency resolved so far.
+    pub(crate) fn resolved_git_dependencies(
+        &self,
+    ) -> impl Iterator<Item = (&str, &str, &Path)> + '_ {
+        self.resolved
+            .iter()
+            .map(|((git, tag), checkout)| (git.as_str(), tag.as_str(), checkout.dir.as_path()))
+    }
+
+    /// Fetches the git dependency at `tag` and checks its commit against the lockfile,
+    /// returning the directory it was checked out to.
+    ///
+    /// The contents of the package it contains are checked by [DependencyLock::check_git_package].
+    pub(crate) fn resolve_git_dependency(
+        &mut self,
+        git: &str,
+        tag: &str,
+    ) -> Result<PathBuf, ManifestError> {
+        let key = (git.to_string(), tag.to_string());
+        if let Some(checkout) = self.resolved.get(&key) {
+            return Ok(checkout.dir.clone());
+        }
+
+        let checkout = self.checkout_git_dependency(git, tag)?;
+        let dir = checkout.dir.clone();
+        self.resolved.insert(key, checkout);
+        Ok(dir)
+    }
+
+    fn checkout_git_dependency(&self, git: &str, tag: &str) -> Result<Checkout, ManifestError> {
+        if let Some(vendor_dir) = &self.vendor_dir {
+            let dir = git_dep_location(vendor_dir, git, tag).map_err(ManifestError::GitError)?;
+            if dir.exists() {
+                // Vendored dependencies have no git history, so only their contents can be checked
+                return Ok(Checkout { dir, rev: None });
+            }
+        }
+
+        let update = self.mode == LockMode::Update;
+        let dir = clone_git_repo(&self.checkouts_dir, git, tag, update, self.offline)
+            .map_err(ManifestError::GitError)?;
+        let rev = head_revision(&dir).map_err(ManifestError::GitError)?;
+
+        let locked =
+            self.lockfile.packages.iter().find(|locked| locked.git == git && locked.tag == tag);
+        match locked {
+            Some(locked) if locked.rev != rev => Err(ManifestError::LockedRevisionMismatch {
+                git: git.to_string(),
+                tag: tag.to_string(),
+                expected: locked.rev.clone(),
+                found: rev,
+            }),
+            _ => Ok(Checkout { dir, rev: Some(rev) }),
+        }
+    }
+
+    /// Checks the contents of the package in `package_dir`, which is either the directory the git
+    /// dependency at `tag` was checked out to or its `directory` within it, against the lockfile.
+    ///
+    /// Packages which aren't locked yet are added to the lockfile unless it is [LockMode::Locked].
+    pub(crate) fn check_git_package(
+        &mut self,
+        git: &str,
+        tag: &str,
+        directory: Option<&str>,
+        package_dir: &Path,
+    ) -> Result<(), ManifestError> {
+        let checksum = cached_checksum_directory(package_dir).map_err(|err| {
+            ManifestError::GitError(format!("Failed to read {}: {err}", package_dir.display()))
+        })?;
+
+        let locked = self.lockfile.packages.iter().find(|locked| {
+            locked.git == git && locked.tag == tag && locked.directory.as_deref() == directory
+        });
+        if let Some(locked) = locked {
+            return if locked.checksum == checksum {
+                Ok(())
+            } else {
+                Err(ManifestError::LockedChecksumMismatch {
+                    git: git.to_string(),
+                    tag: tag.to_string(),
+                    dir: package_dir.to_path_buf(),
+                })
+            };
+        }
+
+        let rev = self.resolved[&(git.to_string(), tag.to_string())].rev.clone();
+        let Some(rev) = rev else {
+            return Err(ManifestError::UnlockedVendoredDependency {
+                git: git.to_string(),
+                tag: tag.to_string(),
+            });
+        };
+        if self.mode == LockMode::Locked {
+            return Err(ManifestError::UnlockedDependency {
+                git: git.to_string(),
+                tag: tag.to_string(),
+            });
+        }
+
+        self.lockfile.packages.push(LockedDependency {
+            git: git.to_string(),
+            tag: tag.to_string(),
+            directory: directory.map(String::from),
+            rev,
+            checksum,
+        });
+        self.changed = true;
+        Ok(())
+    }
+
+    /// Writes the lockfile back to disk if any dependency was added to it.
+    pub(crate) fn save(mut self) -> Result<(), ManifestError> {
+        if !self.changed {
+            return Ok(());
+        }
+
+        self.lockfile.packages.sort_by(|a, b| {
+            (a.git.as_str(), a.tag.as_str(), a.directory.as_deref()).cmp(&(
+                b.git.as_str(),
+                b.tag.as_str(),
+                b.directory.as_deref(),
+            ))
+        });
+        let contents = toml::to_string(&self.lockfile)
+            .expect("lockfile should always be serializable to toml");
+        std::fs::write(&self.path, format!("{LOCKFILE_HEADER}{contents}"))
+            .map_err(|_| ManifestError::WriteFailed(self.path))
+    }
+}
+
+/// Size and modification time of a file, along with its path relative to the directory it is in.
+type FileStamp = (String, u64, SystemTime);
+
+/// Hashes the paths and contents of every file in `dir`, ignoring `.git` directories.
+///
+/// The checksum is cached along with the size and modification time of the files, and `dir` is
+/// only hashed again once one of its files changes.
+///
+/// Git dependencies are checked each time a workspace is resolved, which the language server does
+/// on every change, so this avoids reading whole dependencies over and over.
+fn cached_checksum_directory(dir: &Path) -> std::io::Result<String> {
+    static CHECKSUMS: OnceLock<Mutex<HashMap<PathBuf, (Vec<FileStamp>, String)>>> = OnceLock::new();
+
+    let files = collect_sorted_files(dir)?;
+    let stamps = files
+        .iter()
+        .map(|(relative_path, path)| {
+            let metadata = std::fs::metadata(path)?;
+            Ok((relative_path.clone(), metadata.len(), metadata.modified()?))
+        })
+        .collect::<std::io::Result<Vec<_>>>()?;
+
+    let checksums = CHECKSUMS.get_or_init(Default::default);
+    if let Some((cached_stamps, checksum)) = checksums.lock().unwrap().get(dir) {
+        if *cached_stamps == stamps {
+            return Ok(checksum.clone());
+        }
+    }
+
+    let checksum = checksum_files(&files)?;
+    checksums.lock().unwrap().insert(dir.to_path_buf(), (stamps, checksum.clone()));
+    Ok(checksum)
+}
+
+fn checksum_files(files: &[(String, PathBuf)]) -> std::io::Result<String> {
+    let mut hasher = Sha256::new();
+    for (relative_path, path) in files {
+        let contents = std::fs::read(path)?;
+        hasher.update((relative_path.len() as u64).to_le_bytes());
+        hasher.update(relative_path.as_bytes());
+        hasher.update((contents.len() as u64).to_le_bytes());
+        hasher.update(&contents);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+fn collect_sorted_files(dir: &Path) -> std::io::Result<Vec<(String, PathBuf)>> {
+    let mut files = Vec::new();
+    collect_files(dir, dir, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+/// Collects the files under `dir` along with their path relative to `root`,
+/// using `/` as a separator so that checksums are the same on every platform.
+fn collect_files(
+    root: &Path,
+    dir: &Path,
+    files: &mut Vec<(String, PathBuf)>,
+) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() != ".git" {
+                collect_files(root, &path, files)?;
+            }
+        } else {
+            let relative_path = path.strip_prefix(root).expect("path should be inside root");
+            let relative_path = relative_path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.push((relative_path, path));
+        }
+    }
+    Ok(())
+}
+
+/// Writes a `Nargo.lock` in `root_dir` locking the given git dependencies, vendored in its `vendor`
+/// directory, at their current contents.
+#[cfg(test)]
+pub(crate) fn lock_vendored_dependencies(root_dir: &Path, dependencies: &[(&str, &str)]) {
+    let vendor_dir = root_dir.join(VENDOR_DIR);
+    let packages = dependencies
+        .iter()
+        .map(|(git, tag)| {
+            let dir = git_dep_location(&vendor_dir, git, tag).unwrap();
+            LockedDependency {
+                git: git.to_string(),
+                tag: tag.to_string(),
+                directory: None,
+                rev: "0".repeat(40),
+                checksum: cached_checksum_directory(&dir).unwrap(),
+            }
+        })
+        .collect();
+    let contents = toml::to_string(&Lockfile { version: LOCKFILE_VERSION, packages }).unwrap();
+    std::fs::write(root_dir.join(LOCKFILE_NAME), contents).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, process::Command};
+
+    use crate::{errors::ManifestError, git::git_dep_location, vendor::VENDOR_DIR};
+
+    use super::{
+        cached_checksum_directory, DependencyLock, LockMode, LockedDependency, Lockfile,
+        LOCKFILE_NAME,
+    };
+
+    const GIT: &str = "https://github.com/noir-lang/example";
+    const TAG: &str = "v0.1.0";
+
+    #[test]
+    fn lockfile_roundtrips_through_toml() {
+        let lockfile = Lockfile {
+            version: 1,
+            packages: vec![
+                LockedDependency {
+                    git: GIT.to_string(),
+                    tag: TAG.to_string(),
+                    directory: None,
+                    rev: "0123456789abcdef0123456789abcdef01234567".to_string(),
+                    checksum: "00".repeat(32),
+                },
+                LockedDependency {
+                    git: GIT.to_string(),
+                    tag: TAG.to_string(),
+                    directory: Some("crates/lib".to_string()),
+                    rev: "0123456789abcdef0123456789abcdef01234567".to_string(),
+                    checksum: "11".repeat(32),
+                },
+            ],
+        };
+
+        let contents = toml::to_string(&lockfile).unwrap();
+        assert!(contents.contains("[[package]]"));
+        assert_eq!(contents.matches("directory").count(), 1);
+        assert_eq!(toml::from_str::<Lockfile>(&contents).unwrap(), lockfile);
+    }
+
+    #[test]
+    fn checksum_ignores_git_directory_but_not_contents() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src").join("lib.nr"), "fn foo() {}").unwrap();
+        let checksum = cached_checksum_directory(dir.path()).unwrap();
+
+        std::fs::create_dir_all(dir.path().join(".git")).unwrap();
+        std::fs::write(dir.path().join(".git").join("HEAD"), "ref: refs/heads/main").unwrap();
+        assert_eq!(cached_checksum_directory(dir.path()).unwrap(), checksum);
+
+        std::fs::write(dir.path().join("src").join("lib.nr"), "fn foobar() {}").unwrap();
+        assert_ne!(cached_checksum_directory(dir.path()).unwrap(), checksum);
+    }
+
+    /// Commits a package in `dir/crates/lib` to a new git repository in `dir`,
+    /// returning the hash of the commit.
+    fn commit_package(dir: &Path) -> String {
+        let package_dir = dir.join("crates").join("lib");
+        std::fs::create_dir_all(package_dir.join("src")).unwrap();
+        std::fs::write(package_dir.join("src").join("lib.nr"), "fn foo() {}").unwrap();
+        std::fs::write(dir.join("README.md"), "An example").unwrap();
+
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .arg("-C")
+                .arg(dir)
+                .args(["-c", "user.name=nargo", "-c", "user.email=nargo@example.com"])
+                .args(["-c", "commit.gpgsign=false"])
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?} failed");
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        };
+        git(&["init", "-q"]);
+        git(&["add", "-A"]);
+        git(&["commit", "-q", "-m", "Add the package"]);
+        git(&["rev-parse", "HEAD"])
+    }
+
+    fn write_lockfile(root_dir: &Path, rev: &str, checksum: &str) {
+        let lockfile = Lockfile {
+            version: 1,
+            packages: vec![LockedDependency {
+                git: GIT.to_string(),
+                tag: TAG.to_string(),
+                directory: Some("crates/lib".to_string()),
+                rev: rev.to_string(),
+                checksum: checksum.to_string(),
+            }],
+        };
+        std::fs::write(root_dir.join(LOCKFILE_NAME), toml::to_string(&lockfile).unwrap()).unwrap();
+    }
+
+    /// Resolves the package in the checked out or vendored dependency against the lockfile
+    /// in `root_dir`, with the dependency cloned into `checkouts_dir`.
+    fn check_package(
+        root_dir: &Path,
+        checkouts_dir: &Path,
+        mode: LockMode,
+    ) -> Result<DependencyLock, ManifestError> {
+        let mut lock = DependencyLock::load(root_dir, mode, true)?;
+        lock.checkouts_dir = checkouts_dir.to_path_buf();
+        let dir = lock.resolve_git_dependency(GIT, TAG)?;
+        lock.check_git_package(GIT, TAG, Some("crates/lib"), &dir.join("crates").join("lib"))?;
+        Ok(lock)
+    }
+
+    #[test]
+    fn checks_git_dependencies_against_the_lockfile() {
+        let dir = tempfile::tempdir().unwrap();
+        let root_dir = dir.path().join("workspace");
+        let checkouts_dir = dir.path().join("checkouts");
+        std::fs::create_dir_all(&root_dir).unwrap();
+        let checkout = git_dep_location(&checkouts_dir, GIT, TAG).unwrap();
+        std::fs::create_dir_all(&checkout).unwrap();
+        let rev = commit_package(&checkout);
+
+        // Only the package directory is hashed
+        let checksum = cached_checksum_directory(&checkout.join("crates").join("lib")).unwrap();
+        std::fs::write(checkout.join("README.md"), "Another example").unwrap();
+
+        // Dependencies are only added to the lockfile when asked to
+        assert!(matches!(
+            check_package(&root_dir, &checkouts_dir, LockMode::Locked),
+            Err(ManifestError::UnlockedDependency { .. })
+        ));
+        check_package(&root_dir, &checkouts_dir, LockMode::AddMissing).unwrap().save().unwrap();
+        let lockfile: Lockfile =
+            toml::from_str(&std::fs::read_to_string(root_dir.join(LOCKFILE_NAME)).unwrap())
+                .unwrap();
+        assert_eq!(lockfile.packages[0].rev, rev);
+        assert_eq!(lockfile.packages[0].checksum, checksum);
+        assert_eq!(lockfile.packages[0].directory.as_deref(), Some("crates/lib"));
+        check_package(&root_dir, &checkouts_dir, LockMode::Locked).unwrap();
+
+        write_lockfile(&root_dir, &"0".repeat(40), &checksum);
+        assert!(matches!(
+            check_package(&root_dir, &checkouts_dir, LockMode::Locked),
+            Err(ManifestError::LockedRevisionMismatch { expected, found, .. })
+                if expected == "0".repeat(40) && found == rev
+        ));
+
+        write_lockfile(&root_dir, &rev, &"0".repeat(64));
+        assert!(matches!(
+            check_package(&root_dir, &checkouts_dir, LockMode::Locked),
+            Err(ManifestError::LockedChecksumMismatch { .. })
+        ));
+
+        // Modified checkouts no longer match the lockfile
+        write_lockfile(&root_dir, &rev, &checksum);
+        let lib = checkout.join("crates").join("lib").join("src").join("lib.nr");
+        std::fs::write(&lib, "fn foobar() {}").unwrap();
+        assert!(matches!(
+            check_package(&root_dir, &checkouts_dir, LockMode::Locked),
+            Err(ManifestError::LockedChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn checks_vendored_dependencies_against_the_lockfile() {
+        let dir = tempfile::tempdir().unwrap();
+        let vendored = git_dep_location(&dir.path().join(VENDOR_DIR), GIT, TAG).unwrap();
+        let lib = vendored.join("crates").join("lib").join("src").join("lib.nr");
+        std::fs::create_dir_all(lib.parent().unwrap()).unwrap();
+        std::fs::write(&lib, "fn foo() {}").unwrap();
+        let checksum = cached_checksum_directory(&vendored.join("crates").join("lib")).unwrap();
+        let checkouts_dir = dir.path().join("checkouts");
+
+        // Vendored dependencies have no commit, so they can only be locked by vendoring them
+        assert!(matches!(
+            check_package(dir.path(), &checkouts_dir, LockMode::AddMissing),
+            Err(ManifestError::UnlockedVendoredDependency { .. })
+        ));
+
+        write_lockfile(dir.path(), &"0".repeat(40), &checksum);
+        check_package(dir.path(), &checkouts_dir, LockMode::Locked).unwrap();
+
+        std::fs::write(&lib, "fn foobar() {}").unwrap();
+        assert!(matches!(
+            check_package(dir.path(), &checkouts_dir, LockMode::Locked),
+            Err(ManifestError::LockedChecksumMismatch { .. })
+        ));
+    }
+}
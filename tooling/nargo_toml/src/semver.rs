@@ -1,6 +1,8 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use crate::{errors::SemverError, ManifestError};
 use nargo::{
-    package::{Dependency, Package},
+    package::{CrateName, Dependency, Package},
     workspace::Workspace,
 };
 use semver::{Error, Version, VersionReq};
@@ -50,9 +52,9 @@ pub(crate) fn semver_check_package(
     }
 
     // Check that all of this package's dependencies' compiler version requirements are satisfied
-    for dep in package.dependencies.values() {
+    for dep in package.dependencies.values().chain(package.dev_dependencies.values()) {
         match dep {
-            Dependency::Local { package } | Dependency::Remote { package } => {
+            Dependency::Local { package, .. } | Dependency::Remote { package, .. } => {
                 semver_check_package(package, compiler_version)?;
             }
         }
@@ -61,6 +63,173 @@ pub(crate) fn semver_check_package(
     Ok(())
 }
 
+// Resolve every package in the dependency graph of the workspace to a single version, then check
+// that the version requirements on all dependencies are satisfied.
+//
+// A package resolved from several tags of the same git repository is unified to the tag with the
+// highest version if all of its versions are semver compatible with it, otherwise it is an error.
+// Packages from different repositories or paths are unrelated, even if they have the same name.
+pub(crate) fn resolve_dependency_versions(workspace: &mut Workspace) -> Result<(), SemverError> {
+    let mut sources: BTreeMap<(CrateName, String), Vec<Dependency>> = BTreeMap::new();
+    for member in &workspace.members {
+        collect_dependency_sources(member, &mut sources);
+    }
+
+    let mut unified = BTreeMap::new();
+    for ((name, git), dependencies) in sources {
+        if dependencies.len() > 1 {
+            let dependency = unify_dependency_versions(&name, dependencies)?;
+            unified.insert((name, git), dependency);
+        }
+    }
+
+    for member in &mut workspace.members {
+        replace_unified_dependencies(member, &unified);
+    }
+    for member in &workspace.members {
+        check_dependency_versions(member)?;
+    }
+
+    Ok(())
+}
+
+// Collect each distinct tag of every package from a git repository in the dependency graph of
+// `package`, keyed by the package's name and repository, along with the features enabled on it
+fn collect_dependency_sources(
+    package: &Package,
+    sources: &mut BTreeMap<(CrateName, String), Vec<Dependency>>,
+) {
+    for dep in package.dependencies.values().chain(package.dev_dependencies.values()) {
+        let dep_package = dep.package();
+        if let Dependency::Remote { git, .. } = dep {
+            let key = (dep_package.name.clone(), git.clone());
+            let dep_sources = sources.entry(key).or_default();
+            let same_source = dep_sources
+                .iter_mut()
+                .find(|source| source.package().root_dir == dep_package.root_dir);
+            match same_source {
+                Some(Dependency::Local { package, .. } | Dependency::Remote { package, .. }) => {
+                    package.enabled_features.extend(dep_package.enabled_features.iter().cloned());
+                }
+                None => dep_sources.push(dep.clone()),
+            }
+        }
+        collect_dependency_sources(dep_package, sources);
+    }
+}
+
+// Pick the source with the highest version of a package, if all of its sources are compatible with it.
+// The package is then compiled with the features enabled by any of its sources.
+fn unify_dependency_versions(
+    name: &CrateName,
+    dependencies: Vec<Dependency>,
+) -> Result<Dependency, SemverError> {
+    let versions: Option<Vec<Version>> = dependencies
+        .iter()
+        .map(|dep| dep.package().version.as_ref().and_then(|version| Version::parse(version).ok()))
+        .collect();
+
+    let highest = versions.as_ref().and_then(|versions| {
+        let (index, highest) = versions.iter().enumerate().max_by_key(|(_, version)| *version)?;
+        versions.iter().all(|version| is_semver_compatible(version, highest)).then_some(index)
+    });
+
+    let Some(index) = highest else {
+        let versions = dependencies
+            .iter()
+            .map(|dep| {
+                let version = dep.package().version.as_deref().unwrap_or("no version");
+                format!("{version} ({dep})")
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(SemverError::ConflictingDependencyVersions {
+            dependency_name: name.clone(),
+            versions,
+        });
+    };
+
+    let enabled_features: BTreeSet<String> = dependencies
+        .iter()
+        .flat_map(|dep| dep.package().enabled_features.iter().cloned())
+        .collect();
+    let mut dependency = dependencies[index].clone();
+    match &mut dependency {
+        Dependency::Local { package, .. } | Dependency::Remote { package, .. } => {
+            package.enabled_features = enabled_features;
+        }
+    }
+    Ok(dependency)
+}
+
+// Versions are compatible if they only differ after their first non-zero component, as with `^` requirements
+fn is_semver_compatible(version: &Version, highest: &Version) -> bool {
+    match (highest.major, highest.minor) {
+        (0, 0) => version.major == 0 && version.minor == 0 && version.patch == highest.patch,
+        (0, minor) => version.major == 0 && version.minor == minor,
+        (major, _) => version.major == major,
+    }
+}
+
+// Replace the dependencies of `package` which were unified, keeping their version requirement
+fn replace_unified_dependencies(
+    package: &mut Package,
+    unified: &BTreeMap<(CrateName, String), Dependency>,
+) {
+    for dep in package.dependencies.values_mut().chain(package.dev_dependencies.values_mut()) {
+        let key = match dep {
+            Dependency::Remote { package, git, .. } => Some((package.name.clone(), git.clone())),
+            Dependency::Local { .. } => None,
+        };
+        let unified_dep = key.and_then(|key| unified.get(&key)).cloned();
+        if let Some(Dependency::Remote { package, git, tag, .. }) = unified_dep {
+            let version_req = dep.version_req().map(ToString::to_string);
+            *dep = Dependency::Remote { package, git, tag, version_req };
+        }
+        match dep {
+            Dependency::Local { package, .. } | Dependency::Remote { package, .. } => {
+                replace_unified_dependencies(package, unified);
+            }
+        }
+    }
+}
+
+// Check that the version requirements on the dependencies of `package` are satisfied, recursively
+fn check_dependency_versions(package: &Package) -> Result<(), SemverError> {
+    for dep in package.dependencies.values().chain(package.dev_dependencies.values()) {
+        let dep_package = dep.package();
+        if let Some(required_version) = dep.version_req() {
+            let version_req = VersionReq::parse(required_version).map_err(|err| {
+                SemverError::CouldNotParseDependencyVersion {
+                    package_name: package.name.clone(),
+                    dependency_name: dep_package.name.clone(),
+                    error: err.to_string(),
+                }
+            })?;
+            let Some(version) = &dep_package.version else {
+                return Err(SemverError::MissingDependencyVersion {
+                    package_name: package.name.clone(),
+                    dependency_name: dep_package.name.clone(),
+                    required_version: required_version.to_string(),
+                });
+            };
+            let matches =
+                Version::parse(version).is_ok_and(|version| version_req.matches(&version));
+            if !matches {
+                return Err(SemverError::IncompatibleDependencyVersion {
+                    package_name: package.name.clone(),
+                    dependency_name: dep_package.name.clone(),
+                    required_version: required_version.to_string(),
+                    version_found: version.clone(),
+                });
+            }
+        }
+        check_dependency_versions(dep_package)?;
+    }
+
+    Ok(())
+}
+
 // Strip the build meta data from the version string since it is ignored by semver.
 fn strip_build_meta_data(version: &Version) -> String {
     let version_string = version.to_string();
@@ -70,7 +239,11 @@ fn strip_build_meta_data(version: &Version) -> String {
 
 #[cfg(test)]
 mod tests {
-    use std::{collections::BTreeMap, path::PathBuf, str::FromStr};
+    use std::{
+        collections::{BTreeMap, BTreeSet},
+        path::PathBuf,
+        str::FromStr,
+    };
 
     use nargo::package::PackageType;
     use noirc_frontend::graph::CrateName;
@@ -88,8 +261,12 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("test").unwrap(),
             dependencies: BTreeMap::new(),
+            dev_dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            lints: Vec::new(),
+            features: BTreeMap::new(),
+            enabled_features: BTreeSet::new(),
         };
         if let Err(err) = semver_check_package(&package, &compiler_version) {
             panic!("semver check should have passed. compiler version is 0.1.0 and required version from the package is 0.1.0\n error: {err:?}")
@@ -120,8 +297,12 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("test").unwrap(),
             dependencies: BTreeMap::new(),
+            dev_dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            lints: Vec::new(),
+            features: BTreeMap::new(),
+            enabled_features: BTreeSet::new(),
         };
 
         let valid_dependency = Package {
@@ -131,8 +312,12 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("good_dependency").unwrap(),
             dependencies: BTreeMap::new(),
+            dev_dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            lints: Vec::new(),
+            features: BTreeMap::new(),
+            enabled_features: BTreeSet::new(),
         };
         let invalid_dependency = Package {
             compiler_required_version: Some("0.2.0".to_string()),
@@ -141,13 +326,17 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("bad_dependency").unwrap(),
             dependencies: BTreeMap::new(),
+            dev_dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            lints: Vec::new(),
+            features: BTreeMap::new(),
+            enabled_features: BTreeSet::new(),
         };
 
         package.dependencies.insert(
             CrateName::from_str("test_dep_valid").unwrap(),
-            Dependency::Local { package: valid_dependency.clone() },
+            Dependency::Local { package: valid_dependency.clone(), version_req: None },
         );
 
         if let Err(err) = semver_check_package(&package, &compiler_version) {
@@ -156,7 +345,7 @@ mod tests {
 
         package.dependencies.insert(
             CrateName::from_str("test_dep_invalid").unwrap(),
-            Dependency::Local { package: invalid_dependency.clone() },
+            Dependency::Local { package: invalid_dependency.clone(), version_req: None },
         );
         let got_err = match semver_check_package(&package,&compiler_version) {
             Ok(_) => panic!("semver check should have failed. compiler version is 0.1.0 and required version from the package is 0.2.0"),
@@ -182,8 +371,12 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("test").unwrap(),
             dependencies: BTreeMap::new(),
+            dev_dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            lints: Vec::new(),
+            features: BTreeMap::new(),
+            enabled_features: BTreeSet::new(),
         };
 
         if let Err(err) = semver_check_package(&package, &compiler_version) {
@@ -202,12 +395,117 @@ mod tests {
             entry_path: PathBuf::new(),
             name: CrateName::from_str("test").unwrap(),
             dependencies: BTreeMap::new(),
+            dev_dependencies: BTreeMap::new(),
             version: Some("1.0".to_string()),
             expression_width: None,
+            lints: Vec::new(),
+            features: BTreeMap::new(),
+            enabled_features: BTreeSet::new(),
         };
 
         if let Err(err) = semver_check_package(&package, &compiler_version) {
             panic!("semver check should have passed. compiler version is 0.1.0+build_data and required version from the package is 0.1.0\n The build data should be ignored\n error: {err:?}")
         };
     }
+
+    fn library(name: &str, root_dir: &str, version: Option<&str>, features: &[&str]) -> Package {
+        Package {
+            compiler_required_version: None,
+            root_dir: PathBuf::from(root_dir),
+            package_type: PackageType::Library,
+            entry_path: PathBuf::from(root_dir).join("src/lib.nr"),
+            name: CrateName::from_str(name).unwrap(),
+            dependencies: BTreeMap::new(),
+            dev_dependencies: BTreeMap::new(),
+            version: version.map(ToString::to_string),
+            expression_width: None,
+            lints: Vec::new(),
+            features: BTreeMap::new(),
+            enabled_features: features.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    fn remote(package: Package, git: &str, tag: &str) -> Dependency {
+        Dependency::Remote {
+            package,
+            git: git.to_string(),
+            tag: tag.to_string(),
+            version_req: None,
+        }
+    }
+
+    fn workspace_depending_on(first: Dependency, second: Dependency) -> Workspace {
+        let mut intermediate = library("intermediate", "/intermediate", None, &[]);
+        intermediate.dependencies.insert(CrateName::from_str("second").unwrap(), second);
+        let mut member = library("member", "/member", None, &[]);
+        member.dependencies.insert(CrateName::from_str("first").unwrap(), first);
+        member.dependencies.insert(
+            CrateName::from_str("intermediate").unwrap(),
+            Dependency::Local { package: intermediate, version_req: None },
+        );
+        Workspace {
+            root_dir: PathBuf::from("/member"),
+            members: vec![member],
+            selected_package_index: None,
+            is_assumed: false,
+            profiles: BTreeMap::new(),
+            comptime_inputs: Default::default(),
+        }
+    }
+
+    fn resolved_dependencies(workspace: &Workspace) -> (&Dependency, &Dependency) {
+        let member = &workspace.members[0];
+        let first = &member.dependencies[&CrateName::from_str("first").unwrap()];
+        let intermediate =
+            member.dependencies[&CrateName::from_str("intermediate").unwrap()].package();
+        let second = &intermediate.dependencies[&CrateName::from_str("second").unwrap()];
+        (first, second)
+    }
+
+    #[test]
+    fn unifies_tags_of_the_same_repository_with_their_features() {
+        let git = "https://github.com/noir-lang/lib";
+        let mut workspace = workspace_depending_on(
+            remote(library("lib", "/git/lib@v1.2.0", Some("1.2.0"), &["a"]), git, "v1.2.0"),
+            remote(library("lib", "/git/lib@v1.4.1", Some("1.4.1"), &["b"]), git, "v1.4.1"),
+        );
+        resolve_dependency_versions(&mut workspace).unwrap();
+
+        let (first, second) = resolved_dependencies(&workspace);
+        for dependency in [first, second] {
+            assert_eq!(dependency.to_string(), format!("{git}#v1.4.1"));
+            let expected = BTreeSet::from(["a".to_string(), "b".to_string()]);
+            assert_eq!(dependency.package().enabled_features, expected);
+        }
+
+        let mut workspace = workspace_depending_on(
+            remote(library("lib", "/git/lib@v1", None, &[]), git, "v1"),
+            remote(library("lib", "/git/lib@v2", None, &[]), git, "v2"),
+        );
+        assert!(matches!(
+            resolve_dependency_versions(&mut workspace),
+            Err(SemverError::ConflictingDependencyVersions { .. })
+        ));
+    }
+
+    #[test]
+    fn keeps_unrelated_packages_with_the_same_name() {
+        let mut workspace = workspace_depending_on(
+            remote(library("lib", "/git/a/lib", None, &[]), "https://github.com/a/lib", "v1"),
+            remote(library("lib", "/git/b/lib", None, &[]), "https://github.com/b/lib", "v2"),
+        );
+        resolve_dependency_versions(&mut workspace).unwrap();
+        let (first, second) = resolved_dependencies(&workspace);
+        assert_eq!(first.package().root_dir, PathBuf::from("/git/a/lib"));
+        assert_eq!(second.package().root_dir, PathBuf::from("/git/b/lib"));
+
+        let mut workspace = workspace_depending_on(
+            Dependency::Local { package: library("lib", "/a/lib", None, &[]), version_req: None },
+            Dependency::Local { package: library("lib", "/b/lib", None, &[]), version_req: None },
+        );
+        resolve_dependency_versions(&mut workspace).unwrap();
+        let (first, second) = resolved_dependencies(&workspace);
+        assert_eq!(first.package().root_dir, PathBuf::from("/a/lib"));
+        assert_eq!(second.package().root_dir, PathBuf::from("/b/lib"));
+    }
 }
@@ -0,0 +1,162 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use crate::{errors::ManifestError, git::git_dep_location};
+
+/// Name of the directory, at the root of a workspace, holding vendored git dependencies.
+pub(crate) const VENDOR_DIR: &str = "vendor";
+
+/// Name of the file in the vendor directory listing the directories which Nargo vendored there,
+/// relative to it and one per line.
+const VENDORED_DIRS_FILE: &str = ".nargo-vendored";
+
+/// Replaces the dependencies previously vendored in `vendor_dir` with copies of the given git
+/// dependencies, laid out so that they are found when resolving the workspace.
+///
+/// Fails without changing anything if `vendor_dir` contains files which Nargo didn't vendor.
+pub(crate) fn vendor_git_dependencies<'a>(
+    vendor_dir: &Path,
+    dependencies: impl Iterator<Item = (&'a str, &'a str, &'a Path)>,
+) -> Result<(), ManifestError> {
+    let previously_vendored_dirs: BTreeSet<PathBuf> =
+        std::fs::read_to_string(vendor_dir.join(VENDORED_DIRS_FILE))
+            .unwrap_or_default()
+            .lines()
+            .map(|directory| vendor_dir.join(directory))
+            .collect();
+    check_only_vendored_files(vendor_dir, &previously_vendored_dirs)?;
+
+    for directory in &previously_vendored_dirs {
+        if directory.exists() {
+            std::fs::remove_dir_all(directory)
+                .map_err(|_| ManifestError::WriteFailed(directory.clone()))?;
+        }
+    }
+    remove_empty_dirs(vendor_dir);
+
+    let mut vendored_dirs = String::new();
+    for (git, tag, source) in dependencies {
+        let destination =
+            git_dep_location(vendor_dir, git, tag).map_err(ManifestError::GitError)?;
+        copy_dir_without_git(source, &destination)
+            .map_err(|_| ManifestError::WriteFailed(destination.clone()))?;
+        let directory = destination.strip_prefix(vendor_dir).expect("vendored in vendor_dir");
+        vendored_dirs.push_str(&format!("{}\n", directory.display()));
+    }
+
+    let vendored_dirs_file = vendor_dir.join(VENDORED_DIRS_FILE);
+    std::fs::create_dir_all(vendor_dir)
+        .and_then(|_| std::fs::write(&vendored_dirs_file, vendored_dirs))
+        .map_err(|_| ManifestError::WriteFailed(vendored_dirs_file))
+}
+
+/// Returns an error for the first file in `directory` which isn't in one of `vendored_dirs`, the
+/// directories previously vendored by Nargo, or isn't the file listing them.
+fn check_only_vendored_files(
+    directory: &Path,
+    vendored_dirs: &BTreeSet<PathBuf>,
+) -> Result<(), ManifestError> {
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return Ok(());
+    };
+    for entry in entries {
+        let entry = entry.map_err(|_| ManifestError::ReadFailed(directory.to_path_buf()))?;
+        let path = entry.path();
+        if vendored_dirs.contains(&path) || entry.file_name() == VENDORED_DIRS_FILE {
+            continue;
+        }
+        if path.is_dir() {
+            check_only_vendored_files(&path, vendored_dirs)?;
+        } else {
+            return Err(ManifestError::UnknownVendoredFile(path));
+        }
+    }
+    Ok(())
+}
+
+/// Removes the empty directories left in `directory` once the vendored ones are removed.
+fn remove_empty_dirs(directory: &Path) {
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return;
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            remove_empty_dirs(&path);
+            // Fails if the directory isn't empty
+            let _ = std::fs::remove_dir(&path);
+        }
+    }
+}
+
+/// Recursively copies `source` into `destination`, leaving out the `.git` directory.
+fn copy_dir_without_git(source: &Path, destination: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(destination)?;
+    for entry in std::fs::read_dir(source)? {
+        let entry = entry?;
+        let target = destination.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() != ".git" {
+                copy_dir_without_git(&entry.path(), &target)?;
+            }
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{copy_dir_without_git, vendor_git_dependencies};
+    use crate::errors::ManifestError;
+
+    #[test]
+    fn copies_everything_but_git_directory() {
+        let source = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(source.path().join("src")).unwrap();
+        std::fs::create_dir_all(source.path().join(".git")).unwrap();
+        std::fs::write(source.path().join("Nargo.toml"), "[package]").unwrap();
+        std::fs::write(source.path().join("src").join("lib.nr"), "fn foo() {}").unwrap();
+        std::fs::write(source.path().join(".git").join("HEAD"), "ref: refs/heads/main").unwrap();
+
+        let destination = tempfile::tempdir().unwrap();
+        let vendored = destination.path().join("dep");
+        copy_dir_without_git(source.path(), &vendored).unwrap();
+
+        assert!(vendored.join("Nargo.toml").exists());
+        assert!(vendored.join("src").join("lib.nr").exists());
+        assert!(!vendored.join(".git").exists());
+    }
+
+    #[test]
+    fn only_replaces_directories_it_vendored() {
+        let source = tempfile::tempdir().unwrap();
+        std::fs::write(source.path().join("Nargo.toml"), "[package]").unwrap();
+        let git = "https://github.com/noir-lang/lib";
+
+        let workspace = tempfile::tempdir().unwrap();
+        let vendor_dir = workspace.path().join("vendor");
+        let dependencies = || std::iter::once((git, "v1.0.0", source.path()));
+        vendor_git_dependencies(&vendor_dir, dependencies()).unwrap();
+        let vendored = vendor_dir.join("github.com/noir-lang/libv1.0.0");
+        assert!(vendored.join("Nargo.toml").exists());
+
+        // Vendoring again replaces the dependencies vendored previously
+        let dependencies = || std::iter::once((git, "v2.0.0", source.path()));
+        vendor_git_dependencies(&vendor_dir, dependencies()).unwrap();
+        assert!(!vendored.exists());
+        assert!(vendor_dir.join("github.com/noir-lang/libv2.0.0/Nargo.toml").exists());
+
+        // Files which weren't vendored by Nargo are never removed
+        let unknown_file = vendor_dir.join("github.com/notes.txt");
+        std::fs::write(&unknown_file, "keep me").unwrap();
+        let result = vendor_git_dependencies(&vendor_dir, dependencies());
+        assert!(
+            matches!(result, Err(ManifestError::UnknownVendoredFile(path)) if path == unknown_file)
+        );
+        assert!(unknown_file.exists());
+        assert!(vendor_dir.join("github.com/noir-lang/libv2.0.0/Nargo.toml").exists());
+    }
+}
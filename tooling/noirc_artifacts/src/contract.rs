@@ -1,6 +1,6 @@
 use acvm::{acir::circuit::Program, FieldElement};
 use noirc_abi::{Abi, AbiType, AbiValue};
-use noirc_driver::{CompiledContract, CompiledContractOutputs, ContractFunction};
+use noirc_driver::{CompileProfile, CompiledContract, CompiledContractOutputs, ContractFunction};
 use serde::{Deserialize, Serialize};
 
 use noirc_driver::DebugFile;
@@ -33,6 +33,10 @@ pub struct ContractArtifact {
     pub outputs: ContractOutputsArtifact,
     /// Map of file Id to the source code so locations in debug info can be mapped to source code they point to.
     pub file_map: BTreeMap<FileId, DebugFile>,
+
+    /// The build profile from `Nargo.toml` which the contract was compiled with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<CompileProfile>,
 }
 
 impl From<CompiledContract> for ContractArtifact {
@@ -43,6 +47,7 @@ impl From<CompiledContract> for ContractArtifact {
             functions: contract.functions.into_iter().map(ContractFunctionArtifact::from).collect(),
             outputs: contract.outputs.into(),
             file_map: contract.file_map,
+            profile: None,
         }
     }
 }
@@ -5,7 +5,7 @@ use acvm::FieldElement;
 use fm::FileId;
 use noirc_abi::Abi;
 use noirc_driver::CompiledProgram;
-use noirc_driver::DebugFile;
+use noirc_driver::{CompileProfile, DebugFile};
 use noirc_errors::debug_info::ProgramDebugInfo;
 use serde::{Deserialize, Serialize};
 
@@ -39,6 +39,10 @@ pub struct ProgramArtifact {
     pub names: Vec<String>,
     /// Names of the unconstrained functions in the program.
     pub brillig_names: Vec<String>,
+
+    /// The build profile from `Nargo.toml` which the program was compiled with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<CompileProfile>,
 }
 
 impl From<CompiledProgram> for ProgramArtifact {
@@ -52,6 +56,7 @@ impl From<CompiledProgram> for ProgramArtifact {
             file_map: compiled_program.file_map,
             names: compiled_program.names,
             brillig_names: compiled_program.brillig_names,
+            profile: None,
         }
     }
 }
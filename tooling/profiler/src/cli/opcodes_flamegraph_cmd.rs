@@ -192,6 +192,7 @@ mod tests {
             file_map: BTreeMap::default(),
             names: vec!["main".to_string()],
             brillig_names: Vec::new(),
+            profile: None,
         };
 
         // Write the artifact to a file
@@ -233,6 +234,7 @@ mod tests {
             file_map: BTreeMap::default(),
             names: vec!["main".to_string()],
             brillig_names: vec!["main".to_string()],
+            profile: None,
         };
 
         // Write the artifact to a file